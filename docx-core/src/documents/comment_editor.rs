@@ -0,0 +1,404 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+use crate::documents::*;
+
+#[derive(Debug, Clone, PartialEq)]
+enum CommentEdit {
+    // Only collects the comments of the ranges.
+    Find,
+    // Places the reply's range right inside the range of the comment replied to.
+    Reply { id: usize, reply: Comment },
+    Resolve(HashSet<usize>),
+    Delete(HashSet<usize>),
+}
+
+// Content that may hold comment range markers.
+trait CommentMarkers: Sized {
+    fn comment_start(&mut self) -> Option<&mut CommentRangeStart>;
+    fn comment_end_id(&self) -> Option<usize>;
+    fn from_comment_start(c: CommentRangeStart) -> Self;
+    fn from_comment_end(c: CommentRangeEnd) -> Self;
+    // Edits the markers nested inside the child, e.g. in the paragraphs of a table.
+    fn edit_nested(&mut self, _editor: &CommentEditor) {}
+}
+
+impl CommentMarkers for DocumentChild {
+    fn comment_start(&mut self) -> Option<&mut CommentRangeStart> {
+        match self {
+            DocumentChild::CommentStart(c) => Some(c.as_mut()),
+            _ => None,
+        }
+    }
+
+    fn comment_end_id(&self) -> Option<usize> {
+        match self {
+            DocumentChild::CommentEnd(c) => Some(c.get_id()),
+            _ => None,
+        }
+    }
+
+    fn from_comment_start(c: CommentRangeStart) -> Self {
+        DocumentChild::CommentStart(Box::new(c))
+    }
+
+    fn from_comment_end(c: CommentRangeEnd) -> Self {
+        DocumentChild::CommentEnd(c)
+    }
+
+    fn edit_nested(&mut self, editor: &CommentEditor) {
+        match self {
+            DocumentChild::Paragraph(p) => editor.edit_children(&mut p.children),
+            DocumentChild::Table(t) => editor.edit_table(t),
+            DocumentChild::StructuredDataTag(t) => editor.edit_children(&mut t.children),
+            DocumentChild::TableOfContents(toc) => editor.edit_toc(toc),
+            DocumentChild::Section(section) => editor.edit_children(&mut section.children),
+            _ => {}
+        }
+    }
+}
+
+impl CommentMarkers for SectionChild {
+    fn comment_start(&mut self) -> Option<&mut CommentRangeStart> {
+        match self {
+            SectionChild::CommentStart(c) => Some(c.as_mut()),
+            _ => None,
+        }
+    }
+
+    fn comment_end_id(&self) -> Option<usize> {
+        match self {
+            SectionChild::CommentEnd(c) => Some(c.get_id()),
+            _ => None,
+        }
+    }
+
+    fn from_comment_start(c: CommentRangeStart) -> Self {
+        SectionChild::CommentStart(Box::new(c))
+    }
+
+    fn from_comment_end(c: CommentRangeEnd) -> Self {
+        SectionChild::CommentEnd(c)
+    }
+
+    fn edit_nested(&mut self, editor: &CommentEditor) {
+        match self {
+            SectionChild::Paragraph(p) => editor.edit_children(&mut p.children),
+            SectionChild::Table(t) => editor.edit_table(t),
+            SectionChild::StructuredDataTag(t) => editor.edit_children(&mut t.children),
+            SectionChild::TableOfContents(toc) => editor.edit_toc(toc),
+            _ => {}
+        }
+    }
+}
+
+impl CommentMarkers for StructuredDataTagChild {
+    fn comment_start(&mut self) -> Option<&mut CommentRangeStart> {
+        match self {
+            StructuredDataTagChild::CommentStart(c) => Some(c.as_mut()),
+            _ => None,
+        }
+    }
+
+    fn comment_end_id(&self) -> Option<usize> {
+        match self {
+            StructuredDataTagChild::CommentEnd(c) => Some(c.get_id()),
+            _ => None,
+        }
+    }
+
+    fn from_comment_start(c: CommentRangeStart) -> Self {
+        StructuredDataTagChild::CommentStart(Box::new(c))
+    }
+
+    fn from_comment_end(c: CommentRangeEnd) -> Self {
+        StructuredDataTagChild::CommentEnd(c)
+    }
+
+    fn edit_nested(&mut self, editor: &CommentEditor) {
+        match self {
+            StructuredDataTagChild::Paragraph(p) => editor.edit_children(&mut p.children),
+            StructuredDataTagChild::Table(t) => editor.edit_table(t),
+            StructuredDataTagChild::TableRow(row) => editor.edit_row(row),
+            StructuredDataTagChild::StructuredDataTag(t) => editor.edit_children(&mut t.children),
+            _ => {}
+        }
+    }
+}
+
+impl CommentMarkers for ParagraphChild {
+    fn comment_start(&mut self) -> Option<&mut CommentRangeStart> {
+        match self {
+            ParagraphChild::CommentStart(c) => Some(c.as_mut()),
+            _ => None,
+        }
+    }
+
+    fn comment_end_id(&self) -> Option<usize> {
+        match self {
+            ParagraphChild::CommentEnd(c) => Some(c.get_id()),
+            _ => None,
+        }
+    }
+
+    fn from_comment_start(c: CommentRangeStart) -> Self {
+        ParagraphChild::CommentStart(Box::new(c))
+    }
+
+    fn from_comment_end(c: CommentRangeEnd) -> Self {
+        ParagraphChild::CommentEnd(c)
+    }
+
+    fn edit_nested(&mut self, editor: &CommentEditor) {
+        match self {
+            ParagraphChild::Insert(Insert { children, .. })
+            | ParagraphChild::MoveTo(MoveTo { children, .. }) => editor.edit_children(children),
+            ParagraphChild::Delete(Delete { children, .. })
+            | ParagraphChild::MoveFrom(MoveFrom { children, .. }) => editor.edit_children(children),
+            ParagraphChild::Hyperlink(link) => editor.edit_children(&mut link.children),
+            ParagraphChild::StructuredDataTag(t) => editor.edit_children(&mut t.children),
+            _ => {}
+        }
+    }
+}
+
+impl CommentMarkers for InsertChild {
+    fn comment_start(&mut self) -> Option<&mut CommentRangeStart> {
+        match self {
+            InsertChild::CommentStart(c) => Some(c.as_mut()),
+            _ => None,
+        }
+    }
+
+    fn comment_end_id(&self) -> Option<usize> {
+        match self {
+            InsertChild::CommentEnd(c) => Some(c.get_id()),
+            _ => None,
+        }
+    }
+
+    fn from_comment_start(c: CommentRangeStart) -> Self {
+        InsertChild::CommentStart(Box::new(c))
+    }
+
+    fn from_comment_end(c: CommentRangeEnd) -> Self {
+        InsertChild::CommentEnd(c)
+    }
+
+    fn edit_nested(&mut self, editor: &CommentEditor) {
+        if let InsertChild::Delete(del) = self {
+            editor.edit_children(&mut del.children);
+        }
+    }
+}
+
+impl CommentMarkers for DeleteChild {
+    fn comment_start(&mut self) -> Option<&mut CommentRangeStart> {
+        match self {
+            DeleteChild::CommentStart(c) => Some(c.as_mut()),
+            _ => None,
+        }
+    }
+
+    fn comment_end_id(&self) -> Option<usize> {
+        match self {
+            DeleteChild::CommentEnd(c) => Some(c.get_id()),
+            _ => None,
+        }
+    }
+
+    fn from_comment_start(c: CommentRangeStart) -> Self {
+        DeleteChild::CommentStart(Box::new(c))
+    }
+
+    fn from_comment_end(c: CommentRangeEnd) -> Self {
+        DeleteChild::CommentEnd(c)
+    }
+}
+
+// Edits the comment ranges of a document, wherever their markers are placed.
+pub(crate) struct CommentEditor {
+    edit: CommentEdit,
+    // The comments of the visited ranges, in document order.
+    comments: RefCell<Vec<Comment>>,
+}
+
+impl CommentEditor {
+    fn new(edit: CommentEdit) -> Self {
+        Self {
+            edit,
+            comments: Default::default(),
+        }
+    }
+
+    pub(crate) fn find() -> Self {
+        Self::new(CommentEdit::Find)
+    }
+
+    pub(crate) fn reply(id: usize, reply: Comment) -> Self {
+        Self::new(CommentEdit::Reply { id, reply })
+    }
+
+    pub(crate) fn resolve(ids: HashSet<usize>) -> Self {
+        Self::new(CommentEdit::Resolve(ids))
+    }
+
+    pub(crate) fn delete(ids: HashSet<usize>) -> Self {
+        Self::new(CommentEdit::Delete(ids))
+    }
+
+    // Returns the comments found in the document.
+    pub(crate) fn edit(self, document: &mut Document) -> Vec<Comment> {
+        self.edit_children(&mut document.children);
+        self.comments.into_inner()
+    }
+
+    fn edit_children<T: CommentMarkers>(&self, children: &mut Vec<T>) {
+        let mut edited = Vec::with_capacity(children.len());
+        for mut child in std::mem::take(children) {
+            child.edit_nested(self);
+            let start_id = child.comment_start().map(|start| {
+                if matches!(&self.edit, CommentEdit::Resolve(ids) if ids.contains(&start.id)) {
+                    start.comment.done = true;
+                }
+                self.comments.borrow_mut().push(start.get_comment());
+                start.get_id()
+            });
+            let marker_id = start_id.or_else(|| child.comment_end_id());
+            match (&self.edit, marker_id) {
+                (CommentEdit::Delete(ids), Some(marker_id)) if ids.contains(&marker_id) => {}
+                (CommentEdit::Reply { id, reply }, Some(marker_id)) if *id == marker_id => {
+                    edited.push(child);
+                    edited.push(if start_id.is_some() {
+                        T::from_comment_start(CommentRangeStart::new(reply.clone()))
+                    } else {
+                        T::from_comment_end(CommentRangeEnd::new(reply.id()))
+                    });
+                }
+                _ => edited.push(child),
+            }
+        }
+        *children = edited;
+    }
+
+    fn edit_table(&self, table: &mut Table) {
+        for child in &mut table.rows {
+            match child {
+                TableChild::TableRow(row) => self.edit_row(row),
+                TableChild::StructuredDataTag(t) => self.edit_children(&mut t.children),
+                TableChild::PermStart(_) | TableChild::PermEnd(_) => {}
+            }
+        }
+    }
+
+    fn edit_row(&self, row: &mut TableRow) {
+        for TableRowChild::TableCell(cell) in &mut row.cells {
+            for content in &mut cell.children {
+                match content {
+                    TableCellContent::Paragraph(p) => self.edit_children(&mut p.children),
+                    TableCellContent::Table(t) => self.edit_table(t),
+                    TableCellContent::StructuredDataTag(t) => self.edit_children(&mut t.children),
+                    TableCellContent::TableOfContents(toc) => self.edit_toc(toc),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn edit_toc(&self, toc: &mut TableOfContents) {
+        for content in toc
+            .before_contents
+            .iter_mut()
+            .chain(toc.after_contents.iter_mut())
+        {
+            match content {
+                TocContent::Paragraph(p) => self.edit_children(&mut p.children),
+                TocContent::Table(t) => self.edit_table(t),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn markers(children: &[ParagraphChild]) -> Vec<String> {
+        children
+            .iter()
+            .filter_map(|c| match c {
+                ParagraphChild::CommentStart(c) => Some(format!("start {}", c.get_id())),
+                ParagraphChild::CommentEnd(c) => Some(format!("end {}", c.get_id())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn document() -> Document {
+        Document::new().add_paragraph(
+            Paragraph::new()
+                .add_comment_start(Comment::new(1).author("bokuweb"))
+                .add_run(Run::new().add_text("Hello"))
+                .add_comment_end(1)
+                .add_insert(
+                    Insert::new(Run::new().add_text("World"))
+                        .add_comment_start(Comment::new(2))
+                        .add_comment_end(2),
+                ),
+        )
+    }
+
+    fn paragraph(document: &Document) -> &Paragraph {
+        match &document.children[0] {
+            DocumentChild::Paragraph(p) => p,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_find_comments() {
+        let mut document = document();
+        let comments = CommentEditor::find().edit(&mut document);
+        assert_eq!(
+            comments.iter().map(|c| c.id).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert_eq!(document, self::document());
+    }
+
+    #[test]
+    fn test_reply_markers() {
+        let mut document = document();
+        CommentEditor::reply(1, Comment::new(3).parent_comment_id(1)).edit(&mut document);
+        assert_eq!(
+            markers(&paragraph(&document).children),
+            vec!["start 1", "start 3", "end 1", "end 3"]
+        );
+    }
+
+    #[test]
+    fn test_resolve_nested_comment() {
+        let mut document = document();
+        CommentEditor::resolve(HashSet::from([2])).edit(&mut document);
+        let comments = CommentEditor::find().edit(&mut document);
+        assert_eq!(
+            comments.iter().map(|c| c.done).collect::<Vec<_>>(),
+            vec![false, true]
+        );
+    }
+
+    #[test]
+    fn test_delete_markers() {
+        let mut document = document();
+        CommentEditor::delete(HashSet::from([1, 2])).edit(&mut document);
+        let p = paragraph(&document);
+        assert_eq!(markers(&p.children), Vec::<String>::new());
+        match &p.children[1] {
+            ParagraphChild::Insert(ins) => assert_eq!(ins.children.len(), 1),
+            _ => unreachable!(),
+        }
+    }
+}
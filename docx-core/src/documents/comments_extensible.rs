@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+use super::*;
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// i.e.    <w16cex:commentExtensible w16cex:durableId="4C7250AB" w16cex:dateUtc="2024-09-06T02:13:00Z"/>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentsExtensible {
+    #[serde(
+        rename(serialize = "children", deserialize = "commentExtensible"),
+        alias = "w16cex:commentExtensible",
+        alias = "children",
+        default
+    )]
+    pub children: Vec<CommentExtensible>,
+}
+
+impl CommentsExtensible {
+    pub fn new() -> CommentsExtensible {
+        Default::default()
+    }
+
+    pub fn add_comments_extensible(&mut self, c: Vec<CommentExtensible>) {
+        self.children = c;
+    }
+
+    pub fn find_by_durable_id(&self, durable_id: &str) -> Option<&CommentExtensible> {
+        self.children.iter().find(|c| c.durable_id == durable_id)
+    }
+}
+
+impl BuildXML for CommentsExtensible {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .declaration(Some(true))?
+            .open_comments_extensible()?
+            .add_children(&self.children)?
+            .close()?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_comments_extensible() {
+        let mut c = CommentsExtensible::new();
+        c.add_comments_extensible(vec![
+            CommentExtensible::new("4C7250AB").date_utc("2024-09-06T02:13:00Z")
+        ]);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><w16cex:commentsExtensible xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml" xmlns:w16cex="http://schemas.microsoft.com/office/word/2018/wordml/cex" xmlns:w16cid="http://schemas.microsoft.com/office/word/2016/wordml/cid" mc:Ignorable="w14 w15 w16cex w16cid"><w16cex:commentExtensible w16cex:durableId="4C7250AB" w16cex:dateUtc="2024-09-06T02:13:00Z" /></w16cex:commentsExtensible>"#
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+use super::*;
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// i.e.    <w16cid:commentId w16cid:paraId="37D90BEC" w16cid:durableId="4C7250AB"/>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentsIds {
+    #[serde(
+        rename(serialize = "children", deserialize = "commentId"),
+        alias = "w16cid:commentId",
+        alias = "children",
+        default
+    )]
+    pub children: Vec<CommentDurableId>,
+}
+
+impl CommentsIds {
+    pub fn new() -> CommentsIds {
+        Default::default()
+    }
+
+    pub fn add_comments_ids(&mut self, c: Vec<CommentDurableId>) {
+        self.children = c;
+    }
+
+    pub fn find_by_paragraph_id(&self, paragraph_id: &str) -> Option<&CommentDurableId> {
+        self.children
+            .iter()
+            .find(|c| c.paragraph_id == paragraph_id)
+    }
+}
+
+impl BuildXML for CommentsIds {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .declaration(Some(true))?
+            .open_comments_ids()?
+            .add_children(&self.children)?
+            .close()?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_comments_ids() {
+        let mut c = CommentsIds::new();
+        c.add_comments_ids(vec![CommentDurableId::new("37D90BEC", "4C7250AB")]);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><w16cid:commentsIds xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml" xmlns:w16cex="http://schemas.microsoft.com/office/word/2018/wordml/cex" xmlns:w16cid="http://schemas.microsoft.com/office/word/2016/wordml/cid" mc:Ignorable="w14 w15 w16cex w16cid"><w16cid:commentId w16cid:paraId="37D90BEC" w16cid:durableId="4C7250AB" /></w16cid:commentsIds>"#
+        );
+    }
+}
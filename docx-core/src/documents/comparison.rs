@@ -0,0 +1,1036 @@
+use crate::documents::*;
+use crate::types::*;
+
+// Author and date of the tracked changes recorded by a comparison.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompareOptions {
+    author: String,
+    date: String,
+}
+
+impl Default for CompareOptions {
+    fn default() -> Self {
+        Self {
+            author: "unnamed".to_owned(),
+            date: "1970-01-01T00:00:00Z".to_owned(),
+        }
+    }
+}
+
+impl CompareOptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> Self {
+        self.author = author.into();
+        self
+    }
+
+    pub fn date(mut self, date: impl Into<String>) -> Self {
+        self.date = date.into();
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DiffOp {
+    Equal(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+// Changed regions with more cells in their LCS table are not aligned, so that the
+// table stays below 16 MB. Everything in them is deleted and inserted instead.
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+// Longest common subsequence of the two sequences.
+// Within each changed region the deletions come before the insertions.
+fn diff<T>(a: &[T], b: &[T], eq: impl Fn(&T, &T) -> bool) -> Vec<DiffOp> {
+    // The common prefix and suffix are skipped so that small edits stay cheap.
+    let prefix = a.iter().zip(b).take_while(|(x, y)| eq(x, y)).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| eq(x, y))
+        .count();
+    let a_mid = &a[prefix..a.len() - suffix];
+    let b_mid = &b[prefix..b.len() - suffix];
+
+    let mut ops: Vec<DiffOp> = (0..prefix).map(|i| DiffOp::Equal(i, i)).collect();
+    if (a_mid.len() + 1).saturating_mul(b_mid.len() + 1) > MAX_DIFF_CELLS {
+        ops.extend((0..a_mid.len()).map(|i| DiffOp::Delete(prefix + i)));
+        ops.extend((0..b_mid.len()).map(|j| DiffOp::Insert(prefix + j)));
+    } else {
+        ops.extend(lcs(a_mid, b_mid, prefix, &eq));
+    }
+    ops.extend((0..suffix).map(|k| DiffOp::Equal(a.len() - suffix + k, b.len() - suffix + k)));
+    ops
+}
+
+// The positions in the returned operations are shifted by `offset`.
+fn lcs<T>(a: &[T], b: &[T], offset: usize, eq: &impl Fn(&T, &T) -> bool) -> Vec<DiffOp> {
+    let (n, m) = (a.len(), b.len());
+
+    // lengths[i * (m + 1) + j] is the length of the LCS of a[i..] and b[j..].
+    let mut lengths = vec![0u32; (n + 1) * (m + 1)];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lengths[i * (m + 1) + j] = if eq(&a[i], &b[j]) {
+                lengths[(i + 1) * (m + 1) + j + 1] + 1
+            } else {
+                lengths[(i + 1) * (m + 1) + j].max(lengths[i * (m + 1) + j + 1])
+            };
+        }
+    }
+
+    let mut ops = vec![];
+    let mut deleted = vec![];
+    let mut inserted = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && eq(&a[i], &b[j]) {
+            ops.append(&mut deleted);
+            ops.append(&mut inserted);
+            ops.push(DiffOp::Equal(offset + i, offset + j));
+            i += 1;
+            j += 1;
+        } else if j == m
+            || (i < n && lengths[(i + 1) * (m + 1) + j] >= lengths[i * (m + 1) + j + 1])
+        {
+            deleted.push(DiffOp::Delete(offset + i));
+            i += 1;
+        } else {
+            inserted.push(DiffOp::Insert(offset + j));
+            j += 1;
+        }
+    }
+    ops.append(&mut deleted);
+    ops.append(&mut inserted);
+    ops
+}
+
+// Splits escaped text into words, whitespace and single punctuation characters.
+// Entities such as "&amp;" are kept in one token.
+fn tokenize(text: &str) -> Vec<String> {
+    #[derive(PartialEq)]
+    enum Class {
+        Word,
+        Space,
+        Other,
+    }
+    let class = |c: char| {
+        if c.is_alphanumeric() || c == '_' {
+            Class::Word
+        } else if c.is_whitespace() {
+            Class::Space
+        } else {
+            Class::Other
+        }
+    };
+
+    let mut tokens: Vec<String> = vec![];
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let mut token = c.to_string();
+        if c == '&' {
+            while let Some(n) = chars.next_if(|n| *n != ';' && !n.is_whitespace()) {
+                token.push(n);
+            }
+            if let Some(n) = chars.next_if(|n| *n == ';') {
+                token.push(n);
+            }
+        } else if class(c) != Class::Other {
+            while let Some(n) = chars.next_if(|n| class(*n) == class(c)) {
+                token.push(n);
+            }
+        }
+        tokens.push(token);
+    }
+    tokens
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Text(String),
+    Child(RunChild),
+    // A zero-width token for other content than runs, e.g. a bookmark, a link or a field.
+    // It only matches the same content, so that the text around it is still compared.
+    Content(ParagraphChild),
+}
+
+// A token with the formatting of the run it comes from.
+#[derive(Debug, Clone)]
+struct Piece {
+    token: Token,
+    property: RunProperty,
+}
+
+// Splits the children of a paragraph into pieces.
+fn paragraph_pieces(p: &Paragraph) -> Vec<Piece> {
+    let mut pieces = vec![];
+    for child in &p.children {
+        let ParagraphChild::Run(run) = child else {
+            pieces.push(Piece {
+                token: Token::Content(child.clone()),
+                property: RunProperty::new(),
+            });
+            continue;
+        };
+        for c in &run.children {
+            match c {
+                RunChild::Text(t) => pieces.extend(tokenize(&t.text).into_iter().map(|t| Piece {
+                    token: Token::Text(t),
+                    property: run.run_property.clone(),
+                })),
+                c => pieces.push(Piece {
+                    token: Token::Child(c.clone()),
+                    property: run.run_property.clone(),
+                }),
+            }
+        }
+    }
+    pieces
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Change {
+    // The formatting before the comparison, when it has changed.
+    Kept(Option<RunProperty>),
+    Deleted,
+    Inserted,
+}
+
+fn paragraph_key(p: &Paragraph) -> String {
+    format!("p:{}", p.raw_text())
+}
+
+fn table_key(t: &Table) -> String {
+    let mut key = "t:".to_owned();
+    for row in t.table_rows() {
+        key.push_str(&row_key(row));
+        key.push('\n');
+    }
+    key
+}
+
+fn row_key(row: &TableRow) -> String {
+    row.cells
+        .iter()
+        .map(|TableRowChild::TableCell(cell)| {
+            cell.children
+                .iter()
+                .map(|c| match c {
+                    TableCellContent::Paragraph(p) => paragraph_key(p),
+                    TableCellContent::Table(t) => table_key(t),
+                    _ => String::new(),
+                })
+                .collect::<Vec<_>>()
+                .join("\r")
+        })
+        .collect::<Vec<_>>()
+        .join("\t")
+}
+
+// The content of a document body or of a table cell.
+trait Block: Clone + PartialEq {
+    fn as_paragraph(&self) -> Option<&Paragraph>;
+    fn as_table(&self) -> Option<&Table>;
+    fn from_paragraph(p: Paragraph) -> Self;
+    fn from_table(t: Table) -> Self;
+}
+
+impl Block for DocumentChild {
+    fn as_paragraph(&self) -> Option<&Paragraph> {
+        match self {
+            DocumentChild::Paragraph(p) => Some(p),
+            _ => None,
+        }
+    }
+
+    fn as_table(&self) -> Option<&Table> {
+        match self {
+            DocumentChild::Table(t) => Some(t),
+            _ => None,
+        }
+    }
+
+    fn from_paragraph(p: Paragraph) -> Self {
+        DocumentChild::Paragraph(Box::new(p))
+    }
+
+    fn from_table(t: Table) -> Self {
+        DocumentChild::Table(Box::new(t))
+    }
+}
+
+impl Block for TableCellContent {
+    fn as_paragraph(&self) -> Option<&Paragraph> {
+        match self {
+            TableCellContent::Paragraph(p) => Some(p),
+            _ => None,
+        }
+    }
+
+    fn as_table(&self) -> Option<&Table> {
+        match self {
+            TableCellContent::Table(t) => Some(t),
+            _ => None,
+        }
+    }
+
+    fn from_paragraph(p: Paragraph) -> Self {
+        TableCellContent::Paragraph(p)
+    }
+
+    fn from_table(t: Table) -> Self {
+        TableCellContent::Table(t)
+    }
+}
+
+fn block_key<B: Block>(b: &B) -> Option<String> {
+    if let Some(p) = b.as_paragraph() {
+        return Some(paragraph_key(p));
+    }
+    b.as_table().map(table_key)
+}
+
+// Records the differences between an original and a revised document as tracked changes.
+pub(crate) struct DocumentComparer {
+    options: CompareOptions,
+}
+
+impl DocumentComparer {
+    pub(crate) fn new(options: CompareOptions) -> Self {
+        Self { options }
+    }
+
+    pub(crate) fn compare(&self, original: &Document, revised: &Document) -> Document {
+        let mut document = revised.clone();
+        document.children = self.compare_blocks(&original.children, &revised.children);
+        document
+    }
+
+    fn insert(&self) -> Insert {
+        Insert::new_with_empty()
+            .author(&self.options.author)
+            .date(&self.options.date)
+    }
+
+    fn delete(&self) -> Delete {
+        Delete::new()
+            .author(&self.options.author)
+            .date(&self.options.date)
+    }
+
+    // Blocks are matched by their text. Unmatched paragraphs and tables in the same
+    // changed region are compared pairwise, the rest is deleted or inserted as a whole.
+    fn compare_blocks<B: Block>(&self, original: &[B], revised: &[B]) -> Vec<B> {
+        let a: Vec<_> = original.iter().map(|b| (block_key(b), b)).collect();
+        let b: Vec<_> = revised.iter().map(|b| (block_key(b), b)).collect();
+        let ops = diff(&a, &b, |(x_key, x), (y_key, y)| match (x_key, y_key) {
+            (Some(x_key), Some(y_key)) => x_key == y_key,
+            (None, None) => x == y,
+            _ => false,
+        });
+
+        let mut blocks = vec![];
+        let mut deleted: Vec<&B> = vec![];
+        let mut inserted: Vec<&B> = vec![];
+        for op in ops {
+            match op {
+                DiffOp::Equal(i, j) => {
+                    blocks.extend(self.compare_changed_blocks(&deleted, &inserted));
+                    deleted.clear();
+                    inserted.clear();
+                    blocks.push(self.compare_block(&original[i], &revised[j]));
+                }
+                DiffOp::Delete(i) => deleted.push(&original[i]),
+                DiffOp::Insert(j) => inserted.push(&revised[j]),
+            }
+        }
+        blocks.extend(self.compare_changed_blocks(&deleted, &inserted));
+        blocks
+    }
+
+    fn compare_changed_blocks<B: Block>(&self, deleted: &[&B], inserted: &[&B]) -> Vec<B> {
+        let mut blocks = vec![];
+        for k in 0..deleted.len().max(inserted.len()) {
+            match (deleted.get(k).copied(), inserted.get(k).copied()) {
+                (Some(a), Some(b))
+                    if (a.as_paragraph().is_some() && b.as_paragraph().is_some())
+                        || (a.as_table().is_some() && b.as_table().is_some()) =>
+                {
+                    blocks.push(self.compare_block(a, b));
+                }
+                (a, b) => {
+                    blocks.extend(a.and_then(|a| self.deleted_block(a)));
+                    blocks.extend(b.map(|b| self.inserted_block(b)));
+                }
+            }
+        }
+        blocks
+    }
+
+    fn compare_block<B: Block>(&self, a: &B, b: &B) -> B {
+        if let (Some(a), Some(b)) = (a.as_paragraph(), b.as_paragraph()) {
+            return B::from_paragraph(self.compare_paragraph(a, b));
+        }
+        if let (Some(a), Some(b)) = (a.as_table(), b.as_table()) {
+            return B::from_table(self.compare_table(a, b));
+        }
+        b.clone()
+    }
+
+    // Other content than paragraphs and tables is not tracked, so it is only dropped.
+    fn deleted_block<B: Block>(&self, b: &B) -> Option<B> {
+        if let Some(p) = b.as_paragraph() {
+            return Some(B::from_paragraph(self.deleted_paragraph(p)));
+        }
+        b.as_table().map(|t| B::from_table(self.deleted_table(t)))
+    }
+
+    fn inserted_block<B: Block>(&self, b: &B) -> B {
+        if let Some(p) = b.as_paragraph() {
+            return B::from_paragraph(self.inserted_paragraph(p));
+        }
+        if let Some(t) = b.as_table() {
+            return B::from_table(self.inserted_table(t));
+        }
+        b.clone()
+    }
+
+    fn deleted_paragraph(&self, p: &Paragraph) -> Paragraph {
+        let mut p = p.clone();
+        p.children = self.deleted_children(std::mem::take(&mut p.children));
+        p.property.run_property.ins = None;
+        p.property.run_property.del = Some(self.delete());
+        p
+    }
+
+    fn inserted_paragraph(&self, p: &Paragraph) -> Paragraph {
+        let mut p = p.clone();
+        p.children = self.inserted_children(std::mem::take(&mut p.children));
+        p.property.run_property.del = None;
+        p.property.run_property.ins = Some(self.insert());
+        p
+    }
+
+    // Markers and links of the original document are dropped, since their ids and
+    // relationships belong to the original document. Their text is still deleted.
+    // Tracked changes are kept and the inserted content in them is deleted.
+    fn deleted_children(&self, children: Vec<ParagraphChild>) -> Vec<ParagraphChild> {
+        let mut deleted = vec![];
+        self.push_deleted_children(&mut deleted, children);
+        deleted
+    }
+
+    fn push_deleted_children(
+        &self,
+        deleted: &mut Vec<ParagraphChild>,
+        children: Vec<ParagraphChild>,
+    ) {
+        for child in children {
+            match child {
+                ParagraphChild::Run(run) => self.push_deleted_run(deleted, deleted_run(*run)),
+                ParagraphChild::Hyperlink(link) => {
+                    self.push_deleted_children(deleted, link.children)
+                }
+                ParagraphChild::Insert(mut ins) => {
+                    ins.children = self.deleted_insert_children(ins.children);
+                    deleted.push(ParagraphChild::Insert(ins));
+                }
+                ParagraphChild::MoveTo(mut m) => {
+                    m.children = self.deleted_insert_children(m.children);
+                    deleted.push(ParagraphChild::MoveTo(m));
+                }
+                c @ (ParagraphChild::Delete(_) | ParagraphChild::MoveFrom(_)) => deleted.push(c),
+                ParagraphChild::Field(field) => self.push_deleted_field(deleted, *field),
+                ParagraphChild::PageNum(_) => self.push_deleted_field(
+                    deleted,
+                    Field::new(FieldInstruction::new(FieldType::Page)).result("1"),
+                ),
+                ParagraphChild::NumPages(_) => self.push_deleted_field(
+                    deleted,
+                    Field::new(FieldInstruction::new(FieldType::NumPages)).result("1"),
+                ),
+                // A deletion cannot hold a content control, so only its content is deleted.
+                ParagraphChild::StructuredDataTag(tag) => {
+                    let children = tag
+                        .children
+                        .into_iter()
+                        .filter_map(|c| match c {
+                            StructuredDataTagChild::Run(run) => Some(ParagraphChild::Run(run)),
+                            StructuredDataTagChild::StructuredDataTag(tag) => {
+                                Some(ParagraphChild::StructuredDataTag(tag))
+                            }
+                            _ => None,
+                        })
+                        .collect();
+                    self.push_deleted_children(deleted, children);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn deleted_insert_children(&self, children: Vec<InsertChild>) -> Vec<InsertChild> {
+        children
+            .into_iter()
+            .filter_map(|c| match c {
+                InsertChild::Run(run) => Some(InsertChild::Delete(
+                    self.delete().add_run(deleted_run(*run)),
+                )),
+                c @ InsertChild::Delete(_) => Some(c),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn push_deleted_field(&self, children: &mut Vec<ParagraphChild>, field: Field) {
+        for run in field.into_deleted_runs() {
+            self.push_deleted_run(children, deleted_run(run));
+        }
+    }
+
+    // The run joins the previous deletion unless that one was already in the document.
+    fn push_deleted_run(&self, children: &mut Vec<ParagraphChild>, run: Run) {
+        match children.last_mut() {
+            Some(ParagraphChild::Delete(del))
+                if del.author == self.options.author && del.date == self.options.date =>
+            {
+                del.children.push(DeleteChild::Run(run));
+            }
+            _ => children.push(ParagraphChild::Delete(self.delete().add_run(run))),
+        }
+    }
+
+    fn inserted_children(&self, children: Vec<ParagraphChild>) -> Vec<ParagraphChild> {
+        let mut inserted: Vec<ParagraphChild> = vec![];
+        for child in children {
+            match child {
+                ParagraphChild::Run(run) => {
+                    if let Some(ParagraphChild::Insert(ins)) = inserted.last_mut() {
+                        ins.children.push(InsertChild::Run(run));
+                    } else {
+                        inserted.push(ParagraphChild::Insert(self.insert().add_run(*run)));
+                    }
+                }
+                ParagraphChild::Hyperlink(mut link) => {
+                    link.children = self.inserted_children(link.children);
+                    inserted.push(ParagraphChild::Hyperlink(link));
+                }
+                c => inserted.push(c),
+            }
+        }
+        inserted
+    }
+
+    fn compare_paragraph(&self, a: &Paragraph, b: &Paragraph) -> Paragraph {
+        let mut p = b.clone();
+        if a.children == b.children && a.property == b.property {
+            return p;
+        }
+        p.children = self.compare_pieces(&paragraph_pieces(a), &paragraph_pieces(b));
+
+        let mut original = a.property.clone();
+        let mut revised = b.property.clone();
+        let original_mark = std::mem::take(&mut original.run_property);
+        let revised_mark = std::mem::take(&mut revised.run_property);
+        if original != revised {
+            p.property.paragraph_property_change = Some(
+                ParagraphPropertyChange::new()
+                    .property(original)
+                    .author(&self.options.author)
+                    .date(&self.options.date),
+            );
+        }
+        if original_mark != revised_mark {
+            p.property.run_property.run_property_change = Some(
+                RunPropertyChange::new()
+                    .property(original_mark)
+                    .author(&self.options.author)
+                    .date(&self.options.date),
+            );
+        }
+        p
+    }
+
+    fn compare_pieces(&self, a: &[Piece], b: &[Piece]) -> Vec<ParagraphChild> {
+        let ops = diff(a, b, |x, y| x.token == y.token);
+        let mut changes = vec![];
+        for op in ops {
+            match op {
+                DiffOp::Equal(i, j) => {
+                    let original = (a[i].property != b[j].property).then(|| a[i].property.clone());
+                    changes.push((Change::Kept(original), &b[j]));
+                }
+                DiffOp::Delete(i) => changes.push((Change::Deleted, &a[i])),
+                DiffOp::Insert(j) => changes.push((Change::Inserted, &b[j])),
+            }
+        }
+
+        let mut children: Vec<ParagraphChild> = vec![];
+        let mut current: Option<(Change, Run)> = None;
+        for (change, piece) in changes {
+            if let Token::Content(c) = &piece.token {
+                if let Some((c, run)) = current.take() {
+                    self.push_run(&mut children, c, run);
+                }
+                match change {
+                    Change::Kept(_) => children.push(c.clone()),
+                    Change::Deleted => children.extend(self.deleted_children(vec![c.clone()])),
+                    Change::Inserted => children.extend(self.inserted_children(vec![c.clone()])),
+                }
+                continue;
+            }
+            let same_run = matches!(
+                &current,
+                Some((c, run)) if *c == change && run.run_property == piece.property
+            );
+            if !same_run {
+                if let Some((c, run)) = current.take() {
+                    self.push_run(&mut children, c, run);
+                }
+                current = Some((
+                    change,
+                    Run {
+                        run_property: piece.property.clone(),
+                        children: vec![],
+                    },
+                ));
+            }
+            if let Some((_, run)) = current.as_mut() {
+                match &piece.token {
+                    Token::Text(t) => match run.children.last_mut() {
+                        Some(RunChild::Text(last)) => last.text.push_str(t),
+                        _ => run.children.push(RunChild::Text(Text::without_escape(t))),
+                    },
+                    Token::Child(c) => run.children.push(c.clone()),
+                    Token::Content(_) => {}
+                }
+            }
+        }
+        if let Some((c, run)) = current {
+            self.push_run(&mut children, c, run);
+        }
+        children
+    }
+
+    fn push_run(&self, children: &mut Vec<ParagraphChild>, change: Change, mut run: Run) {
+        match change {
+            Change::Kept(original) => {
+                if let Some(original) = original {
+                    run.run_property.run_property_change = Some(
+                        RunPropertyChange::new()
+                            .property(original)
+                            .author(&self.options.author)
+                            .date(&self.options.date),
+                    );
+                }
+                children.push(ParagraphChild::Run(Box::new(run)));
+            }
+            Change::Deleted => self.push_deleted_run(children, deleted_run(run)),
+            Change::Inserted => {
+                if let Some(ParagraphChild::Insert(ins)) = children.last_mut() {
+                    ins.children.push(InsertChild::Run(Box::new(run)));
+                } else {
+                    children.push(ParagraphChild::Insert(self.insert().add_run(run)));
+                }
+            }
+        }
+    }
+
+    fn deleted_table(&self, t: &Table) -> Table {
+        let mut t = t.clone();
+        for row in t.table_rows_mut() {
+            *row = self.deleted_row(row);
+        }
+        t
+    }
+
+    fn inserted_table(&self, t: &Table) -> Table {
+        let mut t = t.clone();
+        for row in t.table_rows_mut() {
+            *row = self.inserted_row(row);
+        }
+        t
+    }
+
+    fn deleted_row(&self, row: &TableRow) -> TableRow {
+        let mut row = row.clone();
+        row.property.ins = None;
+        row.property.del = Some(self.delete());
+        for TableRowChild::TableCell(cell) in &mut row.cells {
+            for c in &mut cell.children {
+                if let TableCellContent::Paragraph(p) = c {
+                    p.children = self.deleted_children(std::mem::take(&mut p.children));
+                }
+            }
+        }
+        row
+    }
+
+    fn inserted_row(&self, row: &TableRow) -> TableRow {
+        let mut row = row.clone();
+        row.property.del = None;
+        row.property.ins = Some(self.insert());
+        for TableRowChild::TableCell(cell) in &mut row.cells {
+            for c in &mut cell.children {
+                if let TableCellContent::Paragraph(p) = c {
+                    p.children = self.inserted_children(std::mem::take(&mut p.children));
+                }
+            }
+        }
+        row
+    }
+
+    // Rows are matched by the text of their cells. Rows with the same number of cells
+    // are compared cell by cell, the others are deleted or inserted as a whole.
+    fn compare_table(&self, a: &Table, b: &Table) -> Table {
+        let row_keys = |t: &Table| -> Vec<Option<String>> {
+            t.rows
+                .iter()
+                .map(|r| match r {
+                    TableChild::TableRow(row) => Some(row_key(row)),
+                    _ => None,
+                })
+                .collect()
+        };
+        let a_keys: Vec<_> = row_keys(a).into_iter().zip(&a.rows).collect();
+        let b_keys: Vec<_> = row_keys(b).into_iter().zip(&b.rows).collect();
+        let ops = diff(&a_keys, &b_keys, |(x_key, x), (y_key, y)| {
+            match (x_key, y_key) {
+                (Some(x_key), Some(y_key)) => x_key == y_key,
+                (None, None) => x == y,
+                _ => false,
+            }
+        });
+
+        let mut rows = vec![];
+        let mut deleted: Vec<&TableChild> = vec![];
+        let mut inserted: Vec<&TableChild> = vec![];
+        for op in ops {
+            match op {
+                DiffOp::Equal(i, j) => {
+                    rows.extend(self.compare_changed_rows(&deleted, &inserted));
+                    deleted.clear();
+                    inserted.clear();
+                    rows.extend(self.compare_changed_rows(&[&a.rows[i]], &[&b.rows[j]]));
+                }
+                DiffOp::Delete(i) => deleted.push(&a.rows[i]),
+                DiffOp::Insert(j) => inserted.push(&b.rows[j]),
+            }
+        }
+        rows.extend(self.compare_changed_rows(&deleted, &inserted));
+
+        let mut t = b.clone();
+        t.rows = rows;
+        if a.property != b.property {
+            t.property = t.property.table_property_change(
+                TablePropertyChange::new()
+                    .property(a.property.clone())
+                    .author(&self.options.author)
+                    .date(&self.options.date),
+            );
+        }
+        t
+    }
+
+    fn compare_changed_rows(
+        &self,
+        deleted: &[&TableChild],
+        inserted: &[&TableChild],
+    ) -> Vec<TableChild> {
+        let mut rows = vec![];
+        for k in 0..deleted.len().max(inserted.len()) {
+            match (deleted.get(k), inserted.get(k)) {
+                (Some(TableChild::TableRow(a)), Some(TableChild::TableRow(b)))
+                    if a.cells.len() == b.cells.len() =>
+                {
+                    rows.push(TableChild::TableRow(self.compare_row(a, b)));
+                }
+                (a, b) => {
+                    if let Some(TableChild::TableRow(a)) = a {
+                        rows.push(TableChild::TableRow(self.deleted_row(a)));
+                    }
+                    rows.extend(b.map(|b| match b {
+                        TableChild::TableRow(b) => TableChild::TableRow(self.inserted_row(b)),
+                        b => (*b).clone(),
+                    }));
+                }
+            }
+        }
+        rows
+    }
+
+    fn compare_row(&self, a: &TableRow, b: &TableRow) -> TableRow {
+        let mut row = b.clone();
+        if a == b {
+            return row;
+        }
+        for (TableRowChild::TableCell(cell), TableRowChild::TableCell(original)) in
+            row.cells.iter_mut().zip(&a.cells)
+        {
+            cell.children = self.compare_blocks(&original.children, &cell.children);
+        }
+        row
+    }
+}
+
+// Turns the text of a run into deleted text.
+fn deleted_run(mut run: Run) -> Run {
+    run.children = run
+        .children
+        .into_iter()
+        .map(|c| match c {
+            RunChild::Text(t) => RunChild::DeleteText(DeleteText::without_escape(t.text)),
+            c => c,
+        })
+        .collect();
+    run
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn texts(document: &Document) -> Vec<String> {
+        document
+            .children
+            .iter()
+            .filter_map(|c| match c {
+                DocumentChild::Paragraph(p) => Some(p.raw_text()),
+                DocumentChild::Table(t) => Some(table_key(t)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn docx(paragraphs: &[&str]) -> Docx {
+        paragraphs.iter().fold(Docx::new(), |docx, text| {
+            docx.add_paragraph(Paragraph::new().add_run(Run::new().add_text(*text)))
+        })
+    }
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(
+            tokenize("Tom &amp; Jerry, 2024"),
+            vec!["Tom", " ", "&amp;", " ", "Jerry", ",", " ", "2024"]
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        let a = ["a", "b", "c", "d"];
+        let b = ["a", "x", "c", "d", "e"];
+        assert_eq!(
+            diff(&a, &b, |x, y| x == y),
+            vec![
+                DiffOp::Equal(0, 0),
+                DiffOp::Delete(1),
+                DiffOp::Insert(1),
+                DiffOp::Equal(2, 2),
+                DiffOp::Equal(3, 3),
+                DiffOp::Insert(4),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_large_region() {
+        let a: Vec<usize> = (0..3000).collect();
+        let b: Vec<usize> = (0..3000).map(|x| x + 3000).collect();
+        let ops = diff(&a, &b, |x, y| x == y);
+        assert_eq!(ops.len(), 6000);
+        assert_eq!(ops[0], DiffOp::Delete(0));
+        assert_eq!(ops[3000], DiffOp::Insert(0));
+    }
+
+    #[test]
+    fn test_compare_words() {
+        let original = docx(&["The quick brown fox"]);
+        let revised = docx(&["The slow brown fox"]);
+        let compared = original.compare(
+            &revised,
+            CompareOptions::new()
+                .author("bob")
+                .date("2024-01-01T00:00:00Z"),
+        );
+
+        let DocumentChild::Paragraph(p) = &compared.document.children[0] else {
+            unreachable!()
+        };
+        assert_eq!(
+            p.children,
+            vec![
+                ParagraphChild::Run(Box::new(Run::new().add_text("The "))),
+                ParagraphChild::Delete(
+                    Delete::new()
+                        .add_run(Run::new().add_delete_text("quick"))
+                        .author("bob")
+                        .date("2024-01-01T00:00:00Z")
+                ),
+                ParagraphChild::Insert(
+                    Insert::new(Run::new().add_text("slow"))
+                        .author("bob")
+                        .date("2024-01-01T00:00:00Z")
+                ),
+                ParagraphChild::Run(Box::new(Run::new().add_text(" brown fox"))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compare_paragraphs() {
+        let original = docx(&["Title", "Removed", "Body"]);
+        let revised = docx(&["Title", "Body", "Added"]);
+        let compared = original.compare(&revised, CompareOptions::new());
+
+        assert_eq!(
+            texts(&compared.clone().accept_all_revisions().document),
+            texts(&revised.document)
+        );
+        assert_eq!(
+            texts(&compared.reject_all_revisions().document),
+            texts(&original.document)
+        );
+    }
+
+    #[test]
+    fn test_compare_words_around_bookmark() {
+        let paragraph = |text: &str| {
+            Paragraph::new()
+                .add_run(Run::new().add_text("Hello "))
+                .add_bookmark_start(1, "greeting")
+                .add_run(Run::new().add_text(text))
+                .add_bookmark_end(1)
+        };
+        let original = Docx::new().add_paragraph(paragraph("world"));
+        let revised = Docx::new().add_paragraph(paragraph("there"));
+        let compared = original.compare(
+            &revised,
+            CompareOptions::new()
+                .author("bob")
+                .date("2024-01-01T00:00:00Z"),
+        );
+
+        let DocumentChild::Paragraph(p) = &compared.document.children[0] else {
+            unreachable!()
+        };
+        assert_eq!(
+            p.children,
+            vec![
+                ParagraphChild::Run(Box::new(Run::new().add_text("Hello "))),
+                ParagraphChild::BookmarkStart(BookmarkStart::new(1, "greeting")),
+                ParagraphChild::Delete(
+                    Delete::new()
+                        .add_run(Run::new().add_delete_text("world"))
+                        .author("bob")
+                        .date("2024-01-01T00:00:00Z")
+                ),
+                ParagraphChild::Insert(
+                    Insert::new(Run::new().add_text("there"))
+                        .author("bob")
+                        .date("2024-01-01T00:00:00Z")
+                ),
+                ParagraphChild::BookmarkEnd(BookmarkEnd::new(1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deleted_paragraph_keeps_tracked_changes_and_fields() {
+        let original = Docx::new().add_paragraph(
+            Paragraph::new()
+                .add_insert(Insert::new(Run::new().add_text("Added")).author("alice"))
+                .add_delete(
+                    Delete::new()
+                        .add_run(Run::new().add_delete_text("Removed"))
+                        .author("alice"),
+                )
+                .add_field(Field::new(FieldInstruction::new(FieldType::Page)).result("1"))
+                .add_structured_data_tag(
+                    StructuredDataTag::new().add_run(Run::new().add_text("Tagged")),
+                ),
+        );
+        let compared = original.compare(
+            &Docx::new(),
+            CompareOptions::new()
+                .author("bob")
+                .date("2024-01-01T00:00:00Z"),
+        );
+
+        let DocumentChild::Paragraph(p) = &compared.document.children[0] else {
+            unreachable!()
+        };
+        assert_eq!(
+            p.children[0],
+            ParagraphChild::Insert(
+                Insert::new_with_empty()
+                    .add_delete(
+                        Delete::new()
+                            .add_run(Run::new().add_delete_text("Added"))
+                            .author("bob")
+                            .date("2024-01-01T00:00:00Z")
+                    )
+                    .author("alice")
+            )
+        );
+        assert_eq!(
+            p.children[1],
+            ParagraphChild::Delete(
+                Delete::new()
+                    .add_run(Run::new().add_delete_text("Removed"))
+                    .author("alice")
+            )
+        );
+        let ParagraphChild::Delete(del) = &p.children[2] else {
+            unreachable!()
+        };
+        assert_eq!(del.author, "bob");
+        let xml = String::from_utf8(del.build()).unwrap();
+        assert!(xml.contains("<w:delInstrText>PAGE</w:delInstrText>"));
+        assert!(xml.contains(r#"<w:delText xml:space="preserve">1</w:delText>"#));
+        assert!(xml.contains(r#"<w:delText xml:space="preserve">Tagged</w:delText>"#));
+        assert_eq!(p.children.len(), 3);
+    }
+
+    #[test]
+    fn test_compare_table_rows() {
+        let table = |rows: &[&str]| {
+            Table::new(
+                rows.iter()
+                    .map(|text| {
+                        TableRow::new(vec![TableCell::new()
+                            .add_paragraph(Paragraph::new().add_run(Run::new().add_text(*text)))])
+                    })
+                    .collect(),
+            )
+        };
+        let original = Docx::new().add_table(table(&["a", "b", "c"]));
+        let revised = Docx::new().add_table(table(&["a", "c", "d"]));
+        let compared = original.compare(&revised, CompareOptions::new());
+
+        let DocumentChild::Table(t) = &compared.document.children[0] else {
+            unreachable!()
+        };
+        let rows = t.table_rows();
+        assert_eq!(rows.len(), 4);
+        assert!(rows[1].property.del.is_some());
+        assert!(rows[3].property.ins.is_some());
+        assert_eq!(
+            texts(&compared.clone().accept_all_revisions().document),
+            texts(&revised.document)
+        );
+        assert_eq!(
+            texts(&compared.reject_all_revisions().document),
+            texts(&original.document)
+        );
+    }
+}
@@ -13,6 +13,8 @@ pub struct ContentTypes {
     custom_xml_count: usize,
     header_count: usize,
     footer_count: usize,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    has_obfuscated_fonts: bool,
 }
 
 impl ContentTypes {
@@ -52,6 +54,10 @@ impl ContentTypes {
             "application/vnd.openxmlformats-officedocument.wordprocessingml.fontTable+xml"
                 .to_owned(),
         );
+        self.types.insert(
+            "/word/theme/theme1.xml".to_owned(),
+            "application/vnd.openxmlformats-officedocument.theme+xml".to_owned(),
+        );
         self.types.insert(
             "/word/document.xml".to_owned(),
             "application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"
@@ -129,6 +135,30 @@ impl ContentTypes {
         );
         self
     }
+
+    pub fn add_obfuscated_fonts(mut self) -> Self {
+        self.has_obfuscated_fonts = true;
+        self
+    }
+
+    pub fn add_comment_parts(mut self) -> Self {
+        self.types.insert(
+            "/word/commentsIds.xml".to_owned(),
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.commentsIds+xml"
+                .to_owned(),
+        );
+        self.types.insert(
+            "/word/commentsExtensible.xml".to_owned(),
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.commentsExtensible+xml"
+                .to_owned(),
+        );
+        self.types.insert(
+            "/word/people.xml".to_owned(),
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.people+xml".to_owned(),
+        );
+        self
+    }
+
     pub fn add_footnotes(mut self) -> Self {
         self.types.insert(
             "/word/footnotes.xml".to_owned(),
@@ -147,6 +177,7 @@ impl Default for ContentTypes {
             custom_xml_count: 1,
             header_count: 0,
             footer_count: 0,
+            has_obfuscated_fonts: false,
         }
     }
 }
@@ -169,6 +200,12 @@ impl BuildXML for ContentTypes {
                 "application/vnd.openxmlformats-package.relationships+xml",
             )?
             .add_default("xml", "application/xml")?
+            .apply_if(self.has_obfuscated_fonts, |b| {
+                b.add_default(
+                    "odttf",
+                    "application/vnd.openxmlformats-officedocument.obfuscatedFont",
+                )
+            })?
             .apply_each(self.types.iter(), |(k, v), b| b.add_override(k, v))?
             .close()?
             .into_inner()
@@ -247,6 +284,7 @@ mod tests {
                 custom_xml_count: 1,
                 header_count: 0,
                 footer_count: 0,
+                has_obfuscated_fonts: false,
             },
             c
         );
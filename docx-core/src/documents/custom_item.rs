@@ -7,7 +7,7 @@ use std::io::Write;
 use std::str::FromStr;
 
 #[derive(Debug, Clone)]
-pub struct CustomItem(XmlDocument);
+pub struct CustomItem(pub(crate) XmlDocument);
 
 impl FromStr for CustomItem {
     type Err = ParseXmlError;
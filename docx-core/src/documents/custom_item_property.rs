@@ -6,7 +6,7 @@ use crate::xml_builder::*;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct CustomItemProperty {
-    id: String,
+    pub(crate) id: String,
 }
 
 impl CustomItemProperty {
@@ -0,0 +1,646 @@
+use std::collections::HashMap;
+
+use crate::documents::*;
+use crate::escape::replace_escaped;
+use crate::types::*;
+use crate::xml_json::XmlData;
+
+use super::field_evaluator::FieldDateTime;
+
+#[derive(Debug, Clone, PartialEq)]
+enum XPathStep {
+    Element {
+        prefix: Option<String>,
+        name: String,
+        position: usize,
+    },
+    Attribute {
+        prefix: Option<String>,
+        name: String,
+    },
+    Text,
+}
+
+// The subset of XPath used by data bindings: an absolute path of element names with
+// optional position predicates, optionally ending with an attribute or text(),
+// e.g. "/ns0:root[1]/ns0:items[1]/ns0:item[2]/@id".
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct XPath {
+    steps: Vec<XPathStep>,
+    namespaces: HashMap<String, String>,
+}
+
+fn split_name(s: &str) -> (Option<String>, String) {
+    match s.split_once(':') {
+        Some((prefix, name)) => (Some(prefix.to_owned()), name.to_owned()),
+        None => (None, s.to_owned()),
+    }
+}
+
+// e.g. "xmlns:ns0='http://example.com/' xmlns:ns1='urn:example'"
+fn parse_prefix_mappings(s: &str) -> HashMap<String, String> {
+    let mut namespaces = HashMap::new();
+    let mut rest = s;
+    while let Some(start) = rest.find("xmlns:") {
+        let Some((prefix, value)) = rest[start + 6..].split_once('=') else {
+            break;
+        };
+        let value = value.trim_start();
+        let Some(quote) = value.chars().next().filter(|c| *c == '\'' || *c == '"') else {
+            break;
+        };
+        let Some(end) = value[1..].find(quote) else {
+            break;
+        };
+        namespaces.insert(prefix.trim().to_owned(), value[1..end + 1].to_owned());
+        rest = &value[end + 2..];
+    }
+    namespaces
+}
+
+// Adds the namespaces declared on the node to the scope. The default namespace has an empty key.
+fn node_scope(node: &XmlData, scope: &HashMap<String, String>) -> HashMap<String, String> {
+    let mut scope = scope.clone();
+    for (k, v) in node.attributes.iter() {
+        if k == "xmlns" {
+            scope.insert(String::new(), v.clone());
+        } else if let Some(prefix) = k.strip_prefix("xmlns:") {
+            scope.insert(prefix.to_owned(), v.clone());
+        }
+    }
+    scope
+}
+
+fn is_namespace_declaration(name: &str) -> bool {
+    name == "xmlns" || name.starts_with("xmlns:")
+}
+
+fn node_at<'a>(roots: &'a [XmlData], path: &[usize]) -> &'a XmlData {
+    let mut node = &roots[path[0]];
+    for i in &path[1..] {
+        node = &node.children[*i];
+    }
+    node
+}
+
+fn node_at_mut<'a>(roots: &'a mut [XmlData], path: &[usize]) -> &'a mut XmlData {
+    let mut node = &mut roots[path[0]];
+    for i in &path[1..] {
+        node = &mut node.children[*i];
+    }
+    node
+}
+
+impl XPath {
+    pub(crate) fn parse(xpath: &str, prefix_mappings: Option<&str>) -> Option<Self> {
+        let path = xpath.trim().strip_prefix('/')?;
+        let parts: Vec<&str> = path.split('/').collect();
+        let mut steps = vec![];
+        for (i, part) in parts.iter().enumerate() {
+            let last = i + 1 == parts.len();
+            let step = if let Some(attr) = part.strip_prefix('@') {
+                let (prefix, name) = split_name(attr);
+                XPathStep::Attribute { prefix, name }
+            } else if *part == "text()" {
+                XPathStep::Text
+            } else {
+                let (name, position) = match part.split_once('[') {
+                    Some((name, predicate)) => {
+                        (name, predicate.strip_suffix(']')?.trim().parse().ok()?)
+                    }
+                    None => (*part, 1),
+                };
+                if name.is_empty() || position == 0 {
+                    return None;
+                }
+                let (prefix, name) = split_name(name);
+                XPathStep::Element {
+                    prefix,
+                    name,
+                    position,
+                }
+            };
+            if !last && !matches!(step, XPathStep::Element { .. }) {
+                return None;
+            }
+            steps.push(step);
+        }
+        if !matches!(steps.first(), Some(XPathStep::Element { .. })) {
+            return None;
+        }
+        Some(Self {
+            steps,
+            namespaces: prefix_mappings
+                .map(parse_prefix_mappings)
+                .unwrap_or_default(),
+        })
+    }
+
+    fn matches_name(
+        &self,
+        prefix: &Option<String>,
+        name: &str,
+        qualified_name: &str,
+        scope: &HashMap<String, String>,
+        is_element: bool,
+    ) -> bool {
+        let (node_prefix, local_name) = match qualified_name.split_once(':') {
+            Some((p, n)) => (Some(p), n),
+            None => (None, qualified_name),
+        };
+        if name != "*" && name != local_name {
+            return false;
+        }
+        let Some(prefix) = prefix else {
+            return true;
+        };
+        let namespace = match node_prefix {
+            Some(p) => scope.get(p),
+            // Unprefixed attributes are not in the default namespace.
+            None if is_element => scope.get(""),
+            None => None,
+        };
+        match (self.namespaces.get(prefix), namespace) {
+            (Some(expected), Some(namespace)) => expected == namespace,
+            _ => node_prefix == Some(prefix.as_str()),
+        }
+    }
+
+    fn matches_attribute(&self, key: &str, scope: &HashMap<String, String>) -> bool {
+        match self.steps.last() {
+            Some(XPathStep::Attribute { prefix, name }) => {
+                !is_namespace_declaration(key) && self.matches_name(prefix, name, key, scope, false)
+            }
+            _ => false,
+        }
+    }
+
+    // The indices from the root to the selected element and the namespaces in its scope.
+    fn locate(&self, roots: &[XmlData]) -> Option<(Vec<usize>, HashMap<String, String>)> {
+        let mut path = vec![];
+        let mut nodes = roots;
+        let mut scope = HashMap::new();
+        for step in self.steps.iter() {
+            if let XPathStep::Element {
+                prefix,
+                name,
+                position,
+            } = step
+            {
+                let mut count = 0;
+                let (i, next_scope) = nodes.iter().enumerate().find_map(|(i, node)| {
+                    let s = node_scope(node, &scope);
+                    if self.matches_name(prefix, name, &node.name, &s, true) {
+                        count += 1;
+                        if count == *position {
+                            return Some((i, s));
+                        }
+                    }
+                    None
+                })?;
+                path.push(i);
+                scope = next_scope;
+                nodes = &nodes[i].children;
+            }
+        }
+        Some((path, scope))
+    }
+
+    pub(crate) fn select(&self, roots: &[XmlData]) -> Option<String> {
+        let (path, scope) = self.locate(roots)?;
+        let node = node_at(roots, &path);
+        if let Some(XPathStep::Attribute { .. }) = self.steps.last() {
+            node.attributes
+                .iter()
+                .find(|(k, _)| self.matches_attribute(k, &scope))
+                .map(|(_, v)| v.clone())
+        } else {
+            Some(node.data.clone().unwrap_or_default())
+        }
+    }
+
+    // Missing nodes are not created. Returns false when the path is not found.
+    pub(crate) fn update(&self, roots: &mut [XmlData], value: &str) -> bool {
+        let Some((path, scope)) = self.locate(roots) else {
+            return false;
+        };
+        let node = node_at_mut(roots, &path);
+        if let Some(XPathStep::Attribute { prefix, name }) = self.steps.last() {
+            if let Some(attr) = node
+                .attributes
+                .iter_mut()
+                .find(|(k, _)| self.matches_attribute(k, &scope))
+            {
+                attr.1 = value.to_owned();
+            } else if prefix.is_none() && name != "*" {
+                node.attributes.push((name.clone(), value.to_owned()));
+            } else {
+                return false;
+            }
+        } else {
+            node.data = Some(value.to_owned());
+        }
+        true
+    }
+}
+
+fn is_same_item_id(a: &str, b: &str) -> bool {
+    let trim = |s: &str| s.trim().trim_matches(|c| c == '{' || c == '}').to_owned();
+    trim(a).eq_ignore_ascii_case(&trim(b))
+}
+
+// Runs of a content control are rewritten unless it holds other content such as tables.
+fn is_text_content(tag: &StructuredDataTag) -> bool {
+    tag.children.iter().any(|c| {
+        matches!(
+            c,
+            StructuredDataTagChild::Paragraph(_) | StructuredDataTagChild::Run(_)
+        )
+    }) || !tag.children.iter().any(|c| {
+        matches!(
+            c,
+            StructuredDataTagChild::Table(_)
+                | StructuredDataTagChild::TableRow(_)
+                | StructuredDataTagChild::StructuredDataTag(_)
+        )
+    })
+}
+
+fn first_run_property(tag: &StructuredDataTag) -> RunProperty {
+    for c in tag.children.iter() {
+        match c {
+            StructuredDataTagChild::Run(r) => return r.run_property.clone(),
+            StructuredDataTagChild::Paragraph(p) => {
+                for c in p.children.iter() {
+                    if let ParagraphChild::Run(r) = c {
+                        return r.run_property.clone();
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    tag.property.run_property.clone()
+}
+
+// Replaces the runs with the given one. Block level content keeps its first paragraph.
+fn replace_content(tag: &mut StructuredDataTag, run: Run) {
+    let mut run = Some(run);
+    for child in std::mem::take(&mut tag.children) {
+        match child {
+            StructuredDataTagChild::Paragraph(mut p) => {
+                if let Some(run) = run.take() {
+                    p.children = vec![ParagraphChild::Run(Box::new(run))];
+                    tag.children.push(StructuredDataTagChild::Paragraph(p));
+                }
+            }
+            StructuredDataTagChild::Run(_) => {
+                if let Some(run) = run.take() {
+                    tag.children
+                        .push(StructuredDataTagChild::Run(Box::new(run)));
+                }
+            }
+            c => tag.children.push(c),
+        }
+    }
+    if let Some(run) = run {
+        tag.children
+            .push(StructuredDataTagChild::Run(Box::new(run)));
+    }
+}
+
+fn is_data_bindable(tag: &StructuredDataTag) -> bool {
+    !matches!(
+        tag.property.control,
+        Some(ContentControl::RepeatingSection(_))
+            | Some(ContentControl::RepeatingSectionItem)
+            | Some(ContentControl::Picture)
+    )
+}
+
+pub(crate) fn write_bound_value(tag: &mut StructuredDataTag, value: &str) {
+    if !is_data_bindable(tag) || !is_text_content(tag) {
+        return;
+    }
+    // Word shows the placeholder for empty values.
+    if value.is_empty() && tag.property.placeholder.is_some() {
+        return;
+    }
+    let run_property = first_run_property(tag);
+    let text = match &mut tag.property.control {
+        Some(ContentControl::CheckBox(c)) => {
+            c.checked = matches!(value.trim(), "true" | "1");
+            let run = c.symbol_run();
+            tag.property.showing_placeholder = false;
+            replace_content(tag, run);
+            return;
+        }
+        Some(ContentControl::DropDownList(l)) | Some(ContentControl::ComboBox(l)) => l
+            .items
+            .iter()
+            .find(|i| replace_escaped(&i.value) == value)
+            .map(|i| replace_escaped(i.text()))
+            .unwrap_or_else(|| value.to_owned()),
+        Some(ContentControl::Date(d)) => match FieldDateTime::parse(value) {
+            Some(date) => {
+                d.full_date = Some(value.to_owned());
+                match &d.date_format {
+                    Some(f) => date.format(f),
+                    None => value.to_owned(),
+                }
+            }
+            None => value.to_owned(),
+        },
+        _ => value.to_owned(),
+    };
+    let multi_line = matches!(&tag.property.control, Some(ContentControl::Text(t)) if t.multi_line);
+    let mut run = Run {
+        run_property,
+        ..Default::default()
+    };
+    for (i, line) in text.split('\n').enumerate() {
+        if i > 0 && multi_line {
+            run = run.add_break(BreakType::TextWrapping);
+        }
+        run = run.add_text(line);
+    }
+    tag.property.showing_placeholder = false;
+    replace_content(tag, run);
+}
+
+fn bound_value(tag: &StructuredDataTag) -> Option<String> {
+    match &tag.property.control {
+        Some(ContentControl::Date(d)) => match d.store_mapped_data_as.as_deref() {
+            Some("text") => tag.value().map(|_| tag.text()),
+            Some("date") => tag
+                .value()
+                .map(|v| v.split('T').next().unwrap_or_default().to_owned()),
+            _ => tag.value(),
+        },
+        _ => tag.value(),
+    }
+}
+
+fn for_each_sdt_in_paragraph(p: &mut Paragraph, f: &mut impl FnMut(&mut StructuredDataTag)) {
+    for child in &mut p.children {
+        if let ParagraphChild::StructuredDataTag(t) = child {
+            for_each_sdt(t, f);
+        }
+    }
+}
+
+fn for_each_sdt_in_row(row: &mut TableRow, f: &mut impl FnMut(&mut StructuredDataTag)) {
+    for TableRowChild::TableCell(cell) in &mut row.cells {
+        for content in &mut cell.children {
+            match content {
+                TableCellContent::Paragraph(p) => for_each_sdt_in_paragraph(p, f),
+                TableCellContent::Table(t) => for_each_sdt_in_table(t, f),
+                TableCellContent::StructuredDataTag(t) => for_each_sdt(t, f),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn for_each_sdt_in_table(table: &mut Table, f: &mut impl FnMut(&mut StructuredDataTag)) {
+    for child in &mut table.rows {
+        match child {
+            TableChild::TableRow(row) => for_each_sdt_in_row(row, f),
+            TableChild::StructuredDataTag(t) => for_each_sdt(t, f),
+            TableChild::PermStart(_) | TableChild::PermEnd(_) => {}
+        }
+    }
+}
+
+// Visits the content control and then the ones nested in it.
+fn for_each_sdt(tag: &mut StructuredDataTag, f: &mut impl FnMut(&mut StructuredDataTag)) {
+    f(tag);
+    for child in &mut tag.children {
+        match child {
+            StructuredDataTagChild::Paragraph(p) => for_each_sdt_in_paragraph(p, f),
+            StructuredDataTagChild::Table(t) => for_each_sdt_in_table(t, f),
+            StructuredDataTagChild::TableRow(r) => for_each_sdt_in_row(r, f),
+            StructuredDataTagChild::StructuredDataTag(t) => for_each_sdt(t, f),
+            _ => {}
+        }
+    }
+}
+
+fn for_each_header_footer_sdt(
+    property: &mut SectionProperty,
+    f: &mut impl FnMut(&mut StructuredDataTag),
+) {
+    let headers = [
+        &mut property.header,
+        &mut property.first_header,
+        &mut property.even_header,
+    ];
+    for (_, header) in headers.into_iter().flatten() {
+        for child in &mut header.children {
+            match child {
+                HeaderChild::Paragraph(p) => for_each_sdt_in_paragraph(p, f),
+                HeaderChild::Table(t) => for_each_sdt_in_table(t, f),
+                HeaderChild::StructuredDataTag(t) => for_each_sdt(t, f),
+            }
+        }
+    }
+    let footers = [
+        &mut property.footer,
+        &mut property.first_footer,
+        &mut property.even_footer,
+    ];
+    for (_, footer) in footers.into_iter().flatten() {
+        for child in &mut footer.children {
+            match child {
+                FooterChild::Paragraph(p) => for_each_sdt_in_paragraph(p, f),
+                FooterChild::Table(t) => for_each_sdt_in_table(t, f),
+                FooterChild::StructuredDataTag(t) => for_each_sdt(t, f),
+            }
+        }
+    }
+}
+
+fn for_each_document_sdt(document: &mut Document, f: &mut impl FnMut(&mut StructuredDataTag)) {
+    for child in &mut document.children {
+        match child {
+            DocumentChild::Paragraph(p) => for_each_sdt_in_paragraph(p, f),
+            DocumentChild::Table(t) => for_each_sdt_in_table(t, f),
+            DocumentChild::StructuredDataTag(t) => for_each_sdt(t, f),
+            DocumentChild::Section(section) => {
+                for child in &mut section.children {
+                    match child {
+                        SectionChild::Paragraph(p) => for_each_sdt_in_paragraph(p, f),
+                        SectionChild::Table(t) => for_each_sdt_in_table(t, f),
+                        SectionChild::StructuredDataTag(t) => for_each_sdt(t, f),
+                        _ => {}
+                    }
+                }
+                for_each_header_footer_sdt(&mut section.property, f);
+            }
+            _ => {}
+        }
+    }
+    for_each_header_footer_sdt(&mut document.section_property, f);
+}
+
+// Resolves the data bindings of content controls against the custom XML parts.
+pub(crate) struct DataBinder<'a> {
+    props: &'a [CustomItemProperty],
+    items: &'a mut [CustomItem],
+}
+
+impl<'a> DataBinder<'a> {
+    pub(crate) fn new(props: &'a [CustomItemProperty], items: &'a mut [CustomItem]) -> Self {
+        Self { props, items }
+    }
+
+    // Without a store item id, the first part containing the path is used like Word does.
+    fn find(&self, binding: &DataBinding) -> Option<(usize, XPath)> {
+        let xpath = XPath::parse(binding.xpath.as_ref()?, binding.prefix_mappings.as_deref())?;
+        let i = (0..self.items.len()).find(|i| {
+            let same_item = match (&binding.store_item_id, self.props.get(*i)) {
+                (Some(id), Some(prop)) => is_same_item_id(id, &prop.id),
+                (Some(_), None) => false,
+                (None, _) => true,
+            };
+            same_item && xpath.locate(&self.items[*i].0.data).is_some()
+        })?;
+        Some((i, xpath))
+    }
+
+    pub(crate) fn get(&self, binding: &DataBinding) -> Option<String> {
+        let (i, xpath) = self.find(binding)?;
+        xpath.select(&self.items[i].0.data)
+    }
+
+    pub(crate) fn set(&mut self, binding: &DataBinding, value: &str) -> bool {
+        match self.find(binding) {
+            Some((i, xpath)) => xpath.update(&mut self.items[i].0.data, value),
+            None => false,
+        }
+    }
+
+    // Writes the bound values into the content of the content controls.
+    pub(crate) fn populate(&self, document: &mut Document) {
+        for_each_document_sdt(document, &mut |tag| {
+            let value = tag.property.data_binding.as_ref().and_then(|b| self.get(b));
+            if let Some(value) = value {
+                write_bound_value(tag, &value);
+            }
+        });
+    }
+
+    // Stores the values of the content controls into the custom XML parts.
+    pub(crate) fn extract(&mut self, document: &mut Document) {
+        for_each_document_sdt(document, &mut |tag| {
+            if !is_data_bindable(tag) {
+                return;
+            }
+            if let (Some(binding), Some(value)) = (&tag.property.data_binding, bound_value(tag)) {
+                self.set(binding, &value);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str::FromStr;
+
+    const ITEM: &str = r#"<ns0:root xmlns:ns0="http://example.com/order"><ns0:customer id="c1">Alice &amp; Bob</ns0:customer><ns0:items><ns0:item>Apple</ns0:item><ns0:item>Banana</ns0:item></ns0:items><ns0:paid>true</ns0:paid></ns0:root>"#;
+
+    const MAPPINGS: &str = "xmlns:ns0='http://example.com/order'";
+
+    #[test]
+    fn test_xpath_select() {
+        let item = CustomItem::from_str(ITEM).unwrap();
+        let select = |xpath: &str, mappings: Option<&str>| {
+            XPath::parse(xpath, mappings).and_then(|x| x.select(&item.0.data))
+        };
+        assert_eq!(
+            select("/ns0:root[1]/ns0:customer[1]", Some(MAPPINGS)),
+            Some("Alice & Bob".to_string())
+        );
+        assert_eq!(
+            select("/ns0:root/ns0:items/ns0:item[2]", Some(MAPPINGS)),
+            Some("Banana".to_string())
+        );
+        assert_eq!(
+            select(
+                "/x:root/x:customer/@id",
+                Some("xmlns:x='http://example.com/order'")
+            ),
+            Some("c1".to_string())
+        );
+        assert_eq!(
+            select("/root/items/item[1]/text()", None),
+            Some("Apple".to_string())
+        );
+        assert_eq!(
+            select("/x:root/x:customer", Some("xmlns:x='urn:other'")),
+            None
+        );
+        assert_eq!(select("//ns0:item", Some(MAPPINGS)), None);
+    }
+
+    #[test]
+    fn test_populate_and_extract() {
+        let mut items = vec![CustomItem::from_str(ITEM).unwrap()];
+        let props = vec![CustomItemProperty::new(
+            "06AC5857-5C65-A94A-BCEC-37356A209BC3",
+        )];
+        let binding = |xpath: &str| {
+            DataBinding::new()
+                .xpath(xpath)
+                .prefix_mappings(MAPPINGS)
+                .store_item_id("{06AC5857-5C65-A94A-BCEC-37356A209BC3}")
+        };
+        let mut document = Document::new()
+            .add_paragraph(
+                Paragraph::new().add_structured_data_tag(
+                    StructuredDataTag::new()
+                        .data_binding(binding("/ns0:root[1]/ns0:customer[1]"))
+                        .add_run(Run::new().bold().add_text("Click here")),
+                ),
+            )
+            .add_structured_data_tag(
+                StructuredDataTag::new()
+                    .data_binding(binding("/ns0:root[1]/ns0:paid[1]"))
+                    .control(ContentControl::CheckBox(CheckBox::new()))
+                    .add_paragraph(Paragraph::new()),
+            );
+
+        DataBinder::new(&props, &mut items).populate(&mut document);
+
+        let DocumentChild::Paragraph(p) = &mut document.children[0] else {
+            panic!("Expected Paragraph");
+        };
+        let ParagraphChild::StructuredDataTag(customer) = &mut p.children[0] else {
+            panic!("Expected StructuredDataTag");
+        };
+        assert_eq!(customer.text(), "Alice & Bob");
+        let StructuredDataTagChild::Run(run) = &customer.children[0] else {
+            panic!("Expected Run");
+        };
+        assert_eq!(run.run_property.bold, Some(Bold::new()));
+
+        **customer = (**customer)
+            .clone()
+            .add_run(Run::new().add_text(" and Carol"));
+        DataBinder::new(&props, &mut items).extract(&mut document);
+        assert_eq!(
+            items[0].0.data[0].children[0].data,
+            Some("Alice & Bob and Carol".to_string())
+        );
+
+        let DocumentChild::StructuredDataTag(paid) = &document.children[1] else {
+            panic!("Expected StructuredDataTag");
+        };
+        assert_eq!(paid.value(), Some("true".to_string()));
+        assert_eq!(paid.text(), "☒");
+    }
+}
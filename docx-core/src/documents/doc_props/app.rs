@@ -1,17 +1,83 @@
 use serde::Serialize;
 use std::io::Write;
 
+use super::DocumentStatistics;
 use crate::documents::BuildXML;
+use crate::escape::escape;
 use crate::xml_builder::*;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Default)]
 #[serde(rename_all = "camelCase")]
-pub struct AppProps {}
+pub struct AppProps {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    application: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    app_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    company: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    manager: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hyperlink_base: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    template: Option<String>,
+    // Computed from the document when the docx is built.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    statistics: Option<DocumentStatistics>,
+}
 
 impl AppProps {
     pub fn new() -> AppProps {
         Default::default()
     }
+
+    pub fn application(mut self, name: impl Into<String>) -> Self {
+        self.application = Some(escape(&name.into()));
+        self
+    }
+
+    // e.g. "16.0000"
+    pub fn app_version(mut self, version: impl Into<String>) -> Self {
+        self.app_version = Some(escape(&version.into()));
+        self
+    }
+
+    pub fn company(mut self, company: impl Into<String>) -> Self {
+        self.company = Some(escape(&company.into()));
+        self
+    }
+
+    pub fn manager(mut self, manager: impl Into<String>) -> Self {
+        self.manager = Some(escape(&manager.into()));
+        self
+    }
+
+    pub fn hyperlink_base(mut self, base: impl Into<String>) -> Self {
+        self.hyperlink_base = Some(escape(&base.into()));
+        self
+    }
+
+    pub fn template(mut self, template: impl Into<String>) -> Self {
+        self.template = Some(escape(&template.into()));
+        self
+    }
+
+    pub fn statistics(mut self, s: DocumentStatistics) -> Self {
+        self.statistics = Some(s);
+        self
+    }
+
+    // Built-in property by its DOCPROPERTY name, e.g. "Company". The value is escaped.
+    pub(crate) fn property(&self, name: &str) -> Option<&str> {
+        let v = match name.to_ascii_lowercase().as_str() {
+            "company" => &self.company,
+            "manager" => &self.manager,
+            "template" => &self.template,
+            "hyperlinkbase" => &self.hyperlink_base,
+            _ => return None,
+        };
+        v.as_deref()
+    }
 }
 
 impl BuildXML for AppProps {
@@ -19,12 +85,30 @@ impl BuildXML for AppProps {
         &self,
         stream: xml::writer::EventWriter<W>,
     ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        let stats = self.statistics.as_ref();
         XMLBuilder::from(stream)
             .declaration(Some(true))?
             .open_properties(
                 "http://schemas.openxmlformats.org/officeDocument/2006/extended-properties",
                 "http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes",
             )?
+            .apply_opt(self.template.as_ref(), |v, b| b.template(v))?
+            .apply_opt(stats, |s, b| {
+                b.words(&s.words.to_string())?
+                    .characters(&s.characters.to_string())
+            })?
+            .apply_opt(self.application.as_ref(), |v, b| b.application(v))?
+            .apply_opt(stats, |s, b| {
+                b.lines(&s.lines.to_string())?
+                    .paragraphs(&s.paragraphs.to_string())
+            })?
+            .apply_opt(self.manager.as_ref(), |v, b| b.manager(v))?
+            .apply_opt(self.company.as_ref(), |v, b| b.company(v))?
+            .apply_opt(stats, |s, b| {
+                b.characters_with_spaces(&s.characters_with_spaces.to_string())
+            })?
+            .apply_opt(self.hyperlink_base.as_ref(), |v, b| b.hyperlink_base(v))?
+            .apply_opt(self.app_version.as_ref(), |v, b| b.app_version(v))?
             .close()?
             .into_inner()
     }
@@ -47,4 +131,27 @@ mod tests {
             r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/extended-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes" />"#
         );
     }
+
+    #[test]
+    fn test_doc_props_app_build() {
+        let c = AppProps::new()
+            .application("docx-rs")
+            .app_version("1.0000")
+            .company("R & D")
+            .manager("Alice")
+            .hyperlink_base("https://example.com/")
+            .template("Normal.dotm")
+            .statistics(DocumentStatistics {
+                words: 2,
+                characters: 10,
+                characters_with_spaces: 11,
+                paragraphs: 1,
+                lines: 1,
+            });
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/extended-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes"><Template>Normal.dotm</Template><Words>2</Words><Characters>10</Characters><Application>docx-rs</Application><Lines>1</Lines><Paragraphs>1</Paragraphs><Manager>Alice</Manager><Company>R &amp; D</Company><CharactersWithSpaces>11</CharactersWithSpaces><HyperlinkBase>https://example.com/</HyperlinkBase><AppVersion>1.0000</AppVersion></Properties>"#
+        );
+    }
 }
@@ -38,6 +38,21 @@ impl CoreProps {
         self.config.modified = Some(date.to_owned());
         self
     }
+
+    // Built-in property by its DOCPROPERTY name, e.g. "Author".
+    pub(crate) fn property(&self, name: &str) -> Option<&str> {
+        let v = match name.to_ascii_lowercase().as_str() {
+            "title" => &self.config.title,
+            "subject" => &self.config.subject,
+            "author" => &self.config.creator,
+            "comments" => &self.config.description,
+            "lastsavedby" => &self.config.last_modified_by,
+            "createtime" => &self.config.created,
+            "lastsavedtime" => &self.config.modified,
+            _ => return None,
+        };
+        v.as_deref()
+    }
 }
 
 impl CorePropsConfig {
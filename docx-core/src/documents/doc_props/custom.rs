@@ -2,12 +2,79 @@ use serde::Serialize;
 use std::io::Write;
 
 use crate::documents::BuildXML;
+use crate::escape::escape;
 use crate::xml_builder::*;
 
+/// Value of a custom document property and the `vt:*` type it is written as.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "camelCase")]
+pub enum CustomPropertyValue {
+    // vt:lpwstr
+    Text(String),
+    // vt:i4
+    Int(i32),
+    // vt:r8
+    Float(f64),
+    // vt:bool
+    Bool(bool),
+    // vt:filetime, an ISO 8601 UTC date time e.g. "2024-01-31T09:00:00Z"
+    DateTime(String),
+}
+
+impl CustomPropertyValue {
+    pub fn date_time(v: impl Into<String>) -> Self {
+        CustomPropertyValue::DateTime(v.into())
+    }
+}
+
+impl From<String> for CustomPropertyValue {
+    fn from(v: String) -> Self {
+        CustomPropertyValue::Text(v)
+    }
+}
+
+impl From<&str> for CustomPropertyValue {
+    fn from(v: &str) -> Self {
+        CustomPropertyValue::Text(v.to_owned())
+    }
+}
+
+impl From<&String> for CustomPropertyValue {
+    fn from(v: &String) -> Self {
+        CustomPropertyValue::Text(v.clone())
+    }
+}
+
+impl From<i32> for CustomPropertyValue {
+    fn from(v: i32) -> Self {
+        CustomPropertyValue::Int(v)
+    }
+}
+
+impl From<f64> for CustomPropertyValue {
+    fn from(v: f64) -> Self {
+        CustomPropertyValue::Float(v)
+    }
+}
+
+impl From<bool> for CustomPropertyValue {
+    fn from(v: bool) -> Self {
+        CustomPropertyValue::Bool(v)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomProperty {
+    pub name: String,
+    pub value: CustomPropertyValue,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomProps {
-    pub properties: std::collections::HashMap<String, String>,
+    // Kept in insertion order.
+    pub properties: Vec<CustomProperty>,
 }
 
 impl CustomProps {
@@ -15,10 +82,32 @@ impl CustomProps {
         Self::default()
     }
 
-    pub fn add_custom_property(mut self, name: impl Into<String>, item: impl Into<String>) -> Self {
-        self.properties.insert(name.into(), item.into());
+    /// Adds a property, replacing the value of an existing property with the same name.
+    pub fn add_custom_property(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<CustomPropertyValue>,
+    ) -> Self {
+        let name = escape(&name.into());
+        let value = match value.into() {
+            CustomPropertyValue::Text(v) => CustomPropertyValue::Text(escape(&v)),
+            v => v,
+        };
+        if let Some(p) = self.properties.iter_mut().find(|p| p.name == name) {
+            p.value = value;
+        } else {
+            self.properties.push(CustomProperty { name, value });
+        }
         self
     }
+
+    pub fn get(&self, name: &str) -> Option<&CustomPropertyValue> {
+        let name = escape(name);
+        self.properties
+            .iter()
+            .find(|p| p.name == name)
+            .map(|p| &p.value)
+    }
 }
 
 impl BuildXML for CustomProps {
@@ -26,24 +115,77 @@ impl BuildXML for CustomProps {
         &self,
         stream: xml::writer::EventWriter<W>,
     ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        // NaN and infinite values can not be written as `vt:r8`, so those properties are skipped.
+        let properties = self
+            .properties
+            .iter()
+            .filter(|p| !matches!(p.value, CustomPropertyValue::Float(v) if !v.is_finite()));
         XMLBuilder::from(stream)
             .declaration(Some(true))?
             .open_custom_properties(
                 "http://schemas.openxmlformats.org/officeDocument/2006/custom-properties",
                 "http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes",
             )?
-            .apply_each(self.properties.iter().enumerate(), |(i, (key, item)), b| {
-                b.open_property(
+            .apply_each(properties.enumerate(), |(i, p), b| {
+                let b = b.open_property(
                     "{D5CDD505-2E9C-101B-9397-08002B2CF9AE}",
                     // I can not find spec about this id.
                     // It is invalid if pid starts from 1...
                     &format!("{}", i + 2),
-                    key,
-                )?
-                .lpwstr(item)?
-                .close()
+                    &p.name,
+                )?;
+                let b = match &p.value {
+                    CustomPropertyValue::Text(v) => b.lpwstr(v)?,
+                    CustomPropertyValue::Int(v) => b.i4(&v.to_string())?,
+                    CustomPropertyValue::Float(v) => b.r8(&v.to_string())?,
+                    CustomPropertyValue::Bool(v) => b.vt_bool(&v.to_string())?,
+                    CustomPropertyValue::DateTime(v) => b.filetime(v)?,
+                };
+                b.close()
             })?
             .close()?
             .into_inner()
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_custom_props_build() {
+        let c = CustomProps::new()
+            .add_custom_property("Client", "R & D")
+            .add_custom_property("Version", 3)
+            .add_custom_property("Ratio", 0.5)
+            .add_custom_property("Approved", true)
+            .add_custom_property(
+                "Due",
+                CustomPropertyValue::date_time("2024-01-31T09:00:00Z"),
+            )
+            .add_custom_property("Version", 4);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/custom-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes"><property fmtid="{D5CDD505-2E9C-101B-9397-08002B2CF9AE}" pid="2" name="Client"><vt:lpwstr>R &amp; D</vt:lpwstr></property><property fmtid="{D5CDD505-2E9C-101B-9397-08002B2CF9AE}" pid="3" name="Version"><vt:i4>4</vt:i4></property><property fmtid="{D5CDD505-2E9C-101B-9397-08002B2CF9AE}" pid="4" name="Ratio"><vt:r8>0.5</vt:r8></property><property fmtid="{D5CDD505-2E9C-101B-9397-08002B2CF9AE}" pid="5" name="Approved"><vt:bool>true</vt:bool></property><property fmtid="{D5CDD505-2E9C-101B-9397-08002B2CF9AE}" pid="6" name="Due"><vt:filetime>2024-01-31T09:00:00Z</vt:filetime></property></Properties>"#
+        );
+    }
+
+    #[test]
+    fn test_custom_props_build_skips_non_finite_float() {
+        let c = CustomProps::new()
+            .add_custom_property("Nan", f64::NAN)
+            .add_custom_property("Inf", f64::INFINITY)
+            .add_custom_property("NegInf", f64::NEG_INFINITY)
+            .add_custom_property("Ratio", 0.5);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/custom-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes"><property fmtid="{D5CDD505-2E9C-101B-9397-08002B2CF9AE}" pid="2" name="Ratio"><vt:r8>0.5</vt:r8></property></Properties>"#
+        );
+    }
+}
@@ -1,12 +1,15 @@
 mod app;
 mod core;
 mod custom;
+mod statistics;
 
 pub use self::app::*;
 pub use self::core::*;
 pub use self::custom::*;
+pub use self::statistics::*;
 
 use crate::documents::BuildXML;
+use crate::escape::{escape, replace_escaped};
 
 use serde::Serialize;
 
@@ -36,11 +39,40 @@ impl DocProps {
         self
     }
 
-    pub fn custom_property(mut self, name: impl Into<String>, item: impl Into<String>) -> Self {
-        self.custom = self.custom.add_custom_property(name.into(), item.into());
+    pub fn custom_property(
+        mut self,
+        name: impl Into<String>,
+        item: impl Into<CustomPropertyValue>,
+    ) -> Self {
+        self.custom = self.custom.add_custom_property(name, item);
         self
     }
 
+    // Value of a custom or built-in property by its DOCPROPERTY name. Names are case-insensitive.
+    pub(crate) fn property_value(&self, name: &str) -> Option<CustomPropertyValue> {
+        let escaped = escape(name);
+        if let Some(p) = self
+            .custom
+            .properties
+            .iter()
+            .find(|p| p.name.eq_ignore_ascii_case(&escaped))
+        {
+            return Some(match &p.value {
+                CustomPropertyValue::Text(v) => CustomPropertyValue::Text(replace_escaped(v)),
+                v => v.clone(),
+            });
+        }
+        if let Some(v) = self.core.property(name) {
+            return Some(match name.to_ascii_lowercase().as_str() {
+                "createtime" | "lastsavedtime" => CustomPropertyValue::date_time(v),
+                _ => CustomPropertyValue::Text(v.to_owned()),
+            });
+        }
+        self.app
+            .property(name)
+            .map(|v| CustomPropertyValue::Text(replace_escaped(v)))
+    }
+
     pub(crate) fn build(&self) -> XMLDocProps {
         XMLDocProps {
             app: self.app.build(),
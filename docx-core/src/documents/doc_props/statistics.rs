@@ -0,0 +1,218 @@
+use serde::Serialize;
+
+use crate::documents::*;
+
+// Average advance of a half-width character for an 11pt font in dxa.
+// Full-width (CJK) characters are counted twice as wide.
+const HALF_WIDTH_CHAR: usize = 110;
+
+/// Document statistics written to `docProps/app.xml`.
+/// Only the main document body is counted, headers, footers, footnotes and comments are not.
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentStatistics {
+    pub words: usize,
+    pub characters: usize,
+    pub characters_with_spaces: usize,
+    pub paragraphs: usize,
+    pub lines: usize,
+}
+
+impl DocumentStatistics {
+    pub fn from_document(document: &Document) -> Self {
+        let mut texts = vec![];
+        for child in &document.children {
+            match child {
+                DocumentChild::Paragraph(p) => texts.push(paragraph_text(p)),
+                DocumentChild::Table(t) => collect_table_texts(t, &mut texts),
+                DocumentChild::StructuredDataTag(t) => collect_sdt_texts(t, &mut texts),
+                DocumentChild::Section(section) => {
+                    for child in &section.children {
+                        match child {
+                            SectionChild::Paragraph(p) => texts.push(paragraph_text(p)),
+                            SectionChild::Table(t) => collect_table_texts(t, &mut texts),
+                            SectionChild::StructuredDataTag(t) => collect_sdt_texts(t, &mut texts),
+                            _ => {}
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        let line_width = document.section_property.text_width().max(HALF_WIDTH_CHAR);
+        let mut stats = DocumentStatistics::default();
+        for text in &texts {
+            stats.add_paragraph(text, line_width);
+        }
+        stats
+    }
+
+    fn add_paragraph(&mut self, text: &str, line_width: usize) {
+        let mut in_word = false;
+        let mut width = 0;
+        for c in text.chars() {
+            self.characters_with_spaces += 1;
+            if is_full_width(c) {
+                width += HALF_WIDTH_CHAR * 2;
+            } else {
+                width += HALF_WIDTH_CHAR;
+            }
+            if c.is_whitespace() || is_cjk_punctuation(c) {
+                in_word = false;
+                if !c.is_whitespace() {
+                    self.characters += 1;
+                }
+                continue;
+            }
+            self.characters += 1;
+            // Each Chinese / Japanese character is counted as a word.
+            if is_cjk_ideograph_or_kana(c) {
+                self.words += 1;
+                in_word = false;
+            } else if !in_word {
+                self.words += 1;
+                in_word = true;
+            }
+        }
+        if !text.trim().is_empty() {
+            self.paragraphs += 1;
+        }
+        self.lines += width.div_ceil(line_width).max(1);
+    }
+}
+
+fn is_cjk_ideograph_or_kana(c: char) -> bool {
+    matches!(c as u32,
+        0x3040..=0x30FF // Hiragana, Katakana
+        | 0x3400..=0x4DBF // CJK Extension A
+        | 0x4E00..=0x9FFF // CJK Unified Ideographs
+        | 0xF900..=0xFAFF // CJK Compatibility Ideographs
+        | 0xFF66..=0xFF9F // Halfwidth Katakana
+        | 0x20000..=0x2FA1F)
+}
+
+fn is_cjk_punctuation(c: char) -> bool {
+    matches!(c as u32, 0x3000..=0x303F | 0xFF01..=0xFF0F | 0xFF1A..=0xFF20)
+}
+
+fn is_full_width(c: char) -> bool {
+    matches!(c as u32,
+        0x1100..=0x115F
+        | 0x2E80..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x20000..=0x3FFFD)
+}
+
+fn push_run_text(run: &Run, s: &mut String) {
+    for c in &run.children {
+        match c {
+            RunChild::Text(t) => s.push_str(&t.text),
+            RunChild::Tab(_) => s.push('\t'),
+            _ => {}
+        }
+    }
+}
+
+fn push_paragraph_children_text(children: &[ParagraphChild], s: &mut String) {
+    for c in children {
+        match c {
+            ParagraphChild::Run(run) => push_run_text(run, s),
+            ParagraphChild::Insert(ins) => {
+                for c in &ins.children {
+                    if let InsertChild::Run(run) = c {
+                        push_run_text(run, s);
+                    }
+                }
+            }
+            ParagraphChild::Hyperlink(h) => push_paragraph_children_text(&h.children, s),
+            ParagraphChild::Field(f) => {
+                for run in &f.result {
+                    push_run_text(run, s);
+                }
+            }
+            ParagraphChild::StructuredDataTag(tag) => {
+                for c in &tag.children {
+                    if let StructuredDataTagChild::Run(run) = c {
+                        push_run_text(run, s);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn paragraph_text(p: &Paragraph) -> String {
+    let mut s = String::new();
+    push_paragraph_children_text(&p.children, &mut s);
+    s
+}
+
+fn collect_table_texts(table: &Table, texts: &mut Vec<String>) {
+    for row in table.table_rows() {
+        for TableRowChild::TableCell(cell) in &row.cells {
+            for content in &cell.children {
+                match content {
+                    TableCellContent::Paragraph(p) => texts.push(paragraph_text(p)),
+                    TableCellContent::Table(t) => collect_table_texts(t, texts),
+                    TableCellContent::StructuredDataTag(t) => collect_sdt_texts(t, texts),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+fn collect_sdt_texts(tag: &StructuredDataTag, texts: &mut Vec<String>) {
+    for child in &tag.children {
+        match child {
+            StructuredDataTagChild::Paragraph(p) => texts.push(paragraph_text(p)),
+            StructuredDataTagChild::Table(t) => collect_table_texts(t, texts),
+            StructuredDataTagChild::StructuredDataTag(t) => collect_sdt_texts(t, texts),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::PageMargin;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_statistics() {
+        let document = Document::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello  world.")))
+            .add_paragraph(Paragraph::new())
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("日本語のテキスト")))
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Rust、docx。")));
+        let stats = DocumentStatistics::from_document(&document);
+        assert_eq!(
+            stats,
+            DocumentStatistics {
+                words: 2 + 8 + 2,
+                characters: 11 + 8 + 10,
+                characters_with_spaces: 13 + 8 + 10,
+                paragraphs: 3,
+                lines: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn test_statistics_lines() {
+        let text = "a".repeat(200);
+        let document = Document::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text(&text)))
+            .page_margin(PageMargin::new().left(1000).right(1000));
+        let stats = DocumentStatistics::from_document(&document);
+        // (11906 - 2000) / 110 = 90 chars per line
+        assert_eq!(stats.lines, 3);
+    }
+}
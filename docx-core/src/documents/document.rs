@@ -50,6 +50,18 @@ enum DocumentChildXml {
     CommentStart(XmlIdNode),
     #[serde(rename = "commentRangeEnd", alias = "w:commentRangeEnd")]
     CommentEnd(XmlIdNode),
+    #[serde(rename = "permStart", alias = "w:permStart")]
+    PermStart(XmlPermStartNode),
+    #[serde(rename = "permEnd", alias = "w:permEnd")]
+    PermEnd(XmlIdNode),
+    #[serde(rename = "moveFromRangeStart", alias = "w:moveFromRangeStart")]
+    MoveFromRangeStart(XmlMoveRangeStartNode),
+    #[serde(rename = "moveFromRangeEnd", alias = "w:moveFromRangeEnd")]
+    MoveFromRangeEnd(XmlIdNode),
+    #[serde(rename = "moveToRangeStart", alias = "w:moveToRangeStart")]
+    MoveToRangeStart(XmlMoveRangeStartNode),
+    #[serde(rename = "moveToRangeEnd", alias = "w:moveToRangeEnd")]
+    MoveToRangeEnd(XmlIdNode),
     #[serde(rename = "sdt", alias = "w:sdt")]
     StructuredDataTag(StructuredDataTag),
     #[serde(rename = "sectPr", alias = "w:sectPr")]
@@ -85,6 +97,27 @@ fn document_child_from_xml(xml: DocumentChildXml) -> Option<DocumentChild> {
             let id = parse_optional_usize_doc(node.id)?;
             Some(DocumentChild::CommentEnd(CommentRangeEnd::new(id)))
         }
+        DocumentChildXml::PermStart(node) => {
+            Some(DocumentChild::PermStart(node.into_perm_start()?))
+        }
+        DocumentChildXml::PermEnd(node) => {
+            let id = parse_optional_usize_doc(node.id)?;
+            Some(DocumentChild::PermEnd(PermEnd::new(id)))
+        }
+        DocumentChildXml::MoveFromRangeStart(node) => Some(DocumentChild::MoveFromRangeStart(
+            node.into_move_from_range_start()?,
+        )),
+        DocumentChildXml::MoveFromRangeEnd(node) => {
+            let id = parse_optional_usize_doc(node.id)?;
+            Some(DocumentChild::MoveFromRangeEnd(MoveFromRangeEnd::new(id)))
+        }
+        DocumentChildXml::MoveToRangeStart(node) => Some(DocumentChild::MoveToRangeStart(
+            node.into_move_to_range_start()?,
+        )),
+        DocumentChildXml::MoveToRangeEnd(node) => {
+            let id = parse_optional_usize_doc(node.id)?;
+            Some(DocumentChild::MoveToRangeEnd(MoveToRangeEnd::new(id)))
+        }
         DocumentChildXml::StructuredDataTag(sdt) => {
             Some(DocumentChild::StructuredDataTag(Box::new(sdt)))
         }
@@ -153,6 +186,12 @@ pub enum DocumentChild {
     BookmarkEnd(BookmarkEnd),
     CommentStart(Box<CommentRangeStart>),
     CommentEnd(CommentRangeEnd),
+    PermStart(PermStart),
+    PermEnd(PermEnd),
+    MoveFromRangeStart(MoveFromRangeStart),
+    MoveFromRangeEnd(MoveFromRangeEnd),
+    MoveToRangeStart(MoveToRangeStart),
+    MoveToRangeEnd(MoveToRangeEnd),
     StructuredDataTag(Box<StructuredDataTag>),
     TableOfContents(Box<TableOfContents>),
     Section(Box<Section>),
@@ -200,6 +239,42 @@ impl Serialize for DocumentChild {
                 t.serialize_field("data", r)?;
                 t.end()
             }
+            DocumentChild::PermStart(ref r) => {
+                let mut t = serializer.serialize_struct("PermStart", 2)?;
+                t.serialize_field("type", "permStart")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            DocumentChild::PermEnd(ref r) => {
+                let mut t = serializer.serialize_struct("PermEnd", 2)?;
+                t.serialize_field("type", "permEnd")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            DocumentChild::MoveFromRangeStart(ref r) => {
+                let mut t = serializer.serialize_struct("MoveFromRangeStart", 2)?;
+                t.serialize_field("type", "moveFromRangeStart")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            DocumentChild::MoveFromRangeEnd(ref r) => {
+                let mut t = serializer.serialize_struct("MoveFromRangeEnd", 2)?;
+                t.serialize_field("type", "moveFromRangeEnd")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            DocumentChild::MoveToRangeStart(ref r) => {
+                let mut t = serializer.serialize_struct("MoveToRangeStart", 2)?;
+                t.serialize_field("type", "moveToRangeStart")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            DocumentChild::MoveToRangeEnd(ref r) => {
+                let mut t = serializer.serialize_struct("MoveToRangeEnd", 2)?;
+                t.serialize_field("type", "moveToRangeEnd")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
             DocumentChild::StructuredDataTag(ref r) => {
                 let mut t = serializer.serialize_struct("StructuredDataTag", 2)?;
                 t.serialize_field("type", "structuredDataTag")?;
@@ -278,6 +353,39 @@ impl Document {
         self
     }
 
+    pub fn add_perm_start(mut self, perm: PermStart) -> Self {
+        self.children.push(DocumentChild::PermStart(perm));
+        self
+    }
+
+    pub fn add_perm_end(mut self, id: usize) -> Self {
+        self.children.push(DocumentChild::PermEnd(PermEnd::new(id)));
+        self
+    }
+
+    // Move ranges covering whole paragraphs or tables are marked between them.
+    pub fn add_move_from_range_start(mut self, start: MoveFromRangeStart) -> Self {
+        self.children.push(DocumentChild::MoveFromRangeStart(start));
+        self
+    }
+
+    pub fn add_move_from_range_end(mut self, id: usize) -> Self {
+        self.children
+            .push(DocumentChild::MoveFromRangeEnd(MoveFromRangeEnd::new(id)));
+        self
+    }
+
+    pub fn add_move_to_range_start(mut self, start: MoveToRangeStart) -> Self {
+        self.children.push(DocumentChild::MoveToRangeStart(start));
+        self
+    }
+
+    pub fn add_move_to_range_end(mut self, id: usize) -> Self {
+        self.children
+            .push(DocumentChild::MoveToRangeEnd(MoveToRangeEnd::new(id)));
+        self
+    }
+
     pub fn add_section(mut self, sec: Section) -> Self {
         self.children.push(DocumentChild::Section(Box::new(sec)));
         self
@@ -373,6 +481,21 @@ impl Document {
         self
     }
 
+    pub fn column_space(mut self, space: usize) -> Self {
+        self.section_property = self.section_property.column_space(space);
+        self
+    }
+
+    pub fn add_column(mut self, width: usize, space: Option<usize>) -> Self {
+        self.section_property = self.section_property.add_column(width, space);
+        self
+    }
+
+    pub fn column_separator(mut self) -> Self {
+        self.section_property = self.section_property.column_separator();
+        self
+    }
+
     pub fn text_direction(mut self, direction: String) -> Self {
         self.section_property.text_direction = direction;
         self
@@ -382,6 +505,31 @@ impl Document {
         self.section_property = self.section_property.page_num_type(p);
         self
     }
+
+    pub fn line_num_type(mut self, l: LineNumType) -> Self {
+        self.section_property = self.section_property.line_num_type(l);
+        self
+    }
+
+    pub fn page_borders(mut self, b: PageBorders) -> Self {
+        self.section_property = self.section_property.page_borders(b);
+        self
+    }
+
+    pub fn vertical_align(mut self, v: crate::types::VAlignType) -> Self {
+        self.section_property = self.section_property.vertical_align(v);
+        self
+    }
+
+    pub fn bidi(mut self) -> Self {
+        self.section_property = self.section_property.bidi();
+        self
+    }
+
+    pub fn rtl_gutter(mut self) -> Self {
+        self.section_property = self.section_property.rtl_gutter();
+        self
+    }
 }
 
 impl BuildXML for DocumentChild {
@@ -396,6 +544,12 @@ impl BuildXML for DocumentChild {
             DocumentChild::BookmarkEnd(v) => v.build_to(stream),
             DocumentChild::CommentStart(v) => v.build_to(stream),
             DocumentChild::CommentEnd(v) => v.build_to(stream),
+            DocumentChild::PermStart(v) => v.build_to(stream),
+            DocumentChild::PermEnd(v) => v.build_to(stream),
+            DocumentChild::MoveFromRangeStart(v) => v.build_to(stream),
+            DocumentChild::MoveFromRangeEnd(v) => v.build_to(stream),
+            DocumentChild::MoveToRangeStart(v) => v.build_to(stream),
+            DocumentChild::MoveToRangeEnd(v) => v.build_to(stream),
             DocumentChild::StructuredDataTag(v) => v.build_to(stream),
             DocumentChild::TableOfContents(v) => v.build_to(stream),
             DocumentChild::Section(v) => v.build_to(stream),
@@ -436,7 +590,7 @@ mod tests {
             .build();
         assert_eq!(
             str::from_utf8(&b).unwrap(),
-            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><w:document xmlns:o="urn:schemas-microsoft-com:office:office" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:v="urn:schemas-microsoft-com:vml" xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w10="urn:schemas-microsoft-com:office:word" xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" xmlns:wps="http://schemas.microsoft.com/office/word/2010/wordprocessingShape" xmlns:wpg="http://schemas.microsoft.com/office/word/2010/wordprocessingGroup" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:wp14="http://schemas.microsoft.com/office/word/2010/wordprocessingDrawing" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml" mc:Ignorable="w14 wp14"><w:body><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr><w:r><w:rPr /><w:t xml:space="preserve">Hello</w:t></w:r></w:p><w:sectPr><w:pgSz w:w="11906" w:h="16838" /><w:pgMar w:top="1985" w:right="1701" w:bottom="1701" w:left="1701" w:header="851" w:footer="992" w:gutter="0" /><w:cols w:space="425" w:num="1" /></w:sectPr></w:body></w:document>"#
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><w:document xmlns:o="urn:schemas-microsoft-com:office:office" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:v="urn:schemas-microsoft-com:vml" xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w10="urn:schemas-microsoft-com:office:word" xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" xmlns:wps="http://schemas.microsoft.com/office/word/2010/wordprocessingShape" xmlns:wpg="http://schemas.microsoft.com/office/word/2010/wordprocessingGroup" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:wp14="http://schemas.microsoft.com/office/word/2010/wordprocessingDrawing" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml" mc:Ignorable="w14 w15 wp14"><w:body><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr><w:r><w:rPr /><w:t xml:space="preserve">Hello</w:t></w:r></w:p><w:sectPr><w:pgSz w:w="11906" w:h="16838" /><w:pgMar w:top="1985" w:right="1701" w:bottom="1701" w:left="1701" w:header="851" w:footer="992" w:gutter="0" /><w:cols w:space="425" w:num="1" /></w:sectPr></w:body></w:document>"#
         );
     }
 
@@ -446,7 +600,7 @@ mod tests {
         let b = Document::new().add_table_of_contents(toc).build();
         assert_eq!(
             str::from_utf8(&b).unwrap(),
-            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><w:document xmlns:o="urn:schemas-microsoft-com:office:office" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:v="urn:schemas-microsoft-com:vml" xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w10="urn:schemas-microsoft-com:office:word" xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" xmlns:wps="http://schemas.microsoft.com/office/word/2010/wordprocessingShape" xmlns:wpg="http://schemas.microsoft.com/office/word/2010/wordprocessingGroup" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:wp14="http://schemas.microsoft.com/office/word/2010/wordprocessingDrawing" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml" mc:Ignorable="w14 wp14"><w:body><w:sdt><w:sdtPr><w:rPr /></w:sdtPr><w:sdtContent><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr><w:r><w:rPr /><w:fldChar w:fldCharType="begin" w:dirty="true" /><w:instrText>TOC \o &quot;1-3&quot;</w:instrText><w:fldChar w:fldCharType="separate" w:dirty="false" /></w:r></w:p><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr><w:r><w:rPr /><w:fldChar w:fldCharType="end" w:dirty="false" /></w:r></w:p></w:sdtContent></w:sdt><w:sectPr><w:pgSz w:w="11906" w:h="16838" /><w:pgMar w:top="1985" w:right="1701" w:bottom="1701" w:left="1701" w:header="851" w:footer="992" w:gutter="0" /><w:cols w:space="425" w:num="1" /></w:sectPr></w:body></w:document>"#
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><w:document xmlns:o="urn:schemas-microsoft-com:office:office" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:v="urn:schemas-microsoft-com:vml" xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w10="urn:schemas-microsoft-com:office:word" xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" xmlns:wps="http://schemas.microsoft.com/office/word/2010/wordprocessingShape" xmlns:wpg="http://schemas.microsoft.com/office/word/2010/wordprocessingGroup" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:wp14="http://schemas.microsoft.com/office/word/2010/wordprocessingDrawing" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml" mc:Ignorable="w14 w15 wp14"><w:body><w:sdt><w:sdtPr><w:rPr /></w:sdtPr><w:sdtContent><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr><w:r><w:rPr /><w:fldChar w:fldCharType="begin" w:dirty="true" /><w:instrText>TOC \o &quot;1-3&quot;</w:instrText><w:fldChar w:fldCharType="separate" w:dirty="false" /></w:r></w:p><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr><w:r><w:rPr /><w:fldChar w:fldCharType="end" w:dirty="false" /></w:r></w:p></w:sdtContent></w:sdt><w:sectPr><w:pgSz w:w="11906" w:h="16838" /><w:pgMar w:top="1985" w:right="1701" w:bottom="1701" w:left="1701" w:header="851" w:footer="992" w:gutter="0" /><w:cols w:space="425" w:num="1" /></w:sectPr></w:body></w:document>"#
         );
     }
 
@@ -458,7 +612,7 @@ mod tests {
             .build();
         assert_eq!(
             str::from_utf8(&b).unwrap(),
-            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><w:document xmlns:o="urn:schemas-microsoft-com:office:office" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:v="urn:schemas-microsoft-com:vml" xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w10="urn:schemas-microsoft-com:office:word" xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" xmlns:wps="http://schemas.microsoft.com/office/word/2010/wordprocessingShape" xmlns:wpg="http://schemas.microsoft.com/office/word/2010/wordprocessingGroup" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:wp14="http://schemas.microsoft.com/office/word/2010/wordprocessingDrawing" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml" mc:Ignorable="w14 wp14"><w:body><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr><w:r><w:rPr /><w:t xml:space="preserve">Hello</w:t></w:r></w:p><w:sectPr><w:pgSz w:w="11906" w:h="16838" /><w:pgMar w:top="1985" w:right="1701" w:bottom="1701" w:left="1701" w:header="851" w:footer="992" w:gutter="0" /><w:cols w:space="425" w:num="2" /></w:sectPr></w:body></w:document>"#
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><w:document xmlns:o="urn:schemas-microsoft-com:office:office" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:v="urn:schemas-microsoft-com:vml" xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w10="urn:schemas-microsoft-com:office:word" xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" xmlns:wps="http://schemas.microsoft.com/office/word/2010/wordprocessingShape" xmlns:wpg="http://schemas.microsoft.com/office/word/2010/wordprocessingGroup" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:wp14="http://schemas.microsoft.com/office/word/2010/wordprocessingDrawing" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml" mc:Ignorable="w14 w15 wp14"><w:body><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr><w:r><w:rPr /><w:t xml:space="preserve">Hello</w:t></w:r></w:p><w:sectPr><w:pgSz w:w="11906" w:h="16838" /><w:pgMar w:top="1985" w:right="1701" w:bottom="1701" w:left="1701" w:header="851" w:footer="992" w:gutter="0" /><w:cols w:space="425" w:num="2" /></w:sectPr></w:body></w:document>"#
         );
     }
 
@@ -503,4 +657,76 @@ mod tests {
         let doc: Document = quick_xml::de::from_str(xml).unwrap();
         assert!(doc.has_numbering);
     }
+
+    #[test]
+    fn test_document_xml_deserialize_perm_range() {
+        let xml = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+            <w:body>
+                <w:permStart w:id="1" w:ed="legal@example.com"/>
+                <w:p><w:r><w:t>Signature</w:t></w:r></w:p>
+                <w:permEnd w:id="1"/>
+            </w:body>
+        </w:document>"#;
+
+        let doc: Document = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(
+            doc.children[0],
+            DocumentChild::PermStart(PermStart::new(1).editor("legal@example.com"))
+        );
+        assert_eq!(doc.children[2], DocumentChild::PermEnd(PermEnd::new(1)));
+    }
+
+    #[test]
+    fn test_document_xml_deserialize_move_ranges() {
+        let xml = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+            <w:body>
+                <w:moveFromRangeStart w:id="1" w:name="move1" w:author="R&amp;D" w:date="2024-01-01T00:00:00Z"/>
+                <w:p><w:r><w:t>Moved</w:t></w:r></w:p>
+                <w:moveFromRangeEnd w:id="1"/>
+                <w:moveToRangeStart w:id="2" w:name="move1" w:author="R&amp;D" w:date="2024-01-01T00:00:00Z"/>
+                <w:p><w:r><w:t>Moved</w:t></w:r></w:p>
+                <w:moveToRangeEnd w:id="2"/>
+            </w:body>
+        </w:document>"#;
+
+        let doc: Document = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(doc.children.len(), 6);
+        assert_eq!(
+            doc.children[0],
+            DocumentChild::MoveFromRangeStart(
+                MoveFromRangeStart::new(1, "move1")
+                    .author("R&D")
+                    .date("2024-01-01T00:00:00Z")
+            )
+        );
+        assert_eq!(
+            doc.children[2],
+            DocumentChild::MoveFromRangeEnd(MoveFromRangeEnd::new(1))
+        );
+        assert_eq!(
+            doc.children[3],
+            DocumentChild::MoveToRangeStart(
+                MoveToRangeStart::new(2, "move1")
+                    .author("R&D")
+                    .date("2024-01-01T00:00:00Z")
+            )
+        );
+        assert_eq!(
+            doc.children[5],
+            DocumentChild::MoveToRangeEnd(MoveToRangeEnd::new(2))
+        );
+    }
+
+    #[test]
+    fn test_document_with_move_ranges() {
+        let b = Document::new()
+            .add_move_to_range_start(MoveToRangeStart::new(1, "move1").author("bob"))
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Moved")))
+            .add_move_to_range_end(1)
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><w:document xmlns:o="urn:schemas-microsoft-com:office:office" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:v="urn:schemas-microsoft-com:vml" xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w10="urn:schemas-microsoft-com:office:word" xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" xmlns:wps="http://schemas.microsoft.com/office/word/2010/wordprocessingShape" xmlns:wpg="http://schemas.microsoft.com/office/word/2010/wordprocessingGroup" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:wp14="http://schemas.microsoft.com/office/word/2010/wordprocessingDrawing" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml" mc:Ignorable="w14 w15 wp14"><w:body><w:moveToRangeStart w:id="1" w:name="move1" w:author="bob" w:date="1970-01-01T00:00:00Z" /><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr><w:r><w:rPr /><w:t xml:space="preserve">Moved</w:t></w:r></w:p><w:moveToRangeEnd w:id="1" /><w:sectPr><w:pgSz w:w="11906" w:h="16838" /><w:pgMar w:top="1985" w:right="1701" w:bottom="1701" w:left="1701" w:header="851" w:footer="992" w:gutter="0" /><w:cols w:space="425" w:num="1" /></w:sectPr></w:body></w:document>"#
+        );
+    }
 }
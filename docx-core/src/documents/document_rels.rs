@@ -68,6 +68,11 @@ impl BuildXML for DocumentRels {
                 "http://schemas.openxmlformats.org/officeDocument/2006/relationships/settings",
                 "settings.xml",
             )?
+            .relationship(
+                "rId4",
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/theme",
+                "theme/theme1.xml",
+            )?
             .relationship(
                 "rId5",
                 "http://schemas.microsoft.com/office/2011/relationships/commentsExtended",
@@ -78,6 +83,21 @@ impl BuildXML for DocumentRels {
                     "rId6",
                     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/comments",
                     "comments.xml",
+                )?
+                .relationship(
+                    "rIdCommentsIds",
+                    "http://schemas.microsoft.com/office/2016/09/relationships/commentsIds",
+                    "commentsIds.xml",
+                )?
+                .relationship(
+                    "rIdCommentsExtensible",
+                    "http://schemas.microsoft.com/office/2018/08/relationships/commentsExtensible",
+                    "commentsExtensible.xml",
+                )?
+                .relationship(
+                    "rIdPeople",
+                    "http://schemas.microsoft.com/office/2011/relationships/people",
+                    "people.xml",
                 )
             })?
             .apply_if(self.has_numberings, |b| {
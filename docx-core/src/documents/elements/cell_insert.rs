@@ -0,0 +1,69 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::{BuildXML, HistoryId};
+use crate::escape;
+use crate::xml_builder::*;
+
+// A tracked insertion of a table cell.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct CellInsert {
+    pub author: String,
+    pub date: String,
+}
+
+impl Default for CellInsert {
+    fn default() -> CellInsert {
+        CellInsert {
+            author: "unnamed".to_owned(),
+            date: "1970-01-01T00:00:00Z".to_owned(),
+        }
+    }
+}
+
+impl CellInsert {
+    pub fn new() -> CellInsert {
+        Default::default()
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> CellInsert {
+        self.author = escape::escape(&author.into());
+        self
+    }
+
+    pub fn date(mut self, date: impl Into<String>) -> CellInsert {
+        self.date = date.into();
+        self
+    }
+}
+
+impl HistoryId for CellInsert {}
+
+impl BuildXML for CellInsert {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .cell_insert(&self.generate(), &self.author, &self.date)?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_cell_insert() {
+        let b = CellInsert::new().author("bob").build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:cellIns w:id="123" w:author="bob" w:date="1970-01-01T00:00:00Z" />"#
+        );
+    }
+}
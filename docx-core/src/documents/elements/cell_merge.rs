@@ -0,0 +1,113 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::{BuildXML, HistoryId, VMerge};
+use crate::escape;
+use crate::types::*;
+use crate::xml_builder::*;
+
+// A tracked change of the vertical merge of a table cell.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CellMerge {
+    pub author: String,
+    pub date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub v_merge: Option<VMerge>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub v_merge_orig: Option<VMerge>,
+}
+
+impl Default for CellMerge {
+    fn default() -> CellMerge {
+        CellMerge {
+            author: "unnamed".to_owned(),
+            date: "1970-01-01T00:00:00Z".to_owned(),
+            v_merge: None,
+            v_merge_orig: None,
+        }
+    }
+}
+
+// The attributes use "cont" and "rest" instead of the values of w:vMerge.
+fn annotation_v_merge(v: &VMerge) -> Option<&'static str> {
+    match v.val {
+        VMergeType::Continue => Some("cont"),
+        VMergeType::Restart => Some("rest"),
+        VMergeType::Unsupported => None,
+    }
+}
+
+pub(crate) fn parse_annotation_v_merge(v: &str) -> Option<VMergeType> {
+    match v {
+        "cont" => Some(VMergeType::Continue),
+        "rest" => Some(VMergeType::Restart),
+        _ => None,
+    }
+}
+
+impl CellMerge {
+    pub fn new() -> CellMerge {
+        Default::default()
+    }
+
+    pub fn v_merge(mut self, v: VMergeType) -> CellMerge {
+        self.v_merge = Some(VMerge::new(v));
+        self
+    }
+
+    pub fn v_merge_orig(mut self, v: VMergeType) -> CellMerge {
+        self.v_merge_orig = Some(VMerge::new(v));
+        self
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> CellMerge {
+        self.author = escape::escape(&author.into());
+        self
+    }
+
+    pub fn date(mut self, date: impl Into<String>) -> CellMerge {
+        self.date = date.into();
+        self
+    }
+}
+
+impl HistoryId for CellMerge {}
+
+impl BuildXML for CellMerge {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .cell_merge(
+                &self.generate(),
+                &self.author,
+                &self.date,
+                self.v_merge.as_ref().and_then(annotation_v_merge),
+                self.v_merge_orig.as_ref().and_then(annotation_v_merge),
+            )?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_cell_merge() {
+        let b = CellMerge::new()
+            .v_merge(VMergeType::Continue)
+            .v_merge_orig(VMergeType::Restart)
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:cellMerge w:id="123" w:author="unnamed" w:date="1970-01-01T00:00:00Z" w:vMerge="cont" w:vMergeOrig="rest" />"#
+        );
+    }
+}
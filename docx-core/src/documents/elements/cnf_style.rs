@@ -0,0 +1,36 @@
+use serde::{Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// Conditional table style formatting applied to the paragraph (17.3.1.8).
+// `val` is the 12 digit bitmask written by Word, e.g. "100000000000" for the first row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CnfStyle {
+    val: String,
+}
+
+impl CnfStyle {
+    pub fn new(val: impl Into<String>) -> CnfStyle {
+        CnfStyle { val: val.into() }
+    }
+}
+
+impl BuildXML for CnfStyle {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream).cnf_style(&self.val)?.into_inner()
+    }
+}
+
+impl Serialize for CnfStyle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.val)
+    }
+}
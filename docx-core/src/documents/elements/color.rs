@@ -1,16 +1,17 @@
-use serde::{Deserialize, Serialize, Serializer};
+use serde::{Serialize, Serializer};
 use std::io::Write;
 
 use crate::documents::BuildXML;
+use crate::types::*;
 use crate::xml_builder::*;
 
-#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Color {
-    val: String,
+    val: ColorValue,
 }
 
 impl Color {
-    pub fn new(val: impl Into<String>) -> Color {
+    pub fn new(val: impl Into<ColorValue>) -> Color {
         Color { val: val.into() }
     }
 }
@@ -29,7 +30,7 @@ impl Serialize for Color {
     where
         S: Serializer,
     {
-        serializer.serialize_str(&self.val)
+        self.val.serialize(serializer)
     }
 }
 
@@ -47,4 +48,14 @@ mod tests {
         let b = c.build();
         assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:color w:val="FFFFFF" />"#);
     }
+
+    #[test]
+    fn test_build_theme_color() {
+        let c = Color::new(ColorValue::theme(ThemeColorType::Accent1).shade(0xBF));
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:color w:val="auto" w:themeColor="accent1" w:themeShade="BF" />"#
+        );
+    }
 }
@@ -0,0 +1,249 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
+
+// Colors are RGB hex values without '#'.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct ColorScheme {
+    pub name: String,
+    pub dk1: String,
+    pub lt1: String,
+    pub dk2: String,
+    pub lt2: String,
+    pub accent1: String,
+    pub accent2: String,
+    pub accent3: String,
+    pub accent4: String,
+    pub accent5: String,
+    pub accent6: String,
+    pub hlink: String,
+    pub fol_hlink: String,
+}
+
+impl Default for ColorScheme {
+    fn default() -> Self {
+        ColorScheme {
+            name: "Office".to_owned(),
+            dk1: "000000".to_owned(),
+            lt1: "FFFFFF".to_owned(),
+            dk2: "44546A".to_owned(),
+            lt2: "E7E6E6".to_owned(),
+            accent1: "4472C4".to_owned(),
+            accent2: "ED7D31".to_owned(),
+            accent3: "A5A5A5".to_owned(),
+            accent4: "FFC000".to_owned(),
+            accent5: "5B9BD5".to_owned(),
+            accent6: "70AD47".to_owned(),
+            hlink: "0563C1".to_owned(),
+            fol_hlink: "954F72".to_owned(),
+        }
+    }
+}
+
+impl ColorScheme {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Sets the scheme slot referenced by `t`.
+    /// `Text1`/`Background1`/`Text2`/`Background2` map to `dk1`/`lt1`/`dk2`/`lt2`; `None` is ignored.
+    pub fn color(mut self, t: ThemeColorType, val: impl Into<String>) -> Self {
+        if let Some(slot) = self.slot_mut(t) {
+            *slot = val.into();
+        }
+        self
+    }
+
+    pub fn get(&self, t: ThemeColorType) -> Option<&str> {
+        let v = match t {
+            ThemeColorType::Dark1 | ThemeColorType::Text1 => &self.dk1,
+            ThemeColorType::Light1 | ThemeColorType::Background1 => &self.lt1,
+            ThemeColorType::Dark2 | ThemeColorType::Text2 => &self.dk2,
+            ThemeColorType::Light2 | ThemeColorType::Background2 => &self.lt2,
+            ThemeColorType::Accent1 => &self.accent1,
+            ThemeColorType::Accent2 => &self.accent2,
+            ThemeColorType::Accent3 => &self.accent3,
+            ThemeColorType::Accent4 => &self.accent4,
+            ThemeColorType::Accent5 => &self.accent5,
+            ThemeColorType::Accent6 => &self.accent6,
+            ThemeColorType::Hyperlink => &self.hlink,
+            ThemeColorType::FollowedHyperlink => &self.fol_hlink,
+            ThemeColorType::None => return None,
+        };
+        Some(v)
+    }
+
+    fn slot_mut(&mut self, t: ThemeColorType) -> Option<&mut String> {
+        let v = match t {
+            ThemeColorType::Dark1 | ThemeColorType::Text1 => &mut self.dk1,
+            ThemeColorType::Light1 | ThemeColorType::Background1 => &mut self.lt1,
+            ThemeColorType::Dark2 | ThemeColorType::Text2 => &mut self.dk2,
+            ThemeColorType::Light2 | ThemeColorType::Background2 => &mut self.lt2,
+            ThemeColorType::Accent1 => &mut self.accent1,
+            ThemeColorType::Accent2 => &mut self.accent2,
+            ThemeColorType::Accent3 => &mut self.accent3,
+            ThemeColorType::Accent4 => &mut self.accent4,
+            ThemeColorType::Accent5 => &mut self.accent5,
+            ThemeColorType::Accent6 => &mut self.accent6,
+            ThemeColorType::Hyperlink => &mut self.hlink,
+            ThemeColorType::FollowedHyperlink => &mut self.fol_hlink,
+            ThemeColorType::None => return None,
+        };
+        Some(v)
+    }
+}
+
+impl BuildXML for ColorScheme {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .open_clr_scheme(&self.name)?
+            .scheme_color("a:dk1", &self.dk1)?
+            .scheme_color("a:lt1", &self.lt1)?
+            .scheme_color("a:dk2", &self.dk2)?
+            .scheme_color("a:lt2", &self.lt2)?
+            .scheme_color("a:accent1", &self.accent1)?
+            .scheme_color("a:accent2", &self.accent2)?
+            .scheme_color("a:accent3", &self.accent3)?
+            .scheme_color("a:accent4", &self.accent4)?
+            .scheme_color("a:accent5", &self.accent5)?
+            .scheme_color("a:accent6", &self.accent6)?
+            .scheme_color("a:hlink", &self.hlink)?
+            .scheme_color("a:folHlink", &self.fol_hlink)?
+            .close()?
+            .into_inner()
+    }
+}
+
+// ============================================================================
+// XML Deserialization (quick-xml serde)
+// ============================================================================
+
+#[derive(Deserialize)]
+struct SrgbColorXml {
+    #[serde(rename = "@val", default)]
+    val: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct SysColorXml {
+    #[serde(rename = "@lastClr", default)]
+    last_clr: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+struct SchemeColorXml {
+    #[serde(rename = "srgbClr", alias = "a:srgbClr", default)]
+    srgb: Option<SrgbColorXml>,
+    #[serde(rename = "sysClr", alias = "a:sysClr", default)]
+    sys: Option<SysColorXml>,
+}
+
+impl SchemeColorXml {
+    fn into_val(self) -> Option<String> {
+        self.srgb
+            .and_then(|c| c.val)
+            .or_else(|| self.sys.and_then(|c| c.last_clr))
+    }
+}
+
+#[derive(Deserialize, Default)]
+struct ColorSchemeXml {
+    #[serde(rename = "@name", default)]
+    name: Option<String>,
+    #[serde(rename = "dk1", alias = "a:dk1", default)]
+    dk1: Option<SchemeColorXml>,
+    #[serde(rename = "lt1", alias = "a:lt1", default)]
+    lt1: Option<SchemeColorXml>,
+    #[serde(rename = "dk2", alias = "a:dk2", default)]
+    dk2: Option<SchemeColorXml>,
+    #[serde(rename = "lt2", alias = "a:lt2", default)]
+    lt2: Option<SchemeColorXml>,
+    #[serde(rename = "accent1", alias = "a:accent1", default)]
+    accent1: Option<SchemeColorXml>,
+    #[serde(rename = "accent2", alias = "a:accent2", default)]
+    accent2: Option<SchemeColorXml>,
+    #[serde(rename = "accent3", alias = "a:accent3", default)]
+    accent3: Option<SchemeColorXml>,
+    #[serde(rename = "accent4", alias = "a:accent4", default)]
+    accent4: Option<SchemeColorXml>,
+    #[serde(rename = "accent5", alias = "a:accent5", default)]
+    accent5: Option<SchemeColorXml>,
+    #[serde(rename = "accent6", alias = "a:accent6", default)]
+    accent6: Option<SchemeColorXml>,
+    #[serde(rename = "hlink", alias = "a:hlink", default)]
+    hlink: Option<SchemeColorXml>,
+    #[serde(rename = "folHlink", alias = "a:folHlink", default)]
+    fol_hlink: Option<SchemeColorXml>,
+}
+
+impl<'de> Deserialize<'de> for ColorScheme {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let xml = ColorSchemeXml::deserialize(deserializer)?;
+        let mut scheme = ColorScheme::default();
+        if let Some(name) = xml.name {
+            scheme.name = name;
+        }
+        let slots = [
+            (xml.dk1, ThemeColorType::Dark1),
+            (xml.lt1, ThemeColorType::Light1),
+            (xml.dk2, ThemeColorType::Dark2),
+            (xml.lt2, ThemeColorType::Light2),
+            (xml.accent1, ThemeColorType::Accent1),
+            (xml.accent2, ThemeColorType::Accent2),
+            (xml.accent3, ThemeColorType::Accent3),
+            (xml.accent4, ThemeColorType::Accent4),
+            (xml.accent5, ThemeColorType::Accent5),
+            (xml.accent6, ThemeColorType::Accent6),
+            (xml.hlink, ThemeColorType::Hyperlink),
+            (xml.fol_hlink, ThemeColorType::FollowedHyperlink),
+        ];
+        for (c, t) in slots {
+            if let Some(v) = c.and_then(|c| c.into_val()) {
+                scheme = scheme.color(t, v);
+            }
+        }
+        Ok(scheme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_color_scheme_xml_deserialize() {
+        let xml = r#"<a:clrScheme xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" name="Corporate">
+            <a:dk1><a:sysClr val="windowText" lastClr="111111"/></a:dk1>
+            <a:lt1><a:sysClr val="window" lastClr="FFFFFF"/></a:lt1>
+            <a:accent1><a:srgbClr val="C00000"/></a:accent1>
+        </a:clrScheme>"#;
+        let scheme: ColorScheme = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(
+            scheme,
+            ColorScheme::new()
+                .name("Corporate")
+                .color(ThemeColorType::Text1, "111111")
+                .color(ThemeColorType::Accent1, "C00000")
+        );
+    }
+}
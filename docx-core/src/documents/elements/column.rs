@@ -0,0 +1,57 @@
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+use serde::Serialize;
+use std::io::Write;
+
+/// A single column of a section with unequal column widths (`w:col`).
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct Column {
+    pub width: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub space: Option<usize>,
+}
+
+impl Column {
+    pub fn new(width: usize) -> Self {
+        Self { width, space: None }
+    }
+
+    pub fn space(self, s: usize) -> Self {
+        Self {
+            space: Some(s),
+            ..self
+        }
+    }
+}
+
+impl BuildXML for Column {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .column(self.width, self.space)?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_column() {
+        let b = Column::new(4000).space(720).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:col w:w="4000" w:space="720" />"#
+        );
+    }
+}
@@ -13,6 +13,12 @@ pub struct Comment {
     pub date: String,
     pub children: Vec<CommentChild>,
     pub parent_comment_id: Option<usize>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub done: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub durable_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_utc: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -68,6 +74,9 @@ impl<'de> Deserialize<'de> for Comment {
             date: xml.date,
             children,
             parent_comment_id: None,
+            done: false,
+            durable_id: None,
+            date_utc: None,
         })
     }
 }
@@ -102,6 +111,9 @@ impl Default for Comment {
             date: "1970-01-01T00:00:00Z".to_owned(),
             children: vec![],
             parent_comment_id: None,
+            done: false,
+            durable_id: None,
+            date_utc: None,
         }
     }
 }
@@ -139,9 +151,33 @@ impl Comment {
         self
     }
 
+    // Mark this comment thread as resolved.
+    pub fn done(mut self) -> Comment {
+        self.done = true;
+        self
+    }
+
+    pub fn durable_id(mut self, durable_id: impl Into<String>) -> Comment {
+        self.durable_id = Some(durable_id.into());
+        self
+    }
+
+    pub fn date_utc(mut self, date: impl Into<String>) -> Comment {
+        self.date_utc = Some(date.into());
+        self
+    }
+
     pub fn id(&self) -> usize {
         self.id
     }
+
+    // The last paragraph id is the one Word refers to from commentsExtended and commentsIds.
+    pub(crate) fn paragraph_id(&self) -> Option<&String> {
+        self.children.iter().rev().find_map(|c| match c {
+            CommentChild::Paragraph(p) => Some(&p.id),
+            CommentChild::Table(_) => None,
+        })
+    }
 }
 
 impl BuildXML for CommentChild {
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// i.e. <w16cid:commentId w16cid:paraId="37D90BEC" w16cid:durableId="4C7250AB" />
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentDurableId {
+    #[serde(
+        rename(serialize = "paragraphId", deserialize = "@paraId"),
+        alias = "@w16cid:paraId",
+        alias = "paragraphId"
+    )]
+    pub paragraph_id: String,
+    #[serde(
+        rename(serialize = "durableId", deserialize = "@durableId"),
+        alias = "@w16cid:durableId",
+        alias = "durableId"
+    )]
+    pub durable_id: String,
+}
+
+impl CommentDurableId {
+    pub fn new(paragraph_id: impl Into<String>, durable_id: impl Into<String>) -> Self {
+        Self {
+            paragraph_id: paragraph_id.into(),
+            durable_id: durable_id.into(),
+        }
+    }
+}
+
+impl BuildXML for CommentDurableId {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .comment_durable_id(&self.paragraph_id, &self.durable_id)?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_comment_durable_id() {
+        let b = CommentDurableId::new("37D90BEC", "4C7250AB").build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w16cid:commentId w16cid:paraId="37D90BEC" w16cid:durableId="4C7250AB" />"#
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// i.e. <w16cex:commentExtensible w16cex:durableId="4C7250AB" w16cex:dateUtc="2024-09-06T02:13:00Z" />
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentExtensible {
+    #[serde(
+        rename(serialize = "durableId", deserialize = "@durableId"),
+        alias = "@w16cex:durableId",
+        alias = "durableId"
+    )]
+    pub durable_id: String,
+    #[serde(
+        rename(serialize = "dateUtc", deserialize = "@dateUtc"),
+        alias = "@w16cex:dateUtc",
+        alias = "dateUtc",
+        default
+    )]
+    pub date_utc: Option<String>,
+}
+
+impl CommentExtensible {
+    pub fn new(durable_id: impl Into<String>) -> Self {
+        Self {
+            durable_id: durable_id.into(),
+            date_utc: None,
+        }
+    }
+
+    pub fn date_utc(mut self, date: impl Into<String>) -> Self {
+        self.date_utc = Some(date.into());
+        self
+    }
+}
+
+impl BuildXML for CommentExtensible {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .comment_extensible(&self.durable_id, &self.date_utc)?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_comment_extensible() {
+        let b = CommentExtensible::new("4C7250AB")
+            .date_utc("2024-09-06T02:13:00Z")
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w16cex:commentExtensible w16cex:durableId="4C7250AB" w16cex:dateUtc="2024-09-06T02:13:00Z" />"#
+        );
+    }
+}
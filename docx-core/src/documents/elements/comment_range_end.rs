@@ -13,6 +13,10 @@ impl CommentRangeEnd {
     pub fn new(id: usize) -> CommentRangeEnd {
         CommentRangeEnd { id }
     }
+
+    pub(crate) fn get_id(&self) -> usize {
+        self.id
+    }
 }
 
 impl BuildXML for CommentRangeEnd {
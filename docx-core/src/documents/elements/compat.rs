@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+const WORD_URI: &str = "http://schemas.microsoft.com/office/word";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompatSetting {
+    pub name: String,
+    pub uri: String,
+    pub val: String,
+}
+
+impl CompatSetting {
+    pub fn new(name: impl Into<String>, uri: impl Into<String>, val: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            uri: uri.into(),
+            val: val.into(),
+        }
+    }
+}
+
+impl BuildXML for CompatSetting {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .compat_setting(&self.name, &self.uri, &self.val)?
+            .into_inner()
+    }
+}
+
+// w:compat. characterSpacingControl and adjustLineHeightInTable are kept on Settings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Compat {
+    pub space_for_ul: bool,
+    pub balance_single_byte_double_byte_width: bool,
+    pub do_not_leave_backslash_alone: bool,
+    pub ul_trail_space: bool,
+    pub do_not_expand_shift_return: bool,
+    pub use_fe_layout: bool,
+    pub settings: Vec<CompatSetting>,
+}
+
+impl Compat {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    // Without any flag or setting, e.g. for a read w:compat.
+    pub fn empty() -> Self {
+        Self {
+            space_for_ul: false,
+            balance_single_byte_double_byte_width: false,
+            do_not_leave_backslash_alone: false,
+            ul_trail_space: false,
+            do_not_expand_shift_return: false,
+            use_fe_layout: false,
+            settings: vec![],
+        }
+    }
+
+    // Replaces the setting with the same name and uri.
+    pub fn add_setting(
+        mut self,
+        name: impl Into<String>,
+        uri: impl Into<String>,
+        val: impl Into<String>,
+    ) -> Self {
+        let setting = CompatSetting::new(name, uri, val);
+        if let Some(s) = self
+            .settings
+            .iter_mut()
+            .find(|s| s.name == setting.name && s.uri == setting.uri)
+        {
+            *s = setting;
+        } else {
+            self.settings.push(setting);
+        }
+        self
+    }
+
+    // 15 is Word 2013 and later. Lower values open the document in Compatibility Mode.
+    pub fn compatibility_mode(self, mode: usize) -> Self {
+        self.add_setting("compatibilityMode", WORD_URI, mode.to_string())
+    }
+
+    pub fn get_compatibility_mode(&self) -> Option<usize> {
+        self.settings
+            .iter()
+            .find(|s| s.name == "compatibilityMode" && s.uri == WORD_URI)
+            .and_then(|s| s.val.parse().ok())
+    }
+}
+
+impl Default for Compat {
+    fn default() -> Self {
+        Self {
+            space_for_ul: true,
+            balance_single_byte_double_byte_width: true,
+            do_not_leave_backslash_alone: true,
+            ul_trail_space: true,
+            do_not_expand_shift_return: true,
+            use_fe_layout: true,
+            settings: vec![],
+        }
+        .compatibility_mode(15)
+        .add_setting("overrideTableStyleFontSizeAndJustification", WORD_URI, "1")
+        .add_setting("enableOpenTypeFeatures", WORD_URI, "1")
+        .add_setting("doNotFlipMirrorIndents", WORD_URI, "1")
+        .add_setting("differentiateMultirowTableHeaders", WORD_URI, "1")
+        .add_setting("useWord2013TrackBottomHyphenation", WORD_URI, "0")
+    }
+}
@@ -0,0 +1,531 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use std::io::Write;
+
+use super::*;
+use crate::documents::BuildXML;
+use crate::escape::escape;
+use crate::xml_builder::*;
+
+// ============================================================================
+// XML Deserialization Helper Structures (for quick-xml serde)
+// ============================================================================
+
+#[derive(Debug, Deserialize, Default)]
+struct XmlW14ValNode {
+    #[serde(rename = "@val", alias = "@w14:val", default)]
+    val: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct XmlCheckBoxStateNode {
+    #[serde(rename = "@val", alias = "@w14:val", default)]
+    val: Option<String>,
+    #[serde(rename = "@font", alias = "@w14:font", default)]
+    font: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct CheckBoxXml {
+    #[serde(rename = "checked", alias = "w14:checked", default)]
+    checked: Option<XmlW14ValNode>,
+    #[serde(rename = "checkedState", alias = "w14:checkedState", default)]
+    checked_state: Option<XmlCheckBoxStateNode>,
+    #[serde(rename = "uncheckedState", alias = "w14:uncheckedState", default)]
+    unchecked_state: Option<XmlCheckBoxStateNode>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct XmlValNode {
+    #[serde(rename = "@val", alias = "@w:val", default)]
+    val: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct ListItemXml {
+    #[serde(rename = "@displayText", alias = "@w:displayText", default)]
+    display_text: Option<String>,
+    #[serde(rename = "@value", alias = "@w:value", default)]
+    value: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct ListControlXml {
+    #[serde(rename = "@lastValue", alias = "@w:lastValue", default)]
+    last_value: Option<String>,
+    #[serde(rename = "listItem", alias = "w:listItem", default)]
+    items: Vec<ListItemXml>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct DateControlXml {
+    #[serde(rename = "@fullDate", alias = "@w:fullDate", default)]
+    full_date: Option<String>,
+    #[serde(rename = "dateFormat", alias = "w:dateFormat", default)]
+    date_format: Option<XmlValNode>,
+    #[serde(rename = "lid", alias = "w:lid", default)]
+    lid: Option<XmlValNode>,
+    #[serde(rename = "storeMappedDataAs", alias = "w:storeMappedDataAs", default)]
+    store_mapped_data_as: Option<XmlValNode>,
+    #[serde(rename = "calendar", alias = "w:calendar", default)]
+    calendar: Option<XmlValNode>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct TextControlXml {
+    #[serde(rename = "@multiLine", alias = "@w:multiLine", default)]
+    multi_line: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct XmlW15ValNode {
+    #[serde(rename = "@val", alias = "@w15:val", default)]
+    val: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct RepeatingSectionXml {
+    #[serde(rename = "sectionTitle", alias = "w15:sectionTitle", default)]
+    section_title: Option<XmlW15ValNode>,
+    #[serde(
+        rename = "doNotAllowInsertDeleteSection",
+        alias = "w15:doNotAllowInsertDeleteSection",
+        default
+    )]
+    do_not_allow_insert_delete_section: Option<XmlW15ValNode>,
+}
+
+fn is_on(v: &str) -> bool {
+    !matches!(v.trim(), "0" | "false" | "off")
+}
+
+// A symbol of a check box state. `val` is the hex code point, e.g. "2612" for ☒.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckBoxSymbol {
+    pub val: String,
+    pub font: String,
+}
+
+impl CheckBoxSymbol {
+    pub fn new(val: impl Into<String>, font: impl Into<String>) -> Self {
+        Self {
+            val: val.into(),
+            font: font.into(),
+        }
+    }
+
+    pub fn to_char(&self) -> Option<char> {
+        u32::from_str_radix(&self.val, 16)
+            .ok()
+            .and_then(char::from_u32)
+    }
+}
+
+// w14:checkbox
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckBox {
+    pub checked: bool,
+    pub checked_state: CheckBoxSymbol,
+    pub unchecked_state: CheckBoxSymbol,
+}
+
+impl Default for CheckBox {
+    fn default() -> Self {
+        Self {
+            checked: false,
+            checked_state: CheckBoxSymbol::new("2612", "MS Gothic"),
+            unchecked_state: CheckBoxSymbol::new("2610", "MS Gothic"),
+        }
+    }
+}
+
+impl CheckBox {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    pub fn checked_state(mut self, s: CheckBoxSymbol) -> Self {
+        self.checked_state = s;
+        self
+    }
+
+    pub fn unchecked_state(mut self, s: CheckBoxSymbol) -> Self {
+        self.unchecked_state = s;
+        self
+    }
+
+    // The run to put in the content so that Word shows the current state.
+    pub fn symbol_run(&self) -> Run {
+        let symbol = if self.checked {
+            &self.checked_state
+        } else {
+            &self.unchecked_state
+        };
+        Run::new()
+            .fonts(
+                RunFonts::new()
+                    .ascii(&symbol.font)
+                    .east_asia(&symbol.font)
+                    .hi_ansi(&symbol.font),
+            )
+            .add_text(symbol.to_char().map(String::from).unwrap_or_default())
+    }
+}
+
+impl<'de> Deserialize<'de> for CheckBox {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let xml = CheckBoxXml::deserialize(deserializer)?;
+        let default = CheckBox::default();
+        let symbol = |s: Option<XmlCheckBoxStateNode>, default: CheckBoxSymbol| match s {
+            Some(XmlCheckBoxStateNode {
+                val: Some(val),
+                font,
+            }) => CheckBoxSymbol::new(val, font.unwrap_or(default.font)),
+            _ => default,
+        };
+        Ok(CheckBox {
+            checked: xml
+                .checked
+                .and_then(|c| c.val)
+                .map(|v| is_on(&v))
+                .unwrap_or(false),
+            checked_state: symbol(xml.checked_state, default.checked_state),
+            unchecked_state: symbol(xml.unchecked_state, default.unchecked_state),
+        })
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ListItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_text: Option<String>,
+    pub value: String,
+}
+
+impl ListItem {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self {
+            display_text: None,
+            value: escape(&value.into()),
+        }
+    }
+
+    pub fn display_text(mut self, t: impl Into<String>) -> Self {
+        self.display_text = Some(escape(&t.into()));
+        self
+    }
+
+    // Word shows the value when the display text is omitted.
+    pub fn text(&self) -> &str {
+        self.display_text.as_deref().unwrap_or(&self.value)
+    }
+}
+
+// w:dropDownList or w:comboBox
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ListControl {
+    pub items: Vec<ListItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_value: Option<String>,
+}
+
+impl ListControl {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn add_item(mut self, item: ListItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub fn last_value(mut self, v: impl Into<String>) -> Self {
+        self.last_value = Some(escape(&v.into()));
+        self
+    }
+}
+
+impl<'de> Deserialize<'de> for ListControl {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let xml = ListControlXml::deserialize(deserializer)?;
+        Ok(ListControl {
+            items: xml
+                .items
+                .into_iter()
+                .filter_map(|i| {
+                    let item = ListItem::new(i.value.or_else(|| i.display_text.clone())?);
+                    Some(match i.display_text {
+                        Some(t) => item.display_text(t),
+                        None => item,
+                    })
+                })
+                .collect(),
+            last_value: xml.last_value.map(|v| escape(&v)),
+        })
+    }
+}
+
+// w:date
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DateControl {
+    // The selected date in ISO 8601, e.g. "2024-01-31T00:00:00Z".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store_mapped_data_as: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calendar: Option<String>,
+}
+
+impl DateControl {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn full_date(mut self, d: impl Into<String>) -> Self {
+        self.full_date = Some(d.into());
+        self
+    }
+
+    pub fn date_format(mut self, f: impl Into<String>) -> Self {
+        self.date_format = Some(f.into());
+        self
+    }
+
+    pub fn lid(mut self, lid: impl Into<String>) -> Self {
+        self.lid = Some(lid.into());
+        self
+    }
+
+    pub fn store_mapped_data_as(mut self, v: impl Into<String>) -> Self {
+        self.store_mapped_data_as = Some(v.into());
+        self
+    }
+
+    pub fn calendar(mut self, v: impl Into<String>) -> Self {
+        self.calendar = Some(v.into());
+        self
+    }
+}
+
+impl<'de> Deserialize<'de> for DateControl {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let xml = DateControlXml::deserialize(deserializer)?;
+        Ok(DateControl {
+            full_date: xml.full_date,
+            date_format: xml.date_format.and_then(|v| v.val),
+            lid: xml.lid.and_then(|v| v.val),
+            store_mapped_data_as: xml.store_mapped_data_as.and_then(|v| v.val),
+            calendar: xml.calendar.and_then(|v| v.val),
+        })
+    }
+}
+
+// w:text
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TextControl {
+    pub multi_line: bool,
+}
+
+impl TextControl {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn multi_line(mut self) -> Self {
+        self.multi_line = true;
+        self
+    }
+}
+
+impl<'de> Deserialize<'de> for TextControl {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let xml = TextControlXml::deserialize(deserializer)?;
+        Ok(TextControl {
+            multi_line: xml.multi_line.map(|v| is_on(&v)).unwrap_or(false),
+        })
+    }
+}
+
+// w15:repeatingSection
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RepeatingSection {
+    pub section_title: Option<String>,
+    pub do_not_allow_insert_delete_section: bool,
+}
+
+impl RepeatingSection {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn section_title(mut self, v: impl Into<String>) -> Self {
+        self.section_title = Some(escape(&v.into()));
+        self
+    }
+
+    pub fn do_not_allow_insert_delete_section(mut self) -> Self {
+        self.do_not_allow_insert_delete_section = true;
+        self
+    }
+}
+
+impl<'de> Deserialize<'de> for RepeatingSection {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let xml = RepeatingSectionXml::deserialize(deserializer)?;
+        Ok(RepeatingSection {
+            section_title: xml.section_title.and_then(|n| n.val).map(|v| escape(&v)),
+            do_not_allow_insert_delete_section: xml
+                .do_not_allow_insert_delete_section
+                .map(|n| n.val.map(|v| is_on(&v)).unwrap_or(true))
+                .unwrap_or(false),
+        })
+    }
+}
+
+// The type of a content control. Rich text controls have none.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", content = "data", rename_all = "camelCase")]
+pub enum ContentControl {
+    CheckBox(CheckBox),
+    DropDownList(ListControl),
+    ComboBox(ListControl),
+    Date(DateControl),
+    Text(TextControl),
+    Picture,
+    // Wraps repeating section items. Word lets users add and remove items.
+    RepeatingSection(RepeatingSection),
+    RepeatingSectionItem,
+}
+
+impl BuildXML for ContentControl {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        let b = XMLBuilder::from(stream);
+        let b = match self {
+            ContentControl::CheckBox(c) => b
+                .open_sdt_checkbox()?
+                .sdt_checked(if c.checked { "1" } else { "0" })?
+                .sdt_checked_state(&c.checked_state.val, &c.checked_state.font)?
+                .sdt_unchecked_state(&c.unchecked_state.val, &c.unchecked_state.font)?
+                .close()?,
+            ContentControl::DropDownList(l) | ContentControl::ComboBox(l) => {
+                let el = if matches!(self, ContentControl::ComboBox(_)) {
+                    "w:comboBox"
+                } else {
+                    "w:dropDownList"
+                };
+                b.open_sdt_list(el, l.last_value.as_ref())?
+                    .apply_each(&l.items, |i, b| {
+                        b.sdt_list_item(i.display_text.as_ref(), &i.value)
+                    })?
+                    .close()?
+            }
+            ContentControl::Date(d) => b
+                .open_sdt_date(d.full_date.as_ref())?
+                .apply_opt(d.date_format.as_ref(), |v, b| b.date_format(v))?
+                .apply_opt(d.lid.as_ref(), |v, b| b.lid(v))?
+                .apply_opt(d.store_mapped_data_as.as_ref(), |v, b| {
+                    b.store_mapped_data_as(v)
+                })?
+                .apply_opt(d.calendar.as_ref(), |v, b| b.calendar(v))?
+                .close()?,
+            ContentControl::Text(t) => {
+                if t.multi_line {
+                    b.sdt_text_multi_line("1")?
+                } else {
+                    b.sdt_text()?
+                }
+            }
+            ContentControl::Picture => b.sdt_picture()?,
+            ContentControl::RepeatingSection(s) => b
+                .open_repeating_section()?
+                .apply_opt(s.section_title.as_ref(), |v, b| b.section_title(v))?
+                .apply_if(s.do_not_allow_insert_delete_section, |b| {
+                    b.do_not_allow_insert_delete_section("1")
+                })?
+                .close()?,
+            ContentControl::RepeatingSectionItem => b.repeating_section_item()?,
+        };
+        b.into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_checkbox() {
+        let b = ContentControl::CheckBox(CheckBox::new().checked(true)).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w14:checkbox><w14:checked w14:val="1" /><w14:checkedState w14:val="2612" w14:font="MS Gothic" /><w14:uncheckedState w14:val="2610" w14:font="MS Gothic" /></w14:checkbox>"#
+        );
+    }
+
+    #[test]
+    fn test_drop_down_list() {
+        let b = ContentControl::DropDownList(
+            ListControl::new()
+                .add_item(ListItem::new("a").display_text("Apple"))
+                .add_item(ListItem::new("b")),
+        )
+        .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:dropDownList><w:listItem w:displayText="Apple" w:value="a" /><w:listItem w:value="b" /></w:dropDownList>"#
+        );
+    }
+
+    #[test]
+    fn test_date() {
+        let b = ContentControl::Date(
+            DateControl::new()
+                .full_date("2024-01-31T00:00:00Z")
+                .date_format("M/d/yyyy")
+                .lid("en-US"),
+        )
+        .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:date w:fullDate="2024-01-31T00:00:00Z"><w:dateFormat w:val="M/d/yyyy" /><w:lid w:val="en-US" /></w:date>"#
+        );
+    }
+}
@@ -67,6 +67,7 @@ impl BuildXML for DataBinding {
     ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
         XMLBuilder::from(stream)
             .data_binding(
+                "w:dataBinding",
                 self.xpath.as_ref(),
                 self.prefix_mappings.as_ref(),
                 self.store_item_id.as_ref(),
@@ -14,6 +14,18 @@ pub enum DeleteInstrText {
     Unsupported(String),
 }
 
+impl DeleteInstrText {
+    pub(crate) fn into_instr_text(self) -> InstrText {
+        match self {
+            DeleteInstrText::TOC(toc) => InstrText::TOC(toc),
+            DeleteInstrText::TC(tc) => InstrText::TC(tc),
+            DeleteInstrText::PAGEREF(page_ref) => InstrText::PAGEREF(page_ref),
+            DeleteInstrText::HYPERLINK(link) => InstrText::HYPERLINK(link),
+            DeleteInstrText::Unsupported(s) => InstrText::Unsupported(s),
+        }
+    }
+}
+
 impl BuildXML for DeleteInstrText {
     fn build_to<W: Write>(
         &self,
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::io::Write;
 
-use crate::documents::BuildXML;
+use crate::documents::{BuildXML, Text};
 use crate::escape::escape;
 use crate::xml_builder::*;
 
@@ -26,6 +26,13 @@ impl DeleteText {
             preserve_space: true,
         }
     }
+
+    pub(crate) fn into_text(self) -> Text {
+        Text {
+            text: self.text,
+            preserve_space: self.preserve_space,
+        }
+    }
 }
 
 impl BuildXML for DeleteText {
@@ -93,6 +93,10 @@ impl DocDefaults {
         self.paragraph_property_default = self.paragraph_property_default.paragraph_property(p);
         self
     }
+
+    pub(crate) fn get_run_property(&self) -> &RunProperty {
+        self.run_property_default.get_run_property()
+    }
 }
 
 impl Default for DocDefaults {
@@ -6,8 +6,8 @@ use crate::xml_builder::*;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct DocVar {
-    name: String,
-    val: String,
+    pub name: String,
+    pub val: String,
 }
 
 impl DocVar {
@@ -0,0 +1,156 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::password_hash::*;
+use crate::documents::BuildXML;
+use crate::types::DocumentProtectionType;
+use crate::xml_builder::*;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS), ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentProtection {
+    pub edit: DocumentProtectionType,
+    pub enforcement: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatting: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crypt_algorithm_sid: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spin_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub salt: Option<String>,
+    // Kept so the hash can follow a later spin count change.
+    #[serde(skip)]
+    password: Option<(String, Vec<u8>)>,
+}
+
+impl DocumentProtection {
+    pub fn new(edit: DocumentProtectionType) -> Self {
+        Self {
+            edit,
+            enforcement: true,
+            formatting: None,
+            crypt_algorithm_sid: None,
+            spin_count: None,
+            hash: None,
+            salt: None,
+            password: None,
+        }
+    }
+
+    pub fn enforcement(mut self, enforcement: bool) -> Self {
+        self.enforcement = enforcement;
+        self
+    }
+
+    // Restrict formatting to the styles allowed in styles.xml.
+    pub fn formatting(mut self, formatting: bool) -> Self {
+        self.formatting = Some(formatting);
+        self
+    }
+
+    // Require this password to stop enforcing the protection in Word.
+    pub fn password(self, password: &str) -> Self {
+        let salt = generate_salt();
+        self.password_with_salt(password, &salt)
+    }
+
+    pub fn password_with_salt(mut self, password: &str, salt: &[u8]) -> Self {
+        self.password = Some((password.to_owned(), salt.to_vec()));
+        self.update_hash();
+        self
+    }
+
+    pub fn spin_count(mut self, spin_count: usize) -> Self {
+        self.spin_count = Some(spin_count);
+        self.update_hash();
+        self
+    }
+
+    fn update_hash(&mut self) {
+        if let Some((password, salt)) = &self.password {
+            let spin_count = *self.spin_count.get_or_insert(DEFAULT_SPIN_COUNT);
+            self.crypt_algorithm_sid = Some(SHA512_ALGORITHM_SID);
+            self.hash = Some(encode_base64(&hash_password(password, salt, spin_count)));
+            self.salt = Some(encode_base64(salt));
+        }
+    }
+}
+
+impl BuildXML for DocumentProtection {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .document_protection(
+                &self.edit.to_string(),
+                self.formatting,
+                self.enforcement,
+                self.crypt_algorithm_sid,
+                self.spin_count,
+                self.hash.as_ref(),
+                self.salt.as_ref(),
+            )?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_document_protection() {
+        let c = DocumentProtection::new(DocumentProtectionType::ReadOnly);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:documentProtection w:edit="readOnly" w:enforcement="1" />"#
+        );
+    }
+
+    #[test]
+    fn test_document_protection_with_password() {
+        let salt: Vec<u8> = (0..16).collect();
+        let c = DocumentProtection::new(DocumentProtectionType::Forms)
+            .spin_count(10)
+            .password_with_salt("secret", &salt);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:documentProtection w:edit="forms" w:enforcement="1" w:cryptProviderType="rsaAES" w:cryptAlgorithmClass="hash" w:cryptAlgorithmType="typeAny" w:cryptAlgorithmSid="14" w:cryptSpinCount="10" w:hash="h/fncd+P0XpWpZBdkBblP7jaUK9PTQgoC/dqaTlf43O1WGh09nSmGWXGpH6g5tovld8BdMW8erqnrKAhGBXO6A==" w:salt="AAECAwQFBgcICQoLDA0ODw==" />"#
+        );
+    }
+
+    #[test]
+    fn test_document_protection_spin_count_after_password() {
+        let salt: Vec<u8> = (0..16).collect();
+        let a = DocumentProtection::new(DocumentProtectionType::Forms)
+            .password_with_salt("secret", &salt)
+            .spin_count(10);
+        let b = DocumentProtection::new(DocumentProtectionType::Forms)
+            .spin_count(10)
+            .password_with_salt("secret", &salt);
+        assert_eq!(a.build(), b.build());
+    }
+
+    #[test]
+    fn test_document_protection_default_spin_count() {
+        let salt: Vec<u8> = (0..16).collect();
+        let c = DocumentProtection::new(DocumentProtectionType::ReadOnly)
+            .password_with_salt("Password1", &salt);
+        assert_eq!(c.spin_count, Some(100_000));
+        assert_eq!(
+            c.hash.as_deref(),
+            Some("PTHdg10zYPCwiKvTAyQk3jIABgHus++peA1iw5bvlDlJAh/d+e79UhTvxoDr9cWazr6ahudMBBsQ/ajdRLgfFg==")
+        );
+    }
+}
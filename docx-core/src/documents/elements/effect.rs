@@ -0,0 +1,37 @@
+use serde::{Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Effect {
+    val: TextEffectType,
+}
+
+impl Effect {
+    pub fn new(val: TextEffectType) -> Effect {
+        Effect { val }
+    }
+}
+
+impl BuildXML for Effect {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .effect(&self.val.to_string())?
+            .into_inner()
+    }
+}
+
+impl Serialize for Effect {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.val.to_string())
+    }
+}
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Emboss {
+    val: bool,
+}
+
+impl Emboss {
+    pub fn new() -> Emboss {
+        Default::default()
+    }
+
+    pub fn disable(mut self) -> Emboss {
+        self.val = false;
+        self
+    }
+}
+
+impl Default for Emboss {
+    fn default() -> Self {
+        Self { val: true }
+    }
+}
+
+impl Serialize for Emboss {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(self.val)
+    }
+}
+
+impl BuildXML for Emboss {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        if self.val {
+            XMLBuilder::from(stream).emboss()?.into_inner()
+        } else {
+            XMLBuilder::from(stream)
+                .disabled_toggle("w:emboss")?
+                .into_inner()
+        }
+    }
+}
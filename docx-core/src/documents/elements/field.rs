@@ -0,0 +1,701 @@
+use serde::Serialize;
+use std::fmt;
+use std::io::Write;
+use std::str::FromStr;
+
+use crate::documents::*;
+use crate::escape::{escape, replace_escaped};
+use crate::types::*;
+use crate::xml_builder::*;
+
+// e.g. \h, \* MERGEFORMAT, \@ "yyyy-MM-dd"
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldSwitch {
+    // Including the leading backslash.
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub argument: Option<String>,
+}
+
+impl FieldSwitch {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            argument: None,
+        }
+    }
+
+    pub fn argument(mut self, a: impl Into<String>) -> Self {
+        self.argument = Some(a.into());
+        self
+    }
+}
+
+// https://c-rex.net/projects/samples/ooxml/e1/Part4/OOXML_P4_DOCX_FieldDefinitions_topic_ID0EMJ11.html
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldInstruction {
+    pub field_type: FieldType,
+    // The field name as written. Kept so that unsupported fields can be written back.
+    pub name: String,
+    pub arguments: Vec<String>,
+    pub switches: Vec<FieldSwitch>,
+}
+
+impl FieldInstruction {
+    pub fn new(field_type: FieldType) -> Self {
+        Self {
+            field_type,
+            name: field_type.to_string(),
+            arguments: vec![],
+            switches: vec![],
+        }
+    }
+
+    pub fn add_argument(mut self, a: impl Into<String>) -> Self {
+        self.arguments.push(a.into());
+        self
+    }
+
+    pub fn add_switch(mut self, name: impl Into<String>) -> Self {
+        self.switches.push(FieldSwitch::new(name));
+        self
+    }
+
+    pub fn add_switch_with_argument(
+        mut self,
+        name: impl Into<String>,
+        argument: impl Into<String>,
+    ) -> Self {
+        self.switches
+            .push(FieldSwitch::new(name).argument(argument));
+        self
+    }
+
+    pub fn find_switch(&self, name: &str) -> Option<&FieldSwitch> {
+        self.switches.iter().find(|s| s.name == name)
+    }
+
+    pub fn has_switch(&self, name: &str) -> bool {
+        self.find_switch(name).is_some()
+    }
+}
+
+fn write_field_argument(f: &mut fmt::Formatter, a: &str, force_quote: bool) -> fmt::Result {
+    // Backslashes are quoted too, otherwise an argument like `\foo` would be read as a switch.
+    if force_quote
+        || a.is_empty()
+        || a.contains(char::is_whitespace)
+        || a.contains('"')
+        || a.contains('\\')
+    {
+        write!(f, " \"{}\"", a.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        write!(f, " {}", a)
+    }
+}
+
+impl fmt::Display for FieldInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        for a in &self.arguments {
+            write_field_argument(f, a, false)?;
+        }
+        for s in &self.switches {
+            write!(f, " {}", s.name)?;
+            if let Some(a) = &s.argument {
+                // Date-time and numeric pictures are quoted by Word.
+                write_field_argument(f, a, s.name == "\\@" || s.name == "\\#")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// (token, quoted)
+fn tokenize_field_instruction(instr: &str) -> Vec<(String, bool)> {
+    let mut tokens = vec![];
+    let mut chars = instr.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_whitespace() {
+            continue;
+        }
+        let mut token = String::new();
+        if c == '"' {
+            while let Some(c) = chars.next() {
+                match c {
+                    // `\"` and `\\` are an escaped quote and backslash, e.g. "C:\\Docs\\".
+                    '\\' if matches!(chars.peek(), Some('"') | Some('\\')) => {
+                        token.extend(chars.next());
+                    }
+                    '"' => break,
+                    _ => token.push(c),
+                }
+            }
+            tokens.push((token, true));
+        } else {
+            token.push(c);
+            while let Some(c) = chars.peek() {
+                if c.is_whitespace() {
+                    break;
+                }
+                token.push(*c);
+                chars.next();
+            }
+            tokens.push((token, false));
+        }
+    }
+    tokens
+}
+
+impl FromStr for FieldInstruction {
+    type Err = ();
+
+    fn from_str(instr: &str) -> Result<Self, Self::Err> {
+        let mut tokens = tokenize_field_instruction(instr).into_iter().peekable();
+        let (name, _) = tokens.next().ok_or(())?;
+        let mut instruction = FieldInstruction::new(FieldType::from_str(&name).map_err(|_| ())?);
+        instruction.name = name;
+        let is_switch = |t: &(String, bool)| !t.1 && t.0.starts_with('\\') && t.0.len() > 1;
+        while let Some(t) = tokens.next() {
+            if is_switch(&t) {
+                // A switch takes the following token as its argument unless it is another switch.
+                let argument = match tokens.peek() {
+                    Some(n) if !is_switch(n) => tokens.next().map(|(a, _)| a),
+                    _ => None,
+                };
+                instruction.switches.push(FieldSwitch {
+                    name: t.0,
+                    argument,
+                });
+            } else {
+                instruction.arguments.push(t.0);
+            }
+        }
+        Ok(instruction)
+    }
+}
+
+// A field with its instruction and cached result.
+// Written as a complex field (w:fldChar begin/separate/end) by default or as w:fldSimple.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Field {
+    pub instruction: FieldInstruction,
+    pub result: Vec<Run>,
+    // Applied to the runs holding the field characters and the instruction.
+    pub run_property: RunProperty,
+    pub simple: bool,
+    pub dirty: bool,
+    // Legacy form fields (FORMTEXT, FORMCHECKBOX and FORMDROPDOWN) only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form_field_data: Option<FormFieldData>,
+}
+
+impl Field {
+    pub fn new(instruction: FieldInstruction) -> Self {
+        Self {
+            instruction,
+            result: vec![],
+            run_property: RunProperty::new(),
+            simple: false,
+            dirty: false,
+            form_field_data: None,
+        }
+    }
+
+    pub fn with_instr_text(s: &str) -> Option<Self> {
+        FieldInstruction::from_str(s).ok().map(Self::new)
+    }
+
+    pub fn field_type(&self) -> FieldType {
+        self.instruction.field_type
+    }
+
+    // Replaces the cached result with a single run.
+    pub fn result(mut self, text: impl Into<String>) -> Self {
+        self.result = vec![Run {
+            run_property: self.run_property.clone(),
+            ..Default::default()
+        }
+        .add_text(text)];
+        self
+    }
+
+    pub fn add_result_run(mut self, run: Run) -> Self {
+        self.result.push(run);
+        self
+    }
+
+    pub fn run_property(mut self, p: RunProperty) -> Self {
+        self.run_property = p;
+        self
+    }
+
+    pub fn simple(mut self) -> Self {
+        self.simple = true;
+        self
+    }
+
+    pub fn dirty(mut self) -> Self {
+        self.dirty = true;
+        self
+    }
+
+    pub fn form_field_data(mut self, d: FormFieldData) -> Self {
+        self.form_field_data = Some(d);
+        self
+    }
+
+    pub fn form_field_name(&self) -> Option<String> {
+        self.form_field_data
+            .as_ref()
+            .and_then(|d| d.name.as_ref())
+            .map(|n| replace_escaped(n))
+    }
+
+    // The current value of a legacy form field: the text of FORMTEXT, "true" or "false"
+    // for FORMCHECKBOX and the selected entry of FORMDROPDOWN.
+    pub fn form_field_value(&self) -> Option<String> {
+        match self.form_field_data.as_ref()?.control.as_ref() {
+            Some(FormFieldControl::CheckBox(c)) => Some(c.is_checked().to_string()),
+            Some(FormFieldControl::DropDownList(l)) => l.selected(),
+            _ => Some(replace_escaped(&self.result_text())),
+        }
+    }
+
+    pub fn result_text(&self) -> String {
+        let mut s = String::new();
+        for run in &self.result {
+            for c in &run.children {
+                match c {
+                    RunChild::Text(t) => s.push_str(&t.text),
+                    RunChild::Tab(_) => s.push('\t'),
+                    _ => {}
+                }
+            }
+        }
+        s
+    }
+
+    // The field as runs of a complex field with the instruction in w:delInstrText,
+    // e.g. for a field inside a deletion.
+    pub(crate) fn into_deleted_runs(self) -> Vec<Run> {
+        let instr = DeleteInstrText::Unsupported(escape(&self.instruction.to_string()));
+        let mut runs = vec![
+            self.field_char_run(FieldCharType::Begin),
+            Run {
+                run_property: self.run_property.clone(),
+                children: vec![RunChild::DeleteInstrText(Box::new(instr))],
+            },
+            self.field_char_run(FieldCharType::Separate),
+        ];
+        let end = self.field_char_run(FieldCharType::End);
+        runs.extend(self.result);
+        runs.push(end);
+        runs
+    }
+
+    fn field_char_run(&self, t: FieldCharType) -> Run {
+        let mut c = FieldChar::new(t);
+        if t == FieldCharType::Begin {
+            c.dirty = self.dirty;
+            c.form_field_data = self.form_field_data.clone();
+        }
+        Run {
+            run_property: self.run_property.clone(),
+            children: vec![RunChild::FieldChar(c)],
+        }
+    }
+}
+
+impl BuildXML for Field {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        let instr = escape(&self.instruction.to_string());
+        if self.simple {
+            return XMLBuilder::from(stream)
+                .apply(|b| {
+                    if self.dirty {
+                        b.open_dirty_field_simple(&instr, "true")
+                    } else {
+                        b.open_field_simple(&instr)
+                    }
+                })?
+                .add_children(&self.result)?
+                .close()?
+                .into_inner();
+        }
+        XMLBuilder::from(stream)
+            .add_child(&self.field_char_run(FieldCharType::Begin))?
+            .open_run()?
+            .add_child(&self.run_property)?
+            .open_instr_text()?
+            .plain_text(&instr)?
+            .close()?
+            .close()?
+            .add_child(&self.field_char_run(FieldCharType::Separate))?
+            .add_children(&self.result)?
+            .add_child(&self.field_char_run(FieldCharType::End))?
+            .into_inner()
+    }
+}
+
+#[derive(PartialEq)]
+enum FieldPart {
+    Instruction,
+    Result,
+}
+
+struct OpenField {
+    instr: String,
+    run_property: RunProperty,
+    dirty: bool,
+    form_field_data: Option<FormFieldData>,
+    part: FieldPart,
+    result: Vec<Run>,
+    // Depth of fields nested in the result. These are kept as plain runs.
+    nested: usize,
+}
+
+impl OpenField {
+    fn into_field(self) -> Option<Field> {
+        let instruction = FieldInstruction::from_str(&self.instr).ok()?;
+        let mut field = Field::new(instruction).run_property(self.run_property);
+        field.result = self.result;
+        field.dirty = self.dirty;
+        field.form_field_data = self.form_field_data;
+        Some(field)
+    }
+}
+
+// Complex fields are only converted to `Field` when every field in the paragraph
+// begins and ends in it, no field is nested in another field's instruction and
+// every instruction can be parsed.
+// Otherwise the runs are kept as they are, e.g. for a TOC spanning many paragraphs.
+fn has_only_closed_fields(children: &[ParagraphChild]) -> bool {
+    let mut stack: Vec<FieldPart> = vec![];
+    let mut found = false;
+    let mut instr = String::new();
+    for child in children {
+        let run = match child {
+            ParagraphChild::Run(run) => run,
+            _ if stack.is_empty() => continue,
+            _ => return false,
+        };
+        for c in &run.children {
+            match c {
+                RunChild::FieldChar(f) => match f.field_char_type {
+                    FieldCharType::Begin => {
+                        if stack.last() == Some(&FieldPart::Instruction) {
+                            return false;
+                        }
+                        found = true;
+                        stack.push(FieldPart::Instruction);
+                    }
+                    FieldCharType::Separate => match stack.last_mut() {
+                        Some(p) => *p = FieldPart::Result,
+                        None => return false,
+                    },
+                    FieldCharType::End => {
+                        if stack.pop().is_none() {
+                            return false;
+                        }
+                        if stack.is_empty() {
+                            if FieldInstruction::from_str(&instr).is_err() {
+                                return false;
+                            }
+                            instr.clear();
+                        }
+                    }
+                    FieldCharType::Unsupported => return false,
+                },
+                RunChild::InstrTextString(s) => {
+                    if stack == [FieldPart::Instruction] {
+                        instr.push_str(s);
+                    }
+                }
+                _ if stack.last() == Some(&FieldPart::Instruction) => return false,
+                _ => {}
+            }
+        }
+    }
+    found && stack.is_empty()
+}
+
+// Converts begin/separate/end runs read from a paragraph into `ParagraphChild::Field`.
+pub(crate) fn collect_complex_fields(children: Vec<ParagraphChild>) -> Vec<ParagraphChild> {
+    if !has_only_closed_fields(&children) {
+        return children;
+    }
+    let mut out = vec![];
+    let mut open: Option<OpenField> = None;
+    for child in children {
+        let run = match child {
+            ParagraphChild::Run(run) => *run,
+            child => {
+                out.push(child);
+                continue;
+            }
+        };
+        if open.is_none()
+            && !run
+                .children
+                .iter()
+                .any(|c| matches!(c, RunChild::FieldChar(_)))
+        {
+            out.push(ParagraphChild::Run(Box::new(run)));
+            continue;
+        }
+        let run_property = run.run_property;
+        let mut pending: Vec<RunChild> = vec![];
+        for c in run.children {
+            let Some(field) = open.as_mut() else {
+                if let RunChild::FieldChar(f) = &c {
+                    if f.field_char_type == FieldCharType::Begin {
+                        flush_run(&mut pending, &run_property, &mut out, None);
+                        open = Some(OpenField {
+                            instr: String::new(),
+                            run_property: run_property.clone(),
+                            dirty: f.dirty,
+                            form_field_data: f.form_field_data.clone(),
+                            part: FieldPart::Instruction,
+                            result: vec![],
+                            nested: 0,
+                        });
+                        continue;
+                    }
+                }
+                pending.push(c);
+                continue;
+            };
+            let in_result = field.part == FieldPart::Result;
+            match c {
+                RunChild::InstrTextString(s) if !in_result => field.instr.push_str(&s),
+                RunChild::FieldChar(f) if !in_result => {
+                    if f.field_char_type == FieldCharType::Separate {
+                        field.part = FieldPart::Result;
+                    } else if let Some(f) = open.take().and_then(OpenField::into_field) {
+                        out.push(ParagraphChild::Field(Box::new(f)));
+                    }
+                }
+                _ if !in_result => {}
+                RunChild::FieldChar(f) => match f.field_char_type {
+                    FieldCharType::Begin => {
+                        field.nested += 1;
+                        pending.push(RunChild::FieldChar(f));
+                    }
+                    FieldCharType::End if field.nested > 0 => {
+                        field.nested -= 1;
+                        pending.push(RunChild::FieldChar(f));
+                    }
+                    FieldCharType::End => {
+                        flush_run(&mut pending, &run_property, &mut out, Some(field));
+                        if let Some(f) = open.take().and_then(OpenField::into_field) {
+                            out.push(ParagraphChild::Field(Box::new(f)));
+                        }
+                    }
+                    _ => pending.push(RunChild::FieldChar(f)),
+                },
+                c => pending.push(c),
+            }
+        }
+        flush_run(&mut pending, &run_property, &mut out, open.as_mut());
+    }
+    out
+}
+
+fn flush_run(
+    pending: &mut Vec<RunChild>,
+    run_property: &RunProperty,
+    out: &mut Vec<ParagraphChild>,
+    field: Option<&mut OpenField>,
+) {
+    if pending.is_empty() {
+        return;
+    }
+    let run = Run {
+        run_property: run_property.clone(),
+        children: std::mem::take(pending),
+    };
+    match field {
+        Some(field) => field.result.push(run),
+        None => out.push(ParagraphChild::Run(Box::new(run))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    fn add_instr_text_string(mut run: Run, s: &str) -> Run {
+        run.children.push(RunChild::InstrTextString(s.to_owned()));
+        run
+    }
+
+    #[test]
+    fn test_parse_field_instruction() {
+        let i =
+            FieldInstruction::from_str(r#" DATE \@ "dddd, MMMM d, yyyy" \* MERGEFORMAT "#).unwrap();
+        assert_eq!(
+            i,
+            FieldInstruction::new(FieldType::Date)
+                .add_switch_with_argument("\\@", "dddd, MMMM d, yyyy")
+                .add_switch_with_argument("\\*", "MERGEFORMAT")
+        );
+
+        let i = FieldInstruction::from_str(r#"ref _Ref123 \h \r"#).unwrap();
+        assert_eq!(i.field_type, FieldType::Ref);
+        assert_eq!(i.name, "ref");
+        assert_eq!(i.arguments, vec!["_Ref123".to_string()]);
+        assert!(i.has_switch("\\h") && i.has_switch("\\r"));
+
+        let i = FieldInstruction::from_str(r#"DOCPROPERTY "Client Name""#).unwrap();
+        assert_eq!(i.arguments, vec!["Client Name".to_string()]);
+        assert_eq!(i.to_string(), r#"DOCPROPERTY "Client Name""#);
+
+        let i = FieldInstruction::from_str(r#"HYPERLINK "C:\\Docs\\" \o "say \"hi\"""#).unwrap();
+        assert_eq!(
+            i,
+            FieldInstruction::new(FieldType::Hyperlink)
+                .add_argument("C:\\Docs\\")
+                .add_switch_with_argument("\\o", "say \"hi\"")
+        );
+        assert_eq!(i.to_string(), r#"HYPERLINK "C:\\Docs\\" \o "say \"hi\"""#);
+
+        let i = FieldInstruction::new(FieldType::Ref).add_argument("\\h");
+        assert_eq!(i.to_string(), r#"REF "\\h""#);
+        assert_eq!(FieldInstruction::from_str(&i.to_string()).unwrap(), i);
+
+        let i = FieldInstruction::from_str("GREETINGLINE \\l 1033").unwrap();
+        assert_eq!(i.field_type, FieldType::Unsupported);
+        assert_eq!(i.to_string(), "GREETINGLINE \\l 1033");
+    }
+
+    #[test]
+    fn test_complex_field() {
+        let b = Field::new(
+            FieldInstruction::new(FieldType::Seq)
+                .add_argument("Figure")
+                .add_switch_with_argument("\\*", "ARABIC"),
+        )
+        .result("1")
+        .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:r><w:rPr /><w:fldChar w:fldCharType="begin" w:dirty="false" /></w:r><w:r><w:rPr /><w:instrText>SEQ Figure \* ARABIC</w:instrText></w:r><w:r><w:rPr /><w:fldChar w:fldCharType="separate" w:dirty="false" /></w:r><w:r><w:rPr /><w:t xml:space="preserve">1</w:t></w:r><w:r><w:rPr /><w:fldChar w:fldCharType="end" w:dirty="false" /></w:r>"#
+        );
+    }
+
+    #[test]
+    fn test_simple_field() {
+        let b = Field::new(
+            FieldInstruction::new(FieldType::Date).add_switch_with_argument("\\@", "yyyy/MM/dd"),
+        )
+        .simple()
+        .dirty()
+        .result("2024/01/31")
+        .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:fldSimple w:instr="DATE \@ &quot;yyyy/MM/dd&quot;" w:dirty="true"><w:r><w:rPr /><w:t xml:space="preserve">2024/01/31</w:t></w:r></w:fldSimple>"#
+        );
+    }
+
+    #[test]
+    fn test_collect_complex_fields() {
+        let children = vec![
+            ParagraphChild::Run(Box::new(Run::new().add_text("Figure "))),
+            ParagraphChild::Run(Box::new(
+                add_instr_text_string(
+                    Run::new().bold().add_field_char(FieldCharType::Begin, true),
+                    " SEQ Figure ",
+                )
+                .add_field_char(FieldCharType::Separate, false),
+            )),
+            ParagraphChild::Run(Box::new(Run::new().bold().add_text("2"))),
+            ParagraphChild::Run(Box::new(
+                Run::new()
+                    .bold()
+                    .add_field_char(FieldCharType::End, false)
+                    .add_text(": caption"),
+            )),
+        ];
+        assert_eq!(
+            collect_complex_fields(children),
+            vec![
+                ParagraphChild::Run(Box::new(Run::new().add_text("Figure "))),
+                ParagraphChild::Field(Box::new(
+                    Field::new(FieldInstruction::new(FieldType::Seq).add_argument("Figure"))
+                        .run_property(RunProperty::new().bold())
+                        .dirty()
+                        .add_result_run(Run::new().bold().add_text("2"))
+                )),
+                ParagraphChild::Run(Box::new(Run::new().bold().add_text(": caption"))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_keep_fields_spanning_paragraphs() {
+        let children = vec![ParagraphChild::Run(Box::new(
+            add_instr_text_string(
+                Run::new().add_field_char(FieldCharType::Begin, false),
+                "TOC \\o \"1-3\"",
+            )
+            .add_field_char(FieldCharType::Separate, false),
+        ))];
+        assert_eq!(collect_complex_fields(children.clone()), children);
+    }
+
+    #[test]
+    fn test_keep_fields_without_instruction() {
+        let children = vec![
+            ParagraphChild::Run(Box::new(
+                Run::new()
+                    .add_field_char(FieldCharType::Begin, false)
+                    .add_field_char(FieldCharType::Separate, false),
+            )),
+            ParagraphChild::Run(Box::new(Run::new().add_text("cached"))),
+            ParagraphChild::Run(Box::new(
+                Run::new().add_field_char(FieldCharType::End, false),
+            )),
+        ];
+        assert_eq!(collect_complex_fields(children.clone()), children);
+    }
+
+    #[test]
+    fn test_collect_form_field() {
+        let data = FormFieldData::new().name("Dropdown1").drop_down_list(
+            FormDropDownList::new()
+                .add_entry("Red")
+                .add_entry("Green")
+                .result(1),
+        );
+        let begin = Run {
+            children: vec![RunChild::FieldChar(
+                FieldChar::new(FieldCharType::Begin).form_field_data(data.clone()),
+            )],
+            ..Default::default()
+        };
+        let children = vec![ParagraphChild::Run(Box::new(
+            add_instr_text_string(begin, " FORMDROPDOWN ")
+                .add_field_char(FieldCharType::Separate, false)
+                .add_field_char(FieldCharType::End, false),
+        ))];
+        let ParagraphChild::Field(field) = &collect_complex_fields(children)[0] else {
+            panic!("Expected Field");
+        };
+        assert_eq!(field.field_type(), FieldType::FormDropDown);
+        assert_eq!(field.form_field_data, Some(data));
+        assert_eq!(field.form_field_name(), Some("Dropdown1".to_string()));
+        assert_eq!(field.form_field_value(), Some("Green".to_string()));
+    }
+}
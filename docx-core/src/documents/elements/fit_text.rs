@@ -0,0 +1,54 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// Fit the run into `val` twips. Consecutive runs sharing an `id` are fitted together.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FitText {
+    pub val: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<usize>,
+}
+
+impl FitText {
+    pub fn new(val: usize) -> FitText {
+        FitText { val, id: None }
+    }
+
+    pub fn id(mut self, id: usize) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+impl BuildXML for FitText {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .fit_text(self.val, self.id)?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_fit_text() {
+        let b = FitText::new(1680).id(1).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:fitText w:val="1680" w:id="1" />"#
+        );
+    }
+}
@@ -12,6 +12,9 @@ use crate::xml_builder::*;
 pub struct FieldChar {
     pub field_char_type: FieldCharType,
     pub dirty: bool,
+    // Only on the begin character of legacy form fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form_field_data: Option<FormFieldData>,
 }
 
 impl FieldChar {
@@ -19,6 +22,7 @@ impl FieldChar {
         Self {
             field_char_type: t,
             dirty: false,
+            form_field_data: None,
         }
     }
 
@@ -26,6 +30,11 @@ impl FieldChar {
         self.dirty = true;
         self
     }
+
+    pub fn form_field_data(mut self, d: FormFieldData) -> Self {
+        self.form_field_data = Some(d);
+        self
+    }
 }
 
 impl BuildXML for FieldChar {
@@ -33,12 +42,18 @@ impl BuildXML for FieldChar {
         &self,
         stream: xml::writer::EventWriter<W>,
     ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
-        XMLBuilder::from(stream)
-            .field_character(
-                &format!("{}", self.field_char_type),
-                &format!("{}", &self.dirty),
-            )?
-            .into_inner()
+        let t = format!("{}", self.field_char_type);
+        let dirty = format!("{}", &self.dirty);
+        match &self.form_field_data {
+            Some(d) => XMLBuilder::from(stream)
+                .open_field_character(&t, &dirty)?
+                .add_child(d)?
+                .close()?
+                .into_inner(),
+            None => XMLBuilder::from(stream)
+                .field_character(&t, &dirty)?
+                .into_inner(),
+        }
     }
 }
 
@@ -58,4 +73,19 @@ mod tests {
             r#"<w:fldChar w:fldCharType="begin" w:dirty="true" />"#
         );
     }
+
+    #[test]
+    fn test_field_character_with_form_field_data() {
+        let b = FieldChar::new(FieldCharType::Begin)
+            .form_field_data(
+                FormFieldData::new()
+                    .name("Text1")
+                    .text_input(FormTextInput::new().default_text("N/A").max_length(10)),
+            )
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:fldChar w:fldCharType="begin" w:dirty="false"><w:ffData><w:name w:val="Text1" /><w:enabled /><w:calcOnExit w:val="0" /><w:textInput><w:default w:val="N/A" /><w:maxLength w:val="10" /></w:textInput></w:ffData></w:fldChar>"#
+        );
+    }
 }
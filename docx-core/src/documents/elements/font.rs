@@ -1,42 +1,238 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use std::io::Write;
+use std::str::FromStr;
+
 use crate::documents::BuildXML;
+use crate::escape::escape;
 use crate::types::*;
 use crate::xml_builder::*;
-use std::io::Write;
 
-#[derive(Debug)]
-pub struct Font<'a> {
-    name: &'a str,
-    charset: &'a str,
-    family: &'a str,
-    pitch: FontPitchType,
+// (panose1, charset, family, pitch) of fonts commonly referenced by documents.
+fn known_font(name: &str) -> Option<(&'static str, &'static str, &'static str, FontPitchType)> {
+    use FontPitchType::{Fixed, Variable};
+    let f = match name {
+        "Times New Roman" => ("02020603050405020304", "00", "roman", Variable),
+        "Arial" => ("020B0604020202020204", "00", "swiss", Variable),
+        "Calibri" => ("020F0502020204030204", "00", "swiss", Variable),
+        "Calibri Light" => ("020F0302020204030204", "00", "swiss", Variable),
+        "Cambria" => ("02040503050406030204", "00", "roman", Variable),
+        "Georgia" => ("02040502050405020303", "00", "roman", Variable),
+        "Verdana" => ("020B0604030504040204", "00", "swiss", Variable),
+        "Segoe UI" => ("020B0502040204020203", "00", "swiss", Variable),
+        "Courier New" => ("02070309020205020404", "00", "modern", Fixed),
+        "Symbol" => ("05050102010706020507", "02", "roman", Variable),
+        "MS Mincho" => ("02020609040205080304", "80", "modern", Fixed),
+        "MS Gothic" => ("020B0609070205080204", "80", "modern", Fixed),
+        "Yu Mincho" => ("02020400000000000000", "80", "roman", Variable),
+        "Yu Gothic" => ("020B0400000000000000", "80", "swiss", Variable),
+        "SimSun" => ("02010600030101010101", "86", "auto", Variable),
+        _ => return None,
+    };
+    Some(f)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FontEmbed {
+    pub embed_type: FontEmbedType,
+    pub rid: String,
+    pub font_key: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Font {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alt_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub panose1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub family: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pitch: Option<FontPitchType>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub embeds: Vec<FontEmbed>,
 }
 
-impl<'a> Font<'a> {
-    pub fn new(name: &'a str, charset: &'a str, family: &'a str, pitch: FontPitchType) -> Font<'a> {
+impl Font {
+    pub fn new(
+        name: impl Into<String>,
+        charset: impl Into<String>,
+        family: impl Into<String>,
+        pitch: FontPitchType,
+    ) -> Font {
         Font {
-            name,
-            charset,
-            family,
-            pitch,
+            name: name.into(),
+            alt_name: None,
+            panose1: None,
+            charset: Some(charset.into()),
+            family: Some(family.into()),
+            pitch: Some(pitch),
+            embeds: vec![],
+        }
+    }
+
+    /// Creates a font entry, filling panose/charset/family/pitch for well known fonts.
+    pub fn from_name(name: impl Into<String>) -> Font {
+        let name = name.into();
+        match known_font(&name) {
+            Some((panose1, charset, family, pitch)) => {
+                Font::new(name, charset, family, pitch).panose1(panose1)
+            }
+            None => Font {
+                name,
+                alt_name: None,
+                panose1: None,
+                charset: None,
+                family: None,
+                pitch: None,
+                embeds: vec![],
+            },
         }
     }
+
+    pub fn alt_name(mut self, name: impl Into<String>) -> Self {
+        self.alt_name = Some(name.into());
+        self
+    }
+
+    pub fn panose1(mut self, panose1: impl Into<String>) -> Self {
+        self.panose1 = Some(panose1.into());
+        self
+    }
+
+    pub fn embed(
+        mut self,
+        embed_type: FontEmbedType,
+        rid: impl Into<String>,
+        font_key: impl Into<String>,
+    ) -> Self {
+        self.embeds.retain(|e| e.embed_type != embed_type);
+        self.embeds.push(FontEmbed {
+            embed_type,
+            rid: rid.into(),
+            font_key: font_key.into(),
+        });
+        self
+    }
+
+    fn find_embed(&self, t: FontEmbedType) -> Option<&FontEmbed> {
+        self.embeds.iter().find(|e| e.embed_type == t)
+    }
 }
 
-impl<'a> BuildXML for Font<'a> {
+impl BuildXML for Font {
     fn build_to<W: Write>(
         &self,
         stream: xml::writer::EventWriter<W>,
     ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
         XMLBuilder::from(stream)
-            .open_font(self.name)?
-            .charset(self.charset)?
-            .family(self.family)?
-            .pitch(&self.pitch.to_string())?
+            .open_font(&self.name)?
+            .apply_opt(self.alt_name.as_ref(), |v, b| b.alt_name(v))?
+            .apply_opt(self.panose1.as_ref(), |v, b| b.panose1(v))?
+            .apply_opt(self.charset.as_ref(), |v, b| b.charset(v))?
+            .apply_opt(self.family.as_ref(), |v, b| b.family(v))?
+            .apply_opt(self.pitch, |v, b| b.pitch(&v.to_string()))?
+            .apply_opt(self.find_embed(FontEmbedType::Regular), |e, b| {
+                b.embed_regular(&e.rid, &e.font_key)
+            })?
+            .apply_opt(self.find_embed(FontEmbedType::Bold), |e, b| {
+                b.embed_bold(&e.rid, &e.font_key)
+            })?
+            .apply_opt(self.find_embed(FontEmbedType::Italic), |e, b| {
+                b.embed_italic(&e.rid, &e.font_key)
+            })?
+            .apply_opt(self.find_embed(FontEmbedType::BoldItalic), |e, b| {
+                b.embed_bold_italic(&e.rid, &e.font_key)
+            })?
             .close()?
             .into_inner()
     }
 }
 
+// ============================================================================
+// XML Deserialization (quick-xml serde)
+// ============================================================================
+
+#[derive(Deserialize, Default)]
+struct FontValueXml {
+    #[serde(rename = "@val", alias = "@w:val", default)]
+    val: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+struct FontEmbedXml {
+    #[serde(rename = "@id", alias = "@r:id", default)]
+    id: Option<String>,
+    #[serde(rename = "@fontKey", alias = "@w:fontKey", default)]
+    font_key: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+struct FontXml {
+    #[serde(rename = "@name", alias = "@w:name", default)]
+    name: Option<String>,
+    #[serde(rename = "altName", alias = "w:altName", default)]
+    alt_name: Option<FontValueXml>,
+    #[serde(rename = "panose1", alias = "w:panose1", default)]
+    panose1: Option<FontValueXml>,
+    #[serde(rename = "charset", alias = "w:charset", default)]
+    charset: Option<FontValueXml>,
+    #[serde(rename = "family", alias = "w:family", default)]
+    family: Option<FontValueXml>,
+    #[serde(rename = "pitch", alias = "w:pitch", default)]
+    pitch: Option<FontValueXml>,
+    #[serde(rename = "embedRegular", alias = "w:embedRegular", default)]
+    embed_regular: Option<FontEmbedXml>,
+    #[serde(rename = "embedBold", alias = "w:embedBold", default)]
+    embed_bold: Option<FontEmbedXml>,
+    #[serde(rename = "embedItalic", alias = "w:embedItalic", default)]
+    embed_italic: Option<FontEmbedXml>,
+    #[serde(rename = "embedBoldItalic", alias = "w:embedBoldItalic", default)]
+    embed_bold_italic: Option<FontEmbedXml>,
+}
+
+impl<'de> Deserialize<'de> for Font {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let xml = FontXml::deserialize(deserializer)?;
+        // Only the values in the font table are kept, so that reading does not add metadata.
+        let mut font = Font {
+            name: escape(&xml.name.unwrap_or_default()),
+            alt_name: xml.alt_name.and_then(|v| v.val).map(|v| escape(&v)),
+            panose1: xml.panose1.and_then(|v| v.val),
+            charset: xml.charset.and_then(|v| v.val),
+            family: xml.family.and_then(|v| v.val),
+            pitch: xml
+                .pitch
+                .and_then(|v| v.val)
+                .and_then(|v| FontPitchType::from_str(&v).ok()),
+            embeds: vec![],
+        };
+        let embeds = [
+            (FontEmbedType::Regular, xml.embed_regular),
+            (FontEmbedType::Bold, xml.embed_bold),
+            (FontEmbedType::Italic, xml.embed_italic),
+            (FontEmbedType::BoldItalic, xml.embed_bold_italic),
+        ];
+        for (t, e) in embeds {
+            if let Some(FontEmbedXml {
+                id: Some(id),
+                font_key: Some(key),
+            }) = e
+            {
+                font = font.embed(t, id, key);
+            }
+        }
+        Ok(font)
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -54,4 +250,58 @@ mod tests {
             r#"<w:font w:name="Arial"><w:charset w:val="00" /><w:family w:val="swiss" /><w:pitch w:val="variable" /></w:font>"#
         );
     }
+
+    #[test]
+    fn test_build_known_font_with_embed() {
+        let c = Font::from_name("Calibri").embed(
+            FontEmbedType::Regular,
+            "rId1",
+            "{00000000-0000-0000-0000-000000000000}",
+        );
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:font w:name="Calibri"><w:panose1 w:val="020F0502020204030204" /><w:charset w:val="00" /><w:family w:val="swiss" /><w:pitch w:val="variable" /><w:embedRegular r:id="rId1" w:fontKey="{00000000-0000-0000-0000-000000000000}" /></w:font>"#
+        );
+    }
+
+    #[test]
+    fn test_font_xml_deserialize() {
+        let xml = r#"<w:font xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" w:name="Corporate Sans">
+            <w:altName w:val="CorpSans"/>
+            <w:charset w:val="00"/>
+            <w:family w:val="swiss"/>
+            <w:pitch w:val="variable"/>
+            <w:sig w:usb0="00000003" w:usb1="00000000" w:usb2="00000000" w:usb3="00000000" w:csb0="00000001" w:csb1="00000000"/>
+        </w:font>"#;
+        let font: Font = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(
+            font,
+            Font::new("Corporate Sans", "00", "swiss", FontPitchType::Variable)
+                .alt_name("CorpSans")
+        );
+    }
+
+    #[test]
+    fn test_font_xml_deserialize_embeds() {
+        let xml = r#"<w:font xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" w:name="Corporate Sans">
+            <w:embedRegular r:id="rId1" w:fontKey="{00000000-0000-0000-0000-000000000001}"/>
+            <w:embedBold r:id="rId2" w:fontKey="{00000000-0000-0000-0000-000000000002}"/>
+        </w:font>"#;
+        let font: Font = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(
+            font,
+            Font::from_name("Corporate Sans")
+                .embed(
+                    FontEmbedType::Regular,
+                    "rId1",
+                    "{00000000-0000-0000-0000-000000000001}"
+                )
+                .embed(
+                    FontEmbedType::Bold,
+                    "rId2",
+                    "{00000000-0000-0000-0000-000000000002}"
+                )
+        );
+    }
 }
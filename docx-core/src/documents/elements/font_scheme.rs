@@ -1,4 +1,8 @@
 use serde::{Deserialize, Deserializer, Serialize};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
@@ -20,20 +24,102 @@ pub struct FontGroup {
     pub fonts: Vec<FontSchemeFont>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+impl FontSchemeFont {
+    pub fn new(script: impl Into<String>, typeface: impl Into<String>) -> Self {
+        FontSchemeFont {
+            script: script.into(),
+            typeface: typeface.into(),
+        }
+    }
+}
+
+impl FontGroup {
+    pub fn new(latin: impl Into<String>) -> Self {
+        FontGroup {
+            latin: latin.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn ea(mut self, ea: impl Into<String>) -> Self {
+        self.ea = ea.into();
+        self
+    }
+
+    pub fn cs(mut self, cs: impl Into<String>) -> Self {
+        self.cs = cs.into();
+        self
+    }
+
+    pub fn add_font(mut self, f: FontSchemeFont) -> Self {
+        self.fonts.push(f);
+        self
+    }
+
+    fn build_fonts<W: Write>(&self, b: XMLBuilder<W>) -> xml::writer::Result<XMLBuilder<W>> {
+        b.a_latin(&self.latin)?
+            .a_ea(&self.ea)?
+            .a_cs(&self.cs)?
+            .apply_each(self.fonts.iter(), |f, b| b.a_font(&f.script, &f.typeface))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
 #[cfg_attr(feature = "wasm", ts(export))]
 #[serde(rename_all = "camelCase")]
 pub struct FontScheme {
+    pub name: String,
     pub major_font: FontGroup,
     pub minor_font: FontGroup,
 }
 
-// For now reader only
+impl Default for FontScheme {
+    fn default() -> Self {
+        FontScheme {
+            name: "Office".to_owned(),
+            major_font: FontGroup::new("Calibri Light"),
+            minor_font: FontGroup::new("Calibri"),
+        }
+    }
+}
+
 impl FontScheme {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn major_font(mut self, f: FontGroup) -> Self {
+        self.major_font = f;
+        self
+    }
+
+    pub fn minor_font(mut self, f: FontGroup) -> Self {
+        self.minor_font = f;
+        self
+    }
+}
+
+impl BuildXML for FontScheme {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        let b = XMLBuilder::from(stream)
+            .open_font_scheme(&self.name)?
+            .open_major_font()?;
+        let b = self.major_font.build_fonts(b)?.close()?.open_minor_font()?;
+        self.minor_font
+            .build_fonts(b)?
+            .close()?
+            .close()?
+            .into_inner()
+    }
 }
 
 // ============================================================================
@@ -101,6 +187,8 @@ impl<'de> Deserialize<'de> for FontGroup {
 
 #[derive(Deserialize)]
 struct FontSchemeXml {
+    #[serde(rename = "@name", default)]
+    name: Option<String>,
     #[serde(rename = "majorFont", alias = "a:majorFont", default)]
     major_font: Option<FontGroup>,
     #[serde(rename = "minorFont", alias = "a:minorFont", default)]
@@ -114,6 +202,7 @@ impl<'de> Deserialize<'de> for FontScheme {
     {
         let xml = FontSchemeXml::deserialize(deserializer)?;
         Ok(FontScheme {
+            name: xml.name.unwrap_or_else(|| "Office".to_owned()),
             major_font: xml.major_font.unwrap_or_default(),
             minor_font: xml.minor_font.unwrap_or_default(),
         })
@@ -0,0 +1,414 @@
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::io::Write;
+use std::str::FromStr;
+
+use crate::documents::BuildXML;
+use crate::escape::{escape, replace_escaped};
+use crate::types::*;
+use crate::xml_builder::*;
+
+// ============================================================================
+// XML Deserialization Helper Structures (for quick-xml serde)
+// ============================================================================
+
+#[derive(Debug, Deserialize, Default)]
+struct XmlValNode {
+    #[serde(rename = "@val", alias = "@w:val", default)]
+    val: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct TextInputXml {
+    #[serde(rename = "type", alias = "w:type", default)]
+    input_type: Option<XmlValNode>,
+    #[serde(rename = "default", alias = "w:default", default)]
+    default: Option<XmlValNode>,
+    #[serde(rename = "maxLength", alias = "w:maxLength", default)]
+    max_length: Option<XmlValNode>,
+    #[serde(rename = "format", alias = "w:format", default)]
+    format: Option<XmlValNode>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct CheckBoxXml {
+    #[serde(rename = "size", alias = "w:size", default)]
+    size: Option<XmlValNode>,
+    #[serde(rename = "sizeAuto", alias = "w:sizeAuto", default)]
+    size_auto: Option<IgnoredAny>,
+    #[serde(rename = "default", alias = "w:default", default)]
+    default: Option<XmlValNode>,
+    #[serde(rename = "checked", alias = "w:checked", default)]
+    checked: Option<XmlValNode>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct DropDownListXml {
+    #[serde(rename = "result", alias = "w:result", default)]
+    result: Option<XmlValNode>,
+    #[serde(rename = "default", alias = "w:default", default)]
+    default: Option<XmlValNode>,
+    #[serde(rename = "listEntry", alias = "w:listEntry", default)]
+    entries: Vec<XmlValNode>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct FormFieldDataXml {
+    #[serde(rename = "name", alias = "w:name", default)]
+    name: Option<XmlValNode>,
+    #[serde(rename = "enabled", alias = "w:enabled", default)]
+    enabled: Option<XmlValNode>,
+    #[serde(rename = "calcOnExit", alias = "w:calcOnExit", default)]
+    calc_on_exit: Option<XmlValNode>,
+    #[serde(rename = "textInput", alias = "w:textInput", default)]
+    text_input: Option<TextInputXml>,
+    #[serde(rename = "checkBox", alias = "w:checkBox", default)]
+    check_box: Option<CheckBoxXml>,
+    #[serde(rename = "ddList", alias = "w:ddList", default)]
+    drop_down_list: Option<DropDownListXml>,
+}
+
+// An on/off element without w:val is on.
+fn is_on(node: Option<XmlValNode>) -> Option<bool> {
+    node.map(|n| {
+        n.val
+            .map(|v| !matches!(v.trim(), "0" | "false" | "off"))
+            .unwrap_or(true)
+    })
+}
+
+fn parse_usize(node: Option<XmlValNode>) -> Option<usize> {
+    node.and_then(|n| n.val).and_then(|v| v.trim().parse().ok())
+}
+
+// w:textInput of FORMTEXT
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS), ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct FormTextInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_type: Option<TextInputType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
+    // e.g. "UPPERCASE" for regular text or "M/d/yyyy" for dates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+}
+
+impl FormTextInput {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn input_type(mut self, t: TextInputType) -> Self {
+        self.input_type = Some(t);
+        self
+    }
+
+    pub fn default_text(mut self, v: impl Into<String>) -> Self {
+        self.default = Some(escape(&v.into()));
+        self
+    }
+
+    pub fn max_length(mut self, v: usize) -> Self {
+        self.max_length = Some(v);
+        self
+    }
+
+    pub fn format(mut self, v: impl Into<String>) -> Self {
+        self.format = Some(escape(&v.into()));
+        self
+    }
+}
+
+// w:checkBox of FORMCHECKBOX
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS), ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct FormCheckBox {
+    // In half-points. Sized to the text when None.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<usize>,
+    pub default: bool,
+    // The current state. The default state applies when None.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checked: Option<bool>,
+}
+
+impl FormCheckBox {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn size(mut self, v: usize) -> Self {
+        self.size = Some(v);
+        self
+    }
+
+    pub fn default_checked(mut self, v: bool) -> Self {
+        self.default = v;
+        self
+    }
+
+    pub fn checked(mut self, v: bool) -> Self {
+        self.checked = Some(v);
+        self
+    }
+
+    pub fn is_checked(&self) -> bool {
+        self.checked.unwrap_or(self.default)
+    }
+}
+
+// w:ddList of FORMDROPDOWN
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS), ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct FormDropDownList {
+    pub entries: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<usize>,
+    // The index of the selected entry. The default entry applies when None.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<usize>,
+}
+
+impl FormDropDownList {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn add_entry(mut self, v: impl Into<String>) -> Self {
+        self.entries.push(escape(&v.into()));
+        self
+    }
+
+    pub fn default_index(mut self, i: usize) -> Self {
+        self.default = Some(i);
+        self
+    }
+
+    pub fn result(mut self, i: usize) -> Self {
+        self.result = Some(i);
+        self
+    }
+
+    pub fn selected(&self) -> Option<String> {
+        let i = self.result.or(self.default).unwrap_or(0);
+        self.entries.get(i).map(|e| replace_escaped(e))
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS), ts(export))]
+#[serde(tag = "type", content = "data", rename_all = "camelCase")]
+pub enum FormFieldControl {
+    TextInput(FormTextInput),
+    CheckBox(FormCheckBox),
+    DropDownList(FormDropDownList),
+}
+
+impl BuildXML for FormFieldControl {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        let b = XMLBuilder::from(stream);
+        let b = match self {
+            FormFieldControl::TextInput(t) => b
+                .open_form_text_input()?
+                .apply_opt(t.input_type, |v, b| b.type_tag(&v.to_string()))?
+                .apply_opt(t.default.as_ref(), |v, b| b.form_field_default(v))?
+                .apply_opt(t.max_length, |v, b| b.form_field_max_length(v))?
+                .apply_opt(t.format.as_ref(), |v, b| b.form_field_format(v))?
+                .close()?,
+            FormFieldControl::CheckBox(c) => b
+                .open_form_check_box()?
+                .apply(|b| match c.size {
+                    Some(size) => b.form_field_size(size),
+                    None => b.form_field_size_auto(),
+                })?
+                .form_field_default(if c.default { "1" } else { "0" })?
+                .apply_opt(c.checked, |v, b| {
+                    b.form_field_checked(if v { "1" } else { "0" })
+                })?
+                .close()?,
+            FormFieldControl::DropDownList(l) => b
+                .open_form_drop_down_list()?
+                .apply_opt(l.result, |v, b| b.form_field_result(v))?
+                .apply_opt(l.default, |v, b| b.form_field_default(&v.to_string()))?
+                .apply_each(&l.entries, |e, b| b.form_field_list_entry(e))?
+                .close()?,
+        };
+        b.into_inner()
+    }
+}
+
+// w:ffData of legacy form fields (FORMTEXT, FORMCHECKBOX and FORMDROPDOWN).
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS), ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct FormFieldData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub enabled: bool,
+    pub calc_on_exit: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub control: Option<FormFieldControl>,
+}
+
+impl Default for FormFieldData {
+    fn default() -> Self {
+        Self {
+            name: None,
+            enabled: true,
+            calc_on_exit: false,
+            control: None,
+        }
+    }
+}
+
+impl FormFieldData {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn name(mut self, v: impl Into<String>) -> Self {
+        self.name = Some(escape(&v.into()));
+        self
+    }
+
+    pub fn disabled(mut self) -> Self {
+        self.enabled = false;
+        self
+    }
+
+    pub fn calc_on_exit(mut self) -> Self {
+        self.calc_on_exit = true;
+        self
+    }
+
+    pub fn text_input(mut self, t: FormTextInput) -> Self {
+        self.control = Some(FormFieldControl::TextInput(t));
+        self
+    }
+
+    pub fn check_box(mut self, c: FormCheckBox) -> Self {
+        self.control = Some(FormFieldControl::CheckBox(c));
+        self
+    }
+
+    pub fn drop_down_list(mut self, l: FormDropDownList) -> Self {
+        self.control = Some(FormFieldControl::DropDownList(l));
+        self
+    }
+}
+
+impl<'de> Deserialize<'de> for FormFieldData {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let xml = FormFieldDataXml::deserialize(deserializer)?;
+        let control = if let Some(t) = xml.text_input {
+            Some(FormFieldControl::TextInput(FormTextInput {
+                input_type: t
+                    .input_type
+                    .and_then(|n| n.val)
+                    .and_then(|v| TextInputType::from_str(&v).ok()),
+                default: t.default.and_then(|n| n.val).map(|v| escape(&v)),
+                max_length: parse_usize(t.max_length).filter(|v| *v > 0),
+                format: t.format.and_then(|n| n.val).map(|v| escape(&v)),
+            }))
+        } else if let Some(c) = xml.check_box {
+            Some(FormFieldControl::CheckBox(FormCheckBox {
+                size: if c.size_auto.is_some() {
+                    None
+                } else {
+                    parse_usize(c.size)
+                },
+                default: is_on(c.default).unwrap_or(false),
+                checked: is_on(c.checked),
+            }))
+        } else {
+            xml.drop_down_list.map(|l| {
+                FormFieldControl::DropDownList(FormDropDownList {
+                    result: parse_usize(l.result),
+                    default: parse_usize(l.default),
+                    entries: l
+                        .entries
+                        .into_iter()
+                        .filter_map(|n| n.val)
+                        .map(|v| escape(&v))
+                        .collect(),
+                })
+            })
+        };
+        Ok(FormFieldData {
+            name: xml.name.and_then(|n| n.val).map(|v| escape(&v)),
+            enabled: is_on(xml.enabled).unwrap_or(true),
+            calc_on_exit: is_on(xml.calc_on_exit).unwrap_or(false),
+            control,
+        })
+    }
+}
+
+impl BuildXML for FormFieldData {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .open_form_field_data()?
+            .apply_opt(self.name.as_ref(), |v, b| b.name(v))?
+            .form_field_enabled(self.enabled)?
+            .calc_on_exit(if self.calc_on_exit { "1" } else { "0" })?
+            .add_optional_child(&self.control)?
+            .close()?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_form_field_data() {
+        let b = FormFieldData::new()
+            .name("Check1")
+            .check_box(FormCheckBox::new().checked(true))
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:ffData><w:name w:val="Check1" /><w:enabled /><w:calcOnExit w:val="0" /><w:checkBox><w:sizeAuto /><w:default w:val="0" /><w:checked w:val="1" /></w:checkBox></w:ffData>"#
+        );
+    }
+
+    #[test]
+    fn test_form_field_data_xml_deserialize() {
+        let xml = r#"<w:ffData xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+            <w:name w:val="Dropdown1"/>
+            <w:enabled/>
+            <w:calcOnExit w:val="0"/>
+            <w:ddList>
+                <w:result w:val="1"/>
+                <w:listEntry w:val="Red"/>
+                <w:listEntry w:val="Green &amp; Blue"/>
+            </w:ddList>
+        </w:ffData>"#;
+        let d: FormFieldData = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(d.name, Some("Dropdown1".to_string()));
+        assert!(d.enabled);
+        let Some(FormFieldControl::DropDownList(l)) = d.control else {
+            panic!("Expected DropDownList");
+        };
+        assert_eq!(l.selected(), Some("Green & Blue".to_string()));
+    }
+}
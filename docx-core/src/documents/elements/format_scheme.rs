@@ -0,0 +1,96 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// Only the name is kept. The fill, line, effect and background style lists are
+// written as a minimal set of three solid / plain entries referencing phClr.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct FormatScheme {
+    pub name: String,
+}
+
+impl Default for FormatScheme {
+    fn default() -> Self {
+        FormatScheme {
+            name: "Office".to_owned(),
+        }
+    }
+}
+
+impl FormatScheme {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+}
+
+const LINE_WIDTHS: [&str; 3] = ["6350", "12700", "19050"];
+
+impl BuildXML for FormatScheme {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .open_fmt_scheme(&self.name)?
+            .open_fill_style_lst()?
+            .apply_each(0..3, |_, b| {
+                b.open_solid_fill()?.scheme_clr("phClr")?.close()
+            })?
+            .close()?
+            .open_ln_style_lst()?
+            .apply_each(LINE_WIDTHS.iter(), |w, b| {
+                b.open_a_ln(w, "flat", "sng", "ctr")?
+                    .open_solid_fill()?
+                    .scheme_clr("phClr")?
+                    .close()?
+                    .prst_dash("solid")?
+                    .miter("800000")?
+                    .close()
+            })?
+            .close()?
+            .open_effect_style_lst()?
+            .apply_each(0..3, |_, b| b.open_effect_style()?.effect_lst()?.close())?
+            .close()?
+            .open_bg_fill_style_lst()?
+            .apply_each(0..3, |_, b| {
+                b.open_solid_fill()?.scheme_clr("phClr")?.close()
+            })?
+            .close()?
+            .close()?
+            .into_inner()
+    }
+}
+
+// ============================================================================
+// XML Deserialization (quick-xml serde)
+// ============================================================================
+
+#[derive(Deserialize)]
+struct FormatSchemeXml {
+    #[serde(rename = "@name", default)]
+    name: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for FormatScheme {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let xml = FormatSchemeXml::deserialize(deserializer)?;
+        let mut scheme = FormatScheme::new();
+        if let Some(name) = xml.name {
+            scheme.name = name;
+        }
+        Ok(scheme)
+    }
+}
@@ -6,7 +6,7 @@ use crate::xml_builder::*;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct GridSpan {
-    val: usize,
+    pub(crate) val: usize,
 }
 
 impl GridSpan {
@@ -0,0 +1,50 @@
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+use std::io::Write;
+
+use serde::*;
+
+// Font kerning is applied from this size (in half-points) and above.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Kern {
+    value: usize,
+}
+
+impl Kern {
+    pub fn new(s: usize) -> Kern {
+        Self { value: s }
+    }
+}
+
+impl BuildXML for Kern {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream).kern(self.value)?.into_inner()
+    }
+}
+
+impl Serialize for Kern {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(self.value as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_kern() {
+        let b = Kern::new(28).build();
+        assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:kern w:val="28" />"#);
+    }
+}
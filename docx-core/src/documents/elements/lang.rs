@@ -0,0 +1,71 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// Languages of the run used for proofing and font selection, e.g. "en-US".
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Lang {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub val: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub east_asia: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bidi: Option<String>,
+}
+
+impl Lang {
+    pub fn new() -> Lang {
+        Default::default()
+    }
+
+    pub fn val(mut self, v: impl Into<String>) -> Self {
+        self.val = Some(v.into());
+        self
+    }
+
+    pub fn east_asia(mut self, v: impl Into<String>) -> Self {
+        self.east_asia = Some(v.into());
+        self
+    }
+
+    pub fn bidi(mut self, v: impl Into<String>) -> Self {
+        self.bidi = Some(v.into());
+        self
+    }
+}
+
+impl BuildXML for Lang {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .lang(
+                self.val.as_deref(),
+                self.east_asia.as_deref(),
+                self.bidi.as_deref(),
+            )?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_lang() {
+        let b = Lang::new().val("en-US").east_asia("ja-JP").build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:lang w:val="en-US" w:eastAsia="ja-JP" />"#
+        );
+    }
+}
@@ -79,7 +79,7 @@ impl Level {
         self
     }
 
-    pub fn color(mut self, color: impl Into<String>) -> Self {
+    pub fn color(mut self, color: impl Into<ColorValue>) -> Self {
         self.run_property = self.run_property.color(color);
         self
     }
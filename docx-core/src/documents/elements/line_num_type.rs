@@ -0,0 +1,86 @@
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
+use serde::Serialize;
+use std::io::Write;
+
+/// Line numbering of a section (`w:lnNumType`).
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LineNumType {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count_by: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restart: Option<LineNumberRestartType>,
+}
+
+impl LineNumType {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn count_by(self, c: usize) -> Self {
+        Self {
+            count_by: Some(c),
+            ..self
+        }
+    }
+
+    pub fn start(self, s: usize) -> Self {
+        Self {
+            start: Some(s),
+            ..self
+        }
+    }
+
+    pub fn distance(self, d: usize) -> Self {
+        Self {
+            distance: Some(d),
+            ..self
+        }
+    }
+
+    pub fn restart(self, r: LineNumberRestartType) -> Self {
+        Self {
+            restart: Some(r),
+            ..self
+        }
+    }
+}
+
+impl BuildXML for LineNumType {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .line_num_type(self.count_by, self.start, self.distance, self.restart)?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_line_num_type() {
+        let b = LineNumType::new()
+            .count_by(1)
+            .distance(360)
+            .restart(LineNumberRestartType::NewPage)
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:lnNumType w:countBy="1" w:distance="360" w:restart="newPage" />"#
+        );
+    }
+}
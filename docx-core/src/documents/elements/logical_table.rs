@@ -0,0 +1,287 @@
+use std::ops::Range;
+
+use super::*;
+use crate::types::*;
+
+/// A cell position in the logical grid of a table.
+///
+/// `row` and `col` are the grid coordinates of the top-left corner of the cell,
+/// `cell_index` is the index of the `w:tc` inside the `w:tr` at `row`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogicalCell {
+    pub row: usize,
+    pub col: usize,
+    pub row_span: usize,
+    pub col_span: usize,
+    pub cell_index: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct CellMerge {
+    rows: Range<usize>,
+    cols: Range<usize>,
+}
+
+impl CellMerge {
+    fn contains(&self, row: usize, col: usize) -> bool {
+        self.rows.contains(&row) && self.cols.contains(&col)
+    }
+
+    fn overlaps(&self, other: &CellMerge) -> bool {
+        self.rows.start < other.rows.end
+            && other.rows.start < self.rows.end
+            && self.cols.start < other.cols.end
+            && other.cols.start < self.cols.end
+    }
+}
+
+/// Builds a `Table` from a logical `rows` x `cols` grid.
+///
+/// Merged regions are emitted as `w:gridSpan` / `w:vMerge` cells, so callers don't
+/// have to place continuation cells by hand.
+///
+/// ```
+/// use docx_rs::*;
+///
+/// let table = LogicalTable::new(2, 3)
+///     .cell(0, 0, TableCell::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("merged"))))
+///     .merge(0..2, 0..2)
+///     .into_table();
+/// assert_eq!(table.grid.len(), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogicalTable {
+    rows: usize,
+    cols: usize,
+    cells: Vec<Vec<Option<TableCell>>>,
+    merges: Vec<CellMerge>,
+    grid: Option<Vec<usize>>,
+    table: Table,
+}
+
+impl LogicalTable {
+    pub fn new(rows: usize, cols: usize) -> LogicalTable {
+        Self {
+            rows,
+            cols,
+            cells: vec![vec![None; cols]; rows],
+            merges: vec![],
+            grid: None,
+            table: Table::new(vec![]),
+        }
+    }
+
+    /// Use `table` for table level properties (borders, style, width...).
+    /// Its rows are replaced by the logical grid.
+    pub fn table(mut self, table: Table) -> Self {
+        self.table = table;
+        self
+    }
+
+    /// Column widths in dxa. When omitted the default text width is split equally.
+    pub fn grid(mut self, grid: Vec<usize>) -> Self {
+        self.grid = Some(grid);
+        self
+    }
+
+    /// Set the cell at `row`, `col`. For a merged region the top-left cell is used.
+    pub fn cell(mut self, row: usize, col: usize, cell: TableCell) -> Self {
+        if row < self.rows && col < self.cols {
+            self.cells[row][col] = Some(cell);
+        }
+        self
+    }
+
+    /// Merge the cells in `rows` x `cols`.
+    /// Empty ranges, ranges outside of the grid and ranges overlapping an earlier merge are ignored.
+    pub fn merge(mut self, rows: Range<usize>, cols: Range<usize>) -> Self {
+        let merge = CellMerge { rows, cols };
+        if merge.rows.is_empty()
+            || merge.cols.is_empty()
+            || merge.rows.end > self.rows
+            || merge.cols.end > self.cols
+            || self.merges.iter().any(|m| m.overlaps(&merge))
+        {
+            return self;
+        }
+        self.merges.push(merge);
+        self
+    }
+
+    pub fn into_table(self) -> Table {
+        let grid = match self.grid {
+            Some(grid) if grid.len() == self.cols => grid,
+            _ => default_grid(self.cols),
+        };
+        let mut cells = self.cells;
+        // Properties of the top-left cell of each merge, shared with its continuation cells.
+        let mut anchors: Vec<Option<TableCellProperty>> = vec![None; self.merges.len()];
+        let mut rows = vec![];
+        for (r, row_cells) in cells.iter_mut().enumerate() {
+            let mut row = vec![];
+            let mut c = 0;
+            while c < self.cols {
+                let merge = self.merges.iter().position(|m| m.contains(r, c));
+                let col_span = merge.map(|i| self.merges[i].cols.len()).unwrap_or(1);
+                let width = grid[c..c + col_span].iter().sum();
+                let mut cell = match merge {
+                    Some(i) if self.merges[i].rows.start != r => {
+                        let mut cell = TableCell::new();
+                        if let Some(property) = &anchors[i] {
+                            cell.property = property.clone();
+                        }
+                        cell.vertical_merge(VMergeType::Continue)
+                    }
+                    Some(i) if self.merges[i].rows.len() > 1 => row_cells[c]
+                        .take()
+                        .unwrap_or_default()
+                        .vertical_merge(VMergeType::Restart),
+                    _ => row_cells[c].take().unwrap_or_default(),
+                };
+                if col_span > 1 {
+                    cell = cell.grid_span(col_span);
+                }
+                cell = cell.width(width, WidthType::Dxa);
+                if let Some(i) = merge {
+                    if self.merges[i].rows.start == r {
+                        anchors[i] = Some(cell.property.clone());
+                    }
+                }
+                row.push(cell);
+                c += col_span;
+            }
+            rows.push(TableRow::new(row));
+        }
+        let mut table = self.table;
+        table.has_numbering = rows.iter().any(|r| r.has_numbering);
+        table.rows = rows.into_iter().map(TableChild::TableRow).collect();
+        table.set_grid(grid)
+    }
+}
+
+fn default_grid(cols: usize) -> Vec<usize> {
+    if cols == 0 {
+        return vec![];
+    }
+    vec![SectionProperty::new().text_width() / cols; cols]
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::documents::BuildXML;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_logical_table_merge() {
+        let t = LogicalTable::new(2, 3)
+            .grid(vec![100, 200, 300])
+            .merge(0..2, 0..2)
+            .into_table();
+        let b = t.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tbl><w:tblPr><w:tblW w:w="0" w:type="auto" /><w:jc w:val="left" /><w:tblBorders><w:top w:val="single" w:sz="2" w:space="0" w:color="000000" /><w:left w:val="single" w:sz="2" w:space="0" w:color="000000" /><w:bottom w:val="single" w:sz="2" w:space="0" w:color="000000" /><w:right w:val="single" w:sz="2" w:space="0" w:color="000000" /><w:insideH w:val="single" w:sz="2" w:space="0" w:color="000000" /><w:insideV w:val="single" w:sz="2" w:space="0" w:color="000000" /></w:tblBorders></w:tblPr><w:tblGrid><w:gridCol w:w="100" w:type="dxa" /><w:gridCol w:w="200" w:type="dxa" /><w:gridCol w:w="300" w:type="dxa" /></w:tblGrid><w:tr><w:trPr /><w:tc><w:tcPr><w:tcW w:w="300" w:type="dxa" /><w:gridSpan w:val="2" /><w:vMerge w:val="restart" /></w:tcPr><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr></w:p></w:tc><w:tc><w:tcPr><w:tcW w:w="300" w:type="dxa" /></w:tcPr><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr></w:p></w:tc></w:tr><w:tr><w:trPr /><w:tc><w:tcPr><w:tcW w:w="300" w:type="dxa" /><w:gridSpan w:val="2" /><w:vMerge w:val="continue" /></w:tcPr><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr></w:p></w:tc><w:tc><w:tcPr><w:tcW w:w="300" w:type="dxa" /></w:tcPr><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr></w:p></w:tc></w:tr></w:tbl>"#
+        );
+    }
+
+    #[test]
+    fn test_logical_table_ignore_overlapped_merge() {
+        let t = LogicalTable::new(3, 3)
+            .merge(0..2, 0..2)
+            .merge(1..3, 1..3)
+            .merge(2..3, 0..4)
+            .into_table();
+        assert_eq!(
+            t.logical_cells(),
+            vec![
+                LogicalCell {
+                    row: 0,
+                    col: 0,
+                    row_span: 2,
+                    col_span: 2,
+                    cell_index: 0
+                },
+                LogicalCell {
+                    row: 0,
+                    col: 2,
+                    row_span: 1,
+                    col_span: 1,
+                    cell_index: 1
+                },
+                LogicalCell {
+                    row: 1,
+                    col: 2,
+                    row_span: 1,
+                    col_span: 1,
+                    cell_index: 1
+                },
+                LogicalCell {
+                    row: 2,
+                    col: 0,
+                    row_span: 1,
+                    col_span: 1,
+                    cell_index: 0
+                },
+                LogicalCell {
+                    row: 2,
+                    col: 1,
+                    row_span: 1,
+                    col_span: 1,
+                    cell_index: 1
+                },
+                LogicalCell {
+                    row: 2,
+                    col: 2,
+                    row_span: 1,
+                    col_span: 1,
+                    cell_index: 2
+                },
+            ]
+        );
+        assert_eq!(t.grid, vec![2834, 2834, 2834]);
+    }
+
+    #[test]
+    fn test_logical_cells_from_xml() {
+        let xml = r#"<w:tbl xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+            <w:tblGrid>
+                <w:gridCol w:w="1000"/>
+                <w:gridCol w:w="1000"/>
+                <w:gridCol w:w="1000"/>
+            </w:tblGrid>
+            <w:tr>
+                <w:tc><w:tcPr><w:vMerge w:val="restart"/></w:tcPr><w:p /></w:tc>
+                <w:tc><w:tcPr><w:gridSpan w:val="2"/></w:tcPr><w:p /></w:tc>
+            </w:tr>
+            <w:tr>
+                <w:tc><w:tcPr><w:vMerge/></w:tcPr><w:p /></w:tc>
+                <w:tc><w:p /></w:tc>
+                <w:tc><w:tcPr><w:vMerge w:val="restart"/></w:tcPr><w:p /></w:tc>
+            </w:tr>
+            <w:tr>
+                <w:tc><w:tcPr><w:gridSpan w:val="2"/></w:tcPr><w:p /></w:tc>
+                <w:tc><w:tcPr><w:vMerge/></w:tcPr><w:p /></w:tc>
+            </w:tr>
+        </w:tbl>"#;
+        let t: Table = quick_xml::de::from_str(xml).unwrap();
+        let cells: Vec<_> = t
+            .logical_cells()
+            .into_iter()
+            .map(|c| (c.row, c.col, c.row_span, c.col_span))
+            .collect();
+        assert_eq!(
+            cells,
+            vec![
+                (0, 0, 2, 1),
+                (0, 1, 1, 2),
+                (1, 1, 1, 1),
+                (1, 2, 2, 1),
+                (2, 0, 1, 2)
+            ]
+        );
+    }
+}
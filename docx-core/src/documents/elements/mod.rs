@@ -10,13 +10,23 @@ mod bookmark_start;
 mod br;
 mod cant_split;
 mod caps;
+mod cell_delete;
+mod cell_insert;
 mod cell_margins;
+mod cell_merge;
 mod character_spacing;
+mod cnf_style;
 mod color;
+mod color_scheme;
+mod column;
 mod comment;
+mod comment_durable_id;
 mod comment_extended;
+mod comment_extensible;
 mod comment_range_end;
 mod comment_range_start;
+mod compat;
+mod content_control;
 mod data_binding;
 mod default_tab_stop;
 mod delete;
@@ -27,19 +37,27 @@ mod doc_defaults;
 mod doc_grid;
 mod doc_id;
 mod doc_var;
+mod document_protection;
 mod drawing;
 mod dstrike;
+mod effect;
+mod emboss;
+mod fit_text;
+mod field;
 mod fld_char;
 mod font;
 mod font_scheme;
 mod footer_reference;
 mod footnote;
 mod footnote_reference;
+mod form_field_data;
+mod format_scheme;
 mod frame_property;
 mod grid_span;
 mod header_reference;
 mod highlight;
 mod hyperlink;
+mod imprint;
 mod indent;
 mod indent_level;
 mod insert;
@@ -54,22 +72,36 @@ mod is_lgl;
 mod italic;
 mod italic_cs;
 mod justification;
+mod kern;
+mod lang;
 mod level;
 mod level_jc;
 mod level_override;
 mod level_restart;
 mod level_text;
+mod line_num_type;
 mod line_spacing;
 mod link;
+mod logical_table;
 mod mc_fallback;
+mod move_from;
+mod move_from_range_end;
+mod move_from_range_start;
+mod move_to;
+mod move_to_range_end;
+mod move_to_range_start;
 mod name;
 mod next;
+mod no_proof;
 mod num_pages;
 mod number_format;
 mod numbering;
+mod numbering_change;
 mod numbering_id;
 mod numbering_property;
+mod outline;
 mod outline_lvl;
+mod page_borders;
 mod page_margin;
 mod page_num;
 mod page_num_type;
@@ -80,18 +112,26 @@ mod paragraph_property;
 mod paragraph_property_change;
 mod paragraph_property_default;
 mod paragraph_style;
+mod perm_end;
+mod perm_start;
+mod person;
 mod pic;
+mod position;
 mod positional_tab;
+mod proof_state;
 mod q_format;
 mod run;
 mod run_fonts;
 mod run_property;
+mod run_property_change;
 mod run_property_default;
 mod run_style;
 mod section;
 mod section_property;
 mod shading;
+mod shadow;
 mod shape;
+mod small_caps;
 mod spec_vanish;
 mod start;
 mod stretch;
@@ -109,6 +149,7 @@ mod table_cell;
 mod table_cell_borders;
 mod table_cell_margins;
 mod table_cell_property;
+mod table_cell_property_change;
 mod table_cell_width;
 mod table_grid;
 mod table_indent;
@@ -117,8 +158,10 @@ mod table_of_contents;
 mod table_of_contents_item;
 mod table_position_property;
 mod table_property;
+mod table_property_change;
 mod table_row;
 mod table_row_property;
+mod table_row_property_change;
 mod table_style;
 mod table_width;
 mod tabs;
@@ -128,11 +171,13 @@ mod text_border;
 mod text_box;
 mod text_box_content;
 mod text_direction;
+mod theme_font_lang;
 mod underline;
 mod v_align;
 mod vanish;
 mod vert_align;
 mod vertical_merge;
+mod web_hidden;
 mod wp_anchor;
 mod wps_shape;
 mod wps_text_box;
@@ -150,13 +195,23 @@ pub use bookmark_start::*;
 pub use br::*;
 pub use cant_split::*;
 pub use caps::*;
+pub use cell_delete::*;
+pub use cell_insert::*;
 pub use cell_margins::*;
+pub use cell_merge::*;
 pub use character_spacing::*;
+pub use cnf_style::*;
 pub use color::*;
+pub use color_scheme::*;
+pub use column::*;
 pub use comment::*;
+pub use comment_durable_id::*;
 pub use comment_extended::*;
+pub use comment_extensible::*;
 pub use comment_range_end::*;
 pub use comment_range_start::*;
+pub use compat::*;
+pub use content_control::*;
 pub use data_binding::*;
 pub use default_tab_stop::*;
 pub use delete::*;
@@ -167,19 +222,27 @@ pub use doc_defaults::*;
 pub use doc_grid::*;
 pub use doc_id::*;
 pub use doc_var::*;
+pub use document_protection::*;
 pub use drawing::*;
 pub use dstrike::*;
+pub use effect::*;
+pub use emboss::*;
+pub use fit_text::*;
+pub use field::*;
 pub use fld_char::*;
 pub use font::*;
 pub use font_scheme::*;
 pub use footer_reference::*;
 pub use footnote::*;
 pub use footnote_reference::*;
+pub use form_field_data::*;
+pub use format_scheme::*;
 pub use frame_property::*;
 pub use grid_span::*;
 pub use header_reference::*;
 pub use highlight::*;
 pub use hyperlink::*;
+pub use imprint::*;
 pub use indent::*;
 pub use indent_level::*;
 pub use insert::*;
@@ -194,22 +257,36 @@ pub use is_lgl::*;
 pub use italic::*;
 pub use italic_cs::*;
 pub use justification::*;
+pub use kern::*;
+pub use lang::*;
 pub use level::*;
 pub use level_jc::*;
 pub use level_override::*;
 pub use level_restart::*;
 pub use level_text::*;
+pub use line_num_type::*;
 pub use line_spacing::*;
 pub use link::*;
+pub use logical_table::*;
 pub use mc_fallback::*;
+pub use move_from::*;
+pub use move_from_range_end::*;
+pub use move_from_range_start::*;
+pub use move_to::*;
+pub use move_to_range_end::*;
+pub use move_to_range_start::*;
 pub use name::*;
 pub use next::*;
+pub use no_proof::*;
 pub use num_pages::*;
 pub use number_format::*;
 pub use numbering::*;
+pub use numbering_change::*;
 pub use numbering_id::*;
 pub use numbering_property::*;
+pub use outline::*;
 pub use outline_lvl::*;
+pub use page_borders::*;
 pub use page_num::*;
 pub use page_num_type::*;
 pub use page_size::*;
@@ -219,18 +296,26 @@ pub use paragraph_property::*;
 pub use paragraph_property_change::*;
 pub use paragraph_property_default::*;
 pub use paragraph_style::*;
+pub use perm_end::*;
+pub use perm_start::*;
+pub use person::*;
 pub use pic::*;
+pub use position::*;
 pub use positional_tab::*;
+pub use proof_state::*;
 pub use q_format::*;
 pub use run::*;
 pub use run_fonts::*;
 pub use run_property::*;
+pub use run_property_change::*;
 pub use run_property_default::*;
 pub use run_style::*;
 pub use section::*;
 pub use section_property::*;
 pub use shading::*;
+pub use shadow::*;
 pub use shape::*;
+pub use small_caps::*;
 pub use spec_vanish::*;
 pub use start::*;
 pub use stretch::*;
@@ -248,6 +333,7 @@ pub use table_cell::*;
 pub use table_cell_borders::*;
 pub use table_cell_margins::*;
 pub use table_cell_property::*;
+pub use table_cell_property_change::*;
 pub use table_cell_width::*;
 pub use table_grid::*;
 pub use table_indent::*;
@@ -256,8 +342,10 @@ pub use table_of_contents::*;
 pub use table_of_contents_item::*;
 pub use table_position_property::*;
 pub use table_property::*;
+pub use table_property_change::*;
 pub use table_row::*;
 pub use table_row_property::*;
+pub use table_row_property_change::*;
 pub use table_style::*;
 pub use table_width::*;
 pub use tabs::*;
@@ -267,11 +355,13 @@ pub use text_border::*;
 pub use text_box::*;
 pub use text_box_content::*;
 pub use text_direction::*;
+pub use theme_font_lang::*;
 pub use underline::*;
 pub use v_align::*;
 pub use vanish::*;
 pub use vert_align::*;
 pub use vertical_merge::*;
+pub use web_hidden::*;
 pub use wp_anchor::*;
 pub use wps_shape::*;
 pub use wps_text_box::*;
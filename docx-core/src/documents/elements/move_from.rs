@@ -0,0 +1,125 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use std::io::Write;
+
+use crate::xml_builder::*;
+use crate::{documents::*, escape};
+
+// The source of moved content. It holds the same content as a deletion.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct MoveFrom {
+    pub author: String,
+    pub date: String,
+    pub children: Vec<DeleteChild>,
+}
+
+impl<'de> Deserialize<'de> for MoveFrom {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let del = Delete::deserialize(deserializer)?;
+        Ok(MoveFrom {
+            author: del.author,
+            date: del.date,
+            children: del.children,
+        })
+    }
+}
+
+impl Default for MoveFrom {
+    fn default() -> MoveFrom {
+        MoveFrom {
+            author: "unnamed".to_owned(),
+            date: "1970-01-01T00:00:00Z".to_owned(),
+            children: vec![],
+        }
+    }
+}
+
+impl MoveFrom {
+    pub fn new() -> MoveFrom {
+        Default::default()
+    }
+
+    pub fn add_run(mut self, run: Run) -> MoveFrom {
+        self.children.push(DeleteChild::Run(run));
+        self
+    }
+
+    pub fn add_comment_start(mut self, comment: Comment) -> MoveFrom {
+        self.children
+            .push(DeleteChild::CommentStart(Box::new(CommentRangeStart::new(
+                comment,
+            ))));
+        self
+    }
+
+    pub fn add_comment_end(mut self, id: usize) -> MoveFrom {
+        self.children
+            .push(DeleteChild::CommentEnd(CommentRangeEnd::new(id)));
+        self
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> MoveFrom {
+        self.author = escape::escape(&author.into());
+        self
+    }
+
+    pub fn date(mut self, date: impl Into<String>) -> MoveFrom {
+        self.date = date.into();
+        self
+    }
+}
+
+impl HistoryId for MoveFrom {}
+
+impl BuildXML for MoveFrom {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        let id = self.generate();
+        XMLBuilder::from(stream)
+            .open_move_from(&id, &self.author, &self.date)?
+            .apply_each(&self.children, |ch, b| match ch {
+                DeleteChild::Run(t) => b.add_child(t),
+                DeleteChild::CommentStart(c) => b.add_child(&c),
+                DeleteChild::CommentEnd(c) => b.add_child(c),
+            })?
+            .close()?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_move_from() {
+        let b = MoveFrom::new()
+            .add_run(Run::new().add_delete_text("moved"))
+            .author("bob")
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:moveFrom w:id="123" w:author="bob" w:date="1970-01-01T00:00:00Z"><w:r><w:rPr /><w:delText xml:space="preserve">moved</w:delText></w:r></w:moveFrom>"#
+        );
+    }
+
+    #[test]
+    fn test_move_from_xml_deserialize() {
+        let xml = r#"<w:moveFrom xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" w:id="3" w:author="Jane" w:date="2024-01-03T00:00:00Z">
+            <w:r><w:delText>moved text</w:delText></w:r>
+        </w:moveFrom>"#;
+
+        let m: MoveFrom = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(m.author, "Jane");
+        assert_eq!(m.date, "2024-01-03T00:00:00Z");
+        assert_eq!(m.children.len(), 1);
+    }
+}
@@ -0,0 +1,27 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct MoveFromRangeEnd {
+    pub id: usize,
+}
+
+impl MoveFromRangeEnd {
+    pub fn new(id: usize) -> MoveFromRangeEnd {
+        MoveFromRangeEnd { id }
+    }
+}
+
+impl BuildXML for MoveFromRangeEnd {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .move_from_range_end(&self.id.to_string())?
+            .into_inner()
+    }
+}
@@ -0,0 +1,115 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use std::io::Write;
+
+use crate::xml_builder::*;
+use crate::{documents::*, escape};
+
+// The destination of moved content. It holds the same content as an insertion.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct MoveTo {
+    pub children: Vec<InsertChild>,
+    pub author: String,
+    pub date: String,
+}
+
+impl<'de> Deserialize<'de> for MoveTo {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let ins = Insert::deserialize(deserializer)?;
+        Ok(MoveTo {
+            children: ins.children,
+            author: ins.author,
+            date: ins.date,
+        })
+    }
+}
+
+impl Default for MoveTo {
+    fn default() -> MoveTo {
+        MoveTo {
+            author: "unnamed".to_owned(),
+            date: "1970-01-01T00:00:00Z".to_owned(),
+            children: vec![],
+        }
+    }
+}
+
+impl MoveTo {
+    pub fn new(run: Run) -> MoveTo {
+        Self {
+            children: vec![InsertChild::Run(Box::new(run))],
+            ..Default::default()
+        }
+    }
+
+    // e.g. the moved paragraph mark.
+    pub fn new_with_empty() -> MoveTo {
+        Default::default()
+    }
+
+    pub fn add_run(mut self, run: Run) -> MoveTo {
+        self.children.push(InsertChild::Run(Box::new(run)));
+        self
+    }
+
+    pub fn add_comment_start(mut self, comment: Comment) -> MoveTo {
+        self.children
+            .push(InsertChild::CommentStart(Box::new(CommentRangeStart::new(
+                comment,
+            ))));
+        self
+    }
+
+    pub fn add_comment_end(mut self, id: usize) -> MoveTo {
+        self.children
+            .push(InsertChild::CommentEnd(CommentRangeEnd::new(id)));
+        self
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> MoveTo {
+        self.author = escape::escape(&author.into());
+        self
+    }
+
+    pub fn date(mut self, date: impl Into<String>) -> MoveTo {
+        self.date = date.into();
+        self
+    }
+}
+
+impl HistoryId for MoveTo {}
+
+impl BuildXML for MoveTo {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .open_move_to(&self.generate(), &self.author, &self.date)?
+            .add_children(&self.children)?
+            .close()?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_move_to() {
+        let b = MoveTo::new(Run::new().add_text("moved"))
+            .author("bob")
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:moveTo w:id="123" w:author="bob" w:date="1970-01-01T00:00:00Z"><w:r><w:rPr /><w:t xml:space="preserve">moved</w:t></w:r></w:moveTo>"#
+        );
+    }
+}
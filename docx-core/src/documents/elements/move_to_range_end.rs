@@ -0,0 +1,27 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct MoveToRangeEnd {
+    pub id: usize,
+}
+
+impl MoveToRangeEnd {
+    pub fn new(id: usize) -> MoveToRangeEnd {
+        MoveToRangeEnd { id }
+    }
+}
+
+impl BuildXML for MoveToRangeEnd {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .move_to_range_end(&self.id.to_string())?
+            .into_inner()
+    }
+}
@@ -22,6 +22,10 @@ impl Name {
         self.name.starts_with(s)
     }
 
+    pub fn eq_ignore_case(&self, s: &str) -> bool {
+        self.name.eq_ignore_ascii_case(s)
+    }
+
     pub fn is_heading(&self) -> bool {
         self.name.to_lowercase().starts_with("heading")
     }
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct NoProof {
+    val: bool,
+}
+
+impl NoProof {
+    pub fn new() -> NoProof {
+        Default::default()
+    }
+
+    pub fn disable(mut self) -> NoProof {
+        self.val = false;
+        self
+    }
+}
+
+impl Default for NoProof {
+    fn default() -> Self {
+        Self { val: true }
+    }
+}
+
+impl Serialize for NoProof {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(self.val)
+    }
+}
+
+impl BuildXML for NoProof {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        if self.val {
+            XMLBuilder::from(stream).no_proof()?.into_inner()
+        } else {
+            XMLBuilder::from(stream)
+                .disabled_toggle("w:noProof")?
+                .into_inner()
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::{BuildXML, HistoryId};
+use crate::escape;
+use crate::xml_builder::*;
+
+// A tracked change of the numbering of a paragraph.
+// The original is the number text shown before the change, e.g. "%1.".
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct NumberingChange {
+    pub author: String,
+    pub date: String,
+    pub original: String,
+}
+
+impl Default for NumberingChange {
+    fn default() -> NumberingChange {
+        NumberingChange {
+            author: "unnamed".to_owned(),
+            date: "1970-01-01T00:00:00Z".to_owned(),
+            original: String::new(),
+        }
+    }
+}
+
+impl NumberingChange {
+    pub fn new(original: impl Into<String>) -> NumberingChange {
+        NumberingChange {
+            original: escape::escape(&original.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> NumberingChange {
+        self.author = escape::escape(&author.into());
+        self
+    }
+
+    pub fn date(mut self, date: impl Into<String>) -> NumberingChange {
+        self.date = date.into();
+        self
+    }
+}
+
+impl HistoryId for NumberingChange {}
+
+impl BuildXML for NumberingChange {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .numbering_change(&self.generate(), &self.author, &self.date, &self.original)?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_numbering_change() {
+        let b = NumberingChange::new("%1.").author("bob").build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:numberingChange w:id="123" w:author="bob" w:date="1970-01-01T00:00:00Z" w:original="%1." />"#
+        );
+    }
+}
@@ -2,7 +2,7 @@ use serde::ser::{SerializeStruct, Serializer};
 use serde::Serialize;
 use std::io::Write;
 
-use super::{IndentLevel, NumberingId};
+use super::{IndentLevel, NumberingChange, NumberingId};
 use crate::documents::BuildXML;
 use crate::xml_builder::*;
 
@@ -10,6 +10,7 @@ use crate::xml_builder::*;
 pub struct NumberingProperty {
     pub id: Option<NumberingId>,
     pub level: Option<IndentLevel>,
+    pub numbering_change: Option<NumberingChange>,
 }
 
 impl NumberingProperty {
@@ -32,6 +33,11 @@ impl NumberingProperty {
         self.level = Some(level);
         self
     }
+
+    pub fn numbering_change(mut self, c: NumberingChange) -> NumberingProperty {
+        self.numbering_change = Some(c);
+        self
+    }
 }
 
 impl BuildXML for NumberingProperty {
@@ -43,6 +49,7 @@ impl BuildXML for NumberingProperty {
             .open_numbering_property()?
             .add_optional_child(&self.id)?
             .add_optional_child(&self.level)?
+            .add_optional_child(&self.numbering_change)?
             .close()?
             .into_inner()
     }
@@ -65,6 +72,9 @@ impl Serialize for NumberingProperty {
             level = Some(n.val);
         }
         t.serialize_field("level", &level)?;
+        if let Some(c) = &self.numbering_change {
+            t.serialize_field("numberingChange", c)?;
+        }
         t.end()
     }
 }
@@ -87,6 +97,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_num_property_with_numbering_change() {
+        let c = NumberingProperty::new()
+            .add_num(NumberingId::new(1), IndentLevel::new(0))
+            .numbering_change(NumberingChange::new("%1.").author("bob"));
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:numPr><w:numId w:val="1" /><w:ilvl w:val="0" /><w:numberingChange w:id="123" w:author="bob" w:date="1970-01-01T00:00:00Z" w:original="%1." /></w:numPr>"#
+        );
+    }
+
     #[test]
     fn test_empty_num_property() {
         let c = NumberingProperty::new();
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Outline {
+    val: bool,
+}
+
+impl Outline {
+    pub fn new() -> Outline {
+        Default::default()
+    }
+
+    pub fn disable(mut self) -> Outline {
+        self.val = false;
+        self
+    }
+}
+
+impl Default for Outline {
+    fn default() -> Self {
+        Self { val: true }
+    }
+}
+
+impl Serialize for Outline {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(self.val)
+    }
+}
+
+impl BuildXML for Outline {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        if self.val {
+            XMLBuilder::from(stream).outline()?.into_inner()
+        } else {
+            XMLBuilder::from(stream)
+                .disabled_toggle("w:outline")?
+                .into_inner()
+        }
+    }
+}
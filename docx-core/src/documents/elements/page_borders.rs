@@ -0,0 +1,196 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PageBorder {
+    position: PageBorderPosition,
+    pub val: BorderType,
+    pub size: usize,
+    pub space: usize,
+    pub color: String,
+    pub shadow: bool,
+    pub frame: bool,
+}
+
+impl PageBorder {
+    pub fn new(position: PageBorderPosition) -> Self {
+        PageBorder {
+            position,
+            val: BorderType::Single,
+            size: 4,
+            space: 24,
+            color: "auto".to_owned(),
+            shadow: false,
+            frame: false,
+        }
+    }
+
+    pub fn val(mut self, val: BorderType) -> Self {
+        self.val = val;
+        self
+    }
+
+    pub fn size(mut self, size: usize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn space(mut self, space: usize) -> Self {
+        self.space = space;
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<String>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    pub fn shadow(mut self) -> Self {
+        self.shadow = true;
+        self
+    }
+
+    pub fn frame(mut self) -> Self {
+        self.frame = true;
+        self
+    }
+}
+
+impl BuildXML for PageBorder {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        let el = match self.position {
+            PageBorderPosition::Top => "w:top",
+            PageBorderPosition::Left => "w:left",
+            PageBorderPosition::Bottom => "w:bottom",
+            PageBorderPosition::Right => "w:right",
+        };
+        XMLBuilder::from(stream)
+            .page_border(
+                el,
+                &self.val.to_string(),
+                self.size,
+                self.space,
+                &self.color,
+                self.shadow,
+                self.frame,
+            )?
+            .into_inner()
+    }
+}
+
+/// Borders around the pages of a section (`w:pgBorders`).
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PageBorders {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset_from: Option<PageBorderOffsetType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display: Option<PageBorderDisplayType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub z_order: Option<PageBorderZOrderType>,
+    top: Option<PageBorder>,
+    left: Option<PageBorder>,
+    bottom: Option<PageBorder>,
+    right: Option<PageBorder>,
+}
+
+impl PageBorders {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn offset_from(mut self, o: PageBorderOffsetType) -> Self {
+        self.offset_from = Some(o);
+        self
+    }
+
+    pub fn display(mut self, d: PageBorderDisplayType) -> Self {
+        self.display = Some(d);
+        self
+    }
+
+    pub fn z_order(mut self, z: PageBorderZOrderType) -> Self {
+        self.z_order = Some(z);
+        self
+    }
+
+    pub fn set(mut self, border: PageBorder) -> Self {
+        match border.position {
+            PageBorderPosition::Top => self.top = Some(border),
+            PageBorderPosition::Left => self.left = Some(border),
+            PageBorderPosition::Bottom => self.bottom = Some(border),
+            PageBorderPosition::Right => self.right = Some(border),
+        };
+        self
+    }
+
+    pub fn clear(mut self, position: PageBorderPosition) -> Self {
+        match position {
+            PageBorderPosition::Top => self.top = None,
+            PageBorderPosition::Left => self.left = None,
+            PageBorderPosition::Bottom => self.bottom = None,
+            PageBorderPosition::Right => self.right = None,
+        };
+        self
+    }
+
+    pub fn get(&self, position: PageBorderPosition) -> Option<&PageBorder> {
+        match position {
+            PageBorderPosition::Top => self.top.as_ref(),
+            PageBorderPosition::Left => self.left.as_ref(),
+            PageBorderPosition::Bottom => self.bottom.as_ref(),
+            PageBorderPosition::Right => self.right.as_ref(),
+        }
+    }
+}
+
+impl BuildXML for PageBorders {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .open_page_borders(self.z_order, self.display, self.offset_from)?
+            .add_optional_child(&self.top)?
+            .add_optional_child(&self.left)?
+            .add_optional_child(&self.bottom)?
+            .add_optional_child(&self.right)?
+            .close()?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_page_borders() {
+        let b = PageBorders::new()
+            .offset_from(PageBorderOffsetType::Page)
+            .set(
+                PageBorder::new(PageBorderPosition::Top)
+                    .val(BorderType::CertificateBanner)
+                    .size(31)
+                    .color("FF0000"),
+            )
+            .set(PageBorder::new(PageBorderPosition::Bottom).shadow())
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:pgBorders w:offsetFrom="page"><w:top w:val="certificateBanner" w:sz="31" w:space="24" w:color="FF0000" /><w:bottom w:val="single" w:sz="4" w:space="24" w:color="auto" w:shadow="1" /></w:pgBorders>"#
+        );
+    }
+}
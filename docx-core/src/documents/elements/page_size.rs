@@ -48,6 +48,10 @@ impl PageSize {
         self
     }
 
+    pub fn get_width(&self) -> u32 {
+        self.w
+    }
+
     pub fn orient(mut self, o: PageOrientationType) -> PageSize {
         self.orient = Some(o);
         self
@@ -2,12 +2,15 @@ use serde::de::IgnoredAny;
 use serde::ser::{SerializeStruct, Serializer};
 use serde::{Deserialize, Deserializer, Serialize};
 use std::io::Write;
+use std::str::FromStr;
 
 use super::*;
 use super::style::{
-    parse_paragraph_property_xml, ParagraphPropertyXml as StyleParagraphPropertyXml, XmlValueAttr,
+    parse_paragraph_property_xml, NumberingPropertyXml,
+    ParagraphPropertyXml as StyleParagraphPropertyXml,
 };
 use crate::documents::BuildXML;
+use crate::escape::escape;
 use crate::types::*;
 use crate::xml_builder::*;
 
@@ -15,22 +18,6 @@ use crate::xml_builder::*;
 // XML Deserialization Helper Structures (for quick-xml serde)
 // ============================================================================
 
-#[derive(Debug, Deserialize, Default)]
-struct NumberingPropertyXml {
-    #[serde(rename = "numId", alias = "w:numId", default)]
-    num_id: Option<XmlValueAttr>,
-    #[serde(rename = "ilvl", alias = "w:ilvl", default)]
-    ilvl: Option<XmlValueAttr>,
-}
-
-#[derive(Debug, Deserialize, Default)]
-struct ParagraphPropertyWithNumPrXml {
-    #[serde(flatten)]
-    base: StyleParagraphPropertyXml,
-    #[serde(rename = "numPr", alias = "w:numPr", default)]
-    num_pr: Option<NumberingPropertyXml>,
-}
-
 #[derive(Debug, Deserialize, Default)]
 struct ParagraphXml {
     #[serde(
@@ -41,7 +28,7 @@ struct ParagraphXml {
     )]
     id: Option<String>,
     #[serde(rename = "pPr", alias = "w:pPr", default)]
-    property: Option<ParagraphPropertyWithNumPrXml>,
+    property: Option<StyleParagraphPropertyXml>,
     #[serde(rename = "$value", default)]
     children: Vec<ParagraphChildXml>,
 }
@@ -54,12 +41,96 @@ struct XmlBookmarkStartNode {
     name: Option<String>,
 }
 
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct XmlPermStartNode {
+    #[serde(rename = "@id", alias = "@w:id", default)]
+    id: Option<String>,
+    #[serde(rename = "@edGrp", alias = "@w:edGrp", default)]
+    editor_group: Option<String>,
+    #[serde(rename = "@ed", alias = "@w:ed", default)]
+    editor: Option<String>,
+    #[serde(rename = "@colFirst", alias = "@w:colFirst", default)]
+    col_first: Option<String>,
+    #[serde(rename = "@colLast", alias = "@w:colLast", default)]
+    col_last: Option<String>,
+}
+
+impl XmlPermStartNode {
+    pub(crate) fn into_perm_start(self) -> Option<PermStart> {
+        let id = parse_optional_usize(self.id)?;
+        let mut perm = PermStart::new(id);
+        perm.editor_group = self
+            .editor_group
+            .and_then(|g| EditorGroupType::from_str(&g).ok());
+        perm.editor = self.editor.map(|e| escape(&e));
+        perm.col_first = parse_optional_usize(self.col_first);
+        perm.col_last = parse_optional_usize(self.col_last);
+        Some(perm)
+    }
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub(crate) struct XmlMoveRangeStartNode {
+    #[serde(rename = "@id", alias = "@w:id", default)]
+    id: Option<String>,
+    #[serde(rename = "@name", alias = "@w:name", default)]
+    name: Option<String>,
+    #[serde(rename = "@author", alias = "@w:author", default)]
+    author: Option<String>,
+    #[serde(rename = "@date", alias = "@w:date", default)]
+    date: Option<String>,
+}
+
+impl XmlMoveRangeStartNode {
+    pub(crate) fn into_move_from_range_start(self) -> Option<MoveFromRangeStart> {
+        let id = parse_optional_usize(self.id)?;
+        let mut start = MoveFromRangeStart::new(id, self.name.unwrap_or_default());
+        if let Some(author) = self.author {
+            start = start.author(author);
+        }
+        if let Some(date) = self.date {
+            start = start.date(date);
+        }
+        Some(start)
+    }
+
+    pub(crate) fn into_move_to_range_start(self) -> Option<MoveToRangeStart> {
+        let id = parse_optional_usize(self.id)?;
+        let mut start = MoveToRangeStart::new(id, self.name.unwrap_or_default());
+        if let Some(author) = self.author {
+            start = start.author(author);
+        }
+        if let Some(date) = self.date {
+            start = start.date(date);
+        }
+        Some(start)
+    }
+}
+
 #[derive(Debug, Deserialize, Default)]
 struct XmlIdNode {
     #[serde(rename = "@id", alias = "@w:id", default)]
     id: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+enum FieldSimpleChildXml {
+    #[serde(rename = "r", alias = "w:r")]
+    Run(Run),
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct XmlFieldSimpleNode {
+    #[serde(rename = "@instr", alias = "@w:instr", default)]
+    instr: Option<String>,
+    #[serde(rename = "@dirty", alias = "@w:dirty", default)]
+    dirty: Option<String>,
+    #[serde(rename = "$value", default)]
+    children: Vec<FieldSimpleChildXml>,
+}
+
 #[derive(Debug, Deserialize)]
 enum ParagraphChildXml {
     #[serde(rename = "r", alias = "w:r")]
@@ -72,14 +143,32 @@ enum ParagraphChildXml {
     CommentStart(XmlIdNode),
     #[serde(rename = "commentRangeEnd", alias = "w:commentRangeEnd")]
     CommentEnd(XmlIdNode),
+    #[serde(rename = "permStart", alias = "w:permStart")]
+    PermStart(XmlPermStartNode),
+    #[serde(rename = "permEnd", alias = "w:permEnd")]
+    PermEnd(XmlIdNode),
     #[serde(rename = "ins", alias = "w:ins")]
     Insert(Insert),
     #[serde(rename = "del", alias = "w:del")]
     Delete(Delete),
+    #[serde(rename = "moveFrom", alias = "w:moveFrom")]
+    MoveFrom(MoveFrom),
+    #[serde(rename = "moveTo", alias = "w:moveTo")]
+    MoveTo(MoveTo),
+    #[serde(rename = "moveFromRangeStart", alias = "w:moveFromRangeStart")]
+    MoveFromRangeStart(XmlMoveRangeStartNode),
+    #[serde(rename = "moveFromRangeEnd", alias = "w:moveFromRangeEnd")]
+    MoveFromRangeEnd(XmlIdNode),
+    #[serde(rename = "moveToRangeStart", alias = "w:moveToRangeStart")]
+    MoveToRangeStart(XmlMoveRangeStartNode),
+    #[serde(rename = "moveToRangeEnd", alias = "w:moveToRangeEnd")]
+    MoveToRangeEnd(XmlIdNode),
     #[serde(rename = "hyperlink", alias = "w:hyperlink")]
     Hyperlink(Hyperlink),
     #[serde(rename = "sdt", alias = "w:sdt")]
     StructuredDataTag(StructuredDataTag),
+    #[serde(rename = "fldSimple", alias = "w:fldSimple")]
+    FieldSimple(XmlFieldSimpleNode),
     #[serde(rename = "pPr", alias = "w:pPr")]
     ParagraphProperty(IgnoredAny), // Already handled separately
     #[serde(other)]
@@ -99,6 +188,16 @@ fn parse_numbering_property_xml(xml: Option<NumberingPropertyXml>) -> Option<Num
     if let Some(level) = parse_optional_usize(xml.ilvl.and_then(|v| v.val)) {
         np = np.level(IndentLevel::new(level));
     }
+    if let Some(change) = xml.numbering_change {
+        let mut c = NumberingChange::new(change.original.unwrap_or_default());
+        if let Some(author) = change.author {
+            c = c.author(author);
+        }
+        if let Some(date) = change.date {
+            c = c.date(date);
+        }
+        np = np.numbering_change(c);
+    }
     Some(np)
 }
 
@@ -124,16 +223,64 @@ fn paragraph_child_from_xml(xml: ParagraphChildXml) -> Option<ParagraphChild> {
             let id = parse_optional_usize(node.id)?;
             Some(ParagraphChild::CommentEnd(CommentRangeEnd::new(id)))
         }
+        ParagraphChildXml::PermStart(node) => {
+            Some(ParagraphChild::PermStart(node.into_perm_start()?))
+        }
+        ParagraphChildXml::PermEnd(node) => {
+            let id = parse_optional_usize(node.id)?;
+            Some(ParagraphChild::PermEnd(PermEnd::new(id)))
+        }
         ParagraphChildXml::Insert(insert) => Some(ParagraphChild::Insert(insert)),
         ParagraphChildXml::Delete(delete) => Some(ParagraphChild::Delete(delete)),
+        ParagraphChildXml::MoveFrom(m) => Some(ParagraphChild::MoveFrom(m)),
+        ParagraphChildXml::MoveTo(m) => Some(ParagraphChild::MoveTo(m)),
+        ParagraphChildXml::MoveFromRangeStart(node) => Some(ParagraphChild::MoveFromRangeStart(
+            node.into_move_from_range_start()?,
+        )),
+        ParagraphChildXml::MoveFromRangeEnd(node) => {
+            let id = parse_optional_usize(node.id)?;
+            Some(ParagraphChild::MoveFromRangeEnd(MoveFromRangeEnd::new(id)))
+        }
+        ParagraphChildXml::MoveToRangeStart(node) => Some(ParagraphChild::MoveToRangeStart(
+            node.into_move_to_range_start()?,
+        )),
+        ParagraphChildXml::MoveToRangeEnd(node) => {
+            let id = parse_optional_usize(node.id)?;
+            Some(ParagraphChild::MoveToRangeEnd(MoveToRangeEnd::new(id)))
+        }
         ParagraphChildXml::Hyperlink(hyperlink) => Some(ParagraphChild::Hyperlink(hyperlink)),
         ParagraphChildXml::StructuredDataTag(sdt) => {
             Some(ParagraphChild::StructuredDataTag(Box::new(sdt)))
         }
-        ParagraphChildXml::ParagraphProperty(_) | ParagraphChildXml::Unknown => None,
+        // Read by `paragraph_children_from_xml`.
+        ParagraphChildXml::FieldSimple(_)
+        | ParagraphChildXml::ParagraphProperty(_)
+        | ParagraphChildXml::Unknown => None,
     }
 }
 
+fn paragraph_children_from_xml(xml: ParagraphChildXml) -> Vec<ParagraphChild> {
+    let ParagraphChildXml::FieldSimple(node) = xml else {
+        return paragraph_child_from_xml(xml).into_iter().collect();
+    };
+    let runs = node.children.into_iter().filter_map(|c| match c {
+        FieldSimpleChildXml::Run(run) => Some(run),
+        FieldSimpleChildXml::Unknown => None,
+    });
+    // The cached result is kept as plain runs when the instruction can not be parsed.
+    let Some(mut field) = node.instr.as_deref().and_then(Field::with_instr_text) else {
+        return runs.map(|r| ParagraphChild::Run(Box::new(r))).collect();
+    };
+    field = field.simple();
+    if matches!(node.dirty.as_deref(), Some("true") | Some("1")) {
+        field = field.dirty();
+    }
+    for run in runs {
+        field = field.add_result_run(run);
+    }
+    vec![ParagraphChild::Field(Box::new(field))]
+}
+
 #[derive(Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Paragraph {
@@ -152,19 +299,21 @@ impl<'de> Deserialize<'de> for Paragraph {
         let mut property = ParagraphProperty::new();
         let mut has_numbering = false;
 
-        if let Some(ppr) = xml.property {
-            property = parse_paragraph_property_xml(Some(ppr.base));
-            if let Some(np) = parse_numbering_property_xml(ppr.num_pr) {
+        if let Some(mut ppr) = xml.property {
+            let num_pr = ppr.num_pr.take();
+            property = parse_paragraph_property_xml(Some(ppr));
+            if let Some(np) = parse_numbering_property_xml(num_pr) {
                 property = property.numbering_property(np);
                 has_numbering = true;
             }
         }
 
-        let children = xml
-            .children
-            .into_iter()
-            .filter_map(paragraph_child_from_xml)
-            .collect();
+        let children = collect_complex_fields(
+            xml.children
+                .into_iter()
+                .flat_map(paragraph_children_from_xml)
+                .collect(),
+        );
 
         let id = xml.id.unwrap_or_else(crate::generate_para_id);
         Ok(Paragraph {
@@ -192,14 +341,23 @@ pub enum ParagraphChild {
     Run(Box<Run>),
     Insert(Insert),
     Delete(Delete),
+    MoveFrom(MoveFrom),
+    MoveTo(MoveTo),
+    MoveFromRangeStart(MoveFromRangeStart),
+    MoveFromRangeEnd(MoveFromRangeEnd),
+    MoveToRangeStart(MoveToRangeStart),
+    MoveToRangeEnd(MoveToRangeEnd),
     BookmarkStart(BookmarkStart),
     Hyperlink(Hyperlink),
     BookmarkEnd(BookmarkEnd),
     CommentStart(Box<CommentRangeStart>),
     CommentEnd(CommentRangeEnd),
+    PermStart(PermStart),
+    PermEnd(PermEnd),
     StructuredDataTag(Box<StructuredDataTag>),
     PageNum(Box<PageNum>),
     NumPages(Box<NumPages>),
+    Field(Box<Field>),
 }
 
 impl BuildXML for ParagraphChild {
@@ -211,14 +369,23 @@ impl BuildXML for ParagraphChild {
             ParagraphChild::Run(v) => v.build_to(stream),
             ParagraphChild::Insert(v) => v.build_to(stream),
             ParagraphChild::Delete(v) => v.build_to(stream),
+            ParagraphChild::MoveFrom(v) => v.build_to(stream),
+            ParagraphChild::MoveTo(v) => v.build_to(stream),
+            ParagraphChild::MoveFromRangeStart(v) => v.build_to(stream),
+            ParagraphChild::MoveFromRangeEnd(v) => v.build_to(stream),
+            ParagraphChild::MoveToRangeStart(v) => v.build_to(stream),
+            ParagraphChild::MoveToRangeEnd(v) => v.build_to(stream),
             ParagraphChild::Hyperlink(v) => v.build_to(stream),
             ParagraphChild::BookmarkStart(v) => v.build_to(stream),
             ParagraphChild::BookmarkEnd(v) => v.build_to(stream),
             ParagraphChild::CommentStart(v) => v.build_to(stream),
             ParagraphChild::CommentEnd(v) => v.build_to(stream),
+            ParagraphChild::PermStart(v) => v.build_to(stream),
+            ParagraphChild::PermEnd(v) => v.build_to(stream),
             ParagraphChild::StructuredDataTag(v) => v.build_to(stream),
             ParagraphChild::PageNum(v) => v.build_to(stream),
             ParagraphChild::NumPages(v) => v.build_to(stream),
+            ParagraphChild::Field(v) => v.build_to(stream),
         }
     }
 }
@@ -247,6 +414,42 @@ impl Serialize for ParagraphChild {
                 t.serialize_field("data", r)?;
                 t.end()
             }
+            ParagraphChild::MoveFrom(ref r) => {
+                let mut t = serializer.serialize_struct("MoveFrom", 2)?;
+                t.serialize_field("type", "moveFrom")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            ParagraphChild::MoveTo(ref r) => {
+                let mut t = serializer.serialize_struct("MoveTo", 2)?;
+                t.serialize_field("type", "moveTo")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            ParagraphChild::MoveFromRangeStart(ref r) => {
+                let mut t = serializer.serialize_struct("MoveFromRangeStart", 2)?;
+                t.serialize_field("type", "moveFromRangeStart")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            ParagraphChild::MoveFromRangeEnd(ref r) => {
+                let mut t = serializer.serialize_struct("MoveFromRangeEnd", 2)?;
+                t.serialize_field("type", "moveFromRangeEnd")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            ParagraphChild::MoveToRangeStart(ref r) => {
+                let mut t = serializer.serialize_struct("MoveToRangeStart", 2)?;
+                t.serialize_field("type", "moveToRangeStart")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            ParagraphChild::MoveToRangeEnd(ref r) => {
+                let mut t = serializer.serialize_struct("MoveToRangeEnd", 2)?;
+                t.serialize_field("type", "moveToRangeEnd")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
             ParagraphChild::Hyperlink(ref r) => {
                 let mut t = serializer.serialize_struct("hyperlink", 2)?;
                 t.serialize_field("type", "hyperlink")?;
@@ -277,6 +480,18 @@ impl Serialize for ParagraphChild {
                 t.serialize_field("data", r)?;
                 t.end()
             }
+            ParagraphChild::PermStart(ref r) => {
+                let mut t = serializer.serialize_struct("PermStart", 2)?;
+                t.serialize_field("type", "permStart")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            ParagraphChild::PermEnd(ref r) => {
+                let mut t = serializer.serialize_struct("PermEnd", 2)?;
+                t.serialize_field("type", "permEnd")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
             ParagraphChild::StructuredDataTag(ref r) => {
                 let mut t = serializer.serialize_struct("StructuredDataTag", 2)?;
                 t.serialize_field("type", "structuredDataTag")?;
@@ -295,6 +510,12 @@ impl Serialize for ParagraphChild {
                 t.serialize_field("data", r)?;
                 t.end()
             }
+            ParagraphChild::Field(ref r) => {
+                let mut t = serializer.serialize_struct("Field", 2)?;
+                t.serialize_field("type", "field")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
         }
     }
 }
@@ -355,6 +576,39 @@ impl Paragraph {
         self
     }
 
+    pub fn add_move_from(mut self, m: MoveFrom) -> Paragraph {
+        self.children.push(ParagraphChild::MoveFrom(m));
+        self
+    }
+
+    pub fn add_move_to(mut self, m: MoveTo) -> Paragraph {
+        self.children.push(ParagraphChild::MoveTo(m));
+        self
+    }
+
+    pub fn add_move_from_range_start(mut self, start: MoveFromRangeStart) -> Paragraph {
+        self.children
+            .push(ParagraphChild::MoveFromRangeStart(start));
+        self
+    }
+
+    pub fn add_move_from_range_end(mut self, id: usize) -> Paragraph {
+        self.children
+            .push(ParagraphChild::MoveFromRangeEnd(MoveFromRangeEnd::new(id)));
+        self
+    }
+
+    pub fn add_move_to_range_start(mut self, start: MoveToRangeStart) -> Paragraph {
+        self.children.push(ParagraphChild::MoveToRangeStart(start));
+        self
+    }
+
+    pub fn add_move_to_range_end(mut self, id: usize) -> Paragraph {
+        self.children
+            .push(ParagraphChild::MoveToRangeEnd(MoveToRangeEnd::new(id)));
+        self
+    }
+
     pub fn add_bookmark_start(mut self, id: usize, name: impl Into<String>) -> Paragraph {
         self.children
             .push(ParagraphChild::BookmarkStart(BookmarkStart::new(id, name)));
@@ -367,6 +621,17 @@ impl Paragraph {
         self
     }
 
+    pub fn add_perm_start(mut self, perm: PermStart) -> Paragraph {
+        self.children.push(ParagraphChild::PermStart(perm));
+        self
+    }
+
+    pub fn add_perm_end(mut self, id: usize) -> Paragraph {
+        self.children
+            .push(ParagraphChild::PermEnd(PermEnd::new(id)));
+        self
+    }
+
     pub fn add_comment_start(mut self, comment: Comment) -> Paragraph {
         self.children.push(ParagraphChild::CommentStart(Box::new(
             CommentRangeStart::new(comment),
@@ -420,6 +685,46 @@ impl Paragraph {
         self
     }
 
+    pub fn shading(mut self, s: Shading) -> Self {
+        self.property = self.property.shading(s);
+        self
+    }
+
+    pub fn contextual_spacing(mut self, v: bool) -> Self {
+        self.property = self.property.contextual_spacing(v);
+        self
+    }
+
+    pub fn suppress_auto_hyphens(mut self, v: bool) -> Self {
+        self.property = self.property.suppress_auto_hyphens(v);
+        self
+    }
+
+    pub fn suppress_line_numbers(mut self, v: bool) -> Self {
+        self.property = self.property.suppress_line_numbers(v);
+        self
+    }
+
+    pub fn mirror_indents(mut self, v: bool) -> Self {
+        self.property = self.property.mirror_indents(v);
+        self
+    }
+
+    pub fn word_wrap(mut self, v: bool) -> Self {
+        self.property = self.property.word_wrap(v);
+        self
+    }
+
+    pub fn kinsoku(mut self, v: bool) -> Self {
+        self.property = self.property.kinsoku(v);
+        self
+    }
+
+    pub fn top_line_punct(mut self, v: bool) -> Self {
+        self.property = self.property.top_line_punct(v);
+        self
+    }
+
     pub fn section_property(mut self, s: SectionProperty) -> Self {
         self.property = self.property.section_property(s);
         self
@@ -469,7 +774,7 @@ impl Paragraph {
         self
     }
 
-    pub fn color(mut self, c: impl Into<String>) -> Self {
+    pub fn color(mut self, c: impl Into<ColorValue>) -> Self {
         self.property.run_property = self.property.run_property.color(c);
         self
     }
@@ -527,11 +832,12 @@ impl Paragraph {
 
     pub fn raw_text(&self) -> String {
         let mut s = "".to_string();
-        // For now support only run and ins.
+        // For now support only run, ins and moveTo.
         for c in self.children.iter() {
             match c {
-                ParagraphChild::Insert(i) => {
-                    for c in i.children.iter() {
+                ParagraphChild::Insert(Insert { children, .. })
+                | ParagraphChild::MoveTo(MoveTo { children, .. }) => {
+                    for c in children.iter() {
                         if let InsertChild::Run(r) = c {
                             for c in r.children.iter() {
                                 if let RunChild::Text(t) = c {
@@ -564,6 +870,11 @@ impl Paragraph {
         self
     }
 
+    pub fn add_field(mut self, f: Field) -> Self {
+        self.children.push(ParagraphChild::Field(Box::new(f)));
+        self
+    }
+
     // frameProperty
     pub fn wrap(mut self, wrap: impl Into<String>) -> Self {
         self.property.frame_property = Some(FrameProperty {
@@ -820,4 +1131,189 @@ mod tests {
         assert!(matches!(&p.children[3], ParagraphChild::CommentEnd(_)));
         assert!(matches!(&p.children[4], ParagraphChild::BookmarkEnd(_)));
     }
+
+    #[test]
+    fn test_paragraph_xml_deserialize_fields() {
+        let xml = r#"<w:p xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+            <w:fldSimple w:instr=" AUTHOR \* MERGEFORMAT "><w:r><w:t>Alice</w:t></w:r></w:fldSimple>
+            <w:r><w:fldChar w:fldCharType="begin"/></w:r>
+            <w:r><w:instrText xml:space="preserve"> REF _Ref1 \h </w:instrText></w:r>
+            <w:r><w:fldChar w:fldCharType="separate"/></w:r>
+            <w:r><w:t>Table 1</w:t></w:r>
+            <w:r><w:fldChar w:fldCharType="end"/></w:r>
+        </w:p>"#;
+
+        let p: Paragraph = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(p.children.len(), 2);
+        match &p.children[0] {
+            ParagraphChild::Field(f) => {
+                assert!(f.simple);
+                assert_eq!(f.field_type(), FieldType::Author);
+                assert_eq!(f.result_text(), "Alice");
+            }
+            _ => panic!("should be a field"),
+        }
+        match &p.children[1] {
+            ParagraphChild::Field(f) => {
+                assert!(!f.simple);
+                assert_eq!(f.instruction.to_string(), "REF _Ref1 \\h");
+                assert_eq!(f.result_text(), "Table 1");
+            }
+            _ => panic!("should be a field"),
+        }
+    }
+
+    #[test]
+    fn test_paragraph_xml_deserialize_fields_without_instruction() {
+        let xml = r#"<w:p xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+            <w:fldSimple w:instr=" "><w:r><w:t>Simple</w:t></w:r></w:fldSimple>
+            <w:r><w:fldChar w:fldCharType="begin"/></w:r>
+            <w:r><w:fldChar w:fldCharType="separate"/></w:r>
+            <w:r><w:t>Complex</w:t></w:r>
+            <w:r><w:fldChar w:fldCharType="end"/></w:r>
+        </w:p>"#;
+
+        let p: Paragraph = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(p.children.len(), 5);
+        assert_eq!(
+            p.children[0],
+            ParagraphChild::Run(Box::new(Run::new().add_text("Simple")))
+        );
+        assert_eq!(
+            p.children[3],
+            ParagraphChild::Run(Box::new(Run::new().add_text("Complex")))
+        );
+    }
+
+    #[test]
+    fn test_perm_range() {
+        let b = Paragraph::new()
+            .add_perm_start(PermStart::new(1).editor_group(EditorGroupType::Everyone))
+            .add_run(Run::new().add_text("Signature"))
+            .add_perm_end(1)
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr><w:permStart w:id="1" w:edGrp="everyone" /><w:r><w:rPr /><w:t xml:space="preserve">Signature</w:t></w:r><w:permEnd w:id="1" /></w:p>"#
+        );
+    }
+
+    #[test]
+    fn test_paragraph_xml_deserialize_perm_range() {
+        let xml = r#"<w:p xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:permStart w:id="7" w:edGrp="editors"/><w:r><w:t>Name</w:t></w:r><w:permEnd w:id="7"/></w:p>"#;
+        let p: Paragraph = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(
+            p.children[0],
+            ParagraphChild::PermStart(PermStart::new(7).editor_group(EditorGroupType::Editors))
+        );
+        assert_eq!(p.children[2], ParagraphChild::PermEnd(PermEnd::new(7)));
+    }
+
+    #[test]
+    fn test_paragraph_xml_deserialize_perm_start_editor() {
+        let xml = r#"<w:p xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:permStart w:id="8" w:ed="R&amp;D"/><w:permEnd w:id="8"/></w:p>"#;
+        let p: Paragraph = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(
+            p.children[0],
+            ParagraphChild::PermStart(PermStart::new(8).editor("R&D"))
+        );
+        assert_eq!(
+            str::from_utf8(&p.build()).unwrap(),
+            r#"<w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr><w:permStart w:id="8" w:ed="R&amp;D" /><w:permEnd w:id="8" /></w:p>"#
+        );
+    }
+
+    #[test]
+    fn test_paragraph_xml_deserialize_moves() {
+        let xml = r#"<w:p xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:pPr><w:rPr><w:moveFrom w:id="1" w:author="R&amp;D" w:date="2024-01-01T00:00:00Z"/></w:rPr></w:pPr><w:moveFromRangeStart w:id="2" w:name="move1" w:author="R&amp;D" w:date="2024-01-01T00:00:00Z"/><w:moveFromRangeEnd w:id="2"/></w:p>"#;
+        let p: Paragraph = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(
+            p.children[0],
+            ParagraphChild::MoveFromRangeStart(
+                MoveFromRangeStart::new(2, "move1")
+                    .author("R&D")
+                    .date("2024-01-01T00:00:00Z")
+            )
+        );
+        assert_eq!(
+            p.property.run_property.move_from,
+            Some(MoveFrom::new().author("R&D").date("2024-01-01T00:00:00Z"))
+        );
+        assert_eq!(
+            str::from_utf8(&p.build()).unwrap(),
+            r#"<w:p w14:paraId="12345678"><w:pPr><w:rPr><w:moveFrom w:id="123" w:author="R&amp;D" w:date="2024-01-01T00:00:00Z" /></w:rPr></w:pPr><w:moveFromRangeStart w:id="2" w:name="move1" w:author="R&amp;D" w:date="2024-01-01T00:00:00Z" /><w:moveFromRangeEnd w:id="2" /></w:p>"#
+        );
+    }
+
+    #[test]
+    fn test_paragraph_xml_deserialize_revisions() {
+        let xml = r#"<w:p xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+            <w:pPr>
+                <w:numPr>
+                    <w:ilvl w:val="0"/>
+                    <w:numId w:val="1"/>
+                    <w:numberingChange w:id="1" w:author="bob" w:date="2024-01-01T00:00:00Z" w:original="%1."/>
+                </w:numPr>
+                <w:rPr>
+                    <w:ins w:id="2" w:author="bob" w:date="2024-01-01T00:00:00Z"/>
+                </w:rPr>
+                <w:pPrChange w:id="3" w:author="bob" w:date="2024-01-01T00:00:00Z">
+                    <w:pPr><w:jc w:val="left"/></w:pPr>
+                </w:pPrChange>
+            </w:pPr>
+            <w:moveFromRangeStart w:id="4" w:name="move1" w:author="bob" w:date="2024-01-01T00:00:00Z"/>
+            <w:moveFrom w:id="5" w:author="bob" w:date="2024-01-01T00:00:00Z">
+                <w:r><w:delText>Moved</w:delText></w:r>
+            </w:moveFrom>
+            <w:moveFromRangeEnd w:id="4"/>
+            <w:moveTo w:id="6" w:author="bob" w:date="2024-01-01T00:00:00Z">
+                <w:r>
+                    <w:rPr>
+                        <w:b/>
+                        <w:rPrChange w:id="7" w:author="bob" w:date="2024-01-01T00:00:00Z"><w:rPr/></w:rPrChange>
+                    </w:rPr>
+                    <w:t>Moved</w:t>
+                </w:r>
+            </w:moveTo>
+        </w:p>"#;
+
+        let p: Paragraph = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(
+            p.property
+                .numbering_property
+                .as_ref()
+                .and_then(|n| n.numbering_change.clone()),
+            Some(
+                NumberingChange::new("%1.")
+                    .author("bob")
+                    .date("2024-01-01T00:00:00Z")
+            )
+        );
+        assert!(p.property.run_property.ins.is_some());
+        assert!(p.property.paragraph_property_change.is_some());
+        assert_eq!(p.children.len(), 4);
+        assert_eq!(
+            p.children[0],
+            ParagraphChild::MoveFromRangeStart(
+                MoveFromRangeStart::new(4, "move1")
+                    .author("bob")
+                    .date("2024-01-01T00:00:00Z")
+            )
+        );
+        assert!(matches!(&p.children[1], ParagraphChild::MoveFrom(m) if m.author == "bob"));
+        assert_eq!(
+            p.children[2],
+            ParagraphChild::MoveFromRangeEnd(MoveFromRangeEnd::new(4))
+        );
+        match &p.children[3] {
+            ParagraphChild::MoveTo(m) => match &m.children[0] {
+                InsertChild::Run(run) => {
+                    assert!(run.run_property.run_property_change.is_some())
+                }
+                _ => panic!("should be a run"),
+            },
+            _ => panic!("should be a move"),
+        }
+        assert_eq!(p.raw_text(), "Moved");
+    }
 }
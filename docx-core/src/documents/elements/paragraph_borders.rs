@@ -12,11 +12,8 @@ pub struct ParagraphBorder {
     pub val: BorderType,
     pub size: usize,
     pub space: usize,
-    pub color: String,
+    pub color: ColorValue,
     // pub shadow: Option<bool>,
-    // pub theme_color: Option<String>,
-    // pub theme_shade: Option<String>,
-    // pub theme_tint: Option<String>,
     // pub frame: Option<bool>,
 }
 
@@ -27,11 +24,8 @@ impl ParagraphBorder {
             val: BorderType::Single,
             size: 2,
             space: 0,
-            color: "auto".to_owned(),
+            color: ColorValue::Auto,
             // shadow: None,
-            // theme_color: None,
-            // theme_shade: None,
-            // theme_tint: None,
             // frame: None,
         }
     }
@@ -50,7 +44,7 @@ impl ParagraphBorder {
         self
     }
 
-    pub fn color(mut self, color: impl Into<String>) -> Self {
+    pub fn color(mut self, color: impl Into<ColorValue>) -> Self {
         self.color = color.into();
         self
     }
@@ -60,21 +54,6 @@ impl ParagraphBorder {
     //     self
     // }
     //
-    // pub fn theme_color(mut self, theme_color: impl Into<String>) -> Self {
-    //     self.theme_color = Some(theme_color.into());
-    //     self
-    // }
-    //
-    // pub fn theme_shade(mut self, theme_shade: impl Into<String>) -> Self {
-    //     self.theme_shade = Some(theme_shade.into());
-    //     self
-    // }
-    //
-    // pub fn theme_tint(mut self, theme_tint: impl Into<String>) -> Self {
-    //     self.theme_tint = Some(theme_tint.into());
-    //     self
-    // }
-    //
     // pub fn frame(mut self, frame: bool) -> Self {
     //     self.frame = Some(frame);
     //     self
@@ -48,6 +48,24 @@ pub struct ParagraphProperty {
     pub adjust_right_ind: Option<AdjustRightInd>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub snap_to_grid: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shading: Option<Shading>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contextual_spacing: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suppress_auto_hyphens: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suppress_line_numbers: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mirror_indents: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub word_wrap: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kinsoku: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_line_punct: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cnf_style: Option<CnfStyle>,
     // read only
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) div_id: Option<String>,
@@ -177,6 +195,53 @@ impl ParagraphProperty {
         self
     }
 
+    pub fn shading(mut self, s: Shading) -> Self {
+        self.shading = Some(s);
+        self
+    }
+
+    // Ignore spacing above and below between paragraphs of the same style.
+    pub fn contextual_spacing(mut self, v: bool) -> Self {
+        self.contextual_spacing = Some(v);
+        self
+    }
+
+    pub fn suppress_auto_hyphens(mut self, v: bool) -> Self {
+        self.suppress_auto_hyphens = Some(v);
+        self
+    }
+
+    pub fn suppress_line_numbers(mut self, v: bool) -> Self {
+        self.suppress_line_numbers = Some(v);
+        self
+    }
+
+    pub fn mirror_indents(mut self, v: bool) -> Self {
+        self.mirror_indents = Some(v);
+        self
+    }
+
+    // East Asian line breaking. `false` allows words to be broken at any character.
+    pub fn word_wrap(mut self, v: bool) -> Self {
+        self.word_wrap = Some(v);
+        self
+    }
+
+    pub fn kinsoku(mut self, v: bool) -> Self {
+        self.kinsoku = Some(v);
+        self
+    }
+
+    pub fn top_line_punct(mut self, v: bool) -> Self {
+        self.top_line_punct = Some(v);
+        self
+    }
+
+    pub fn cnf_style(mut self, v: impl Into<String>) -> Self {
+        self.cnf_style = Some(CnfStyle::new(v));
+        self
+    }
+
     pub(crate) fn hanging_chars(mut self, chars: i32) -> Self {
         if let Some(indent) = self.indent {
             self.indent = Some(indent.hanging_chars(chars));
@@ -222,13 +287,64 @@ impl BuildXML for ParagraphProperty {
             .add_child(&self.run_property)?
             .add_optional_child(&self.style)?
             .add_optional_child(&self.numbering_property)?
+            .apply_opt(self.suppress_line_numbers, |v, b| {
+                if v {
+                    b.suppress_line_numbers()
+                } else {
+                    b.disabled_toggle("w:suppressLineNumbers")
+                }
+            })?
             .add_optional_child(&self.frame_property)?
             .add_optional_child(&self.alignment)?
             .add_optional_child(&self.indent)?
             .add_optional_child(&self.line_spacing)?
+            .apply_opt(self.contextual_spacing, |v, b| {
+                if v {
+                    b.contextual_spacing()
+                } else {
+                    b.disabled_toggle("w:contextualSpacing")
+                }
+            })?
+            .apply_opt(self.mirror_indents, |v, b| {
+                if v {
+                    b.mirror_indents()
+                } else {
+                    b.disabled_toggle("w:mirrorIndents")
+                }
+            })?
             .add_optional_child(&self.outline_lvl)?
+            .add_optional_child(&self.cnf_style)?
             .add_optional_child(&self.paragraph_property_change)?
             .add_optional_child(&self.borders)?
+            .add_optional_child(&self.shading)?
+            .apply_opt(self.suppress_auto_hyphens, |v, b| {
+                if v {
+                    b.suppress_auto_hyphens()
+                } else {
+                    b.disabled_toggle("w:suppressAutoHyphens")
+                }
+            })?
+            .apply_opt(self.kinsoku, |v, b| {
+                if v {
+                    b.kinsoku()
+                } else {
+                    b.disabled_toggle("w:kinsoku")
+                }
+            })?
+            .apply_opt(self.word_wrap, |v, b| {
+                if v {
+                    b.word_wrap()
+                } else {
+                    b.disabled_toggle("w:wordWrap")
+                }
+            })?
+            .apply_opt(self.top_line_punct, |v, b| {
+                if v {
+                    b.top_line_punct()
+                } else {
+                    b.disabled_toggle("w:topLinePunct")
+                }
+            })?
             .add_optional_child(&self.text_alignment)?
             .add_optional_child(&self.adjust_right_ind)?
             .apply_opt(self.snap_to_grid, |v, b| b.snap_to_grid(v))?
@@ -333,4 +449,18 @@ mod tests {
             r#"<w:pPr><w:rPr /><w:spacing w:line="100" w:lineRule="atLeast" /></w:pPr>"#
         )
     }
+
+    #[test]
+    fn test_contextual_spacing_and_shading() {
+        let c = ParagraphProperty::new()
+            .contextual_spacing(true)
+            .shading(Shading::new().fill("DDDDDD"))
+            .word_wrap(false)
+            .cnf_style("100000000000");
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:pPr><w:rPr /><w:contextualSpacing /><w:cnfStyle w:val="100000000000" /><w:shd w:val="clear" w:color="auto" w:fill="DDDDDD" /><w:wordWrap w:val="false" /></w:pPr>"#
+        );
+    }
 }
@@ -0,0 +1,43 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct PermEnd {
+    pub id: usize,
+}
+
+impl PermEnd {
+    pub fn new(id: usize) -> PermEnd {
+        PermEnd { id }
+    }
+}
+
+impl BuildXML for PermEnd {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .perm_end(&self.id.to_string())?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_perm_end() {
+        let c = PermEnd::new(1);
+        let b = c.build();
+        assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:permEnd w:id="1" />"#);
+    }
+}
@@ -0,0 +1,108 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::escape::escape;
+use crate::types::EditorGroupType;
+use crate::xml_builder::*;
+
+// Start of a range that stays editable while the document is protected.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PermStart {
+    pub id: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub editor_group: Option<EditorGroupType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub editor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub col_first: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub col_last: Option<usize>,
+}
+
+impl PermStart {
+    pub fn new(id: usize) -> PermStart {
+        PermStart {
+            id,
+            editor_group: None,
+            editor: None,
+            col_first: None,
+            col_last: None,
+        }
+    }
+
+    pub fn editor_group(mut self, group: EditorGroupType) -> Self {
+        self.editor_group = Some(group);
+        self
+    }
+
+    // A single user, e.g. `DOMAIN\user` or an email address.
+    pub fn editor(mut self, editor: impl Into<String>) -> Self {
+        self.editor = Some(escape(&editor.into()));
+        self
+    }
+
+    // Limit a range placed between table rows to these grid columns.
+    pub fn columns(mut self, first: usize, last: usize) -> Self {
+        self.col_first = Some(first);
+        self.col_last = Some(last);
+        self
+    }
+}
+
+impl BuildXML for PermStart {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .perm_start(
+                &self.id.to_string(),
+                self.editor_group.map(|g| g.to_string()).as_ref(),
+                self.editor.as_ref(),
+                self.col_first,
+                self.col_last,
+            )?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_perm_start() {
+        let c = PermStart::new(1).editor_group(EditorGroupType::Everyone);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:permStart w:id="1" w:edGrp="everyone" />"#
+        );
+    }
+
+    #[test]
+    fn test_perm_start_with_columns() {
+        let c = PermStart::new(2).editor("legal@example.com").columns(0, 1);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:permStart w:id="2" w:ed="legal@example.com" w:colFirst="0" w:colLast="1" />"#
+        );
+    }
+
+    #[test]
+    fn test_perm_start_escape_editor() {
+        let c = PermStart::new(3).editor("R&D <legal>");
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:permStart w:id="3" w:ed="R&amp;D &lt;legal&gt;" />"#
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::{escape, xml_builder::*};
+
+// ============================================================================
+// XML Deserialization Helper Structures (for quick-xml serde)
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+struct PresenceInfoXml {
+    #[serde(
+        rename = "@providerId",
+        alias = "@w15:providerId",
+        alias = "providerId"
+    )]
+    provider_id: String,
+    #[serde(rename = "@userId", alias = "@w15:userId", alias = "userId")]
+    user_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct PersonXml {
+    #[serde(rename = "@author", alias = "@w15:author", alias = "author")]
+    author: String,
+    #[serde(rename = "presenceInfo", alias = "w15:presenceInfo", default)]
+    presence_info: Option<PresenceInfo>,
+}
+
+// The attribute values are unescaped by the reader, so they are escaped again
+// as the builders do.
+impl From<PresenceInfoXml> for PresenceInfo {
+    fn from(xml: PresenceInfoXml) -> Self {
+        PresenceInfo::new(xml.provider_id, xml.user_id)
+    }
+}
+
+impl From<PersonXml> for Person {
+    fn from(xml: PersonXml) -> Self {
+        Person {
+            author: escape::escape(&xml.author),
+            presence_info: xml.presence_info,
+        }
+    }
+}
+
+// i.e. <w15:presenceInfo w15:providerId="Windows Live" w15:userId="36e8d88cd6c0d524" />
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", from = "PresenceInfoXml")]
+pub struct PresenceInfo {
+    pub provider_id: String,
+    pub user_id: String,
+}
+
+impl PresenceInfo {
+    pub fn new(provider_id: impl Into<String>, user_id: impl Into<String>) -> Self {
+        Self {
+            provider_id: escape::escape(&provider_id.into()),
+            user_id: escape::escape(&user_id.into()),
+        }
+    }
+}
+
+// i.e. <w15:person w15:author="Test Name"><w15:presenceInfo ... /></w15:person>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", from = "PersonXml")]
+pub struct Person {
+    pub author: String,
+    pub presence_info: Option<PresenceInfo>,
+}
+
+impl Person {
+    pub fn new(author: impl Into<String>) -> Self {
+        Self {
+            author: escape::escape(&author.into()),
+            presence_info: None,
+        }
+    }
+
+    pub fn presence_info(mut self, info: PresenceInfo) -> Self {
+        self.presence_info = Some(info);
+        self
+    }
+}
+
+impl BuildXML for PresenceInfo {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .presence_info(&self.provider_id, &self.user_id)?
+            .into_inner()
+    }
+}
+
+impl BuildXML for Person {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .open_person(&self.author)?
+            .add_optional_child(&self.presence_info)?
+            .close()?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_person() {
+        let b = Person::new("Test Name")
+            .presence_info(PresenceInfo::new("None", "Test Name"))
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w15:person w15:author="Test Name"><w15:presenceInfo w15:providerId="None" w15:userId="Test Name" /></w15:person>"#
+        );
+    }
+
+    #[test]
+    fn test_person_xml_deserialize_escapes_author() {
+        let xml = r#"<w15:person xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml" w15:author="R&amp;D"><w15:presenceInfo w15:providerId="None" w15:userId="R&amp;D"/></w15:person>"#;
+        let person: Person = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(
+            person,
+            Person::new("R&D").presence_info(PresenceInfo::new("None", "R&D"))
+        );
+        assert_eq!(
+            str::from_utf8(&person.build()).unwrap(),
+            r#"<w15:person w15:author="R&amp;D"><w15:presenceInfo w15:providerId="None" w15:userId="R&amp;D" /></w15:person>"#
+        );
+    }
+
+    #[test]
+    fn test_person_without_presence_info() {
+        let b = Person::new("<Bob>").build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w15:person w15:author="&lt;Bob&gt;" />"#
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+use std::io::Write;
+
+use serde::*;
+
+// Raised (positive) or lowered (negative) text position in half-points.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Position {
+    value: i32,
+}
+
+impl Position {
+    pub fn new(s: i32) -> Position {
+        Self { value: s }
+    }
+}
+
+impl BuildXML for Position {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .position(self.value as isize)?
+            .into_inner()
+    }
+}
+
+impl Serialize for Position {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i32(self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_position() {
+        let b = Position::new(-6).build();
+        assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:position w:val="-6" />"#);
+    }
+}
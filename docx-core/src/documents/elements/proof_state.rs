@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::types::ProofStateType;
+use crate::xml_builder::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofState {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spelling: Option<ProofStateType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grammar: Option<ProofStateType>,
+}
+
+impl ProofState {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn spelling(mut self, s: ProofStateType) -> Self {
+        self.spelling = Some(s);
+        self
+    }
+
+    pub fn grammar(mut self, s: ProofStateType) -> Self {
+        self.grammar = Some(s);
+        self
+    }
+}
+
+impl BuildXML for ProofState {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .proof_state(
+                self.spelling.map(|s| s.to_string()).as_ref(),
+                self.grammar.map(|s| s.to_string()).as_ref(),
+            )?
+            .into_inner()
+    }
+}
@@ -10,7 +10,7 @@ use crate::escape::replace_escaped;
 use crate::types::*;
 use crate::xml_builder::*;
 
-use super::style::{parse_run_property_xml, RunPropertyXml};
+use super::style::{parse_run_property_xml, parse_shading_xml, RunPropertyXml, ShadingXml};
 
 // ============================================================================
 // XML Deserialization Helper Structures (for quick-xml serde)
@@ -60,6 +60,8 @@ struct XmlFieldCharNode {
     field_char_type: Option<String>,
     #[serde(rename = "@dirty", alias = "@w:dirty", default)]
     dirty: Option<String>,
+    #[serde(rename = "ffData", alias = "w:ffData", default)]
+    form_field_data: Option<FormFieldData>,
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -68,16 +70,6 @@ struct XmlFootnoteReferenceNode {
     id: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Default)]
-struct XmlShadingNode {
-    #[serde(rename = "@val", alias = "@w:val", default)]
-    shd_type: Option<String>,
-    #[serde(rename = "@color", alias = "@w:color", default)]
-    color: Option<String>,
-    #[serde(rename = "@fill", alias = "@w:fill", default)]
-    fill: Option<String>,
-}
-
 #[derive(Debug, Deserialize)]
 enum RunChildXml {
     #[serde(rename = "t", alias = "w:t")]
@@ -107,7 +99,7 @@ enum RunChildXml {
     #[serde(rename = "footnoteReference", alias = "w:footnoteReference")]
     FootnoteReference(XmlFootnoteReferenceNode),
     #[serde(rename = "shd", alias = "w:shd")]
-    Shading(XmlShadingNode),
+    Shading(ShadingXml),
     #[serde(rename = "rPr", alias = "w:rPr")]
     RunProperty(IgnoredAny), // Already handled separately in RunXml
     #[serde(other)]
@@ -196,6 +188,7 @@ fn run_child_from_xml(xml: RunChildXml) -> Option<RunChild> {
             {
                 f = f.dirty();
             }
+            f.form_field_data = node.form_field_data;
             Some(RunChild::FieldChar(f))
         }
         RunChildXml::InstrText(node) => {
@@ -215,21 +208,7 @@ fn run_child_from_xml(xml: RunChildXml) -> Option<RunChild> {
                 .and_then(|v| v.parse::<usize>().ok())
                 .map(|id| RunChild::FootnoteReference(FootnoteReference::new(id)))
         }
-        RunChildXml::Shading(node) => {
-            let mut shd = Shading::new();
-            if let Some(v) = node.shd_type {
-                if let Ok(t) = ShdType::from_str(&v) {
-                    shd = shd.shd_type(t);
-                }
-            }
-            if let Some(v) = node.color {
-                shd = shd.color(v);
-            }
-            if let Some(v) = node.fill {
-                shd = shd.fill(v);
-            }
-            Some(RunChild::Shading(shd))
-        }
+        RunChildXml::Shading(node) => parse_shading_xml(Some(node)).map(RunChild::Shading),
         RunChildXml::RunProperty(_) | RunChildXml::Unknown => None,
     }
 }
@@ -514,7 +493,7 @@ impl Run {
         self
     }
 
-    pub fn color(mut self, color: impl Into<String>) -> Run {
+    pub fn color(mut self, color: impl Into<ColorValue>) -> Run {
         self.run_property = self.run_property.color(color);
         self
     }
@@ -590,6 +569,11 @@ impl Run {
         self.run_property = self.run_property.shading(shading);
         self
     }
+
+    pub fn run_property_change(mut self, c: RunPropertyChange) -> Run {
+        self.run_property = self.run_property.run_property_change(c);
+        self
+    }
 }
 
 impl BuildXML for RunChild {
@@ -613,7 +597,11 @@ impl BuildXML for RunChild {
             RunChild::FieldChar(c) => c.build_to(stream),
             RunChild::InstrText(c) => c.build_to(stream),
             RunChild::DeleteInstrText(c) => c.build_to(stream),
-            RunChild::InstrTextString(_) => unreachable!(),
+            RunChild::InstrTextString(c) => XMLBuilder::from(stream)
+                .open_instr_text()?
+                .plain_text(&crate::escape::escape(c))?
+                .close()?
+                .into_inner(),
             RunChild::FootnoteReference(c) => c.build_to(stream),
             RunChild::Shading(s) => s.build_to(stream),
         }
@@ -770,6 +758,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_run_xml_deserialize_extended_formatting() {
+        let xml = r#"<w:r xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+            <w:rPr>
+                <w:smallCaps w:val="0"/>
+                <w:outline w:val="0"/>
+                <w:imprint/>
+                <w:noProof/>
+                <w:webHidden w:val="false"/>
+                <w:effect w:val="sparkle"/>
+                <w:kern w:val="28"/>
+                <w:position w:val="-6"/>
+                <w:fitText w:val="1680" w:id="3"/>
+                <w:lang w:val="en-US" w:bidi="ar-SA"/>
+            </w:rPr>
+            <w:t>Styled</w:t>
+        </w:r>"#;
+        let run: Run = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(
+            run.run_property,
+            RunProperty::new()
+                .disable_small_caps()
+                .disable_outline()
+                .imprint()
+                .no_proof()
+                .disable_web_hidden()
+                .effect(TextEffectType::Sparkle)
+                .kern(28)
+                .position(-6)
+                .fit_text(FitText::new(1680).id(3))
+                .lang(Lang::new().val("en-US").bidi("ar-SA"))
+        );
+    }
+
+    #[test]
+    fn test_run_xml_deserialize_theme_color() {
+        let xml = r#"<w:r xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+            <w:rPr>
+                <w:color w:val="2F5496" w:themeColor="accent1" w:themeShade="BF"/>
+                <w:shd w:val="clear" w:color="auto" w:fill="D9E2F3" w:themeFill="accent1" w:themeFillTint="33"/>
+            </w:rPr>
+            <w:t>Theme</w:t>
+        </w:r>"#;
+        let run: Run = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(
+            run.run_property,
+            RunProperty::new()
+                .color(
+                    ColorValue::theme(ThemeColorType::Accent1)
+                        .shade(0xBF)
+                        .fallback("2F5496")
+                )
+                .shading(
+                    Shading::new().fill(
+                        ColorValue::theme(ThemeColorType::Accent1)
+                            .tint(0x33)
+                            .fallback("D9E2F3")
+                    )
+                )
+        );
+        let b = run.run_property.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:rPr><w:color w:val="2F5496" w:themeColor="accent1" w:themeShade="BF" /><w:shd w:val="clear" w:color="auto" w:fill="D9E2F3" w:themeFill="accent1" w:themeFillTint="33" /></w:rPr>"#
+        );
+    }
+
     #[test]
     fn test_run_xml_deserialize_field_char() {
         let xml = r#"<w:r xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
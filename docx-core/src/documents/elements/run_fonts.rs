@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::io::Write;
 
-use crate::documents::BuildXML;
+use crate::documents::{BuildXML, FontScheme};
 use crate::escape::escape;
 use crate::xml_builder::*;
 
@@ -94,6 +94,39 @@ impl RunFonts {
         self.hint = Some(escape(&s));
         self
     }
+
+    // Pushes the font names used by this run. Theme slots such as `minorHAnsi` are
+    // resolved with the theme font scheme.
+    pub(crate) fn collect_names(&self, scheme: &FontScheme, names: &mut Vec<String>) {
+        let fonts = [&self.ascii, &self.hi_ansi, &self.east_asia, &self.cs];
+        let themes = [
+            &self.ascii_theme,
+            &self.hi_ansi_theme,
+            &self.east_asia_theme,
+            &self.cs_theme,
+        ];
+        let resolved = themes.iter().filter_map(|t| {
+            let t = t.as_ref()?;
+            let group = if t.starts_with("major") {
+                &scheme.major_font
+            } else {
+                &scheme.minor_font
+            };
+            let name = if t.ends_with("EastAsia") {
+                &group.ea
+            } else if t.ends_with("Bidi") {
+                &group.cs
+            } else {
+                &group.latin
+            };
+            Some(name)
+        });
+        for name in fonts.iter().filter_map(|f| f.as_ref()).chain(resolved) {
+            if !name.is_empty() && !names.contains(name) {
+                names.push(name.clone());
+            }
+        }
+    }
 }
 
 impl BuildXML for RunFonts {
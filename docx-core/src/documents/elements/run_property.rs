@@ -30,6 +30,8 @@ pub struct RunProperty {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub caps: Option<Caps>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub small_caps: Option<SmallCaps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub italic: Option<Italic>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub italic_cs: Option<ItalicCs>,
@@ -49,6 +51,11 @@ pub struct RunProperty {
     pub del: Option<Delete>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ins: Option<Insert>,
+    // The paragraph mark was moved away from or to this paragraph.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub move_from: Option<MoveFrom>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub move_to: Option<MoveTo>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub strike: Option<Strike>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -57,6 +64,30 @@ pub struct RunProperty {
     pub positional_tab: Option<PositionalTab>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shading: Option<Shading>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outline: Option<Outline>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shadow: Option<Shadow>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emboss: Option<Emboss>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub imprint: Option<Imprint>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_proof: Option<NoProof>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_hidden: Option<WebHidden>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effect: Option<Effect>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kern: Option<Kern>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<Position>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fit_text: Option<FitText>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lang: Option<Lang>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run_property_change: Option<RunPropertyChange>,
 }
 
 impl RunProperty {
@@ -80,7 +111,7 @@ impl RunProperty {
         self
     }
 
-    pub fn color(mut self, color: impl Into<String>) -> RunProperty {
+    pub fn color(mut self, color: impl Into<ColorValue>) -> RunProperty {
         self.color = Some(Color::new(color));
         self
     }
@@ -112,6 +143,16 @@ impl RunProperty {
         self
     }
 
+    pub fn small_caps(mut self) -> RunProperty {
+        self.small_caps = Some(SmallCaps::new());
+        self
+    }
+
+    pub fn disable_small_caps(mut self) -> RunProperty {
+        self.small_caps = Some(SmallCaps::new().disable());
+        self
+    }
+
     pub fn italic(mut self) -> RunProperty {
         self.italic = Some(Italic::new());
         self.italic_cs = Some(ItalicCs::new());
@@ -191,6 +232,16 @@ impl RunProperty {
         self
     }
 
+    pub fn move_from(mut self, m: MoveFrom) -> Self {
+        self.move_from = Some(m);
+        self
+    }
+
+    pub fn move_to(mut self, m: MoveTo) -> Self {
+        self.move_to = Some(m);
+        self
+    }
+
     pub fn ptab(mut self, ptab: PositionalTab) -> Self {
         self.positional_tab = Some(ptab);
         self
@@ -200,6 +251,99 @@ impl RunProperty {
         self.shading = Some(s);
         self
     }
+
+    pub fn outline(mut self) -> Self {
+        self.outline = Some(Outline::new());
+        self
+    }
+
+    pub fn disable_outline(mut self) -> Self {
+        self.outline = Some(Outline::new().disable());
+        self
+    }
+
+    pub fn shadow(mut self) -> Self {
+        self.shadow = Some(Shadow::new());
+        self
+    }
+
+    pub fn disable_shadow(mut self) -> Self {
+        self.shadow = Some(Shadow::new().disable());
+        self
+    }
+
+    // emboss and imprint are mutually exclusive.
+    pub fn emboss(mut self) -> Self {
+        self.emboss = Some(Emboss::new());
+        self.imprint = None;
+        self
+    }
+
+    pub fn disable_emboss(mut self) -> Self {
+        self.emboss = Some(Emboss::new().disable());
+        self
+    }
+
+    pub fn imprint(mut self) -> Self {
+        self.imprint = Some(Imprint::new());
+        self.emboss = None;
+        self
+    }
+
+    pub fn disable_imprint(mut self) -> Self {
+        self.imprint = Some(Imprint::new().disable());
+        self
+    }
+
+    pub fn no_proof(mut self) -> Self {
+        self.no_proof = Some(NoProof::new());
+        self
+    }
+
+    pub fn disable_no_proof(mut self) -> Self {
+        self.no_proof = Some(NoProof::new().disable());
+        self
+    }
+
+    pub fn web_hidden(mut self) -> Self {
+        self.web_hidden = Some(WebHidden::new());
+        self
+    }
+
+    pub fn disable_web_hidden(mut self) -> Self {
+        self.web_hidden = Some(WebHidden::new().disable());
+        self
+    }
+
+    pub fn effect(mut self, t: TextEffectType) -> Self {
+        self.effect = Some(Effect::new(t));
+        self
+    }
+
+    pub fn kern(mut self, v: usize) -> Self {
+        self.kern = Some(Kern::new(v));
+        self
+    }
+
+    pub fn position(mut self, v: i32) -> Self {
+        self.position = Some(Position::new(v));
+        self
+    }
+
+    pub fn fit_text(mut self, f: FitText) -> Self {
+        self.fit_text = Some(f);
+        self
+    }
+
+    pub fn lang(mut self, l: Lang) -> Self {
+        self.lang = Some(l);
+        self
+    }
+
+    pub fn run_property_change(mut self, c: RunPropertyChange) -> Self {
+        self.run_property_change = Some(c);
+        self
+    }
 }
 
 impl BuildXML for RunProperty {
@@ -215,24 +359,39 @@ impl BuildXML for RunProperty {
             .add_optional_child(&self.bold)?
             .add_optional_child(&self.bold_cs)?
             .add_optional_child(&self.caps)?
+            .add_optional_child(&self.small_caps)?
             .add_optional_child(&self.italic)?
             .add_optional_child(&self.italic_cs)?
             .add_optional_child(&self.strike)?
             .add_optional_child(&self.dstrike)?
+            .add_optional_child(&self.outline)?
+            .add_optional_child(&self.shadow)?
+            .add_optional_child(&self.emboss)?
+            .add_optional_child(&self.imprint)?
+            .add_optional_child(&self.no_proof)?
             .add_optional_child(&self.highlight)?
             .add_optional_child(&self.underline)?
+            .add_optional_child(&self.effect)?
             .add_optional_child(&self.vanish)?
+            .add_optional_child(&self.web_hidden)?
             .add_optional_child(&self.spec_vanish)?
             .add_optional_child(&self.fonts)?
             .add_optional_child(&self.text_border)?
             .add_optional_child(&self.ins)?
             .add_optional_child(&self.del)?
+            .add_optional_child(&self.move_from)?
+            .add_optional_child(&self.move_to)?
             .add_optional_child(&self.vert_align)?
             .add_optional_child(&self.character_spacing)?
             .add_optional_child(&self.stretch)?
+            .add_optional_child(&self.kern)?
+            .add_optional_child(&self.position)?
+            .add_optional_child(&self.fit_text)?
+            .add_optional_child(&self.lang)?
             .add_optional_child(&self.style)?
             .add_optional_child(&self.positional_tab)?
             .add_optional_child(&self.shading)?
+            .add_optional_child(&self.run_property_change)?
             .close()?
             .into_inner()
     }
@@ -374,4 +533,37 @@ mod tests {
             r#"<w:rPr><w:dstrike /></w:rPr>"#
         );
     }
+
+    #[test]
+    fn test_moved_paragraph_mark() {
+        let c = RunProperty::new()
+            .move_from(MoveFrom::new().author("bob").date("2024-01-01T00:00:00Z"))
+            .move_to(
+                MoveTo::new_with_empty()
+                    .author("bob")
+                    .date("2024-01-01T00:00:00Z"),
+            );
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:rPr><w:moveFrom w:id="123" w:author="bob" w:date="2024-01-01T00:00:00Z" /><w:moveTo w:id="123" w:author="bob" w:date="2024-01-01T00:00:00Z" /></w:rPr>"#
+        );
+    }
+
+    #[test]
+    fn test_extended_run_formatting() {
+        let c = RunProperty::new()
+            .small_caps()
+            .emboss()
+            .no_proof()
+            .effect(TextEffectType::Shimmer)
+            .kern(28)
+            .position(-6)
+            .lang(Lang::new().val("en-US").east_asia("ja-JP"));
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:rPr><w:smallCaps /><w:emboss /><w:noProof /><w:effect w:val="shimmer" /><w:kern w:val="28" /><w:position w:val="-6" /><w:lang w:val="en-US" w:eastAsia="ja-JP" /></w:rPr>"#
+        );
+    }
 }
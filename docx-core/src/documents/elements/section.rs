@@ -121,6 +121,15 @@ impl Section {
         page_size(size: PageSize) -> Self,
         page_margin(margin: PageMargin) -> Self,
         page_orient(o: PageOrientationType) -> Self,
+        columns(num: usize) -> Self,
+        column_space(space: usize) -> Self,
+        add_column(width: usize, space: Option<usize>) -> Self,
+        column_separator() -> Self,
+        line_num_type(l: LineNumType) -> Self,
+        page_borders(b: PageBorders) -> Self,
+        vertical_align(v: VAlignType) -> Self,
+        bidi() -> Self,
+        rtl_gutter() -> Self,
         doc_grid(doc_grid: DocGrid) -> Self,
         text_direction(direction: String) -> Self,
         title_pg() -> Self,
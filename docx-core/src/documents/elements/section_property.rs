@@ -16,9 +16,23 @@ pub struct SectionProperty {
     pub page_margin: PageMargin,
     pub columns: usize,
     pub space: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub column_widths: Vec<Column>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub column_separator: bool,
     pub title_pg: bool,
     pub text_direction: String,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_num_type: Option<LineNumType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_borders: Option<PageBorders>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vertical_align: Option<VAlign>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub bidi: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub rtl_gutter: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub doc_grid: Option<DocGrid>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub header_reference: Option<HeaderReference>,
@@ -118,12 +132,90 @@ struct SectionReferenceXml {
     id: Option<String>,
 }
 
+#[derive(Debug, Deserialize, Default)]
+struct SectionColumnXml {
+    #[serde(rename = "@w", alias = "@w:w", default)]
+    w: Option<String>,
+    #[serde(rename = "@space", alias = "@w:space", default)]
+    space: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct SectionColumnsXml {
+    #[serde(rename = "@num", alias = "@w:num", default)]
+    num: Option<String>,
+    #[serde(rename = "@space", alias = "@w:space", default)]
+    space: Option<String>,
+    #[serde(rename = "@sep", alias = "@w:sep", default)]
+    sep: Option<String>,
+    #[serde(rename = "@equalWidth", alias = "@w:equalWidth", default)]
+    equal_width: Option<String>,
+    #[serde(rename = "col", alias = "w:col", default)]
+    cols: Vec<SectionColumnXml>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct SectionLineNumTypeXml {
+    #[serde(rename = "@countBy", alias = "@w:countBy", default)]
+    count_by: Option<String>,
+    #[serde(rename = "@start", alias = "@w:start", default)]
+    start: Option<String>,
+    #[serde(rename = "@distance", alias = "@w:distance", default)]
+    distance: Option<String>,
+    #[serde(rename = "@restart", alias = "@w:restart", default)]
+    restart: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct SectionPageBorderXml {
+    #[serde(rename = "@val", alias = "@w:val", default)]
+    val: Option<String>,
+    #[serde(rename = "@sz", alias = "@w:sz", default)]
+    size: Option<String>,
+    #[serde(rename = "@space", alias = "@w:space", default)]
+    space: Option<String>,
+    #[serde(rename = "@color", alias = "@w:color", default)]
+    color: Option<String>,
+    #[serde(rename = "@shadow", alias = "@w:shadow", default)]
+    shadow: Option<String>,
+    #[serde(rename = "@frame", alias = "@w:frame", default)]
+    frame: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+enum SectionPageBorderChildXml {
+    #[serde(rename = "top", alias = "w:top")]
+    Top(SectionPageBorderXml),
+    #[serde(rename = "left", alias = "w:left")]
+    Left(SectionPageBorderXml),
+    #[serde(rename = "bottom", alias = "w:bottom")]
+    Bottom(SectionPageBorderXml),
+    #[serde(rename = "right", alias = "w:right")]
+    Right(SectionPageBorderXml),
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct SectionPageBordersXml {
+    #[serde(rename = "@zOrder", alias = "@w:zOrder", default)]
+    z_order: Option<String>,
+    #[serde(rename = "@display", alias = "@w:display", default)]
+    display: Option<String>,
+    #[serde(rename = "@offsetFrom", alias = "@w:offsetFrom", default)]
+    offset_from: Option<String>,
+    #[serde(rename = "$value", default)]
+    children: Vec<SectionPageBorderChildXml>,
+}
+
 #[derive(Debug, Deserialize)]
 enum SectionPropertyChildXml {
     #[serde(rename = "pgMar", alias = "w:pgMar")]
     PageMargin(SectionPageMarginXml),
     #[serde(rename = "pgSz", alias = "w:pgSz")]
     PageSize(SectionPageSizeXml),
+    #[serde(rename = "cols", alias = "w:cols")]
+    Columns(SectionColumnsXml),
     #[serde(rename = "docGrid", alias = "w:docGrid")]
     DocGrid(SectionDocGridXml),
     #[serde(rename = "pgNumType", alias = "w:pgNumType")]
@@ -136,6 +228,18 @@ enum SectionPropertyChildXml {
     SectionType(XmlValueAttrSP),
     #[serde(rename = "titlePg", alias = "w:titlePg")]
     TitlePg(IgnoredAny),
+    #[serde(rename = "lnNumType", alias = "w:lnNumType")]
+    LineNumType(SectionLineNumTypeXml),
+    #[serde(rename = "pgBorders", alias = "w:pgBorders")]
+    PageBorders(SectionPageBordersXml),
+    #[serde(rename = "vAlign", alias = "w:vAlign")]
+    VAlign(XmlValueAttrSP),
+    #[serde(rename = "textDirection", alias = "w:textDirection")]
+    TextDirection(XmlValueAttrSP),
+    #[serde(rename = "bidi", alias = "w:bidi")]
+    Bidi(XmlValueAttrSP),
+    #[serde(rename = "rtlGutter", alias = "w:rtlGutter")]
+    RtlGutter(XmlValueAttrSP),
     #[serde(other)]
     Unknown,
 }
@@ -176,6 +280,117 @@ fn parse_doc_grid(xml: SectionDocGridXml) -> Option<DocGrid> {
     Some(doc_grid)
 }
 
+fn parse_bool_attr(raw: Option<String>) -> Option<bool> {
+    match raw?.trim() {
+        "1" | "true" | "on" => Some(true),
+        "0" | "false" | "off" => Some(false),
+        _ => None,
+    }
+}
+
+fn parse_columns(sp: &mut SectionProperty, xml: SectionColumnsXml) {
+    if let Some(num) = xml.num.and_then(|v| v.parse::<usize>().ok()) {
+        sp.columns = num;
+    }
+    if let Some(space) = parse_dxa_u32(xml.space) {
+        sp.space = space as usize;
+    }
+    sp.column_separator = parse_bool_attr(xml.sep).unwrap_or(false);
+    // Per column definitions are only meaningful when widths are not equal.
+    if parse_bool_attr(xml.equal_width) == Some(false) {
+        sp.column_widths = xml
+            .cols
+            .into_iter()
+            .map(|c| {
+                let col = Column::new(parse_dxa_u32(c.w).unwrap_or_default() as usize);
+                match parse_dxa_u32(c.space) {
+                    Some(space) => col.space(space as usize),
+                    None => col,
+                }
+            })
+            .collect();
+        if !sp.column_widths.is_empty() {
+            sp.columns = sp.column_widths.len();
+        }
+    }
+}
+
+fn parse_line_num_type(xml: SectionLineNumTypeXml) -> LineNumType {
+    let mut l = LineNumType::new();
+    if let Some(count_by) = xml.count_by.and_then(|v| v.parse::<usize>().ok()) {
+        l = l.count_by(count_by);
+    }
+    if let Some(start) = xml.start.and_then(|v| v.parse::<usize>().ok()) {
+        l = l.start(start);
+    }
+    if let Some(distance) = parse_dxa_u32(xml.distance) {
+        l = l.distance(distance as usize);
+    }
+    if let Some(restart) = xml.restart {
+        if let Ok(r) = LineNumberRestartType::from_str(&restart) {
+            l = l.restart(r);
+        }
+    }
+    l
+}
+
+fn parse_page_border(position: PageBorderPosition, xml: SectionPageBorderXml) -> PageBorder {
+    let mut border = PageBorder::new(position);
+    if let Some(val) = xml.val {
+        if let Ok(t) = BorderType::from_str(&val) {
+            border = border.val(t);
+        }
+    }
+    if let Some(size) = xml.size.and_then(|v| v.parse::<usize>().ok()) {
+        border = border.size(size);
+    }
+    if let Some(space) = xml.space.and_then(|v| v.parse::<usize>().ok()) {
+        border = border.space(space);
+    }
+    if let Some(color) = xml.color {
+        border = border.color(color);
+    }
+    if parse_bool_attr(xml.shadow) == Some(true) {
+        border = border.shadow();
+    }
+    if parse_bool_attr(xml.frame) == Some(true) {
+        border = border.frame();
+    }
+    border
+}
+
+fn parse_page_borders(xml: SectionPageBordersXml) -> PageBorders {
+    let mut borders = PageBorders::new();
+    if let Some(z) = xml.z_order {
+        if let Ok(z) = PageBorderZOrderType::from_str(&z) {
+            borders = borders.z_order(z);
+        }
+    }
+    if let Some(d) = xml.display {
+        if let Ok(d) = PageBorderDisplayType::from_str(&d) {
+            borders = borders.display(d);
+        }
+    }
+    if let Some(o) = xml.offset_from {
+        if let Ok(o) = PageBorderOffsetType::from_str(&o) {
+            borders = borders.offset_from(o);
+        }
+    }
+    for child in xml.children {
+        let border = match child {
+            SectionPageBorderChildXml::Top(v) => parse_page_border(PageBorderPosition::Top, v),
+            SectionPageBorderChildXml::Left(v) => parse_page_border(PageBorderPosition::Left, v),
+            SectionPageBorderChildXml::Bottom(v) => {
+                parse_page_border(PageBorderPosition::Bottom, v)
+            }
+            SectionPageBorderChildXml::Right(v) => parse_page_border(PageBorderPosition::Right, v),
+            SectionPageBorderChildXml::Unknown => continue,
+        };
+        borders = borders.set(border);
+    }
+    borders
+}
+
 fn parse_page_num_type(xml: SectionPageNumTypeXml) -> PageNumType {
     let mut p = PageNumType::new();
     if let Some(start) = xml.start.and_then(|v| v.parse::<u32>().ok()) {
@@ -232,6 +447,7 @@ impl<'de> Deserialize<'de> for SectionProperty {
                     }
                     sp = sp.page_size(size);
                 }
+                SectionPropertyChildXml::Columns(v) => parse_columns(&mut sp, v),
                 SectionPropertyChildXml::DocGrid(v) => {
                     if let Some(doc_grid) = parse_doc_grid(v) {
                         sp = sp.doc_grid(doc_grid);
@@ -286,6 +502,30 @@ impl<'de> Deserialize<'de> for SectionProperty {
                     }
                 }
                 SectionPropertyChildXml::TitlePg(_) => sp = sp.title_pg(),
+                SectionPropertyChildXml::LineNumType(v) => {
+                    sp = sp.line_num_type(parse_line_num_type(v));
+                }
+                SectionPropertyChildXml::PageBorders(v) => {
+                    sp = sp.page_borders(parse_page_borders(v));
+                }
+                SectionPropertyChildXml::VAlign(v) => {
+                    if let Some(val) = v.val {
+                        if let Ok(t) = VAlignType::from_str(&val) {
+                            sp = sp.vertical_align(t);
+                        }
+                    }
+                }
+                SectionPropertyChildXml::TextDirection(v) => {
+                    if let Some(val) = v.val {
+                        sp.text_direction = val;
+                    }
+                }
+                SectionPropertyChildXml::Bidi(v) => {
+                    sp.bidi = parse_bool_attr(v.val).unwrap_or(true);
+                }
+                SectionPropertyChildXml::RtlGutter(v) => {
+                    sp.rtl_gutter = parse_bool_attr(v.val).unwrap_or(true);
+                }
                 SectionPropertyChildXml::Unknown => {}
             }
         }
@@ -314,6 +554,33 @@ impl SectionProperty {
         self
     }
 
+    pub fn columns(mut self, num: usize) -> Self {
+        self.columns = num;
+        self
+    }
+
+    pub fn column_space(mut self, space: usize) -> Self {
+        self.space = space;
+        self
+    }
+
+    /// Add a column with its own width, which makes the columns of this section unequal.
+    /// `space` is the spacing to the next column in dxa.
+    pub fn add_column(mut self, width: usize, space: Option<usize>) -> Self {
+        let col = Column::new(width);
+        self.column_widths.push(match space {
+            Some(space) => col.space(space),
+            None => col,
+        });
+        self.columns = self.column_widths.len();
+        self
+    }
+
+    pub fn column_separator(mut self) -> Self {
+        self.column_separator = true;
+        self
+    }
+
     pub fn doc_grid(mut self, doc_grid: DocGrid) -> Self {
         self.doc_grid = Some(doc_grid);
         self
@@ -329,6 +596,31 @@ impl SectionProperty {
         self
     }
 
+    pub fn line_num_type(mut self, l: LineNumType) -> Self {
+        self.line_num_type = Some(l);
+        self
+    }
+
+    pub fn page_borders(mut self, b: PageBorders) -> Self {
+        self.page_borders = Some(b);
+        self
+    }
+
+    pub fn vertical_align(mut self, v: VAlignType) -> Self {
+        self.vertical_align = Some(VAlign::new(v));
+        self
+    }
+
+    pub fn bidi(mut self) -> Self {
+        self.bidi = true;
+        self
+    }
+
+    pub fn rtl_gutter(mut self) -> Self {
+        self.rtl_gutter = true;
+        self
+    }
+
     pub fn header(mut self, h: Header, rid: &str) -> Self {
         self.header_reference = Some(HeaderReference::new("default", rid));
         self.header = Some((rid.to_string(), h));
@@ -411,6 +703,19 @@ impl SectionProperty {
         self.page_num_type = Some(h);
         self
     }
+
+    // Width available for the text of a column in dxa.
+    // For unequal columns this is the width of the widest column.
+    pub fn text_width(&self) -> usize {
+        if let Some(width) = self.column_widths.iter().map(|c| c.width).max() {
+            return width;
+        }
+        let m = &self.page_margin;
+        let width = self.page_size.get_width() as i32 - m.left - m.right - m.gutter;
+        let columns = self.columns.max(1);
+        let spacing = (self.space * (columns - 1)) as i32;
+        ((width - spacing).max(0) as usize) / columns
+    }
 }
 
 impl Default for SectionProperty {
@@ -420,8 +725,15 @@ impl Default for SectionProperty {
             page_margin: PageMargin::new(),
             columns: 1,
             space: 425,
+            column_widths: vec![],
+            column_separator: false,
             title_pg: false,
             text_direction: "lrTb".to_string(),
+            line_num_type: None,
+            page_borders: None,
+            vertical_align: None,
+            bidi: false,
+            rtl_gutter: false,
             doc_grid: None,
             // headers
             header_reference: None,
@@ -452,7 +764,16 @@ impl BuildXML for SectionProperty {
             .open_section_property()?
             .add_child(&self.page_size)?
             .add_child(&self.page_margin)?
-            .columns(&format!("{}", &self.space), &format!("{}", &self.columns))?
+            .add_optional_child(&self.page_borders)?
+            .add_optional_child(&self.line_num_type)?
+            .open_columns(
+                self.space,
+                self.columns,
+                self.column_separator,
+                self.column_widths.is_empty(),
+            )?
+            .add_children(&self.column_widths)?
+            .close()?
             .add_optional_child(&self.doc_grid)?
             .add_optional_child(&self.header_reference)?
             .add_optional_child(&self.first_header_reference)?
@@ -465,7 +786,10 @@ impl BuildXML for SectionProperty {
                 b.text_direction(&self.text_direction)
             })?
             .apply_opt(self.section_type, |t, b| b.type_tag(&t.to_string()))?
+            .add_optional_child(&self.vertical_align)?
             .apply_if(self.title_pg, |b| b.title_pg())?
+            .apply_if(self.bidi, |b| b.bidi())?
+            .apply_if(self.rtl_gutter, |b| b.rtl_gutter())?
             .close()?
             .into_inner()
     }
@@ -490,6 +814,15 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_section_property_text_width() {
+        assert_eq!(SectionProperty::new().text_width(), 8504);
+        let mut c = SectionProperty::new();
+        c.columns = 2;
+        c.space = 504;
+        assert_eq!(c.text_width(), 4000);
+    }
+
     #[test]
     fn test_section_property_default() {
         let c = SectionProperty::new();
@@ -500,6 +833,106 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_section_property_with_unequal_columns() {
+        let c = SectionProperty::new()
+            .add_column(3000, Some(720))
+            .add_column(5000, None)
+            .column_separator();
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:sectPr><w:pgSz w:w="11906" w:h="16838" /><w:pgMar w:top="1985" w:right="1701" w:bottom="1701" w:left="1701" w:header="851" w:footer="992" w:gutter="0" /><w:cols w:space="425" w:num="2" w:sep="1" w:equalWidth="0"><w:col w:w="3000" w:space="720" /><w:col w:w="5000" /></w:cols></w:sectPr>"#
+        );
+        assert_eq!(c.text_width(), 5000);
+    }
+
+    #[test]
+    fn test_read_section_property_columns() {
+        let xml = r#"<w:sectPr xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+            <w:cols w:num="2" w:space="720" w:sep="1" w:equalWidth="0">
+                <w:col w:w="3000" w:space="720"/>
+                <w:col w:w="5000"/>
+            </w:cols>
+        </w:sectPr>"#;
+        let sp: SectionProperty = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(sp.columns, 2);
+        assert_eq!(sp.space, 720);
+        assert!(sp.column_separator);
+        assert_eq!(
+            sp.column_widths,
+            vec![Column::new(3000).space(720), Column::new(5000)]
+        );
+    }
+
+    #[test]
+    fn test_section_property_with_line_numbers_and_page_borders() {
+        let c = SectionProperty::new()
+            .line_num_type(
+                LineNumType::new()
+                    .count_by(1)
+                    .restart(LineNumberRestartType::NewPage),
+            )
+            .page_borders(
+                PageBorders::new()
+                    .offset_from(PageBorderOffsetType::Page)
+                    .set(PageBorder::new(PageBorderPosition::Top).val(BorderType::Double)),
+            )
+            .vertical_align(VAlignType::Center)
+            .bidi()
+            .rtl_gutter();
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:sectPr><w:pgSz w:w="11906" w:h="16838" /><w:pgMar w:top="1985" w:right="1701" w:bottom="1701" w:left="1701" w:header="851" w:footer="992" w:gutter="0" /><w:pgBorders w:offsetFrom="page"><w:top w:val="double" w:sz="4" w:space="24" w:color="auto" /></w:pgBorders><w:lnNumType w:countBy="1" w:restart="newPage" /><w:cols w:space="425" w:num="1" /><w:vAlign w:val="center" /><w:bidi /><w:rtlGutter /></w:sectPr>"#
+        );
+    }
+
+    #[test]
+    fn test_read_section_property_page_layout() {
+        let xml = r#"<w:sectPr xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+            <w:pgBorders w:offsetFrom="page" w:display="firstPage">
+                <w:top w:val="certificateBanner" w:sz="31" w:space="24" w:color="auto"/>
+                <w:left w:val="single" w:sz="4" w:space="24" w:color="FF0000" w:shadow="1"/>
+            </w:pgBorders>
+            <w:lnNumType w:countBy="5" w:distance="360" w:restart="continuous"/>
+            <w:vAlign w:val="center"/>
+            <w:bidi/>
+            <w:rtlGutter w:val="0"/>
+        </w:sectPr>"#;
+        let sp: SectionProperty = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(
+            sp.line_num_type,
+            Some(
+                LineNumType::new()
+                    .count_by(5)
+                    .distance(360)
+                    .restart(LineNumberRestartType::Continuous)
+            )
+        );
+        assert_eq!(
+            sp.page_borders,
+            Some(
+                PageBorders::new()
+                    .display(PageBorderDisplayType::FirstPage)
+                    .offset_from(PageBorderOffsetType::Page)
+                    .set(
+                        PageBorder::new(PageBorderPosition::Top)
+                            .val(BorderType::CertificateBanner)
+                            .size(31)
+                    )
+                    .set(
+                        PageBorder::new(PageBorderPosition::Left)
+                            .color("FF0000")
+                            .shadow()
+                    )
+            )
+        );
+        assert_eq!(sp.vertical_align, Some(VAlign::new(VAlignType::Center)));
+        assert!(sp.bidi);
+        assert!(!sp.rtl_gutter);
+    }
+
     #[test]
     fn test_section_property_with_footer() {
         let c = SectionProperty::new().footer(Footer::new(), "rId6");
@@ -5,21 +5,20 @@ use crate::documents::BuildXML;
 use crate::types::*;
 use crate::xml_builder::*;
 
-// INFO: Theme is not supported now.
 #[derive(Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Shading {
     pub shd_type: ShdType,
-    pub color: String,
-    pub fill: String,
+    pub color: ColorValue,
+    pub fill: ColorValue,
 }
 
 impl Default for Shading {
     fn default() -> Self {
         Shading {
             shd_type: ShdType::Clear,
-            color: "auto".to_owned(),
-            fill: "FFFFFF".to_owned(),
+            color: ColorValue::Auto,
+            fill: ColorValue::rgb("FFFFFF"),
         }
     }
 }
@@ -29,12 +28,12 @@ impl Shading {
         Shading::default()
     }
 
-    pub fn color(mut self, color: impl Into<String>) -> Shading {
+    pub fn color(mut self, color: impl Into<ColorValue>) -> Shading {
         self.color = color.into();
         self
     }
 
-    pub fn fill(mut self, fill: impl Into<String>) -> Shading {
+    pub fn fill(mut self, fill: impl Into<ColorValue>) -> Shading {
         self.fill = fill.into();
         self
     }
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Shadow {
+    val: bool,
+}
+
+impl Shadow {
+    pub fn new() -> Shadow {
+        Default::default()
+    }
+
+    pub fn disable(mut self) -> Shadow {
+        self.val = false;
+        self
+    }
+}
+
+impl Default for Shadow {
+    fn default() -> Self {
+        Self { val: true }
+    }
+}
+
+impl Serialize for Shadow {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(self.val)
+    }
+}
+
+impl BuildXML for Shadow {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        if self.val {
+            XMLBuilder::from(stream).shadow()?.into_inner()
+        } else {
+            XMLBuilder::from(stream)
+                .disabled_toggle("w:shadow")?
+                .into_inner()
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct SmallCaps {
+    val: bool,
+}
+
+impl SmallCaps {
+    pub fn new() -> SmallCaps {
+        Default::default()
+    }
+
+    pub fn disable(mut self) -> SmallCaps {
+        self.val = false;
+        self
+    }
+}
+
+impl Default for SmallCaps {
+    fn default() -> Self {
+        Self { val: true }
+    }
+}
+
+impl Serialize for SmallCaps {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(self.val)
+    }
+}
+
+impl BuildXML for SmallCaps {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        if self.val {
+            XMLBuilder::from(stream).small_caps()?.into_inner()
+        } else {
+            XMLBuilder::from(stream)
+                .disabled_toggle("w:smallCaps")?
+                .into_inner()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_build() {
+        let b = SmallCaps::new().build();
+        assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:smallCaps />"#);
+        let b = SmallCaps::new().disable().build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:smallCaps w:val="false" />"#
+        );
+    }
+}
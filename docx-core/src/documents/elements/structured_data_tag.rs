@@ -4,7 +4,8 @@ use std::io::Write;
 
 use super::*;
 use crate::documents::BuildXML;
-// use crate::types::*;
+use crate::escape::replace_escaped;
+use crate::types::*;
 use crate::xml_builder::*;
 
 // ============================================================================
@@ -33,6 +34,8 @@ enum SdtContentChildXml {
     Paragraph(Paragraph),
     #[serde(rename = "tbl", alias = "w:tbl")]
     Table(Table),
+    #[serde(rename = "tr", alias = "w:tr")]
+    TableRow(TableRow),
     #[serde(rename = "bookmarkStart", alias = "w:bookmarkStart")]
     BookmarkStart(XmlBookmarkStartNode),
     #[serde(rename = "bookmarkEnd", alias = "w:bookmarkEnd")]
@@ -72,6 +75,7 @@ fn sdt_child_from_xml(xml: SdtContentChildXml) -> Option<StructuredDataTagChild>
             Some(StructuredDataTagChild::Paragraph(Box::new(p)))
         }
         SdtContentChildXml::Table(t) => Some(StructuredDataTagChild::Table(Box::new(t))),
+        SdtContentChildXml::TableRow(r) => Some(StructuredDataTagChild::TableRow(Box::new(r))),
         SdtContentChildXml::BookmarkStart(node) => {
             let id = parse_optional_usize(node.id)?;
             let name = node.name?;
@@ -122,6 +126,7 @@ impl<'de> Deserialize<'de> for StructuredDataTag {
         let has_numbering = children.iter().any(|c| match c {
             StructuredDataTagChild::Paragraph(p) => p.has_numbering,
             StructuredDataTagChild::Table(t) => t.has_numbering,
+            StructuredDataTagChild::TableRow(r) => r.has_numbering,
             StructuredDataTagChild::StructuredDataTag(s) => s.has_numbering,
             _ => false,
         });
@@ -149,6 +154,8 @@ pub enum StructuredDataTagChild {
     Run(Box<Run>),
     Paragraph(Box<Paragraph>),
     Table(Box<Table>),
+    // Row-level content, e.g. the rows of a repeating section inside a table.
+    TableRow(Box<TableRow>),
     BookmarkStart(BookmarkStart),
     BookmarkEnd(BookmarkEnd),
     CommentStart(Box<CommentRangeStart>),
@@ -165,6 +172,7 @@ impl BuildXML for StructuredDataTagChild {
             StructuredDataTagChild::Run(v) => v.build_to(stream),
             StructuredDataTagChild::Paragraph(v) => v.build_to(stream),
             StructuredDataTagChild::Table(v) => v.build_to(stream),
+            StructuredDataTagChild::TableRow(v) => v.build_to(stream),
             StructuredDataTagChild::BookmarkStart(v) => v.build_to(stream),
             StructuredDataTagChild::BookmarkEnd(v) => v.build_to(stream),
             StructuredDataTagChild::CommentStart(v) => v.build_to(stream),
@@ -198,6 +206,12 @@ impl Serialize for StructuredDataTagChild {
                 t.serialize_field("data", r)?;
                 t.end()
             }
+            StructuredDataTagChild::TableRow(ref r) => {
+                let mut t = serializer.serialize_struct("TableRow", 2)?;
+                t.serialize_field("type", "tableRow")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
             StructuredDataTagChild::BookmarkStart(ref c) => {
                 let mut t = serializer.serialize_struct("BookmarkStart", 2)?;
                 t.serialize_field("type", "bookmarkStart")?;
@@ -261,6 +275,54 @@ impl StructuredDataTag {
         self
     }
 
+    pub fn add_table_row(mut self, r: TableRow) -> Self {
+        if r.has_numbering {
+            self.has_numbering = true
+        }
+        self.children
+            .push(StructuredDataTagChild::TableRow(Box::new(r)));
+        self
+    }
+
+    pub fn add_structured_data_tag(mut self, t: StructuredDataTag) -> Self {
+        if t.has_numbering {
+            self.has_numbering = true
+        }
+        self.children
+            .push(StructuredDataTagChild::StructuredDataTag(Box::new(t)));
+        self
+    }
+
+    pub fn repeating_section(mut self, s: RepeatingSection) -> Self {
+        self.property = self.property.control(ContentControl::RepeatingSection(s));
+        self
+    }
+
+    pub fn repeating_section_item(mut self) -> Self {
+        self.property = self.property.control(ContentControl::RepeatingSectionItem);
+        self
+    }
+
+    pub(crate) fn collect_table_rows<'a>(&'a self, rows: &mut Vec<&'a TableRow>) {
+        for c in self.children.iter() {
+            match c {
+                StructuredDataTagChild::TableRow(r) => rows.push(r),
+                StructuredDataTagChild::StructuredDataTag(t) => t.collect_table_rows(rows),
+                _ => {}
+            }
+        }
+    }
+
+    pub(crate) fn collect_table_rows_mut<'a>(&'a mut self, rows: &mut Vec<&'a mut TableRow>) {
+        for c in self.children.iter_mut() {
+            match c {
+                StructuredDataTagChild::TableRow(r) => rows.push(r),
+                StructuredDataTagChild::StructuredDataTag(t) => t.collect_table_rows_mut(rows),
+                _ => {}
+            }
+        }
+    }
+
     pub fn data_binding(mut self, d: DataBinding) -> Self {
         self.property = self.property.data_binding(d);
         self
@@ -270,6 +332,88 @@ impl StructuredDataTag {
         self.property = self.property.alias(v);
         self
     }
+
+    pub fn tag(mut self, v: impl Into<String>) -> Self {
+        self.property = self.property.tag(v);
+        self
+    }
+
+    pub fn id(mut self, id: i32) -> Self {
+        self.property = self.property.id(id);
+        self
+    }
+
+    pub fn lock(mut self, l: SdtLockType) -> Self {
+        self.property = self.property.lock(l);
+        self
+    }
+
+    pub fn placeholder(mut self, doc_part: impl Into<String>) -> Self {
+        self.property = self.property.placeholder(doc_part);
+        self
+    }
+
+    pub fn showing_placeholder(mut self) -> Self {
+        self.property = self.property.showing_placeholder();
+        self
+    }
+
+    pub fn control(mut self, c: ContentControl) -> Self {
+        self.property = self.property.control(c);
+        self
+    }
+
+    // Text of the content. Paragraphs are separated by line breaks.
+    pub fn text(&self) -> String {
+        replace_escaped(&self.raw_text())
+    }
+
+    fn raw_text(&self) -> String {
+        let mut s = String::new();
+        for c in self.children.iter() {
+            match c {
+                StructuredDataTagChild::Run(r) => {
+                    for c in r.children.iter() {
+                        if let RunChild::Text(t) = c {
+                            s.push_str(&t.text);
+                        }
+                    }
+                }
+                StructuredDataTagChild::Paragraph(p) => {
+                    if !s.is_empty() {
+                        s.push('\n');
+                    }
+                    s.push_str(&p.raw_text());
+                }
+                StructuredDataTagChild::StructuredDataTag(t) => s.push_str(&t.raw_text()),
+                _ => {}
+            }
+        }
+        s
+    }
+
+    // The value entered in the control, e.g. "true" for a checked check box or the
+    // selected item value of a drop down list. None while the placeholder is shown.
+    pub fn value(&self) -> Option<String> {
+        if let Some(ContentControl::CheckBox(c)) = &self.property.control {
+            return Some(c.checked.to_string());
+        }
+        if self.property.showing_placeholder {
+            return None;
+        }
+        let text = self.text();
+        match &self.property.control {
+            Some(ContentControl::DropDownList(l)) | Some(ContentControl::ComboBox(l)) => Some(
+                l.items
+                    .iter()
+                    .find(|i| replace_escaped(i.text()) == text)
+                    .map(|i| replace_escaped(&i.value))
+                    .unwrap_or(text),
+            ),
+            Some(ContentControl::Date(d)) => d.full_date.clone().or(Some(text)),
+            _ => Some(text),
+        }
+    }
 }
 
 impl BuildXML for StructuredDataTag {
@@ -353,4 +497,46 @@ mod tests {
             panic!("Expected nested StructuredDataTag");
         }
     }
+
+    #[test]
+    fn test_sdt_repeating_section() {
+        let b = StructuredDataTag::new()
+            .data_binding(DataBinding::new().xpath("/root/items/item"))
+            .repeating_section(RepeatingSection::new().section_title("Item"))
+            .add_structured_data_tag(
+                StructuredDataTag::new()
+                    .repeating_section_item()
+                    .add_table_row(TableRow::new(vec![])),
+            )
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:sdt><w:sdtPr><w:rPr /><w15:dataBinding w:xpath="/root/items/item" /><w15:repeatingSection><w15:sectionTitle w15:val="Item" /></w15:repeatingSection></w:sdtPr><w:sdtContent><w:sdt><w:sdtPr><w:rPr /><w15:repeatingSectionItem /></w:sdtPr><w:sdtContent><w:tr><w:trPr /></w:tr></w:sdtContent></w:sdt></w:sdtContent></w:sdt>"#
+        );
+    }
+
+    #[test]
+    fn test_sdt_value() {
+        let sdt = StructuredDataTag::new()
+            .control(ContentControl::DropDownList(
+                ListControl::new()
+                    .add_item(ListItem::new("a").display_text("Apple"))
+                    .add_item(ListItem::new("b").display_text("Banana")),
+            ))
+            .add_run(Run::new().add_text("Banana"));
+        assert_eq!(sdt.value(), Some("b".to_string()));
+
+        let sdt = StructuredDataTag::new()
+            .control(ContentControl::Text(TextControl::new()))
+            .showing_placeholder()
+            .add_run(Run::new().add_text("Click here to enter text."));
+        assert_eq!(sdt.value(), None);
+
+        let checkbox = CheckBox::new().checked(true);
+        let sdt = StructuredDataTag::new()
+            .add_run(checkbox.symbol_run())
+            .control(ContentControl::CheckBox(checkbox));
+        assert_eq!(sdt.value(), Some("true".to_string()));
+        assert_eq!(sdt.text(), "☒");
+    }
 }
@@ -1,9 +1,11 @@
 use serde::{Deserialize, Deserializer, Serialize};
 use std::io::Write;
+use std::str::FromStr;
 
 use super::*;
 use crate::documents::BuildXML;
-// use crate::types::*;
+use crate::escape::escape;
+use crate::types::*;
 use crate::xml_builder::*;
 
 // ============================================================================
@@ -16,12 +18,50 @@ struct XmlValNode {
     val: Option<String>,
 }
 
+#[derive(Debug, Deserialize, Default)]
+struct XmlPlaceholderNode {
+    #[serde(rename = "docPart", alias = "w:docPart", default)]
+    doc_part: Option<XmlValNode>,
+}
+
 #[derive(Debug, Deserialize, Default)]
 struct StructuredDataTagPropertyXml {
     #[serde(rename = "dataBinding", alias = "w:dataBinding", default)]
     data_binding: Option<DataBinding>,
+    #[serde(rename = "w15:dataBinding", default)]
+    w15_data_binding: Option<DataBinding>,
     #[serde(rename = "alias", alias = "w:alias", default)]
     alias: Option<XmlValNode>,
+    #[serde(rename = "tag", alias = "w:tag", default)]
+    tag: Option<XmlValNode>,
+    #[serde(rename = "id", alias = "w:id", default)]
+    id: Option<XmlValNode>,
+    #[serde(rename = "lock", alias = "w:lock", default)]
+    lock: Option<XmlValNode>,
+    #[serde(rename = "placeholder", alias = "w:placeholder", default)]
+    placeholder: Option<XmlPlaceholderNode>,
+    #[serde(rename = "showingPlcHdr", alias = "w:showingPlcHdr", default)]
+    showing_placeholder: Option<XmlValNode>,
+    #[serde(rename = "checkbox", alias = "w14:checkbox", default)]
+    checkbox: Option<CheckBox>,
+    #[serde(rename = "dropDownList", alias = "w:dropDownList", default)]
+    drop_down_list: Option<ListControl>,
+    #[serde(rename = "comboBox", alias = "w:comboBox", default)]
+    combo_box: Option<ListControl>,
+    #[serde(rename = "date", alias = "w:date", default)]
+    date: Option<DateControl>,
+    #[serde(rename = "text", alias = "w:text", default)]
+    text: Option<TextControl>,
+    #[serde(rename = "picture", alias = "w:picture", default)]
+    picture: Option<serde::de::IgnoredAny>,
+    #[serde(rename = "repeatingSection", alias = "w15:repeatingSection", default)]
+    repeating_section: Option<RepeatingSection>,
+    #[serde(
+        rename = "repeatingSectionItem",
+        alias = "w15:repeatingSectionItem",
+        default
+    )]
+    repeating_section_item: Option<serde::de::IgnoredAny>,
 }
 
 #[derive(Serialize, Debug, Clone, PartialEq)]
@@ -30,6 +70,19 @@ pub struct StructuredDataTagProperty {
     pub run_property: RunProperty,
     pub data_binding: Option<DataBinding>,
     pub alias: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock: Option<SdtLockType>,
+    // The name of the glossary document part holding the placeholder text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<String>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub showing_placeholder: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub control: Option<ContentControl>,
 }
 
 impl<'de> Deserialize<'de> for StructuredDataTagProperty {
@@ -38,10 +91,44 @@ impl<'de> Deserialize<'de> for StructuredDataTagProperty {
         D: Deserializer<'de>,
     {
         let xml = StructuredDataTagPropertyXml::deserialize(deserializer)?;
+        let control = if let Some(c) = xml.checkbox {
+            Some(ContentControl::CheckBox(c))
+        } else if let Some(l) = xml.drop_down_list {
+            Some(ContentControl::DropDownList(l))
+        } else if let Some(l) = xml.combo_box {
+            Some(ContentControl::ComboBox(l))
+        } else if let Some(d) = xml.date {
+            Some(ContentControl::Date(d))
+        } else if let Some(t) = xml.text {
+            Some(ContentControl::Text(t))
+        } else if xml.picture.is_some() {
+            Some(ContentControl::Picture)
+        } else if let Some(s) = xml.repeating_section {
+            Some(ContentControl::RepeatingSection(s))
+        } else {
+            xml.repeating_section_item
+                .map(|_| ContentControl::RepeatingSectionItem)
+        };
         Ok(StructuredDataTagProperty {
             run_property: RunProperty::new(),
-            data_binding: xml.data_binding,
+            data_binding: xml.data_binding.or(xml.w15_data_binding),
             alias: xml.alias.and_then(|v| v.val),
+            tag: xml.tag.and_then(|v| v.val).map(|v| escape(&v)),
+            id: xml.id.and_then(|v| v.val).and_then(|v| v.parse().ok()),
+            lock: xml
+                .lock
+                .and_then(|v| v.val)
+                .and_then(|v| SdtLockType::from_str(&v).ok()),
+            placeholder: xml
+                .placeholder
+                .and_then(|p| p.doc_part)
+                .and_then(|v| v.val)
+                .map(|v| escape(&v)),
+            showing_placeholder: xml
+                .showing_placeholder
+                .map(|v| v.val.map(|v| v != "0" && v != "false").unwrap_or(true))
+                .unwrap_or(false),
+            control,
         })
     }
 }
@@ -52,6 +139,12 @@ impl Default for StructuredDataTagProperty {
             run_property: RunProperty::new(),
             data_binding: None,
             alias: None,
+            tag: None,
+            id: None,
+            lock: None,
+            placeholder: None,
+            showing_placeholder: false,
+            control: None,
         }
     }
 }
@@ -70,6 +163,37 @@ impl StructuredDataTagProperty {
         self.alias = Some(v.into());
         self
     }
+
+    pub fn tag(mut self, v: impl Into<String>) -> Self {
+        self.tag = Some(escape(&v.into()));
+        self
+    }
+
+    pub fn id(mut self, id: i32) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn lock(mut self, l: SdtLockType) -> Self {
+        self.lock = Some(l);
+        self
+    }
+
+    pub fn placeholder(mut self, doc_part: impl Into<String>) -> Self {
+        self.placeholder = Some(escape(&doc_part.into()));
+        self
+    }
+
+    // The content shows the placeholder text instead of a value.
+    pub fn showing_placeholder(mut self) -> Self {
+        self.showing_placeholder = true;
+        self
+    }
+
+    pub fn control(mut self, c: ContentControl) -> Self {
+        self.control = Some(c);
+        self
+    }
 }
 
 impl BuildXML for StructuredDataTagProperty {
@@ -80,8 +204,29 @@ impl BuildXML for StructuredDataTagProperty {
         XMLBuilder::from(stream)
             .open_structured_tag_property()?
             .add_child(&self.run_property)?
-            .add_optional_child(&self.data_binding)?
             .apply_opt(self.alias.as_ref(), |alias, b| b.alias(alias))?
+            .apply_opt(self.tag.as_ref(), |tag, b| b.sdt_tag(tag))?
+            .apply_opt(self.id, |id, b| b.sdt_id(id as isize))?
+            .apply_opt(self.lock, |lock, b| b.sdt_lock(&lock.to_string()))?
+            .apply_opt(self.placeholder.as_ref(), |doc_part, b| {
+                b.open_placeholder()?.doc_part(doc_part)?.close()
+            })?
+            .apply_if(self.showing_placeholder, |b| b.showing_placeholder())?
+            .apply_opt(self.data_binding.as_ref(), |d, b| {
+                // Word binds repeating sections to a node set with w15:dataBinding.
+                let el = if matches!(self.control, Some(ContentControl::RepeatingSection(_))) {
+                    "w15:dataBinding"
+                } else {
+                    "w:dataBinding"
+                };
+                b.data_binding(
+                    el,
+                    d.xpath.as_ref(),
+                    d.prefix_mappings.as_ref(),
+                    d.store_item_id.as_ref(),
+                )
+            })?
+            .add_optional_child(&self.control)?
             .close()?
             .into_inner()
     }
@@ -130,4 +275,98 @@ mod tests {
             Some("root/data".to_string())
         );
     }
+
+    #[test]
+    fn test_with_tag_and_checkbox() {
+        let c = StructuredDataTagProperty::new()
+            .tag("agree")
+            .id(-123)
+            .lock(SdtLockType::SdtLocked)
+            .placeholder("DefaultPlaceholder_-1854013440")
+            .showing_placeholder()
+            .control(ContentControl::CheckBox(CheckBox::new()));
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:sdtPr><w:rPr /><w:tag w:val="agree" /><w:id w:val="-123" /><w:lock w:val="sdtLocked" /><w:placeholder><w:docPart w:val="DefaultPlaceholder_-1854013440" /></w:placeholder><w:showingPlcHdr /><w14:checkbox><w14:checked w14:val="0" /><w14:checkedState w14:val="2612" w14:font="MS Gothic" /><w14:uncheckedState w14:val="2610" w14:font="MS Gothic" /></w14:checkbox></w:sdtPr>"#
+        );
+    }
+
+    #[test]
+    fn test_with_tag_id_and_data_binding() {
+        let c = StructuredDataTagProperty::new()
+            .data_binding(DataBinding::new().xpath("/root/client"))
+            .alias("Client")
+            .tag("client")
+            .id(42);
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:sdtPr><w:rPr /><w:alias w:val="Client" /><w:tag w:val="client" /><w:id w:val="42" /><w:dataBinding w:xpath="/root/client" /></w:sdtPr>"#
+        );
+    }
+
+    #[test]
+    fn test_sdt_property_xml_deserialize_controls() {
+        let xml = r#"<w:sdtPr xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml">
+            <w:tag w:val="agree" />
+            <w:id w:val="-1854013440" />
+            <w:lock w:val="contentLocked" />
+            <w14:checkbox>
+                <w14:checked w14:val="1" />
+                <w14:checkedState w14:val="2612" w14:font="MS Gothic" />
+                <w14:uncheckedState w14:val="2610" w14:font="MS Gothic" />
+            </w14:checkbox>
+        </w:sdtPr>"#;
+        let prop: StructuredDataTagProperty = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(prop.tag, Some("agree".to_string()));
+        assert_eq!(prop.id, Some(-1854013440));
+        assert_eq!(prop.lock, Some(SdtLockType::ContentLocked));
+        assert_eq!(
+            prop.control,
+            Some(ContentControl::CheckBox(CheckBox::new().checked(true)))
+        );
+
+        let xml = r#"<w:sdtPr xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+            <w:placeholder><w:docPart w:val="Choose" /></w:placeholder>
+            <w:showingPlcHdr />
+            <w:dropDownList w:lastValue="b">
+                <w:listItem w:displayText="Apple" w:value="a" />
+                <w:listItem w:value="b" />
+            </w:dropDownList>
+        </w:sdtPr>"#;
+        let prop: StructuredDataTagProperty = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(prop.placeholder, Some("Choose".to_string()));
+        assert!(prop.showing_placeholder);
+        assert_eq!(
+            prop.control,
+            Some(ContentControl::DropDownList(
+                ListControl::new()
+                    .add_item(ListItem::new("a").display_text("Apple"))
+                    .add_item(ListItem::new("b"))
+                    .last_value("b")
+            ))
+        );
+
+        let xml = r#"<w:sdtPr xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+            <w:date w:fullDate="2024-01-31T00:00:00Z"><w:dateFormat w:val="yyyy/MM/dd" /><w:lid w:val="ja-JP" /></w:date>
+        </w:sdtPr>"#;
+        let prop: StructuredDataTagProperty = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(
+            prop.control,
+            Some(ContentControl::Date(
+                DateControl::new()
+                    .full_date("2024-01-31T00:00:00Z")
+                    .date_format("yyyy/MM/dd")
+                    .lid("ja-JP")
+            ))
+        );
+
+        let xml = r#"<w:sdtPr xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:text w:multiLine="1" /></w:sdtPr>"#;
+        let prop: StructuredDataTagProperty = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(
+            prop.control,
+            Some(ContentControl::Text(TextControl::new().multi_line()))
+        );
+    }
 }
@@ -48,6 +48,87 @@ pub(crate) struct RunFontsXml {
     pub hint: Option<String>,
 }
 
+#[derive(Debug, Deserialize, Default, Clone)]
+pub(crate) struct ShadingXml {
+    #[serde(rename = "@val", alias = "@w:val", default)]
+    pub shd_type: Option<String>,
+    #[serde(rename = "@color", alias = "@w:color", default)]
+    pub color: Option<String>,
+    #[serde(rename = "@themeColor", alias = "@w:themeColor", default)]
+    pub theme_color: Option<String>,
+    #[serde(rename = "@themeTint", alias = "@w:themeTint", default)]
+    pub theme_tint: Option<String>,
+    #[serde(rename = "@themeShade", alias = "@w:themeShade", default)]
+    pub theme_shade: Option<String>,
+    #[serde(rename = "@fill", alias = "@w:fill", default)]
+    pub fill: Option<String>,
+    #[serde(rename = "@themeFill", alias = "@w:themeFill", default)]
+    pub theme_fill: Option<String>,
+    #[serde(rename = "@themeFillTint", alias = "@w:themeFillTint", default)]
+    pub theme_fill_tint: Option<String>,
+    #[serde(rename = "@themeFillShade", alias = "@w:themeFillShade", default)]
+    pub theme_fill_shade: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub(crate) struct ColorXml {
+    #[serde(rename = "@val", alias = "@w:val", default)]
+    pub val: Option<String>,
+    #[serde(rename = "@themeColor", alias = "@w:themeColor", default)]
+    pub theme_color: Option<String>,
+    #[serde(rename = "@themeTint", alias = "@w:themeTint", default)]
+    pub theme_tint: Option<String>,
+    #[serde(rename = "@themeShade", alias = "@w:themeShade", default)]
+    pub theme_shade: Option<String>,
+}
+
+impl ColorXml {
+    pub fn into_color(self) -> Option<ColorValue> {
+        ColorValue::from_attrs(
+            self.val,
+            self.theme_color.as_deref(),
+            self.theme_tint.as_deref(),
+            self.theme_shade.as_deref(),
+        )
+    }
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub(crate) struct LangXml {
+    #[serde(rename = "@val", alias = "@w:val", default)]
+    pub val: Option<String>,
+    #[serde(rename = "@eastAsia", alias = "@w:eastAsia", default)]
+    pub east_asia: Option<String>,
+    #[serde(rename = "@bidi", alias = "@w:bidi", default)]
+    pub bidi: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub(crate) struct FitTextXml {
+    #[serde(rename = "@val", alias = "@w:val", default)]
+    pub val: Option<String>,
+    #[serde(rename = "@id", alias = "@w:id", default)]
+    pub id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+struct TrackChangeXml {
+    #[serde(rename = "@author", alias = "@w:author", default)]
+    author: Option<String>,
+    #[serde(rename = "@date", alias = "@w:date", default)]
+    date: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub(crate) struct RunPropertyChangeXml {
+    #[serde(rename = "@author", alias = "@w:author", default)]
+    author: Option<String>,
+    #[serde(rename = "@date", alias = "@w:date", default)]
+    date: Option<String>,
+    #[serde(rename = "rPr", alias = "w:rPr", default)]
+    run_property: Option<Box<RunPropertyXml>>,
+}
+
 // Internal wrapper for deserializing rPr with potentially duplicate elements
 #[derive(Debug, Deserialize, Default)]
 struct RunPropertyXmlRaw {
@@ -62,7 +143,7 @@ enum RunPropertyChildXml {
     #[serde(rename = "sz", alias = "w:sz")]
     Size(XmlValueAttr),
     #[serde(rename = "color", alias = "w:color")]
-    Color(XmlValueAttr),
+    Color(ColorXml),
     #[serde(rename = "highlight", alias = "w:highlight")]
     Highlight(XmlValueAttr),
     #[serde(rename = "spacing", alias = "w:spacing")]
@@ -87,6 +168,42 @@ enum RunPropertyChildXml {
     Vanish(XmlOnOffAttr),
     #[serde(rename = "specVanish", alias = "w:specVanish")]
     SpecVanish(XmlOnOffAttr),
+    #[serde(rename = "smallCaps", alias = "w:smallCaps")]
+    SmallCaps(XmlOnOffAttr),
+    #[serde(rename = "outline", alias = "w:outline")]
+    Outline(XmlOnOffAttr),
+    #[serde(rename = "shadow", alias = "w:shadow")]
+    Shadow(XmlOnOffAttr),
+    #[serde(rename = "emboss", alias = "w:emboss")]
+    Emboss(XmlOnOffAttr),
+    #[serde(rename = "imprint", alias = "w:imprint")]
+    Imprint(XmlOnOffAttr),
+    #[serde(rename = "noProof", alias = "w:noProof")]
+    NoProof(XmlOnOffAttr),
+    #[serde(rename = "webHidden", alias = "w:webHidden")]
+    WebHidden(XmlOnOffAttr),
+    #[serde(rename = "effect", alias = "w:effect")]
+    Effect(XmlValueAttr),
+    #[serde(rename = "kern", alias = "w:kern")]
+    Kern(XmlValueAttr),
+    #[serde(rename = "position", alias = "w:position")]
+    Position(XmlValueAttr),
+    #[serde(rename = "fitText", alias = "w:fitText")]
+    FitText(FitTextXml),
+    #[serde(rename = "lang", alias = "w:lang")]
+    Lang(LangXml),
+    #[serde(rename = "shd", alias = "w:shd")]
+    Shading(ShadingXml),
+    #[serde(rename = "ins", alias = "w:ins")]
+    Insert(TrackChangeXml),
+    #[serde(rename = "del", alias = "w:del")]
+    Delete(TrackChangeXml),
+    #[serde(rename = "moveFrom", alias = "w:moveFrom")]
+    MoveFrom(TrackChangeXml),
+    #[serde(rename = "moveTo", alias = "w:moveTo")]
+    MoveTo(TrackChangeXml),
+    #[serde(rename = "rPrChange", alias = "w:rPrChange")]
+    RunPropertyChange(RunPropertyChangeXml),
     #[serde(other)]
     Unknown,
 }
@@ -95,7 +212,7 @@ enum RunPropertyChildXml {
 pub(crate) struct RunPropertyXml {
     pub style: Option<XmlValueAttr>,
     pub size: Option<XmlValueAttr>,
-    pub color: Option<XmlValueAttr>,
+    pub color: Option<ColorXml>,
     pub highlight: Option<XmlValueAttr>,
     pub spacing: Option<XmlValueAttr>,
     pub fonts: Option<RunFontsXml>,
@@ -108,6 +225,24 @@ pub(crate) struct RunPropertyXml {
     pub dstrike: Option<XmlOnOffAttr>,
     pub vanish: Option<XmlOnOffAttr>,
     pub spec_vanish: Option<XmlOnOffAttr>,
+    pub small_caps: Option<XmlOnOffAttr>,
+    pub outline: Option<XmlOnOffAttr>,
+    pub shadow: Option<XmlOnOffAttr>,
+    pub emboss: Option<XmlOnOffAttr>,
+    pub imprint: Option<XmlOnOffAttr>,
+    pub no_proof: Option<XmlOnOffAttr>,
+    pub web_hidden: Option<XmlOnOffAttr>,
+    pub effect: Option<XmlValueAttr>,
+    pub kern: Option<XmlValueAttr>,
+    pub position: Option<XmlValueAttr>,
+    pub fit_text: Option<FitTextXml>,
+    pub lang: Option<LangXml>,
+    pub shading: Option<ShadingXml>,
+    ins: Option<TrackChangeXml>,
+    del: Option<TrackChangeXml>,
+    move_from: Option<TrackChangeXml>,
+    move_to: Option<TrackChangeXml>,
+    pub run_property_change: Option<RunPropertyChangeXml>,
 }
 
 impl<'de> Deserialize<'de> for RunPropertyXml {
@@ -149,6 +284,54 @@ impl<'de> Deserialize<'de> for RunPropertyXml {
                 RunPropertyChildXml::SpecVanish(v) if result.spec_vanish.is_none() => {
                     result.spec_vanish = Some(v)
                 }
+                RunPropertyChildXml::SmallCaps(v) if result.small_caps.is_none() => {
+                    result.small_caps = Some(v)
+                }
+                RunPropertyChildXml::Outline(v) if result.outline.is_none() => {
+                    result.outline = Some(v)
+                }
+                RunPropertyChildXml::Shadow(v) if result.shadow.is_none() => {
+                    result.shadow = Some(v)
+                }
+                RunPropertyChildXml::Emboss(v) if result.emboss.is_none() => {
+                    result.emboss = Some(v)
+                }
+                RunPropertyChildXml::Imprint(v) if result.imprint.is_none() => {
+                    result.imprint = Some(v)
+                }
+                RunPropertyChildXml::NoProof(v) if result.no_proof.is_none() => {
+                    result.no_proof = Some(v)
+                }
+                RunPropertyChildXml::WebHidden(v) if result.web_hidden.is_none() => {
+                    result.web_hidden = Some(v)
+                }
+                RunPropertyChildXml::Effect(v) if result.effect.is_none() => {
+                    result.effect = Some(v)
+                }
+                RunPropertyChildXml::Kern(v) if result.kern.is_none() => result.kern = Some(v),
+                RunPropertyChildXml::Position(v) if result.position.is_none() => {
+                    result.position = Some(v)
+                }
+                RunPropertyChildXml::FitText(v) if result.fit_text.is_none() => {
+                    result.fit_text = Some(v)
+                }
+                RunPropertyChildXml::Lang(v) if result.lang.is_none() => result.lang = Some(v),
+                RunPropertyChildXml::Shading(v) if result.shading.is_none() => {
+                    result.shading = Some(v)
+                }
+                RunPropertyChildXml::Insert(v) if result.ins.is_none() => result.ins = Some(v),
+                RunPropertyChildXml::Delete(v) if result.del.is_none() => result.del = Some(v),
+                RunPropertyChildXml::MoveFrom(v) if result.move_from.is_none() => {
+                    result.move_from = Some(v)
+                }
+                RunPropertyChildXml::MoveTo(v) if result.move_to.is_none() => {
+                    result.move_to = Some(v)
+                }
+                RunPropertyChildXml::RunPropertyChange(v)
+                    if result.run_property_change.is_none() =>
+                {
+                    result.run_property_change = Some(v)
+                }
                 _ => {} // Ignore duplicates and unknown elements
             }
         }
@@ -203,8 +386,30 @@ pub(crate) struct LineSpacingXml {
     pub line: Option<String>,
 }
 
+#[derive(Debug, Deserialize, Default, Clone)]
+pub(crate) struct NumberingPropertyXml {
+    #[serde(rename = "numId", alias = "w:numId", default)]
+    pub num_id: Option<XmlValueAttr>,
+    #[serde(rename = "ilvl", alias = "w:ilvl", default)]
+    pub ilvl: Option<XmlValueAttr>,
+    #[serde(rename = "numberingChange", alias = "w:numberingChange", default)]
+    pub numbering_change: Option<NumberingChangeXml>,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub(crate) struct NumberingChangeXml {
+    #[serde(rename = "@author", alias = "@w:author", default)]
+    pub author: Option<String>,
+    #[serde(rename = "@date", alias = "@w:date", default)]
+    pub date: Option<String>,
+    #[serde(rename = "@original", alias = "@w:original", default)]
+    pub original: Option<String>,
+}
+
 #[derive(Debug, Deserialize, Default, Clone)]
 pub(crate) struct ParagraphPropertyXml {
+    #[serde(rename = "numPr", alias = "w:numPr", default)]
+    pub num_pr: Option<NumberingPropertyXml>,
     #[serde(rename = "rPr", alias = "w:rPr", default)]
     pub run_property: Option<RunPropertyXml>,
     #[serde(rename = "pStyle", alias = "w:pStyle", default)]
@@ -233,6 +438,44 @@ pub(crate) struct ParagraphPropertyXml {
     pub widow_control: Option<XmlOnOffAttr>,
     #[serde(rename = "divId", alias = "w:divId", default)]
     pub div_id: Option<XmlValueAttr>,
+    #[serde(rename = "shd", alias = "w:shd", default)]
+    pub shading: Option<ShadingXml>,
+    #[serde(rename = "contextualSpacing", alias = "w:contextualSpacing", default)]
+    pub contextual_spacing: Option<XmlOnOffAttr>,
+    #[serde(
+        rename = "suppressAutoHyphens",
+        alias = "w:suppressAutoHyphens",
+        default
+    )]
+    pub suppress_auto_hyphens: Option<XmlOnOffAttr>,
+    #[serde(
+        rename = "suppressLineNumbers",
+        alias = "w:suppressLineNumbers",
+        default
+    )]
+    pub suppress_line_numbers: Option<XmlOnOffAttr>,
+    #[serde(rename = "mirrorIndents", alias = "w:mirrorIndents", default)]
+    pub mirror_indents: Option<XmlOnOffAttr>,
+    #[serde(rename = "wordWrap", alias = "w:wordWrap", default)]
+    pub word_wrap: Option<XmlOnOffAttr>,
+    #[serde(rename = "kinsoku", alias = "w:kinsoku", default)]
+    pub kinsoku: Option<XmlOnOffAttr>,
+    #[serde(rename = "topLinePunct", alias = "w:topLinePunct", default)]
+    pub top_line_punct: Option<XmlOnOffAttr>,
+    #[serde(rename = "cnfStyle", alias = "w:cnfStyle", default)]
+    pub cnf_style: Option<XmlValueAttr>,
+    #[serde(rename = "pPrChange", alias = "w:pPrChange", default)]
+    pub paragraph_property_change: Option<ParagraphPropertyChangeXml>,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+pub(crate) struct ParagraphPropertyChangeXml {
+    #[serde(rename = "@author", alias = "@w:author", default)]
+    author: Option<String>,
+    #[serde(rename = "@date", alias = "@w:date", default)]
+    date: Option<String>,
+    #[serde(rename = "pPr", alias = "w:pPr", default)]
+    paragraph_property: Option<Box<ParagraphPropertyXml>>,
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -266,6 +509,35 @@ fn parse_on_off(v: Option<&str>) -> bool {
     )
 }
 
+pub(crate) fn parse_shading_xml(xml: Option<ShadingXml>) -> Option<Shading> {
+    let xml = xml?;
+    let mut shading = Shading::new();
+    if let Some(v) = xml
+        .shd_type
+        .as_deref()
+        .and_then(|s| ShdType::from_str(s).ok())
+    {
+        shading = shading.shd_type(v);
+    }
+    if let Some(v) = ColorValue::from_attrs(
+        xml.color,
+        xml.theme_color.as_deref(),
+        xml.theme_tint.as_deref(),
+        xml.theme_shade.as_deref(),
+    ) {
+        shading = shading.color(v);
+    }
+    if let Some(v) = ColorValue::from_attrs(
+        xml.fill,
+        xml.theme_fill.as_deref(),
+        xml.theme_fill_tint.as_deref(),
+        xml.theme_fill_shade.as_deref(),
+    ) {
+        shading = shading.fill(v);
+    }
+    Some(shading)
+}
+
 fn parse_usize(raw: Option<String>) -> Option<usize> {
     raw.and_then(|v| {
         v.parse::<usize>()
@@ -298,7 +570,7 @@ pub(crate) fn parse_run_property_xml(xml: Option<RunPropertyXml>) -> RunProperty
     if let Some(v) = parse_usize(xml.size.and_then(|v| v.val)) {
         rp = rp.size(v);
     }
-    if let Some(v) = xml.color.and_then(|v| v.val) {
+    if let Some(v) = xml.color.and_then(|v| v.into_color()) {
         rp = rp.color(v);
     }
     if let Some(v) = xml.highlight.and_then(|v| v.val) {
@@ -344,6 +616,85 @@ pub(crate) fn parse_run_property_xml(xml: Option<RunPropertyXml>) -> RunProperty
     if xml.spec_vanish.is_some() {
         rp = rp.spec_vanish();
     }
+    if let Some(v) = xml.small_caps {
+        rp = if parse_on_off(v.val.as_deref()) {
+            rp.small_caps()
+        } else {
+            rp.disable_small_caps()
+        };
+    }
+    if let Some(v) = xml.outline {
+        rp = if parse_on_off(v.val.as_deref()) {
+            rp.outline()
+        } else {
+            rp.disable_outline()
+        };
+    }
+    if let Some(v) = xml.shadow {
+        rp = if parse_on_off(v.val.as_deref()) {
+            rp.shadow()
+        } else {
+            rp.disable_shadow()
+        };
+    }
+    if let Some(v) = xml.emboss {
+        rp = if parse_on_off(v.val.as_deref()) {
+            rp.emboss()
+        } else {
+            rp.disable_emboss()
+        };
+    }
+    if let Some(v) = xml.imprint {
+        rp = if parse_on_off(v.val.as_deref()) {
+            rp.imprint()
+        } else {
+            rp.disable_imprint()
+        };
+    }
+    if let Some(v) = xml.no_proof {
+        rp = if parse_on_off(v.val.as_deref()) {
+            rp.no_proof()
+        } else {
+            rp.disable_no_proof()
+        };
+    }
+    if let Some(v) = xml.web_hidden {
+        rp = if parse_on_off(v.val.as_deref()) {
+            rp.web_hidden()
+        } else {
+            rp.disable_web_hidden()
+        };
+    }
+    if let Some(v) = xml.effect.and_then(|v| v.val) {
+        if let Ok(t) = TextEffectType::from_str(&v) {
+            rp = rp.effect(t);
+        }
+    }
+    if let Some(v) = parse_usize(xml.kern.and_then(|v| v.val)) {
+        rp = rp.kern(v);
+    }
+    if let Some(v) = parse_i32(xml.position.and_then(|v| v.val)) {
+        rp = rp.position(v);
+    }
+    if let Some(f) = xml.fit_text {
+        if let Some(v) = parse_usize(f.val) {
+            let mut fit_text = FitText::new(v);
+            if let Some(id) = parse_usize(f.id) {
+                fit_text = fit_text.id(id);
+            }
+            rp = rp.fit_text(fit_text);
+        }
+    }
+    if let Some(l) = xml.lang {
+        let mut lang = Lang::new();
+        lang.val = l.val;
+        lang.east_asia = l.east_asia;
+        lang.bidi = l.bidi;
+        rp = rp.lang(lang);
+    }
+    if let Some(s) = parse_shading_xml(xml.shading) {
+        rp = rp.shading(s);
+    }
     if let Some(fonts) = xml.fonts {
         let mut f = RunFonts::new();
         if let Some(v) = fonts.ascii {
@@ -375,6 +726,58 @@ pub(crate) fn parse_run_property_xml(xml: Option<RunPropertyXml>) -> RunProperty
         }
         rp = rp.fonts(f);
     }
+    // Paragraph mark revisions
+    if let Some(v) = xml.ins {
+        let mut ins = Insert::new_with_empty();
+        if let Some(author) = v.author {
+            ins = ins.author(author);
+        }
+        if let Some(date) = v.date {
+            ins = ins.date(date);
+        }
+        rp = rp.insert(ins);
+    }
+    if let Some(v) = xml.del {
+        let mut del = Delete::new();
+        if let Some(author) = v.author {
+            del = del.author(author);
+        }
+        if let Some(date) = v.date {
+            del = del.date(date);
+        }
+        rp = rp.delete(del);
+    }
+    if let Some(v) = xml.move_from {
+        let mut m = MoveFrom::new();
+        if let Some(author) = v.author {
+            m = m.author(author);
+        }
+        if let Some(date) = v.date {
+            m = m.date(date);
+        }
+        rp = rp.move_from(m);
+    }
+    if let Some(v) = xml.move_to {
+        let mut m = MoveTo::new_with_empty();
+        if let Some(author) = v.author {
+            m = m.author(author);
+        }
+        if let Some(date) = v.date {
+            m = m.date(date);
+        }
+        rp = rp.move_to(m);
+    }
+    if let Some(v) = xml.run_property_change {
+        let mut change =
+            RunPropertyChange::new().property(parse_run_property_xml(v.run_property.map(|p| *p)));
+        if let Some(author) = v.author {
+            change = change.author(author);
+        }
+        if let Some(date) = v.date {
+            change = change.date(date);
+        }
+        rp = rp.run_property_change(change);
+    }
 
     rp
 }
@@ -430,6 +833,33 @@ pub(crate) fn parse_paragraph_property_xml(xml: Option<ParagraphPropertyXml>) ->
     if let Some(v) = xml.div_id.and_then(|v| v.val) {
         p.div_id = Some(v);
     }
+    if let Some(v) = parse_shading_xml(xml.shading) {
+        p = p.shading(v);
+    }
+    if let Some(v) = xml.contextual_spacing {
+        p.contextual_spacing = Some(parse_on_off(v.val.as_deref()));
+    }
+    if let Some(v) = xml.suppress_auto_hyphens {
+        p.suppress_auto_hyphens = Some(parse_on_off(v.val.as_deref()));
+    }
+    if let Some(v) = xml.suppress_line_numbers {
+        p.suppress_line_numbers = Some(parse_on_off(v.val.as_deref()));
+    }
+    if let Some(v) = xml.mirror_indents {
+        p.mirror_indents = Some(parse_on_off(v.val.as_deref()));
+    }
+    if let Some(v) = xml.word_wrap {
+        p.word_wrap = Some(parse_on_off(v.val.as_deref()));
+    }
+    if let Some(v) = xml.kinsoku {
+        p.kinsoku = Some(parse_on_off(v.val.as_deref()));
+    }
+    if let Some(v) = xml.top_line_punct {
+        p.top_line_punct = Some(parse_on_off(v.val.as_deref()));
+    }
+    if let Some(v) = xml.cnf_style.and_then(|v| v.val) {
+        p = p.cnf_style(v);
+    }
     if let Some(ind) = xml.indent {
         let start = parse_i32(ind.left);
         let end = parse_i32(ind.right);
@@ -483,6 +913,18 @@ pub(crate) fn parse_paragraph_property_xml(xml: Option<ParagraphPropertyXml>) ->
     if let Some(run_property) = xml.run_property {
         p.run_property = parse_run_property_xml(Some(run_property));
     }
+    if let Some(v) = xml.paragraph_property_change {
+        let mut change = ParagraphPropertyChange::new().property(parse_paragraph_property_xml(
+            v.paragraph_property.map(|p| *p),
+        ));
+        if let Some(author) = v.author {
+            change = change.author(author);
+        }
+        if let Some(date) = v.date {
+            change = change.date(date);
+        }
+        p = p.paragraph_property_change(change);
+    }
 
     p
 }
@@ -590,7 +1032,7 @@ impl Style {
         self
     }
 
-    pub fn color(mut self, color: impl Into<String>) -> Self {
+    pub fn color(mut self, color: impl Into<ColorValue>) -> Self {
         self.run_property = self.run_property.color(color);
         self
     }
@@ -645,6 +1087,11 @@ impl Style {
         self
     }
 
+    pub fn contextual_spacing(mut self, v: bool) -> Self {
+        self.paragraph_property = self.paragraph_property.contextual_spacing(v);
+        self
+    }
+
     pub fn line_spacing(mut self, spacing: LineSpacing) -> Self {
         self.paragraph_property = self.paragraph_property.line_spacing(spacing);
         self
@@ -877,4 +1324,25 @@ mod tests {
             r#"<w:style w:type="paragraph" w:styleId="Heading"><w:name w:val="Heading1" /><w:rPr /><w:pPr><w:rPr /></w:pPr><w:qFormat /></w:style>"#
         );
     }
+
+    #[test]
+    fn test_style_xml_paragraph_property() {
+        let xml = r#"<w:style xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" w:type="paragraph" w:styleId="ListParagraph">
+            <w:name w:val="List Paragraph"/>
+            <w:pPr>
+                <w:shd w:val="clear" w:color="auto" w:fill="F2F2F2"/>
+                <w:suppressAutoHyphens/>
+                <w:kinsoku w:val="0"/>
+                <w:ind w:left="720"/>
+                <w:contextualSpacing/>
+            </w:pPr>
+        </w:style>"#;
+        let s: Style = quick_xml::de::from_str(xml).unwrap();
+        let p = s.paragraph_property;
+        assert_eq!(p.shading, Some(Shading::new().fill("F2F2F2")));
+        assert_eq!(p.suppress_auto_hyphens, Some(true));
+        assert_eq!(p.kinsoku, Some(false));
+        assert_eq!(p.contextual_spacing, Some(true));
+        assert_eq!(p.word_wrap, None);
+    }
 }
@@ -27,6 +27,12 @@ struct XmlValNode {
     val: Option<String>,
 }
 
+#[derive(Debug, Deserialize, Default)]
+struct XmlIdNode {
+    #[serde(rename = "@id", alias = "@w:id", default)]
+    id: Option<String>,
+}
+
 #[derive(Debug, Deserialize, Default)]
 struct XmlLayoutNode {
     #[serde(rename = "@type", alias = "@w:type", default)]
@@ -49,6 +55,18 @@ struct TablePropertyXml {
     _borders: Option<IgnoredAny>,
     #[serde(rename = "tblCellMar", alias = "w:tblCellMar", default)]
     _margins: Option<IgnoredAny>,
+    #[serde(rename = "tblPrChange", alias = "w:tblPrChange", default)]
+    change: Option<TablePropertyChangeXml>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct TablePropertyChangeXml {
+    #[serde(rename = "@author", alias = "@w:author", default)]
+    author: Option<String>,
+    #[serde(rename = "@date", alias = "@w:date", default)]
+    date: Option<String>,
+    #[serde(rename = "tblPr", alias = "w:tblPr", default)]
+    property: Option<Box<TablePropertyXml>>,
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -67,6 +85,12 @@ struct TableGridXml {
 enum TableChildXml {
     #[serde(rename = "tr", alias = "w:tr")]
     TableRow(TableRow),
+    #[serde(rename = "sdt", alias = "w:sdt")]
+    StructuredDataTag(Box<StructuredDataTag>),
+    #[serde(rename = "permStart", alias = "w:permStart")]
+    PermStart(XmlPermStartNode),
+    #[serde(rename = "permEnd", alias = "w:permEnd")]
+    PermEnd(XmlIdNode),
     #[serde(rename = "tblPr", alias = "w:tblPr")]
     TableProperty(IgnoredAny),
     #[serde(rename = "tblGrid", alias = "w:tblGrid")]
@@ -129,6 +153,17 @@ fn parse_table_property_xml(xml: Option<TablePropertyXml>) -> TableProperty {
             property = property.layout(v);
         }
     }
+    if let Some(change) = xml.change {
+        let mut c = TablePropertyChange::new()
+            .property(parse_table_property_xml(change.property.map(|p| *p)));
+        if let Some(author) = change.author {
+            c = c.author(author);
+        }
+        if let Some(date) = change.date {
+            c = c.date(date);
+        }
+        property = property.table_property_change(c);
+    }
     property
 }
 
@@ -145,12 +180,50 @@ fn parse_table_grid_xml(xml: Option<TableGridXml>) -> Vec<usize> {
 fn table_child_from_xml(xml: TableChildXml) -> Option<TableChild> {
     match xml {
         TableChildXml::TableRow(row) => Some(TableChild::TableRow(row)),
+        TableChildXml::StructuredDataTag(tag) => Some(TableChild::StructuredDataTag(tag)),
+        TableChildXml::PermStart(node) => Some(TableChild::PermStart(node.into_perm_start()?)),
+        TableChildXml::PermEnd(node) => {
+            let id = parse_usize_value(node.id)?;
+            Some(TableChild::PermEnd(PermEnd::new(id)))
+        }
         TableChildXml::TableProperty(_) | TableChildXml::TableGrid(_) | TableChildXml::Unknown => {
             None
         }
     }
 }
 
+// Rough glyph widths in dxa used to size columns from text, assuming 11pt text.
+const NARROW_CHAR_WIDTH: usize = 110;
+const WIDE_CHAR_WIDTH: usize = 220;
+// Word's default left and right cell margins (108dxa each).
+const DEFAULT_CELL_PADDING: usize = 216;
+const MIN_COLUMN_WIDTH: usize = 432;
+
+fn estimate_content_width(cell: &TableCell) -> usize {
+    let text_width = cell
+        .children
+        .iter()
+        .map(|c| match c {
+            TableCellContent::Paragraph(p) => p
+                .raw_text()
+                .chars()
+                .map(|c| {
+                    // CJK and other full-width characters take 3 or more bytes.
+                    if c.len_utf8() > 2 {
+                        WIDE_CHAR_WIDTH
+                    } else {
+                        NARROW_CHAR_WIDTH
+                    }
+                })
+                .sum(),
+            TableCellContent::Table(t) => t.grid.iter().sum(),
+            _ => 0,
+        })
+        .max()
+        .unwrap_or_default();
+    text_width + DEFAULT_CELL_PADDING
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Table {
@@ -158,6 +231,8 @@ pub struct Table {
     pub grid: Vec<usize>,
     pub has_numbering: bool,
     pub property: TableProperty,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_fit: Option<TableAutoFitType>,
 }
 
 impl<'de> Deserialize<'de> for Table {
@@ -173,6 +248,8 @@ impl<'de> Deserialize<'de> for Table {
             .collect();
         let has_numbering = rows.iter().any(|r| match r {
             TableChild::TableRow(row) => row.has_numbering,
+            TableChild::StructuredDataTag(tag) => tag.has_numbering,
+            TableChild::PermStart(_) | TableChild::PermEnd(_) => false,
         });
 
         Ok(Table {
@@ -180,6 +257,7 @@ impl<'de> Deserialize<'de> for Table {
             grid: parse_table_grid_xml(xml.grid),
             has_numbering,
             property: parse_table_property_xml(xml.property),
+            auto_fit: None,
         })
     }
 }
@@ -187,6 +265,11 @@ impl<'de> Deserialize<'de> for Table {
 #[derive(Debug, Clone, PartialEq)]
 pub enum TableChild {
     TableRow(TableRow),
+    // A row-level content control such as a repeating section.
+    StructuredDataTag(Box<StructuredDataTag>),
+    // Editable range boundaries placed between rows.
+    PermStart(PermStart),
+    PermEnd(PermEnd),
 }
 
 impl BuildXML for TableChild {
@@ -196,6 +279,9 @@ impl BuildXML for TableChild {
     ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
         match self {
             TableChild::TableRow(v) => v.build_to(stream),
+            TableChild::StructuredDataTag(v) => v.build_to(stream),
+            TableChild::PermStart(v) => v.build_to(stream),
+            TableChild::PermEnd(v) => v.build_to(stream),
         }
     }
 }
@@ -211,6 +297,7 @@ impl Table {
             rows,
             grid,
             has_numbering,
+            auto_fit: None,
         }
     }
 
@@ -224,6 +311,7 @@ impl Table {
             rows,
             grid,
             has_numbering,
+            auto_fit: None,
         }
     }
 
@@ -232,11 +320,62 @@ impl Table {
         self
     }
 
+    pub fn add_perm_start(mut self, perm: PermStart) -> Table {
+        self.rows.push(TableChild::PermStart(perm));
+        self
+    }
+
+    pub fn add_perm_end(mut self, id: usize) -> Table {
+        self.rows.push(TableChild::PermEnd(PermEnd::new(id)));
+        self
+    }
+
+    // Add a content control wrapping rows, e.g. a repeating section.
+    pub fn add_structured_data_tag(mut self, t: StructuredDataTag) -> Table {
+        if t.has_numbering {
+            self.has_numbering = true
+        }
+        self.rows.push(TableChild::StructuredDataTag(Box::new(t)));
+        self
+    }
+
+    /// Rows in document order, including the rows wrapped in row-level content controls.
+    pub fn table_rows(&self) -> Vec<&TableRow> {
+        let mut rows = vec![];
+        for child in &self.rows {
+            match child {
+                TableChild::TableRow(row) => rows.push(row),
+                TableChild::StructuredDataTag(tag) => tag.collect_table_rows(&mut rows),
+                TableChild::PermStart(_) | TableChild::PermEnd(_) => {}
+            }
+        }
+        rows
+    }
+
+    pub fn table_rows_mut(&mut self) -> Vec<&mut TableRow> {
+        let mut rows = vec![];
+        for child in &mut self.rows {
+            match child {
+                TableChild::TableRow(row) => rows.push(row),
+                TableChild::StructuredDataTag(tag) => tag.collect_table_rows_mut(&mut rows),
+                TableChild::PermStart(_) | TableChild::PermEnd(_) => {}
+            }
+        }
+        rows
+    }
+
     pub fn set_grid(mut self, grid: Vec<usize>) -> Table {
         self.grid = grid;
         self
     }
 
+    /// Derive `w:tblGrid` and missing cell widths on build instead of using `set_grid`.
+    /// The grid is fitted to the usable width of the section the table belongs to.
+    pub fn auto_fit(mut self, t: TableAutoFitType) -> Table {
+        self.auto_fit = Some(t);
+        self
+    }
+
     pub fn indent(mut self, v: i32) -> Table {
         self.property = self.property.indent(v);
         self
@@ -291,6 +430,166 @@ impl Table {
         self.property = self.property.clear_all_border();
         self
     }
+
+    pub fn table_property_change(mut self, c: TablePropertyChange) -> Table {
+        self.property = self.property.table_property_change(c);
+        self
+    }
+
+    /// Column widths in dxa derived from cell widths and grid spans.
+    /// Columns without a fixed width share what is left of `text_width`, or are sized from
+    /// their text for `TableAutoFitType::Contents`.
+    pub fn compute_grid(&self, text_width: usize) -> Vec<usize> {
+        let available = match self.property.get_width() {
+            (w, WidthType::Dxa) if w > 0 => w,
+            (w, WidthType::Pct) if w > 0 => text_width * w / 5000,
+            _ => text_width,
+        };
+        let by_content = self.auto_fit == Some(TableAutoFitType::Contents);
+
+        let mut cols = 0;
+        // (start column, span, fixed width, estimated content width)
+        let mut cells: Vec<(usize, usize, Option<usize>, usize)> = vec![];
+        for row in self.table_rows() {
+            let mut col = row.property.get_grid_before() as usize;
+            for TableRowChild::TableCell(cell) in &row.cells {
+                let span = cell.property.get_grid_span().max(1);
+                let width = match cell.property.get_width() {
+                    Some((w, WidthType::Dxa)) if w > 0 => Some(w),
+                    Some((w, WidthType::Pct)) if w > 0 => Some(available * w / 5000),
+                    _ => None,
+                };
+                let content = if by_content {
+                    estimate_content_width(cell)
+                } else {
+                    0
+                };
+                cells.push((col, span, width, content));
+                col += span;
+            }
+            cols = cols.max(col + row.property.get_grid_after() as usize);
+        }
+        if cols == 0 {
+            return vec![];
+        }
+
+        let mut widths: Vec<Option<usize>> = vec![None; cols];
+        for (col, _, width, _) in cells.iter().filter(|c| c.1 == 1) {
+            if let Some(w) = width {
+                widths[*col] = Some(widths[*col].unwrap_or_default().max(*w));
+            }
+        }
+        // Spanned cells give their remaining width to the columns not fixed yet.
+        for (col, span, width, _) in cells.iter().filter(|c| c.1 > 1) {
+            if let Some(w) = width {
+                let range = *col..(col + span).min(cols);
+                let fixed: usize = widths[range.clone()].iter().flatten().sum();
+                let free: Vec<usize> = range.filter(|i| widths[*i].is_none()).collect();
+                if !free.is_empty() && *w > fixed {
+                    for i in &free {
+                        widths[*i] = Some((w - fixed) / free.len());
+                    }
+                }
+            }
+        }
+
+        let free: Vec<usize> = (0..cols).filter(|i| widths[*i].is_none()).collect();
+        if !free.is_empty() {
+            if by_content {
+                let mut contents = vec![MIN_COLUMN_WIDTH; cols];
+                for (col, _, _, content) in cells.iter().filter(|c| c.1 == 1) {
+                    contents[*col] = contents[*col].max(*content);
+                }
+                for (col, span, _, content) in cells.iter().filter(|c| c.1 > 1) {
+                    let range = *col..(col + span).min(cols);
+                    let current: usize = contents[range.clone()].iter().sum();
+                    if *content > current {
+                        let extra = (content - current) / range.len();
+                        range.for_each(|i| contents[i] += extra);
+                    }
+                }
+                for i in free {
+                    widths[i] = Some(contents[i]);
+                }
+            } else {
+                let fixed: usize = widths.iter().flatten().sum();
+                let w = (available.saturating_sub(fixed) / free.len()).max(MIN_COLUMN_WIDTH);
+                for i in free {
+                    widths[i] = Some(w);
+                }
+            }
+        }
+
+        let mut grid: Vec<usize> = widths.into_iter().map(|w| w.unwrap_or_default()).collect();
+        let total: usize = grid.iter().sum();
+        if total > available {
+            grid = grid.iter().map(|w| w * available / total).collect();
+        }
+        grid
+    }
+
+    // Replace the grid and missing or relative cell widths with the computed grid.
+    pub(crate) fn update_grid(&mut self, text_width: usize) {
+        if self.auto_fit.is_none() {
+            return;
+        }
+        let grid = self.compute_grid(text_width);
+        for row in self.table_rows_mut() {
+            let mut col = row.property.get_grid_before() as usize;
+            for TableRowChild::TableCell(cell) in &mut row.cells {
+                let span = cell.property.get_grid_span().max(1);
+                let start = col.min(grid.len());
+                let width: usize = grid[start..(col + span).min(grid.len())].iter().sum();
+                if !matches!(cell.property.get_width(), Some((_, WidthType::Dxa))) {
+                    cell.property = cell.property.clone().width(width, WidthType::Dxa);
+                }
+                for child in &mut cell.children {
+                    if let TableCellContent::Table(t) = child {
+                        t.update_grid(width.saturating_sub(DEFAULT_CELL_PADDING));
+                    }
+                }
+                col += span;
+            }
+        }
+        self.grid = grid;
+    }
+
+    /// Logical (row, col, row span, col span) of each cell.
+    /// Vertically merged continuation cells are folded into the cell that starts the merge.
+    pub fn logical_cells(&self) -> Vec<LogicalCell> {
+        let mut cells: Vec<LogicalCell> = vec![];
+        // grid column -> index in `cells` of the vertical merge started at that column.
+        let mut open: Vec<(usize, usize)> = vec![];
+        for (r, row) in self.table_rows().into_iter().enumerate() {
+            let mut next_open = vec![];
+            let mut col = row.property.get_grid_before() as usize;
+            for (cell_index, TableRowChild::TableCell(cell)) in row.cells.iter().enumerate() {
+                let col_span = cell.property.get_grid_span().max(1);
+                let continued = open.iter().find(|(c, _)| *c == col).map(|(_, i)| *i);
+                match (cell.property.get_vertical_merge(), continued) {
+                    (Some(VMergeType::Continue), Some(i)) => {
+                        cells[i].row_span += 1;
+                        next_open.push((col, i));
+                    }
+                    (merge, _) => {
+                        if merge == Some(VMergeType::Restart) {
+                            next_open.push((col, cells.len()));
+                        }
+                        cells.push(LogicalCell {
+                            row: r,
+                            col,
+                            row_span: 1,
+                            col_span,
+                            cell_index,
+                        });
+                    }
+                }
+                col += col_span;
+            }
+            open = next_open;
+        }
+        cells
+    }
 }
 
 impl BuildXML for Table {
@@ -298,6 +597,11 @@ impl BuildXML for Table {
         &self,
         stream: xml::writer::EventWriter<W>,
     ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        if self.auto_fit.is_some() && self.grid.is_empty() {
+            let mut table = self.clone();
+            table.update_grid(SectionProperty::new().text_width());
+            return table.build_to(stream);
+        }
         let grid = TableGrid::new(self.grid.clone());
         XMLBuilder::from(stream)
             .open_table()?
@@ -321,6 +625,24 @@ impl Serialize for TableChild {
                 t.serialize_field("data", r)?;
                 t.end()
             }
+            TableChild::StructuredDataTag(ref r) => {
+                let mut t = serializer.serialize_struct("StructuredDataTag", 2)?;
+                t.serialize_field("type", "structuredDataTag")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            TableChild::PermStart(ref r) => {
+                let mut t = serializer.serialize_struct("PermStart", 2)?;
+                t.serialize_field("type", "permStart")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            TableChild::PermEnd(ref r) => {
+                let mut t = serializer.serialize_struct("PermEnd", 2)?;
+                t.serialize_field("type", "permEnd")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
         }
     }
 }
@@ -362,6 +684,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_table_compute_grid_window() {
+        let t = Table::new(vec![
+            TableRow::new(vec![
+                TableCell::new().width(2000, WidthType::Dxa),
+                TableCell::new(),
+                TableCell::new(),
+            ]),
+            TableRow::new(vec![
+                TableCell::new().grid_span(2).width(5000, WidthType::Dxa),
+                TableCell::new().width(1000, WidthType::Pct),
+            ]),
+        ])
+        .auto_fit(TableAutoFitType::Window);
+        assert_eq!(t.compute_grid(10000), vec![2000, 3000, 2000]);
+    }
+
+    #[test]
+    fn test_table_compute_grid_shrink_to_text_width() {
+        let t = Table::new(vec![TableRow::new(vec![
+            TableCell::new().width(6000, WidthType::Dxa),
+            TableCell::new().width(4000, WidthType::Dxa),
+        ])])
+        .auto_fit(TableAutoFitType::Window);
+        assert_eq!(t.compute_grid(5000), vec![3000, 2000]);
+    }
+
+    #[test]
+    fn test_table_compute_grid_contents() {
+        let t = Table::new(vec![TableRow::new(vec![
+            TableCell::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello"))),
+            TableCell::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("日本語"))),
+        ])])
+        .auto_fit(TableAutoFitType::Contents);
+        assert_eq!(t.compute_grid(10000), vec![766, 876]);
+    }
+
+    #[test]
+    fn test_table_auto_fit_build() {
+        let b = Table::without_borders(vec![TableRow::new(vec![
+            TableCell::new().width(2504, WidthType::Dxa),
+            TableCell::new(),
+        ])])
+        .auto_fit(TableAutoFitType::Window)
+        .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tbl><w:tblPr><w:tblW w:w="0" w:type="auto" /><w:jc w:val="left" /><w:tblBorders /></w:tblPr><w:tblGrid><w:gridCol w:w="2504" w:type="dxa" /><w:gridCol w:w="6000" w:type="dxa" /></w:tblGrid><w:tr><w:trPr /><w:tc><w:tcPr><w:tcW w:w="2504" w:type="dxa" /></w:tcPr><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr></w:p></w:tc><w:tc><w:tcPr><w:tcW w:w="6000" w:type="dxa" /></w:tcPr><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr></w:p></w:tc></w:tr></w:tbl>"#
+        );
+    }
+
     // XML Deserialization tests (quick-xml serde)
     #[test]
     fn test_table_xml_deserialize() {
@@ -389,4 +762,64 @@ mod tests {
         assert_eq!(j["property"]["width"]["widthType"], "dxa");
         assert_eq!(j["property"]["justification"], "center");
     }
+
+    #[test]
+    fn test_table_xml_deserialize_repeating_section() {
+        let xml = r#"<w:tbl xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml">
+            <w:tr />
+            <w:sdt>
+                <w:sdtPr>
+                    <w15:dataBinding w:xpath="/root/items/item" w:storeItemID="{1}" />
+                    <w15:repeatingSection />
+                </w:sdtPr>
+                <w:sdtContent>
+                    <w:sdt>
+                        <w:sdtPr><w15:repeatingSectionItem /></w:sdtPr>
+                        <w:sdtContent><w:tr /></w:sdtContent>
+                    </w:sdt>
+                    <w:sdt>
+                        <w:sdtPr><w15:repeatingSectionItem /></w:sdtPr>
+                        <w:sdtContent><w:tr /></w:sdtContent>
+                    </w:sdt>
+                </w:sdtContent>
+            </w:sdt>
+        </w:tbl>"#;
+
+        let t: Table = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(t.rows.len(), 2);
+        assert_eq!(t.table_rows().len(), 3);
+        if let TableChild::StructuredDataTag(sdt) = &t.rows[1] {
+            assert_eq!(
+                sdt.property.control,
+                Some(ContentControl::RepeatingSection(RepeatingSection::new()))
+            );
+            assert_eq!(
+                sdt.property.data_binding.as_ref().unwrap().xpath,
+                Some("/root/items/item".to_string())
+            );
+        } else {
+            panic!("Expected StructuredDataTag");
+        }
+    }
+
+    #[test]
+    fn test_table_xml_deserialize_perm_range() {
+        let xml = r#"<w:tbl xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+            <w:permStart w:id="5" w:edGrp="everyone" w:colFirst="1" w:colLast="1"/>
+            <w:tr />
+            <w:permEnd w:id="5"/>
+        </w:tbl>"#;
+
+        let t: Table = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(
+            t.rows[0],
+            TableChild::PermStart(
+                PermStart::new(5)
+                    .editor_group(EditorGroupType::Everyone)
+                    .columns(1, 1)
+            )
+        );
+        assert_eq!(t.rows[2], TableChild::PermEnd(PermEnd::new(5)));
+        assert_eq!(t.table_rows().len(), 1);
+    }
 }
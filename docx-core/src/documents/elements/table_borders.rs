@@ -22,7 +22,7 @@ use crate::xml_builder::*;
 pub struct TableBorder {
     pub border_type: BorderType,
     pub size: usize,
-    pub color: String,
+    pub color: ColorValue,
     position: TableBorderPosition,
     space: usize,
 }
@@ -34,11 +34,11 @@ impl TableBorder {
             border_type: BorderType::Single,
             size: 2,
             space: 0,
-            color: "000000".to_owned(),
+            color: ColorValue::rgb("000000"),
         }
     }
 
-    pub fn color(mut self, color: impl Into<String>) -> TableBorder {
+    pub fn color(mut self, color: impl Into<ColorValue>) -> TableBorder {
         self.color = color.into();
         self
     }
@@ -4,6 +4,7 @@ use serde::{Deserialize, Deserializer, Serialize};
 use std::io::Write;
 use std::str::FromStr;
 
+use super::style::{parse_shading_xml, ShadingXml};
 use super::*;
 use crate::documents::BuildXML;
 use crate::types::*;
@@ -35,6 +36,12 @@ struct XmlBorderNode {
     size: Option<String>,
     #[serde(rename = "@color", alias = "@w:color", default)]
     color: Option<String>,
+    #[serde(rename = "@themeColor", alias = "@w:themeColor", default)]
+    theme_color: Option<String>,
+    #[serde(rename = "@themeTint", alias = "@w:themeTint", default)]
+    theme_tint: Option<String>,
+    #[serde(rename = "@themeShade", alias = "@w:themeShade", default)]
+    theme_shade: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -57,16 +64,6 @@ struct TableCellBordersXml {
     tr2bl: Option<XmlBorderNode>,
 }
 
-#[derive(Debug, Deserialize, Default)]
-struct ShadingXml {
-    #[serde(rename = "@val", alias = "@w:val", default)]
-    shd_type: Option<String>,
-    #[serde(rename = "@color", alias = "@w:color", default)]
-    color: Option<String>,
-    #[serde(rename = "@fill", alias = "@w:fill", default)]
-    fill: Option<String>,
-}
-
 #[derive(Debug, Deserialize, Default)]
 struct TableCellPropertyXmlHelper {
     #[serde(rename = "tcW", alias = "w:tcW", default)]
@@ -83,6 +80,44 @@ struct TableCellPropertyXmlHelper {
     borders: Option<TableCellBordersXml>,
     #[serde(rename = "shd", alias = "w:shd", default)]
     shading: Option<ShadingXml>,
+    #[serde(rename = "cellIns", alias = "w:cellIns", default)]
+    cell_insert: Option<XmlTrackChangeNode>,
+    #[serde(rename = "cellDel", alias = "w:cellDel", default)]
+    cell_delete: Option<XmlTrackChangeNode>,
+    #[serde(rename = "cellMerge", alias = "w:cellMerge", default)]
+    cell_merge: Option<XmlCellMergeNode>,
+    #[serde(rename = "tcPrChange", alias = "w:tcPrChange", default)]
+    change: Option<TableCellPropertyChangeXml>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct XmlTrackChangeNode {
+    #[serde(rename = "@author", alias = "@w:author", default)]
+    author: Option<String>,
+    #[serde(rename = "@date", alias = "@w:date", default)]
+    date: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct XmlCellMergeNode {
+    #[serde(rename = "@author", alias = "@w:author", default)]
+    author: Option<String>,
+    #[serde(rename = "@date", alias = "@w:date", default)]
+    date: Option<String>,
+    #[serde(rename = "@vMerge", alias = "@w:vMerge", default)]
+    v_merge: Option<String>,
+    #[serde(rename = "@vMergeOrig", alias = "@w:vMergeOrig", default)]
+    v_merge_orig: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct TableCellPropertyChangeXml {
+    #[serde(rename = "@author", alias = "@w:author", default)]
+    author: Option<String>,
+    #[serde(rename = "@date", alias = "@w:date", default)]
+    date: Option<String>,
+    #[serde(rename = "tcPr", alias = "w:tcPr", default)]
+    property: Option<Box<TableCellPropertyXmlHelper>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -93,12 +128,26 @@ enum TableCellChildXml {
     Table(Table),
     #[serde(rename = "sdt", alias = "w:sdt")]
     StructuredDataTag(StructuredDataTag),
+    #[serde(rename = "moveFromRangeStart", alias = "w:moveFromRangeStart")]
+    MoveFromRangeStart(XmlMoveRangeStartNode),
+    #[serde(rename = "moveFromRangeEnd", alias = "w:moveFromRangeEnd")]
+    MoveFromRangeEnd(XmlIdNode),
+    #[serde(rename = "moveToRangeStart", alias = "w:moveToRangeStart")]
+    MoveToRangeStart(XmlMoveRangeStartNode),
+    #[serde(rename = "moveToRangeEnd", alias = "w:moveToRangeEnd")]
+    MoveToRangeEnd(XmlIdNode),
     #[serde(rename = "tcPr", alias = "w:tcPr")]
     TableCellProperty(IgnoredAny),
     #[serde(other)]
     Unknown,
 }
 
+#[derive(Debug, Deserialize, Default)]
+struct XmlIdNode {
+    #[serde(rename = "@id", alias = "@w:id", default)]
+    id: Option<String>,
+}
+
 #[derive(Debug, Deserialize, Default)]
 struct TableCellXml {
     #[serde(rename = "tcPr", alias = "w:tcPr", default)]
@@ -132,7 +181,12 @@ fn parse_table_cell_border_xml(
     if let Some(v) = parse_usize_value(node.size) {
         border = border.size(v);
     }
-    if let Some(v) = node.color {
+    if let Some(v) = ColorValue::from_attrs(
+        node.color,
+        node.theme_color.as_deref(),
+        node.theme_tint.as_deref(),
+        node.theme_shade.as_deref(),
+    ) {
         border = border.color(v);
     }
     border
@@ -168,25 +222,6 @@ fn parse_table_cell_borders_xml(xml: Option<TableCellBordersXml>) -> Option<Tabl
     Some(borders)
 }
 
-fn parse_shading_xml(xml: Option<ShadingXml>) -> Option<Shading> {
-    let xml = xml?;
-    let mut shading = Shading::new();
-    if let Some(v) = xml
-        .shd_type
-        .as_deref()
-        .and_then(|s| ShdType::from_str(s).ok())
-    {
-        shading = shading.shd_type(v);
-    }
-    if let Some(v) = xml.color {
-        shading = shading.color(v);
-    }
-    if let Some(v) = xml.fill {
-        shading = shading.fill(v);
-    }
-    Some(shading)
-}
-
 fn parse_table_cell_property_xml(xml: Option<TableCellPropertyXmlHelper>) -> TableCellProperty {
     let Some(xml) = xml else {
         return TableCellProperty::new();
@@ -234,6 +269,53 @@ fn parse_table_cell_property_xml(xml: Option<TableCellPropertyXmlHelper>) -> Tab
     if let Some(v) = parse_shading_xml(xml.shading) {
         property = property.shading(v);
     }
+    if let Some(v) = xml.cell_insert {
+        let mut ins = CellInsert::new();
+        if let Some(author) = v.author {
+            ins = ins.author(author);
+        }
+        if let Some(date) = v.date {
+            ins = ins.date(date);
+        }
+        property = property.cell_insert(ins);
+    }
+    if let Some(v) = xml.cell_delete {
+        let mut del = CellDelete::new();
+        if let Some(author) = v.author {
+            del = del.author(author);
+        }
+        if let Some(date) = v.date {
+            del = del.date(date);
+        }
+        property = property.cell_delete(del);
+    }
+    if let Some(v) = xml.cell_merge {
+        let mut merge = CellMerge::new();
+        if let Some(t) = v.v_merge.as_deref().and_then(parse_annotation_v_merge) {
+            merge = merge.v_merge(t);
+        }
+        if let Some(t) = v.v_merge_orig.as_deref().and_then(parse_annotation_v_merge) {
+            merge = merge.v_merge_orig(t);
+        }
+        if let Some(author) = v.author {
+            merge = merge.author(author);
+        }
+        if let Some(date) = v.date {
+            merge = merge.date(date);
+        }
+        property = property.cell_merge(merge);
+    }
+    if let Some(change) = xml.change {
+        let mut c = TableCellPropertyChange::new()
+            .property(parse_table_cell_property_xml(change.property.map(|p| *p)));
+        if let Some(author) = change.author {
+            c = c.author(author);
+        }
+        if let Some(date) = change.date {
+            c = c.date(date);
+        }
+        property = property.table_cell_property_change(c);
+    }
     property
 }
 
@@ -244,6 +326,22 @@ fn table_cell_child_from_xml(xml: TableCellChildXml) -> Option<TableCellContent>
         TableCellChildXml::StructuredDataTag(sdt) => {
             Some(TableCellContent::StructuredDataTag(Box::new(sdt)))
         }
+        TableCellChildXml::MoveFromRangeStart(node) => Some(TableCellContent::MoveFromRangeStart(
+            node.into_move_from_range_start()?,
+        )),
+        TableCellChildXml::MoveFromRangeEnd(node) => {
+            let id = node.id?.parse().ok()?;
+            Some(TableCellContent::MoveFromRangeEnd(MoveFromRangeEnd::new(
+                id,
+            )))
+        }
+        TableCellChildXml::MoveToRangeStart(node) => Some(TableCellContent::MoveToRangeStart(
+            node.into_move_to_range_start()?,
+        )),
+        TableCellChildXml::MoveToRangeEnd(node) => {
+            let id = node.id?.parse().ok()?;
+            Some(TableCellContent::MoveToRangeEnd(MoveToRangeEnd::new(id)))
+        }
         TableCellChildXml::TableCellProperty(_) | TableCellChildXml::Unknown => None,
     }
 }
@@ -262,6 +360,10 @@ pub enum TableCellContent {
     Table(Table),
     StructuredDataTag(Box<StructuredDataTag>),
     TableOfContents(Box<TableOfContents>),
+    MoveFromRangeStart(MoveFromRangeStart),
+    MoveFromRangeEnd(MoveFromRangeEnd),
+    MoveToRangeStart(MoveToRangeStart),
+    MoveToRangeEnd(MoveToRangeEnd),
 }
 
 impl<'de> Deserialize<'de> for TableCell {
@@ -279,7 +381,7 @@ impl<'de> Deserialize<'de> for TableCell {
             TableCellContent::Paragraph(p) => p.has_numbering,
             TableCellContent::Table(t) => t.has_numbering,
             TableCellContent::StructuredDataTag(t) => t.has_numbering,
-            TableCellContent::TableOfContents(_) => false,
+            _ => false,
         });
 
         Ok(TableCell {
@@ -320,6 +422,30 @@ impl Serialize for TableCellContent {
                 t.serialize_field("data", r)?;
                 t.end()
             }
+            TableCellContent::MoveFromRangeStart(ref r) => {
+                let mut t = serializer.serialize_struct("MoveFromRangeStart", 2)?;
+                t.serialize_field("type", "moveFromRangeStart")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            TableCellContent::MoveFromRangeEnd(ref r) => {
+                let mut t = serializer.serialize_struct("MoveFromRangeEnd", 2)?;
+                t.serialize_field("type", "moveFromRangeEnd")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            TableCellContent::MoveToRangeStart(ref r) => {
+                let mut t = serializer.serialize_struct("MoveToRangeStart", 2)?;
+                t.serialize_field("type", "moveToRangeStart")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            TableCellContent::MoveToRangeEnd(ref r) => {
+                let mut t = serializer.serialize_struct("MoveToRangeEnd", 2)?;
+                t.serialize_field("type", "moveToRangeEnd")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
         }
     }
 }
@@ -349,6 +475,32 @@ impl TableCell {
         self
     }
 
+    pub fn add_move_from_range_start(mut self, start: MoveFromRangeStart) -> Self {
+        self.children
+            .push(TableCellContent::MoveFromRangeStart(start));
+        self
+    }
+
+    pub fn add_move_from_range_end(mut self, id: usize) -> Self {
+        self.children
+            .push(TableCellContent::MoveFromRangeEnd(MoveFromRangeEnd::new(
+                id,
+            )));
+        self
+    }
+
+    pub fn add_move_to_range_start(mut self, start: MoveToRangeStart) -> Self {
+        self.children
+            .push(TableCellContent::MoveToRangeStart(start));
+        self
+    }
+
+    pub fn add_move_to_range_end(mut self, id: usize) -> Self {
+        self.children
+            .push(TableCellContent::MoveToRangeEnd(MoveToRangeEnd::new(id)));
+        self
+    }
+
     pub fn add_table(mut self, t: Table) -> TableCell {
         if t.has_numbering {
             self.has_numbering = true
@@ -406,6 +558,26 @@ impl TableCell {
         self.property = self.property.clear_all_border();
         self
     }
+
+    pub fn cell_insert(mut self, c: CellInsert) -> TableCell {
+        self.property = self.property.cell_insert(c);
+        self
+    }
+
+    pub fn cell_delete(mut self, c: CellDelete) -> TableCell {
+        self.property = self.property.cell_delete(c);
+        self
+    }
+
+    pub fn cell_merge(mut self, c: CellMerge) -> TableCell {
+        self.property = self.property.cell_merge(c);
+        self
+    }
+
+    pub fn table_cell_property_change(mut self, c: TableCellPropertyChange) -> TableCell {
+        self.property = self.property.table_cell_property_change(c);
+        self
+    }
 }
 
 impl Default for TableCell {
@@ -438,6 +610,10 @@ impl BuildXML for TableCell {
                     }
                     TableCellContent::StructuredDataTag(t) => b.add_child(&t),
                     TableCellContent::TableOfContents(t) => b.add_child(&t),
+                    TableCellContent::MoveFromRangeStart(m) => b.add_child(m),
+                    TableCellContent::MoveFromRangeEnd(m) => b.add_child(m),
+                    TableCellContent::MoveToRangeStart(m) => b.add_child(m),
+                    TableCellContent::MoveToRangeEnd(m) => b.add_child(m),
                 }
             })?
             // INFO: We need to add empty paragraph when parent cell includes only cell.
@@ -550,4 +726,69 @@ mod tests {
             panic!("Expected StructuredDataTag");
         }
     }
+
+    #[test]
+    fn test_cell_xml_deserialize_move_ranges() {
+        let xml = r#"<w:tc xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+            <w:moveToRangeStart w:id="1" w:name="move1" w:author="R&amp;D" w:date="2024-01-01T00:00:00Z"/>
+            <w:p><w:r><w:t>Moved</w:t></w:r></w:p>
+            <w:moveToRangeEnd w:id="1"/>
+        </w:tc>"#;
+
+        let cell: TableCell = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(
+            cell.children[0],
+            TableCellContent::MoveToRangeStart(
+                MoveToRangeStart::new(1, "move1")
+                    .author("R&D")
+                    .date("2024-01-01T00:00:00Z")
+            )
+        );
+        assert_eq!(
+            cell.children[2],
+            TableCellContent::MoveToRangeEnd(MoveToRangeEnd::new(1))
+        );
+        assert_eq!(
+            str::from_utf8(&cell.build()).unwrap(),
+            r#"<w:tc><w:tcPr /><w:moveToRangeStart w:id="1" w:name="move1" w:author="R&amp;D" w:date="2024-01-01T00:00:00Z" /><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr><w:r><w:rPr /><w:t xml:space="preserve">Moved</w:t></w:r></w:p><w:moveToRangeEnd w:id="1" /></w:tc>"#
+        );
+    }
+
+    #[test]
+    fn test_cell_xml_deserialize_revisions() {
+        let xml = r#"<w:tc xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+            <w:tcPr>
+                <w:tcW w:w="3000" w:type="dxa"/>
+                <w:cellIns w:id="1" w:author="bob" w:date="2024-01-01T00:00:00Z"/>
+                <w:cellMerge w:id="2" w:author="bob" w:date="2024-01-01T00:00:00Z" w:vMerge="rest" w:vMergeOrig="cont"/>
+                <w:tcPrChange w:id="3" w:author="bob" w:date="2024-01-01T00:00:00Z">
+                    <w:tcPr><w:tcW w:w="2000" w:type="dxa"/></w:tcPr>
+                </w:tcPrChange>
+            </w:tcPr>
+            <w:p />
+        </w:tc>"#;
+
+        let cell: TableCell = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(
+            cell.property.cell_insert,
+            Some(CellInsert::new().author("bob").date("2024-01-01T00:00:00Z"))
+        );
+        assert_eq!(
+            cell.property.cell_merge,
+            Some(
+                CellMerge::new()
+                    .author("bob")
+                    .date("2024-01-01T00:00:00Z")
+                    .v_merge(VMergeType::Restart)
+                    .v_merge_orig(VMergeType::Continue)
+            )
+        );
+        assert_eq!(
+            cell.property
+                .table_cell_property_change
+                .as_ref()
+                .map(|c| c.property.get_width()),
+            Some(Some((2000, WidthType::Dxa)))
+        );
+    }
 }
@@ -24,7 +24,7 @@ use crate::xml_builder::*;
 pub struct TableCellBorder {
     pub border_type: BorderType,
     pub size: usize,
-    pub color: String,
+    pub color: ColorValue,
     position: TableCellBorderPosition,
     space: usize,
 }
@@ -36,11 +36,11 @@ impl TableCellBorder {
             border_type: BorderType::Single,
             size: 2,
             space: 0,
-            color: "000000".to_owned(),
+            color: ColorValue::rgb("000000"),
         }
     }
 
-    pub fn color(mut self, color: impl Into<String>) -> TableCellBorder {
+    pub fn color(mut self, color: impl Into<ColorValue>) -> TableCellBorder {
         self.color = color.into();
         self
     }
@@ -60,7 +60,7 @@ impl TableCellBorder {
     }
 
     pub fn get_color(&self) -> String {
-        self.color.clone()
+        self.color.val()
     }
 
     pub fn get_border_type(&self) -> BorderType {
@@ -21,6 +21,14 @@ pub struct TableCellProperty {
     shading: Option<Shading>,
     #[serde(skip_serializing_if = "Option::is_none")]
     margins: Option<CellMargins>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) cell_insert: Option<CellInsert>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) cell_delete: Option<CellDelete>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) cell_merge: Option<CellMerge>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) table_cell_property_change: Option<TableCellPropertyChange>,
 }
 
 impl TableCellProperty {
@@ -78,6 +86,18 @@ impl TableCellProperty {
         self
     }
 
+    pub fn get_width(&self) -> Option<(usize, WidthType)> {
+        self.width.as_ref().map(|w| (w.width, w.width_type))
+    }
+
+    pub fn get_grid_span(&self) -> usize {
+        self.grid_span.as_ref().map(|g| g.val).unwrap_or(1)
+    }
+
+    pub fn get_vertical_merge(&self) -> Option<VMergeType> {
+        self.vertical_merge.as_ref().map(|v| v.val)
+    }
+
     pub fn margins(mut self, margins: CellMargins) -> Self {
         self.margins = Some(margins);
         self
@@ -122,6 +142,26 @@ impl TableCellProperty {
         }
         self
     }
+
+    pub fn cell_insert(mut self, c: CellInsert) -> Self {
+        self.cell_insert = Some(c);
+        self
+    }
+
+    pub fn cell_delete(mut self, c: CellDelete) -> Self {
+        self.cell_delete = Some(c);
+        self
+    }
+
+    pub fn cell_merge(mut self, c: CellMerge) -> Self {
+        self.cell_merge = Some(c);
+        self
+    }
+
+    pub fn table_cell_property_change(mut self, c: TableCellPropertyChange) -> Self {
+        self.table_cell_property_change = Some(c);
+        self
+    }
 }
 
 impl BuildXML for TableCellProperty {
@@ -139,6 +179,10 @@ impl BuildXML for TableCellProperty {
             .add_optional_child(&self.text_direction)?
             .add_optional_child(&self.shading)?
             .add_optional_child(&self.margins)?
+            .add_optional_child(&self.cell_insert)?
+            .add_optional_child(&self.cell_delete)?
+            .add_optional_child(&self.cell_merge)?
+            .add_optional_child(&self.table_cell_property_change)?
             .close()?
             .into_inner()
     }
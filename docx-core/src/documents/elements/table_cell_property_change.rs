@@ -0,0 +1,85 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::*;
+use crate::escape;
+use crate::xml_builder::*;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TableCellPropertyChange {
+    pub author: String,
+    pub date: String,
+    pub property: Box<TableCellProperty>,
+}
+
+impl Default for TableCellPropertyChange {
+    fn default() -> TableCellPropertyChange {
+        Self {
+            author: "unnamed".to_owned(),
+            date: "1970-01-01T00:00:00Z".to_owned(),
+            property: Default::default(),
+        }
+    }
+}
+
+impl TableCellPropertyChange {
+    pub fn new() -> TableCellPropertyChange {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    pub fn property(mut self, p: TableCellProperty) -> TableCellPropertyChange {
+        self.property = Box::new(p);
+        self
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> TableCellPropertyChange {
+        self.author = escape::escape(&author.into());
+        self
+    }
+
+    pub fn date(mut self, date: impl Into<String>) -> TableCellPropertyChange {
+        self.date = date.into();
+        self
+    }
+}
+
+impl HistoryId for TableCellPropertyChange {}
+
+impl BuildXML for TableCellPropertyChange {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        let id = self.generate();
+        XMLBuilder::from(stream)
+            .open_table_cell_property_change(&id, &self.author, &self.date)?
+            .add_child(&self.property)?
+            .close()?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_table_cell_property_change() {
+        let b = TableCellPropertyChange::new()
+            .author("bob")
+            .date("2024-01-01T00:00:00Z")
+            .property(TableCellProperty::new().grid_span(2))
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tcPrChange w:id="123" w:author="bob" w:date="2024-01-01T00:00:00Z"><w:tcPr><w:gridSpan w:val="2" /></w:tcPr></w:tcPrChange>"#
+        );
+    }
+}
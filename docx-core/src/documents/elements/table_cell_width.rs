@@ -8,8 +8,8 @@ use crate::xml_builder::*;
 #[derive(Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct TableCellWidth {
-    width: usize,
-    width_type: WidthType,
+    pub(crate) width: usize,
+    pub(crate) width_type: WidthType,
 }
 
 impl TableCellWidth {
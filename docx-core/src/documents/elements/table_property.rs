@@ -25,6 +25,8 @@ pub struct TableProperty {
     layout: Option<TableLayout>,
     #[serde(skip_serializing_if = "Option::is_none")]
     position: Option<TablePositionProperty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) table_property_change: Option<TablePropertyChange>,
 }
 
 impl Default for TableProperty {
@@ -38,6 +40,7 @@ impl Default for TableProperty {
             style: None,
             layout: None,
             position: None,
+            table_property_change: None,
         }
     }
 }
@@ -64,6 +67,10 @@ impl TableProperty {
         self
     }
 
+    pub fn get_width(&self) -> (usize, WidthType) {
+        (self.width.width, self.width.width_type)
+    }
+
     pub fn align(mut self, v: TableAlignmentType) -> TableProperty {
         self.justification = Justification::new(v.to_string());
         self
@@ -148,6 +155,11 @@ impl TableProperty {
         self.position = Some(p);
         self
     }
+
+    pub fn table_property_change(mut self, c: TablePropertyChange) -> Self {
+        self.table_property_change = Some(c);
+        self
+    }
 }
 
 impl BuildXML for TableProperty {
@@ -165,6 +177,7 @@ impl BuildXML for TableProperty {
             .add_optional_child(&self.style)?
             .add_optional_child(&self.layout)?
             .add_optional_child(&self.position)?
+            .add_optional_child(&self.table_property_change)?
             .close()?
             .into_inner()
     }
@@ -0,0 +1,85 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::*;
+use crate::escape;
+use crate::xml_builder::*;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TablePropertyChange {
+    pub author: String,
+    pub date: String,
+    pub property: Box<TableProperty>,
+}
+
+impl Default for TablePropertyChange {
+    fn default() -> TablePropertyChange {
+        Self {
+            author: "unnamed".to_owned(),
+            date: "1970-01-01T00:00:00Z".to_owned(),
+            property: Default::default(),
+        }
+    }
+}
+
+impl TablePropertyChange {
+    pub fn new() -> TablePropertyChange {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    pub fn property(mut self, p: TableProperty) -> TablePropertyChange {
+        self.property = Box::new(p);
+        self
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> TablePropertyChange {
+        self.author = escape::escape(&author.into());
+        self
+    }
+
+    pub fn date(mut self, date: impl Into<String>) -> TablePropertyChange {
+        self.date = date.into();
+        self
+    }
+}
+
+impl HistoryId for TablePropertyChange {}
+
+impl BuildXML for TablePropertyChange {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        let id = self.generate();
+        XMLBuilder::from(stream)
+            .open_table_property_change(&id, &self.author, &self.date)?
+            .add_child(&self.property)?
+            .close()?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_table_property_change() {
+        let b = TablePropertyChange::new()
+            .author("bob")
+            .date("2024-01-01T00:00:00Z")
+            .property(TableProperty::without_borders().style("Grid"))
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tblPrChange w:id="123" w:author="bob" w:date="2024-01-01T00:00:00Z"><w:tblPr><w:tblW w:w="0" w:type="auto" /><w:jc w:val="left" /><w:tblBorders /><w:tblStyle w:val="Grid" /></w:tblPr></w:tblPrChange>"#
+        );
+    }
+}
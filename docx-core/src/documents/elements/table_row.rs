@@ -4,7 +4,7 @@ use serde::{Deserialize, Deserializer, Serialize};
 use std::io::Write;
 use std::str::FromStr;
 
-use super::{Delete, Insert, TableCell, TableRowProperty};
+use super::{Delete, Insert, TableCell, TableRowProperty, TableRowPropertyChange};
 use crate::xml_builder::*;
 use crate::{documents::BuildXML, HeightRule};
 
@@ -58,6 +58,18 @@ struct TableRowPropertyXml {
     ins: Option<TrackChangeXml>,
     #[serde(rename = "del", alias = "w:del", default)]
     del: Option<TrackChangeXml>,
+    #[serde(rename = "trPrChange", alias = "w:trPrChange", default)]
+    change: Option<TableRowPropertyChangeXml>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct TableRowPropertyChangeXml {
+    #[serde(rename = "@author", alias = "@w:author", default)]
+    author: Option<String>,
+    #[serde(rename = "@date", alias = "@w:date", default)]
+    date: Option<String>,
+    #[serde(rename = "trPr", alias = "w:trPr", default)]
+    property: Option<Box<TableRowPropertyXml>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -158,6 +170,17 @@ fn parse_table_row_property_xml(xml: Option<TableRowPropertyXml>) -> TableRowPro
     if let Some(del) = parse_delete_xml(xml.del) {
         property = property.delete(del);
     }
+    if let Some(change) = xml.change {
+        let mut c = TableRowPropertyChange::new()
+            .property(parse_table_row_property_xml(change.property.map(|p| *p)));
+        if let Some(author) = change.author {
+            c = c.author(author);
+        }
+        if let Some(date) = change.date {
+            c = c.date(date);
+        }
+        property = property.table_row_property_change(c);
+    }
     property
 }
 
@@ -271,6 +294,11 @@ impl TableRow {
         self.property = self.property.cant_split();
         self
     }
+
+    pub fn table_row_property_change(mut self, c: TableRowPropertyChange) -> TableRow {
+        self.property = self.property.table_row_property_change(c);
+        self
+    }
 }
 
 impl BuildXML for TableRow {
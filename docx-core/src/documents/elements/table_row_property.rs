@@ -22,6 +22,8 @@ pub struct TableRowProperty {
     pub ins: Option<Insert>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cant_split: Option<CantSplit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table_row_property_change: Option<TableRowPropertyChange>,
 }
 
 impl TableRowProperty {
@@ -49,6 +51,14 @@ impl TableRowProperty {
         self
     }
 
+    pub fn get_grid_before(&self) -> u32 {
+        self.grid_before.unwrap_or_default()
+    }
+
+    pub fn get_grid_after(&self) -> u32 {
+        self.grid_after.unwrap_or_default()
+    }
+
     pub fn row_height(mut self, h: f32) -> Self {
         self.row_height = Some(h);
         self
@@ -73,6 +83,11 @@ impl TableRowProperty {
         self.cant_split = Some(CantSplit::default());
         self
     }
+
+    pub fn table_row_property_change(mut self, c: TableRowPropertyChange) -> Self {
+        self.table_row_property_change = Some(c);
+        self
+    }
 }
 
 impl BuildXML for TableRowProperty {
@@ -91,6 +106,7 @@ impl BuildXML for TableRowProperty {
                     &self.height_rule.unwrap_or_default().to_string(),
                 )
             })?
+            .add_optional_child(&self.table_row_property_change)?
             .close()?
             .into_inner()
     }
@@ -10,7 +10,7 @@ use crate::xml_builder::*;
 pub struct TextBorder {
     pub border_type: BorderType,
     pub size: usize,
-    pub color: String,
+    pub color: ColorValue,
     pub space: usize,
 }
 
@@ -19,7 +19,7 @@ impl TextBorder {
         TextBorder::default()
     }
 
-    pub fn color(mut self, color: impl Into<String>) -> Self {
+    pub fn color(mut self, color: impl Into<ColorValue>) -> Self {
         self.color = color.into();
         self
     }
@@ -46,7 +46,7 @@ impl Default for TextBorder {
             border_type: BorderType::Single,
             size: 4,
             space: 0,
-            color: "auto".to_owned(),
+            color: ColorValue::Auto,
         }
     }
 }
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// Languages used for the theme fonts, e.g. "en-US", "ja-JP" and "ar-SA".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ThemeFontLang {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub val: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub east_asia: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bidi: Option<String>,
+}
+
+impl ThemeFontLang {
+    pub fn new(val: impl Into<String>) -> Self {
+        Self {
+            val: Some(val.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn east_asia(mut self, lang: impl Into<String>) -> Self {
+        self.east_asia = Some(lang.into());
+        self
+    }
+
+    pub fn bidi(mut self, lang: impl Into<String>) -> Self {
+        self.bidi = Some(lang.into());
+        self
+    }
+}
+
+impl BuildXML for ThemeFontLang {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .theme_font_lang(
+                self.val.as_ref(),
+                self.east_asia.as_ref(),
+                self.bidi.as_ref(),
+            )?
+            .into_inner()
+    }
+}
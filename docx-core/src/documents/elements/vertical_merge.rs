@@ -7,7 +7,7 @@ use crate::xml_builder::*;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct VMerge {
-    val: VMergeType,
+    pub(crate) val: VMergeType,
 }
 
 impl VMerge {
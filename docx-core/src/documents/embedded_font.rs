@@ -0,0 +1,117 @@
+use crate::types::FontEmbedType;
+
+/// A TrueType font to embed into `word/fonts`.
+/// The font data is obfuscated with a key derived from its name and content when the docx is built.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmbeddedFont {
+    pub name: String,
+    pub embed_type: FontEmbedType,
+    pub data: Vec<u8>,
+}
+
+impl EmbeddedFont {
+    pub fn new(name: impl Into<String>, data: Vec<u8>) -> Self {
+        EmbeddedFont {
+            name: name.into(),
+            embed_type: FontEmbedType::Regular,
+            data,
+        }
+    }
+
+    pub fn embed_type(mut self, t: FontEmbedType) -> Self {
+        self.embed_type = t;
+        self
+    }
+
+    // A GUID formatted key like `{01234567-89AB-CDEF-0123-456789ABCDEF}`.
+    // It is derived from the font so that repeated builds produce the same package.
+    pub(crate) fn font_key(&self) -> String {
+        let hi = fnv1a(self.name.as_bytes().iter().chain(self.data.iter()));
+        let lo = fnv1a(self.data.iter().chain(self.name.as_bytes().iter()));
+        let hex = format!("{:016X}{:016X}", hi, lo);
+        format!(
+            "{{{}-{}-{}-{}-{}}}",
+            &hex[0..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..32]
+        )
+    }
+
+    // Restores an embedded font from a `word/fonts` part obfuscated with `font_key`.
+    pub(crate) fn from_obfuscated(
+        name: impl Into<String>,
+        embed_type: FontEmbedType,
+        data: &[u8],
+        font_key: &str,
+    ) -> Self {
+        EmbeddedFont::new(name, obfuscate(data, font_key)).embed_type(embed_type)
+    }
+
+    pub(crate) fn obfuscated(&self, font_key: &str) -> Vec<u8> {
+        obfuscate(&self.data, font_key)
+    }
+}
+
+fn fnv1a<'a>(bytes: impl Iterator<Item = &'a u8>) -> u64 {
+    bytes.fold(0xcbf2_9ce4_8422_2325, |h, b| {
+        (h ^ *b as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/*
+  17.8.1 Font Embedding
+  The first 32 bytes of the font are XORed with the font key.
+  The key bytes are the hex digits of the GUID read from the end to the start.
+*/
+pub(crate) fn obfuscate(data: &[u8], font_key: &str) -> Vec<u8> {
+    let digits: Vec<u8> = font_key
+        .chars()
+        .filter_map(|c| c.to_digit(16))
+        .map(|d| d as u8)
+        .collect();
+    let mut key = [0u8; 16];
+    for (i, pair) in digits.chunks(2).take(16).enumerate() {
+        if let [h, l] = pair {
+            key[15 - i] = (h << 4) | l;
+        }
+    }
+    let mut out = data.to_vec();
+    for (i, b) in out.iter_mut().take(32).enumerate() {
+        *b ^= key[i % 16];
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_font_key() {
+        let f = EmbeddedFont::new("Corporate Sans", vec![1, 2, 3]);
+        let key = f.font_key();
+        assert_eq!(key.len(), 38);
+        assert_eq!(
+            key,
+            EmbeddedFont::new("Corporate Sans", vec![1, 2, 3]).font_key()
+        );
+        assert!(key.starts_with('{') && key.ends_with('}'));
+    }
+
+    #[test]
+    fn test_obfuscate() {
+        let key = "{00112233-4455-6677-8899-AABBCCDDEEFF}";
+        let data: Vec<u8> = (0..40).collect();
+        let o = obfuscate(&data, key);
+        assert_eq!(o[0], 0xFF);
+        assert_eq!(o[15], 15);
+        assert_eq!(o[16], 16 ^ 0xFF);
+        assert_eq!(&o[32..], &data[32..]);
+        assert_eq!(obfuscate(&o, key), data);
+    }
+}
@@ -0,0 +1,775 @@
+use std::collections::HashMap;
+
+use crate::documents::*;
+use crate::escape::{escape, replace_escaped};
+use crate::types::*;
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+// A UTC date time used by DATE, TIME, CREATEDATE and SAVEDATE fields.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct FieldDateTime {
+    year: i64,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+}
+
+impl FieldDateTime {
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    pub(crate) fn from_unix_seconds(secs: i64) -> Self {
+        // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let days = secs.div_euclid(86400);
+        let rem = secs.rem_euclid(86400);
+        let z = days + 719468;
+        let era = z.div_euclid(146097);
+        let doe = z.rem_euclid(146097);
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+        Self {
+            year,
+            month: month as u32,
+            day: day as u32,
+            hour: (rem / 3600) as u32,
+            minute: (rem % 3600 / 60) as u32,
+            second: (rem % 60) as u32,
+        }
+    }
+
+    // W3CDTF, e.g. "2024-01-31T09:00:00Z" or "2024-01-31". Offsets are ignored.
+    pub(crate) fn parse(s: &str) -> Option<Self> {
+        let (date, time) = s.trim().split_once('T').unwrap_or((s.trim(), ""));
+        let mut date = date.split('-');
+        let year = date.next()?.parse().ok()?;
+        let month = date.next().map(|v| v.parse()).unwrap_or(Ok(1)).ok()?;
+        let day = date.next().map(|v| v.parse()).unwrap_or(Ok(1)).ok()?;
+        if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+            return None;
+        }
+        let time = time.split(['Z', '+', '-']).next().unwrap_or_default();
+        let mut time = time.split(':').map(|v| {
+            v.split('.')
+                .next()
+                .and_then(|v| v.parse::<u32>().ok())
+                .unwrap_or(0)
+        });
+        Some(Self {
+            year,
+            month,
+            day,
+            hour: time.next().unwrap_or(0),
+            minute: time.next().unwrap_or(0),
+            second: time.next().unwrap_or(0),
+        })
+    }
+
+    fn now() -> Option<Self> {
+        // SystemTime is not available on wasm32-unknown-unknown.
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let secs = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .ok()?
+                .as_secs();
+            Some(Self::from_unix_seconds(secs as i64))
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            None
+        }
+    }
+
+    // 0 is Sunday.
+    fn weekday(&self) -> usize {
+        // http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+        let y = if self.month <= 2 {
+            self.year - 1
+        } else {
+            self.year
+        };
+        let era = y.div_euclid(400);
+        let yoe = y.rem_euclid(400);
+        let m = i64::from(self.month);
+        let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        let days = era * 146097 + doe - 719468;
+        (days + 4).rem_euclid(7) as usize
+    }
+
+    // Formats with a date-time picture such as "dddd, MMMM d, yyyy" or "h:mm AM/PM".
+    pub(crate) fn format(&self, picture: &str) -> String {
+        let chars: Vec<char> = picture.chars().collect();
+        let mut s = String::new();
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            if c == '\'' {
+                i += 1;
+                while i < chars.len() && chars[i] != '\'' {
+                    s.push(chars[i]);
+                    i += 1;
+                }
+                i += 1;
+                continue;
+            }
+            let rest: String = chars[i..].iter().take(5).collect();
+            if rest == "AM/PM" || rest == "am/pm" {
+                let pm = self.hour >= 12;
+                let v = match (pm, rest == "AM/PM") {
+                    (true, true) => "PM",
+                    (false, true) => "AM",
+                    (true, false) => "pm",
+                    (false, false) => "am",
+                };
+                s.push_str(v);
+                i += 5;
+                continue;
+            }
+            let mut n = 1;
+            while i + n < chars.len() && chars[i + n] == c {
+                n += 1;
+            }
+            let hour12 = match self.hour % 12 {
+                0 => 12,
+                h => h,
+            };
+            match c {
+                'd' | 'D' => match n {
+                    1 => s.push_str(&self.day.to_string()),
+                    2 => s.push_str(&format!("{:02}", self.day)),
+                    3 => s.push_str(&WEEKDAYS[self.weekday()][..3]),
+                    _ => s.push_str(WEEKDAYS[self.weekday()]),
+                },
+                'M' => match n {
+                    1 => s.push_str(&self.month.to_string()),
+                    2 => s.push_str(&format!("{:02}", self.month)),
+                    3 => s.push_str(&MONTHS[self.month as usize - 1][..3]),
+                    _ => s.push_str(MONTHS[self.month as usize - 1]),
+                },
+                'y' | 'Y' => match n {
+                    1 | 2 => s.push_str(&format!("{:02}", self.year.rem_euclid(100))),
+                    _ => s.push_str(&format!("{:04}", self.year)),
+                },
+                'H' => push_number(&mut s, self.hour, n),
+                'h' => push_number(&mut s, hour12, n),
+                'm' => push_number(&mut s, self.minute, n),
+                's' | 'S' => push_number(&mut s, self.second, n),
+                _ => {
+                    for _ in 0..n {
+                        s.push(c);
+                    }
+                }
+            }
+            i += n;
+        }
+        s
+    }
+}
+
+fn push_number(s: &mut String, v: u32, digits: usize) {
+    if digits >= 2 {
+        s.push_str(&format!("{:02}", v));
+    } else {
+        s.push_str(&v.to_string());
+    }
+}
+
+fn to_roman(mut n: usize) -> String {
+    const NUMERALS: [(usize, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+    let mut s = String::new();
+    for (v, r) in NUMERALS {
+        while n >= v {
+            s.push_str(r);
+            n -= v;
+        }
+    }
+    s
+}
+
+// 1 => A, 26 => Z, 27 => AA, 28 => BB
+fn to_alphabetic(n: usize) -> String {
+    if n == 0 {
+        return String::new();
+    }
+    let c = (b'A' + ((n - 1) % 26) as u8) as char;
+    std::iter::repeat(c).take((n - 1) / 26 + 1).collect()
+}
+
+// Applies the \* switches of a field to a numeric result.
+fn format_number(n: usize, instruction: &FieldInstruction) -> String {
+    for s in instruction.switches.iter().filter(|s| s.name == "\\*") {
+        match s.argument.as_deref() {
+            Some("ROMAN") | Some("Roman") => return to_roman(n),
+            Some("roman") => return to_roman(n).to_lowercase(),
+            Some("ALPHABETIC") | Some("Alphabetic") => return to_alphabetic(n),
+            Some("alphabetic") => return to_alphabetic(n).to_lowercase(),
+            _ => {}
+        }
+    }
+    n.to_string()
+}
+
+// Applies the \* switches of a field to a text result.
+fn format_text(text: String, instruction: &FieldInstruction) -> String {
+    let mut text = text;
+    for s in instruction.switches.iter().filter(|s| s.name == "\\*") {
+        let format = s
+            .argument
+            .as_deref()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        text = match format.as_str() {
+            "upper" => text.to_uppercase(),
+            "lower" => text.to_lowercase(),
+            "firstcap" => capitalize(&text),
+            "caps" => text
+                .split(' ')
+                .map(capitalize)
+                .collect::<Vec<_>>()
+                .join(" "),
+            _ => text,
+        };
+    }
+    text
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn property_text(v: CustomPropertyValue) -> String {
+    match v {
+        CustomPropertyValue::Text(v) => v,
+        CustomPropertyValue::Int(v) => v.to_string(),
+        CustomPropertyValue::Float(v) => v.to_string(),
+        CustomPropertyValue::Bool(v) => String::from(if v { "Y" } else { "N" }),
+        CustomPropertyValue::DateTime(v) => FieldDateTime::parse(&v)
+            .map(|d| d.format("M/d/yyyy"))
+            .unwrap_or(v),
+    }
+}
+
+fn paragraph_style_id(p: &Paragraph) -> &str {
+    p.property
+        .style
+        .as_ref()
+        .map(|s| s.val.as_str())
+        .unwrap_or("Normal")
+}
+
+fn set_result(field: &mut Field, text: String) {
+    let run_property = field
+        .result
+        .first()
+        .map(|r| r.run_property.clone())
+        .unwrap_or_else(|| field.run_property.clone());
+    field.result = vec![Run {
+        run_property,
+        ..Default::default()
+    }
+    .add_text(text)];
+    field.dirty = false;
+}
+
+fn for_each_field_in_children(children: &mut [ParagraphChild], f: &mut impl FnMut(&mut Field)) {
+    for child in children {
+        match child {
+            ParagraphChild::Field(field) => f(field),
+            ParagraphChild::Hyperlink(h) => for_each_field_in_children(&mut h.children, f),
+            _ => {}
+        }
+    }
+}
+
+fn for_each_paragraph_in_table(table: &mut Table, f: &mut impl FnMut(&mut Paragraph)) {
+    for row in table.table_rows_mut() {
+        for TableRowChild::TableCell(cell) in &mut row.cells {
+            for content in &mut cell.children {
+                match content {
+                    TableCellContent::Paragraph(p) => f(p),
+                    TableCellContent::Table(t) => for_each_paragraph_in_table(t, f),
+                    TableCellContent::StructuredDataTag(t) => for_each_paragraph_in_sdt(t, f),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+fn for_each_paragraph_in_sdt(tag: &mut StructuredDataTag, f: &mut impl FnMut(&mut Paragraph)) {
+    for child in &mut tag.children {
+        match child {
+            StructuredDataTagChild::Paragraph(p) => f(p),
+            StructuredDataTagChild::Table(t) => for_each_paragraph_in_table(t, f),
+            StructuredDataTagChild::StructuredDataTag(t) => for_each_paragraph_in_sdt(t, f),
+            _ => {}
+        }
+    }
+}
+
+// Visits the paragraphs of the main document in document order.
+fn for_each_body_paragraph(document: &mut Document, f: &mut impl FnMut(&mut Paragraph)) {
+    for child in &mut document.children {
+        match child {
+            DocumentChild::Paragraph(p) => f(p),
+            DocumentChild::Table(t) => for_each_paragraph_in_table(t, f),
+            DocumentChild::StructuredDataTag(t) => for_each_paragraph_in_sdt(t, f),
+            DocumentChild::Section(section) => {
+                for child in &mut section.children {
+                    match child {
+                        SectionChild::Paragraph(p) => f(p),
+                        SectionChild::Table(t) => for_each_paragraph_in_table(t, f),
+                        SectionChild::StructuredDataTag(t) => for_each_paragraph_in_sdt(t, f),
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn for_each_header_footer_paragraph(
+    property: &mut SectionProperty,
+    f: &mut impl FnMut(&mut Paragraph),
+) {
+    let headers = [
+        &mut property.header,
+        &mut property.first_header,
+        &mut property.even_header,
+    ];
+    for (_, header) in headers.into_iter().flatten() {
+        for child in &mut header.children {
+            match child {
+                HeaderChild::Paragraph(p) => f(p),
+                HeaderChild::Table(t) => for_each_paragraph_in_table(t, f),
+                HeaderChild::StructuredDataTag(t) => for_each_paragraph_in_sdt(t, f),
+            }
+        }
+    }
+    let footers = [
+        &mut property.footer,
+        &mut property.first_footer,
+        &mut property.even_footer,
+    ];
+    for (_, footer) in footers.into_iter().flatten() {
+        for child in &mut footer.children {
+            match child {
+                FooterChild::Paragraph(p) => f(p),
+                FooterChild::Table(t) => for_each_paragraph_in_table(t, f),
+                FooterChild::StructuredDataTag(t) => for_each_paragraph_in_sdt(t, f),
+            }
+        }
+    }
+}
+
+fn push_run_text(run: &Run, s: &mut String) {
+    for c in &run.children {
+        match c {
+            RunChild::Text(t) => s.push_str(&replace_escaped(&t.text)),
+            RunChild::Tab(_) => s.push('\t'),
+            _ => {}
+        }
+    }
+}
+
+// Computes the results of fields which do not depend on the page layout.
+// PAGE, NUMPAGES, PAGEREF and TOC are left as they are.
+pub(crate) struct FieldEvaluator<'a> {
+    doc_props: &'a DocProps,
+    settings: &'a Settings,
+    styles: &'a Styles,
+    now: Option<FieldDateTime>,
+    sequences: HashMap<String, usize>,
+    bookmarks: HashMap<String, String>,
+    // (style id, text) of the body paragraphs in document order.
+    paragraphs: Vec<(String, String)>,
+}
+
+impl<'a> FieldEvaluator<'a> {
+    pub(crate) fn new(doc_props: &'a DocProps, settings: &'a Settings, styles: &'a Styles) -> Self {
+        Self {
+            doc_props,
+            settings,
+            styles,
+            // Fall back to the last saved time where the clock is not available.
+            now: FieldDateTime::now().or_else(|| {
+                doc_props
+                    .core
+                    .property("LastSavedTime")
+                    .and_then(FieldDateTime::parse)
+            }),
+            sequences: HashMap::new(),
+            bookmarks: HashMap::new(),
+            paragraphs: vec![],
+        }
+    }
+
+    pub(crate) fn evaluate(&mut self, document: &mut Document) {
+        // Values first so that REF and STYLEREF see the updated SEQ and DOCPROPERTY results.
+        for_each_body_paragraph(document, &mut |p| {
+            for_each_field_in_children(&mut p.children, &mut |f| self.evaluate_value(f, true))
+        });
+        let mut properties = vec![&mut document.section_property];
+        for child in &mut document.children {
+            if let DocumentChild::Section(section) = child {
+                properties.push(&mut section.property);
+            }
+        }
+        for property in properties {
+            for_each_header_footer_paragraph(property, &mut |p| {
+                for_each_field_in_children(&mut p.children, &mut |f| self.evaluate_value(f, false))
+            });
+        }
+
+        self.collect_references(document);
+
+        let mut index = 0;
+        for_each_body_paragraph(document, &mut |p| {
+            for_each_field_in_children(&mut p.children, &mut |f| self.evaluate_reference(f, index));
+            index += 1;
+        });
+    }
+
+    fn evaluate_value(&mut self, field: &mut Field, in_body: bool) {
+        let instruction = &field.instruction;
+        let text = match instruction.field_type {
+            FieldType::Seq if in_body => self.next_sequence(instruction),
+            FieldType::DocProperty => instruction
+                .arguments
+                .first()
+                .and_then(|name| self.doc_props.property_value(name))
+                .map(|v| format_text(property_text(v), instruction)),
+            FieldType::DocVariable => instruction
+                .arguments
+                .first()
+                .and_then(|name| self.settings.doc_var(name))
+                .map(|v| format_text(v.to_owned(), instruction)),
+            FieldType::Author
+            | FieldType::Title
+            | FieldType::Subject
+            | FieldType::Comments
+            | FieldType::LastSavedBy
+            | FieldType::Template => {
+                let name = match instruction.field_type {
+                    FieldType::Author => "Author",
+                    FieldType::Title => "Title",
+                    FieldType::Subject => "Subject",
+                    FieldType::Comments => "Comments",
+                    FieldType::LastSavedBy => "LastSavedBy",
+                    _ => "Template",
+                };
+                self.doc_props
+                    .property_value(name)
+                    .map(|v| format_text(property_text(v), instruction))
+            }
+            FieldType::Date | FieldType::Time => self.now.map(|now| {
+                let default = if instruction.field_type == FieldType::Date {
+                    "M/d/yyyy"
+                } else {
+                    "h:mm AM/PM"
+                };
+                format_date(now, instruction, default)
+            }),
+            FieldType::CreateDate | FieldType::SaveDate => {
+                let name = if instruction.field_type == FieldType::CreateDate {
+                    "CreateTime"
+                } else {
+                    "LastSavedTime"
+                };
+                self.doc_props
+                    .property_value(name)
+                    .and_then(|v| match v {
+                        CustomPropertyValue::DateTime(v) => FieldDateTime::parse(&v),
+                        _ => None,
+                    })
+                    .map(|d| format_date(d, instruction, "M/d/yyyy h:mm:ss AM/PM"))
+            }
+            _ => None,
+        };
+        if let Some(text) = text {
+            set_result(field, text);
+        }
+    }
+
+    fn next_sequence(&mut self, instruction: &FieldInstruction) -> Option<String> {
+        let id = instruction.arguments.first()?.to_uppercase();
+        let current = self.sequences.get(&id).copied().unwrap_or(0);
+        let value = if let Some(reset) = instruction
+            .find_switch("\\r")
+            .and_then(|s| s.argument.as_deref())
+            .and_then(|v| v.parse().ok())
+        {
+            reset
+        } else if instruction.has_switch("\\c") {
+            current
+        } else {
+            current + 1
+        };
+        self.sequences.insert(id, value);
+        if instruction.has_switch("\\h") {
+            return Some(String::new());
+        }
+        Some(format_number(value, instruction))
+    }
+
+    fn collect_references(&mut self, document: &mut Document) {
+        // bookmark id => (name, text)
+        let mut open: Vec<(usize, String, String)> = vec![];
+        let bookmarks = &mut self.bookmarks;
+        let paragraphs = &mut self.paragraphs;
+        for_each_body_paragraph(document, &mut |p| {
+            let mut text = String::new();
+            collect_paragraph_text(&p.children, &mut open, bookmarks, &mut text);
+            paragraphs.push((paragraph_style_id(p).to_owned(), text));
+        });
+    }
+
+    fn evaluate_reference(&mut self, field: &mut Field, index: usize) {
+        let instruction = &field.instruction;
+        let text = match instruction.field_type {
+            FieldType::Ref => instruction
+                .arguments
+                .first()
+                .and_then(|name| self.bookmarks.get(name))
+                .map(|t| format_text(t.clone(), instruction)),
+            FieldType::StyleRef => instruction
+                .arguments
+                .first()
+                .and_then(|style| self.find_styled_paragraph(style, index))
+                .map(|t| format_text(t, instruction)),
+            _ => None,
+        };
+        if let Some(text) = text {
+            set_result(field, text);
+        }
+    }
+
+    // The nearest paragraph with the style searching backward from the field, then forward.
+    fn find_styled_paragraph(&self, style: &str, index: usize) -> Option<String> {
+        let escaped = escape(style);
+        let style_id = self
+            .styles
+            .styles
+            .iter()
+            .find(|s| s.style_id.eq_ignore_ascii_case(&escaped) || s.name.eq_ignore_case(&escaped))
+            .map(|s| s.style_id.clone())
+            .unwrap_or(escaped);
+        let index = index.min(self.paragraphs.len().saturating_sub(1));
+        let (before, after) = self
+            .paragraphs
+            .split_at((index + 1).min(self.paragraphs.len()));
+        before
+            .iter()
+            .rev()
+            .chain(after.iter())
+            .find(|(id, _)| id.eq_ignore_ascii_case(&style_id))
+            .map(|(_, text)| text.clone())
+    }
+}
+
+fn format_date(d: FieldDateTime, instruction: &FieldInstruction, default: &str) -> String {
+    let picture = instruction
+        .find_switch("\\@")
+        .and_then(|s| s.argument.as_deref())
+        .unwrap_or(default);
+    format_text(d.format(picture), instruction)
+}
+
+fn collect_paragraph_text(
+    children: &[ParagraphChild],
+    open: &mut Vec<(usize, String, String)>,
+    bookmarks: &mut HashMap<String, String>,
+    text: &mut String,
+) {
+    let push = |s: &str, open: &mut Vec<(usize, String, String)>, text: &mut String| {
+        text.push_str(s);
+        for (_, _, t) in open.iter_mut() {
+            t.push_str(s);
+        }
+    };
+    for child in children {
+        let mut s = String::new();
+        match child {
+            ParagraphChild::BookmarkStart(b) => {
+                open.push((b.id, b.name.clone(), String::new()));
+            }
+            ParagraphChild::BookmarkEnd(b) => {
+                if let Some(i) = open.iter().position(|(id, _, _)| *id == b.id) {
+                    let (_, name, t) = open.remove(i);
+                    bookmarks.insert(name, t);
+                }
+            }
+            ParagraphChild::Run(run) => push_run_text(run, &mut s),
+            ParagraphChild::Insert(Insert { children, .. })
+            | ParagraphChild::MoveTo(MoveTo { children, .. }) => {
+                for c in children {
+                    if let InsertChild::Run(run) = c {
+                        push_run_text(run, &mut s);
+                    }
+                }
+            }
+            ParagraphChild::Field(f) => {
+                for run in &f.result {
+                    push_run_text(run, &mut s);
+                }
+            }
+            ParagraphChild::Hyperlink(h) => {
+                collect_paragraph_text(&h.children, open, bookmarks, text);
+            }
+            _ => {}
+        }
+        push(&s, open, text);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn field_results(document: &Document) -> Vec<String> {
+        let mut results = vec![];
+        for child in &document.children {
+            if let DocumentChild::Paragraph(p) = child {
+                for c in &p.children {
+                    if let ParagraphChild::Field(f) = c {
+                        results.push(replace_escaped(&f.result_text()));
+                    }
+                }
+            }
+        }
+        results
+    }
+
+    #[test]
+    fn test_format_date() {
+        let d = FieldDateTime::parse("2024-01-31T21:05:09Z").unwrap();
+        assert_eq!(d, FieldDateTime::from_unix_seconds(1706735109));
+        assert_eq!(
+            d.format("dddd, MMMM d, yyyy"),
+            "Wednesday, January 31, 2024"
+        );
+        assert_eq!(d.format("yy/MM/dd ddd MMM"), "24/01/31 Wed Jan");
+        assert_eq!(d.format("h:mm AM/PM"), "9:05 PM");
+        assert_eq!(d.format("HH:mm:ss 'at' d"), "21:05:09 at 31");
+    }
+
+    #[test]
+    fn test_format_number() {
+        let i = FieldInstruction::new(FieldType::Seq).add_switch_with_argument("\\*", "ROMAN");
+        assert_eq!(format_number(14, &i), "XIV");
+        let i = FieldInstruction::new(FieldType::Seq).add_switch_with_argument("\\*", "alphabetic");
+        assert_eq!(format_number(28, &i), "bb");
+    }
+
+    #[test]
+    fn test_evaluate_fields() {
+        let seq = || Field::new(FieldInstruction::new(FieldType::Seq).add_argument("Figure"));
+        let mut document = Document::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .style("Heading1")
+                    .add_run(Run::new().add_text("Overview")),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_bookmark_start(1, "_Ref1")
+                    .add_run(Run::new().add_text("Figure "))
+                    .add_field(seq())
+                    .add_bookmark_end(1),
+            )
+            .add_paragraph(Paragraph::new().add_field(seq()))
+            .add_paragraph(
+                Paragraph::new()
+                    .add_field(Field::new(
+                        FieldInstruction::new(FieldType::Ref)
+                            .add_argument("_Ref1")
+                            .add_switch_with_argument("\\*", "Upper"),
+                    ))
+                    .add_field(Field::new(
+                        FieldInstruction::new(FieldType::StyleRef).add_argument("Heading 1"),
+                    ))
+                    .add_field(Field::new(
+                        FieldInstruction::new(FieldType::DocProperty).add_argument("client"),
+                    ))
+                    .add_field(Field::new(
+                        FieldInstruction::new(FieldType::DocVariable).add_argument("Project"),
+                    ))
+                    .add_field(Field::new(
+                        FieldInstruction::new(FieldType::Date)
+                            .add_switch_with_argument("\\@", "yyyy-MM-dd"),
+                    ))
+                    .add_field(Field::new(FieldInstruction::new(FieldType::Page)).result("1")),
+            );
+        let doc_props = DocProps::new(CorePropsConfig::new()).custom_property("Client", "R&D");
+        let settings = Settings::new().add_doc_var("project", "Apollo");
+        let styles =
+            Styles::new().add_style(Style::new("Heading1", StyleType::Paragraph).name("Heading 1"));
+        let mut evaluator = FieldEvaluator::new(&doc_props, &settings, &styles);
+        evaluator.now = FieldDateTime::parse("2024-01-31");
+        evaluator.evaluate(&mut document);
+        assert_eq!(
+            field_results(&document),
+            vec![
+                "1",
+                "2",
+                "FIGURE 1",
+                "Overview",
+                "R&D",
+                "Apollo",
+                "2024-01-31",
+                "1"
+            ]
+        );
+    }
+}
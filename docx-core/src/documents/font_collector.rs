@@ -0,0 +1,228 @@
+use crate::{
+    Delete, DeleteChild, FontScheme, Insert, InsertChild, MoveFrom, MoveTo, Paragraph,
+    ParagraphChild, Run, RunProperty, StructuredDataTag, StructuredDataTagChild, Table,
+    TableCellContent, TableOfContents, TableRowChild, TocContent,
+};
+
+pub(crate) fn collect_fonts_from_run_property(
+    p: &RunProperty,
+    scheme: &FontScheme,
+    names: &mut Vec<String>,
+) {
+    if let Some(fonts) = &p.fonts {
+        fonts.collect_names(scheme, names);
+    }
+}
+
+fn collect_fonts_from_run(run: &Run, scheme: &FontScheme, names: &mut Vec<String>) {
+    collect_fonts_from_run_property(&run.run_property, scheme, names);
+}
+
+fn collect_fonts_from_paragraph_children(
+    children: &[ParagraphChild],
+    scheme: &FontScheme,
+    names: &mut Vec<String>,
+) {
+    for child in children {
+        match child {
+            ParagraphChild::Run(run) => collect_fonts_from_run(run, scheme, names),
+            ParagraphChild::Insert(Insert { children, .. })
+            | ParagraphChild::MoveTo(MoveTo { children, .. }) => {
+                for child in children {
+                    match child {
+                        InsertChild::Run(run) => collect_fonts_from_run(run, scheme, names),
+                        InsertChild::Delete(del) => {
+                            for child in &del.children {
+                                if let DeleteChild::Run(run) = child {
+                                    collect_fonts_from_run(run, scheme, names);
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            ParagraphChild::Delete(Delete { children, .. })
+            | ParagraphChild::MoveFrom(MoveFrom { children, .. }) => {
+                for child in children {
+                    if let DeleteChild::Run(run) = child {
+                        collect_fonts_from_run(run, scheme, names);
+                    }
+                }
+            }
+            ParagraphChild::Hyperlink(h) => {
+                collect_fonts_from_paragraph_children(&h.children, scheme, names)
+            }
+            ParagraphChild::StructuredDataTag(tag) => collect_fonts_from_sdt(tag, scheme, names),
+            ParagraphChild::Field(f) => {
+                collect_fonts_from_run_property(&f.run_property, scheme, names);
+                for run in &f.result {
+                    collect_fonts_from_run(run, scheme, names);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+pub(crate) fn collect_fonts_from_paragraph(
+    paragraph: &Paragraph,
+    scheme: &FontScheme,
+    names: &mut Vec<String>,
+) {
+    collect_fonts_from_run_property(&paragraph.property.run_property, scheme, names);
+    collect_fonts_from_paragraph_children(&paragraph.children, scheme, names);
+}
+
+pub(crate) fn collect_fonts_from_table(
+    table: &Table,
+    scheme: &FontScheme,
+    names: &mut Vec<String>,
+) {
+    for row in table.table_rows() {
+        for TableRowChild::TableCell(cell) in &row.cells {
+            for content in &cell.children {
+                match content {
+                    TableCellContent::Paragraph(paragraph) => {
+                        collect_fonts_from_paragraph(paragraph, scheme, names)
+                    }
+                    TableCellContent::Table(table) => {
+                        collect_fonts_from_table(table, scheme, names)
+                    }
+                    TableCellContent::StructuredDataTag(tag) => {
+                        collect_fonts_from_sdt(tag, scheme, names)
+                    }
+                    TableCellContent::TableOfContents(toc) => {
+                        collect_fonts_from_toc(toc, scheme, names)
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn collect_fonts_from_sdt(
+    tag: &StructuredDataTag,
+    scheme: &FontScheme,
+    names: &mut Vec<String>,
+) {
+    for child in &tag.children {
+        match child {
+            StructuredDataTagChild::Run(run) => collect_fonts_from_run(run, scheme, names),
+            StructuredDataTagChild::Paragraph(paragraph) => {
+                collect_fonts_from_paragraph(paragraph, scheme, names)
+            }
+            StructuredDataTagChild::Table(table) => collect_fonts_from_table(table, scheme, names),
+            StructuredDataTagChild::StructuredDataTag(tag) => {
+                collect_fonts_from_sdt(tag, scheme, names)
+            }
+            _ => {}
+        }
+    }
+}
+
+pub(crate) fn collect_fonts_from_toc(
+    toc: &TableOfContents,
+    scheme: &FontScheme,
+    names: &mut Vec<String>,
+) {
+    for content in toc.before_contents.iter().chain(toc.after_contents.iter()) {
+        match content {
+            TocContent::Paragraph(paragraph) => {
+                collect_fonts_from_paragraph(paragraph, scheme, names)
+            }
+            TocContent::Table(table) => collect_fonts_from_table(table, scheme, names),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_collect_fonts() {
+        let docx = Docx::new()
+            .add_style(
+                Style::new("Heading1", StyleType::Paragraph)
+                    .fonts(RunFonts::new().ascii_theme("majorHAnsi")),
+            )
+            .add_paragraph(
+                Paragraph::new().add_run(
+                    Run::new()
+                        .add_text("Hello")
+                        .fonts(RunFonts::new().ascii("Arial").east_asia("MS Gothic")),
+                ),
+            )
+            .add_table(Table::new(vec![TableRow::new(vec![TableCell::new()
+                .add_paragraph(Paragraph::new().add_run(
+                    Run::new().fonts(RunFonts::new().ascii("Arial").cs("Georgia")),
+                ))])]));
+        assert_eq!(
+            docx.collect_fonts(),
+            vec!["Calibri Light", "Arial", "MS Gothic", "Georgia"]
+        );
+    }
+
+    #[test]
+    fn test_build_font_table_with_embedded_font() {
+        let xml = Docx::new()
+            .add_paragraph(
+                Paragraph::new().add_run(Run::new().fonts(RunFonts::new().ascii("Corporate Sans"))),
+            )
+            .embed_font(EmbeddedFont::new("Corporate Sans", vec![0; 64]))
+            .build();
+        let font_table = str::from_utf8(&xml.font_table).unwrap();
+        assert!(font_table.contains(
+            r#"<w:font w:name="Corporate Sans"><w:embedRegular r:id="rId1" w:fontKey="{"#
+        ));
+        assert_eq!(xml.fonts.len(), 1);
+        assert_eq!(xml.fonts[0].0, "fonts/font1.odttf");
+        assert!(str::from_utf8(&xml.settings)
+            .unwrap()
+            .contains("<w:embedTrueTypeFonts />"));
+        assert!(str::from_utf8(&xml.content_type)
+            .unwrap()
+            .contains(r#"Extension="odttf""#));
+    }
+
+    #[test]
+    fn test_read_embedded_font_roundtrip() {
+        let data: Vec<u8> = (0..64).collect();
+        let mut buf = std::io::Cursor::new(vec![]);
+        Docx::new()
+            .add_paragraph(
+                Paragraph::new().add_run(Run::new().fonts(RunFonts::new().ascii("Corporate Sans"))),
+            )
+            .embed_font(
+                EmbeddedFont::new("Corporate Sans", data.clone()).embed_type(FontEmbedType::Bold),
+            )
+            .build()
+            .pack(&mut buf)
+            .unwrap();
+
+        let docx = read_docx(&buf.into_inner()).unwrap();
+        assert_eq!(
+            docx.embedded_fonts,
+            vec![EmbeddedFont::new("Corporate Sans", data).embed_type(FontEmbedType::Bold)]
+        );
+        assert!(docx
+            .font_table
+            .find("Corporate Sans")
+            .unwrap()
+            .embeds
+            .is_empty());
+        assert!(docx.font_table.find("Times New Roman").is_some());
+
+        let xml = docx.build();
+        assert_eq!(xml.fonts.len(), 1);
+        assert!(str::from_utf8(&xml.font_table)
+            .unwrap()
+            .contains(r#"<w:embedBold r:id="rId1" w:fontKey="{"#));
+    }
+}
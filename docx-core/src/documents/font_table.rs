@@ -4,16 +4,55 @@ use crate::types::FontPitchType;
 use crate::xml_builder::*;
 use std::io::Write;
 
-use serde::Serialize;
+use serde::{Deserialize, Deserializer, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct FontTable {}
+pub struct FontTable {
+    pub fonts: Vec<Font>,
+}
 
 impl FontTable {
     pub fn new() -> FontTable {
         Default::default()
     }
+
+    /// Adds a font entry, replacing any existing entry with the same name.
+    pub fn add_font(mut self, font: Font) -> Self {
+        if let Some(f) = self.fonts.iter_mut().find(|f| f.name == font.name) {
+            *f = font;
+        } else {
+            self.fonts.push(font);
+        }
+        self
+    }
+
+    pub fn find(&self, name: &str) -> Option<&Font> {
+        self.fonts.iter().find(|f| f.name == name)
+    }
+
+    pub(crate) fn find_mut(&mut self, name: &str) -> Option<&mut Font> {
+        self.fonts.iter_mut().find(|f| f.name == name)
+    }
+
+    // Fonts referenced by the document are added with known metadata unless already present.
+    pub(crate) fn add_used(&mut self, name: &str) {
+        if self.find(name).is_none() {
+            self.fonts.push(Font::from_name(name));
+        }
+    }
+}
+
+impl Default for FontTable {
+    fn default() -> Self {
+        Self {
+            fonts: vec![
+                Font::new("Times New Roman", "00", "roman", FontPitchType::Variable),
+                Font::new("Symbol", "02", "roman", FontPitchType::Variable),
+                Font::new("Arial", "00", "swiss", FontPitchType::Variable),
+            ],
+        }
+    }
 }
 
 impl BuildXML for FontTable {
@@ -21,34 +60,61 @@ impl BuildXML for FontTable {
         &self,
         stream: xml::writer::EventWriter<W>,
     ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
-        let b = XMLBuilder::from(stream);
-        let times = Font::new("Times New Roman", "00", "roman", FontPitchType::Variable);
-        let symbol = Font::new("Symbol", "02", "roman", FontPitchType::Variable);
-        let arial = Font::new("Arial", "00", "swiss", FontPitchType::Variable);
-        b.declaration(Some(true))?
+        XMLBuilder::from(stream)
+            .declaration(Some(true))?
             .open_fonts()?
-            .add_child(&times)?
-            .add_child(&symbol)?
-            .add_child(&arial)?
+            .add_children(&self.fonts)?
             .close()?
             .into_inner()
     }
 }
 
+// ============================================================================
+// XML Deserialization (quick-xml serde)
+// ============================================================================
+
+#[derive(Deserialize, Default)]
+struct FontTableXml {
+    #[serde(rename = "font", alias = "w:font", default)]
+    fonts: Vec<Font>,
+}
+
+impl<'de> Deserialize<'de> for FontTable {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let xml = FontTableXml::deserialize(deserializer)?;
+        Ok(FontTable { fonts: xml.fonts })
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
-    use pretty_assertions::assert_str_eq;
+    use crate::reader::FromXML;
+    use pretty_assertions::{assert_eq, assert_str_eq};
     use std::str;
 
     #[test]
-    fn test_settings() {
-        let c = FontTable::new();
+    fn test_font_table() {
+        let c = FontTable::new()
+            .add_font(Font::new("Symbol", "02", "roman", FontPitchType::Variable))
+            .add_font(Font::new("Arial", "00", "swiss", FontPitchType::Variable));
         let b = c.build();
         assert_str_eq!(
             str::from_utf8(&b).unwrap(),
             r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><w:fonts xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><w:font w:name="Times New Roman"><w:charset w:val="00" /><w:family w:val="roman" /><w:pitch w:val="variable" /></w:font><w:font w:name="Symbol"><w:charset w:val="02" /><w:family w:val="roman" /><w:pitch w:val="variable" /></w:font><w:font w:name="Arial"><w:charset w:val="00" /><w:family w:val="swiss" /><w:pitch w:val="variable" /></w:font></w:fonts>"#
         );
     }
+
+    #[test]
+    fn test_font_table_roundtrip() {
+        let mut c = FontTable::new().add_font(Font::from_name("Calibri").alt_name("Body"));
+        c.add_used("Corporate Sans");
+        c.add_used("Calibri");
+        let read = FontTable::from_xml(&c.build()[..]).unwrap();
+        assert_eq!(read, c);
+    }
 }
@@ -0,0 +1,59 @@
+use serde::Serialize;
+use std::io::Write;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+#[derive(Serialize, Debug, PartialEq, Clone, Default)]
+pub struct FontTableRels {
+    pub rels: Vec<(String, String, String)>,
+}
+
+impl FontTableRels {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn add_rel(mut self) -> Self {
+        let index = self.rels.len() + 1;
+        self.rels.push((
+            "http://schemas.openxmlformats.org/officeDocument/2006/relationships/font".to_string(),
+            format!("rId{}", index),
+            format!("fonts/font{}.odttf", index),
+        ));
+        self
+    }
+}
+
+impl BuildXML for FontTableRels {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .declaration(Some(true))?
+            .open_relationships("http://schemas.openxmlformats.org/package/2006/relationships")?
+            .apply_each(&self.rels, |(k, id, v), b| b.relationship(id, k, v))?
+            .close()?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_build() {
+        let c = FontTableRels::new().add_rel();
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/font" Target="fonts/font1.odttf" /></Relationships>"#
+        );
+    }
+}
@@ -16,7 +16,7 @@ pub trait HistoryId {
 
 #[cfg(test)]
 pub trait HistoryId {
-    fn generate(&self) -> &str {
-        "123"
+    fn generate(&self) -> String {
+        "123".to_owned()
     }
 }
@@ -1,6 +1,6 @@
 use crate::{
     DeleteChild, DrawingData, InsertChild, Paragraph, ParagraphChild, RunChild,
-    StructuredDataTagChild, Table, TableCellContent, TableChild, TableRowChild, TocContent,
+    StructuredDataTagChild, Table, TableCellContent, TableRowChild, TocContent,
 };
 
 pub(crate) fn collect_images_from_paragraph(
@@ -103,7 +103,7 @@ pub(crate) fn collect_images_from_table(
     image_bufs: &mut Vec<(String, Vec<u8>)>,
     id_prefix: Option<&str>,
 ) {
-    for TableChild::TableRow(row) in &mut table.rows {
+    for row in table.table_rows_mut() {
         for TableRowChild::TableCell(cell) in &mut row.cells {
             for content in &mut cell.children {
                 match content {
@@ -148,6 +148,7 @@ pub(crate) fn collect_images_from_table(
                             }
                         }
                     }
+                    _ => {}
                 }
             }
         }
@@ -1,18 +1,30 @@
-use std::{collections::HashMap, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
 
 mod bookmark_id;
 mod build_xml;
+mod comment_editor;
 mod comments;
 mod comments_extended;
+mod comments_extensible;
+mod comments_ids;
+mod comparison;
 mod content_types;
 mod custom_item;
 mod custom_item_property;
 mod custom_item_rels;
+mod custom_xml_binding;
 mod doc_props;
 mod document;
 mod document_rels;
 mod elements;
+mod embedded_font;
+mod field_evaluator;
+mod font_collector;
 mod font_table;
+mod font_table_rels;
 mod footer;
 mod footer_id;
 mod footer_rels;
@@ -27,9 +39,12 @@ mod image_collector;
 mod numberings;
 mod paragraph_id;
 mod paragraph_property_change_id;
+mod password_hash;
+mod people;
 mod pic_id;
 mod preset_styles;
 mod rels;
+mod revision_resolver;
 mod settings;
 mod styles;
 mod taskpanes;
@@ -50,6 +65,9 @@ pub(crate) use pic_id::*;
 pub use bookmark_id::*;
 pub use comments::*;
 pub use comments_extended::*;
+pub use comments_extensible::*;
+pub use comments_ids::*;
+pub use comparison::CompareOptions;
 pub use content_types::*;
 pub use custom_item::*;
 pub use custom_item_property::*;
@@ -58,7 +76,9 @@ pub use doc_props::*;
 pub use document::*;
 pub use document_rels::*;
 pub use elements::*;
+pub use embedded_font::*;
 pub use font_table::*;
+pub use font_table_rels::*;
 pub use footer::*;
 pub use footer_id::*;
 pub use footer_rels::*;
@@ -67,7 +87,9 @@ pub use header::*;
 pub use header_id::*;
 pub use header_rels::*;
 pub use numberings::*;
+pub use people::*;
 pub use rels::*;
+pub use revision_resolver::RevisionFilter;
 pub use settings::*;
 pub use styles::*;
 pub use taskpanes::*;
@@ -81,6 +103,7 @@ pub use xml_docx::*;
 use base64::Engine;
 use serde::{ser, Serialize};
 
+use self::font_collector::*;
 use self::image_collector::{collect_images_from_paragraph, collect_images_from_table};
 
 #[derive(Debug, Clone)]
@@ -127,6 +150,12 @@ pub struct Docx {
     pub font_table: FontTable,
     pub media: Vec<(String, Vec<u8>)>,
     pub comments_extended: CommentsExtended,
+    #[serde(skip)]
+    pub comments_ids: CommentsIds,
+    #[serde(skip)]
+    pub comments_extensible: CommentsExtensible,
+    #[serde(skip_serializing_if = "People::is_empty")]
+    pub people: People,
     pub web_settings: WebSettings,
     pub taskpanes: Option<Taskpanes>,
     pub taskpanes_rels: TaskpanesRels,
@@ -134,13 +163,18 @@ pub struct Docx {
     pub custom_items: Vec<CustomItem>,
     pub custom_item_props: Vec<CustomItemProperty>,
     pub custom_item_rels: Vec<CustomItemRels>,
-    // reader only
     pub themes: Vec<Theme>,
     // reader only
     pub images: Vec<(String, String, Image, Png)>,
     // reader only
     pub hyperlinks: Vec<(String, String, String)>,
     pub footnotes: Footnotes,
+    #[serde(skip)]
+    pub embedded_fonts: Vec<EmbeddedFont>,
+    #[serde(skip)]
+    pub refresh_fields: bool,
+    #[serde(skip)]
+    pub populate_data_bindings: bool,
 }
 
 impl Default for Docx {
@@ -173,6 +207,9 @@ impl Default for Docx {
             font_table,
             media,
             comments_extended,
+            comments_ids: CommentsIds::new(),
+            comments_extensible: CommentsExtensible::new(),
+            people: People::new(),
             web_settings,
             taskpanes: None,
             taskpanes_rels: TaskpanesRels::new(),
@@ -184,6 +221,9 @@ impl Default for Docx {
             images: vec![],
             hyperlinks: vec![],
             footnotes,
+            embedded_fonts: vec![],
+            refresh_fields: false,
+            populate_data_bindings: false,
         }
     }
 }
@@ -233,6 +273,21 @@ impl Docx {
         self
     }
 
+    pub fn theme(mut self, t: Theme) -> Self {
+        self.themes = vec![t];
+        self
+    }
+
+    pub fn font_table(mut self, t: FontTable) -> Self {
+        self.font_table = t;
+        self
+    }
+
+    pub fn embed_font(mut self, f: EmbeddedFont) -> Self {
+        self.embedded_fonts.push(f);
+        self
+    }
+
     // reader only
     pub(crate) fn web_settings(mut self, s: WebSettings) -> Self {
         self.web_settings = s;
@@ -287,6 +342,16 @@ impl Docx {
         self
     }
 
+    pub fn people(mut self, p: People) -> Self {
+        self.people = p;
+        self
+    }
+
+    pub fn add_person(mut self, p: Person) -> Self {
+        self.people = self.people.add_person(p);
+        self
+    }
+
     pub fn add_paragraph(mut self, p: Paragraph) -> Docx {
         if p.has_numbering {
             // If this document has numbering, set numberings.xml to document_rels.
@@ -616,11 +681,20 @@ impl Docx {
         self
     }
 
-    pub fn custom_property(mut self, name: impl Into<String>, item: impl Into<String>) -> Self {
+    pub fn custom_property(
+        mut self,
+        name: impl Into<String>,
+        item: impl Into<CustomPropertyValue>,
+    ) -> Self {
         self.doc_props = self.doc_props.custom_property(name, item);
         self
     }
 
+    pub fn app_props(mut self, p: AppProps) -> Self {
+        self.doc_props.app = p;
+        self
+    }
+
     pub fn doc_id(mut self, id: &str) -> Self {
         self.settings = self.settings.doc_id(id);
         self
@@ -636,6 +710,76 @@ impl Docx {
         self
     }
 
+    pub fn mirror_margins(mut self) -> Self {
+        self.settings = self.settings.mirror_margins();
+        self
+    }
+
+    pub fn gutter_at_top(mut self) -> Self {
+        self.settings = self.settings.gutter_at_top();
+        self
+    }
+
+    pub fn book_fold_printing(mut self) -> Self {
+        self.settings = self.settings.book_fold_printing();
+        self
+    }
+
+    pub fn print_two_on_one(mut self) -> Self {
+        self.settings = self.settings.print_two_on_one();
+        self
+    }
+
+    pub fn update_fields(mut self) -> Self {
+        self.settings = self.settings.update_fields();
+        self
+    }
+
+    pub fn track_revisions(mut self) -> Self {
+        self.settings = self.settings.track_revisions();
+        self
+    }
+
+    pub fn document_protection(mut self, protection: DocumentProtection) -> Self {
+        self.settings = self.settings.document_protection(protection);
+        self
+    }
+
+    pub fn auto_hyphenation(mut self) -> Self {
+        self.settings = self.settings.auto_hyphenation();
+        self
+    }
+
+    pub fn hyphenation_zone(mut self, zone: usize) -> Self {
+        self.settings = self.settings.hyphenation_zone(zone);
+        self
+    }
+
+    pub fn default_table_style(mut self, style_id: &str) -> Self {
+        self.settings = self.settings.default_table_style(style_id);
+        self
+    }
+
+    pub fn theme_font_lang(mut self, lang: ThemeFontLang) -> Self {
+        self.settings = self.settings.theme_font_lang(lang);
+        self
+    }
+
+    pub fn decimal_symbol(mut self, symbol: &str) -> Self {
+        self.settings = self.settings.decimal_symbol(symbol);
+        self
+    }
+
+    pub fn list_separator(mut self, separator: &str) -> Self {
+        self.settings = self.settings.list_separator(separator);
+        self
+    }
+
+    pub fn compatibility_mode(mut self, mode: usize) -> Self {
+        self.settings = self.settings.compatibility_mode(mode);
+        self
+    }
+
     pub fn title_pg(mut self) -> Self {
         self.document = self.document.title_pg();
         self
@@ -701,16 +845,190 @@ impl Docx {
         self
     }
 
+    // Write the values of data bound content controls from the custom XML parts on build.
+    pub fn populate_data_bindings(mut self) -> Self {
+        self.populate_data_bindings = true;
+        self
+    }
+
+    // Store the values of data bound content controls into the custom XML parts.
+    pub fn extract_data_bindings(mut self) -> Self {
+        custom_xml_binding::DataBinder::new(&self.custom_item_props, &mut self.custom_items)
+            .extract(&mut self.document);
+        self
+    }
+
+    // Accept all tracked changes, as if they were accepted in Word.
+    pub fn accept_all_revisions(self) -> Self {
+        self.accept_revisions(RevisionFilter::new())
+    }
+
+    // Reject all tracked changes, restoring the content as it was before them.
+    pub fn reject_all_revisions(self) -> Self {
+        self.reject_revisions(RevisionFilter::new())
+    }
+
+    // Accept the tracked changes selected by the filter and keep the others.
+    pub fn accept_revisions(mut self, filter: RevisionFilter) -> Self {
+        revision_resolver::RevisionResolver::accept(filter).resolve(&mut self.document);
+        self
+    }
+
+    // Reject the tracked changes selected by the filter and keep the others.
+    pub fn reject_revisions(mut self, filter: RevisionFilter) -> Self {
+        revision_resolver::RevisionResolver::reject(filter).resolve(&mut self.document);
+        self
+    }
+
+    // Compare with a revised version of the document. Returns the revised document
+    // with the differences recorded as tracked changes.
+    pub fn compare(&self, revised: &Docx, options: CompareOptions) -> Docx {
+        let mut docx = revised.clone();
+        docx.document =
+            comparison::DocumentComparer::new(options).compare(&self.document, &revised.document);
+        docx
+    }
+
+    // Reply to a comment, e.g. one read from a document. The reply joins the comment's thread.
+    pub fn reply_to_comment(
+        self,
+        id: usize,
+        author: impl Into<String>,
+        text: impl Into<String>,
+    ) -> Self {
+        let reply = Comment::new(id)
+            .author(author)
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text(text)));
+        self.add_comment_reply(id, reply)
+    }
+
+    // Reply to a comment with the given content, author and date.
+    // The reply's id is replaced by the next unused comment id.
+    pub fn add_comment_reply(mut self, id: usize, mut reply: Comment) -> Self {
+        let comments = comment_editor::CommentEditor::find().edit(&mut self.document);
+        let Some(parent) = comments.iter().find(|c| c.id == id) else {
+            return self;
+        };
+        // Word links every reply to the first comment of the thread.
+        let root_id = parent.parent_comment_id.unwrap_or(id);
+        reply.id = comments
+            .iter()
+            .chain(self.comments.inner())
+            .map(|c| c.id)
+            .max()
+            .unwrap_or_default()
+            + 1;
+        reply.parent_comment_id = Some(root_id);
+
+        // Keep the comments read from a document in step with the document.
+        let root_paragraph_id = comments
+            .iter()
+            .find(|c| c.id == root_id)
+            .and_then(|c| c.paragraph_id())
+            .cloned();
+        if let (Some(para_id), Some(root_para_id)) = (reply.paragraph_id(), root_paragraph_id) {
+            if self
+                .comments_extended
+                .children
+                .iter()
+                .any(|c| c.paragraph_id == root_para_id)
+            {
+                self.comments_extended
+                    .children
+                    .push(CommentExtended::new(para_id).parent_paragraph_id(root_para_id));
+            }
+        }
+        if self.comments.inner().iter().any(|c| c.id == id) {
+            self.comments.comments.push(reply.clone());
+        }
+
+        comment_editor::CommentEditor::reply(id, reply).edit(&mut self.document);
+        self
+    }
+
+    // Resolve the thread of a comment, marking the comment and its replies as done.
+    pub fn resolve_comment(mut self, id: usize) -> Self {
+        let comments = comment_editor::CommentEditor::find().edit(&mut self.document);
+        let Some(comment) = comments.iter().find(|c| c.id == id) else {
+            return self;
+        };
+        let root_id = comment.parent_comment_id.unwrap_or(id);
+        let thread: Vec<&Comment> = comments
+            .iter()
+            .filter(|c| c.id == root_id || c.parent_comment_id == Some(root_id))
+            .collect();
+        let ids: HashSet<usize> = thread.iter().map(|c| c.id).collect();
+        let paragraph_ids: HashSet<&String> =
+            thread.iter().filter_map(|c| c.paragraph_id()).collect();
+
+        for c in &mut self.comments.comments {
+            if ids.contains(&c.id) {
+                c.done = true;
+            }
+        }
+        for c in &mut self.comments_extended.children {
+            if paragraph_ids.contains(&c.paragraph_id) {
+                c.done = true;
+            }
+        }
+        comment_editor::CommentEditor::resolve(ids).edit(&mut self.document);
+        self
+    }
+
+    // Delete a comment with its range. Deleting the first comment of a thread
+    // deletes its replies as well.
+    pub fn delete_comment(mut self, id: usize) -> Self {
+        let comments = comment_editor::CommentEditor::find().edit(&mut self.document);
+        let deleted: Vec<&Comment> = comments
+            .iter()
+            .filter(|c| c.id == id || c.parent_comment_id == Some(id))
+            .collect();
+        let ids: HashSet<usize> = deleted.iter().map(|c| c.id).collect();
+        let paragraph_ids: HashSet<&String> =
+            deleted.iter().filter_map(|c| c.paragraph_id()).collect();
+
+        self.comments.comments.retain(|c| !ids.contains(&c.id));
+        self.comments_extended
+            .children
+            .retain(|c| !paragraph_ids.contains(&c.paragraph_id));
+        comment_editor::CommentEditor::delete(ids).edit(&mut self.document);
+        self
+    }
+
     pub fn page_num_type(mut self, p: PageNumType) -> Self {
         self.document = self.document.page_num_type(p);
         self
     }
 
+    pub fn line_num_type(mut self, l: LineNumType) -> Self {
+        self.document = self.document.line_num_type(l);
+        self
+    }
+
+    pub fn page_borders(mut self, b: PageBorders) -> Self {
+        self.document = self.document.page_borders(b);
+        self
+    }
+
+    pub fn page_vertical_align(mut self, v: crate::types::VAlignType) -> Self {
+        self.document = self.document.vertical_align(v);
+        self
+    }
+
+    // Compute REF, SEQ, STYLEREF, DOCPROPERTY, DOCVARIABLE and DATE field results on build.
+    // Fields depending on the page layout such as PAGE or NUMPAGES are kept as they are.
+    pub fn refresh_fields(mut self) -> Self {
+        self.refresh_fields = true;
+        self
+    }
+
     pub fn build(mut self) -> XMLDocx {
         self.reset();
 
         self.update_dependencies();
 
+        self.update_table_grids();
+
         let tocs: Vec<(usize, Box<TableOfContents>)> = self
             .document
             .children
@@ -735,6 +1053,16 @@ impl Docx {
             }
         }
 
+        if self.populate_data_bindings {
+            custom_xml_binding::DataBinder::new(&self.custom_item_props, &mut self.custom_items)
+                .populate(&mut self.document);
+        }
+
+        if self.refresh_fields {
+            field_evaluator::FieldEvaluator::new(&self.doc_props, &self.settings, &self.styles)
+                .evaluate(&mut self.document);
+        }
+
         let (images, mut images_bufs) = self.images_in_doc();
         let (header_images, header_images_bufs) = self.images_in_header();
         let (footer_images, footer_images_bufs) = self.images_in_footer();
@@ -799,6 +1127,14 @@ impl Docx {
             self.document_rels.has_footnotes = true;
         }
 
+        let (font_table_rels, fonts) = self.update_font_table();
+
+        // commentsIds.xml, commentsExtensible.xml and people.xml are only written with comments.
+        let has_comments = self.document_rels.has_comments;
+        if has_comments {
+            self.content_type = self.content_type.add_comment_parts();
+        }
+
         if has_toc {
             for i in 1..=9 {
                 if !self
@@ -814,6 +1150,11 @@ impl Docx {
             }
         }
 
+        self.doc_props.app = self
+            .doc_props
+            .app
+            .statistics(DocumentStatistics::from_document(&self.document));
+
         XMLDocx {
             content_type: self.content_type.build(),
             rels: self.rels.build(),
@@ -826,11 +1167,17 @@ impl Docx {
             footer_rels: footer_rels.into_iter().map(|r| r.build()).collect(),
             settings: self.settings.build(),
             font_table: self.font_table.build(),
+            font_table_rels: font_table_rels.map(|r| r.build()),
+            fonts,
+            theme: self.themes.first().cloned().unwrap_or_default().build(),
             numberings: self.numberings.build(),
             media: images_bufs,
             headers,
             footers,
             comments_extended: self.comments_extended.build(),
+            comments_ids: has_comments.then(|| self.comments_ids.build()),
+            comments_extensible: has_comments.then(|| self.comments_extensible.build()),
+            people: has_comments.then(|| self.people.build()),
             taskpanes: self.taskpanes.map(|taskpanes| taskpanes.build()),
             taskpanes_rels: self.taskpanes_rels.build(),
             web_extensions,
@@ -1051,6 +1398,7 @@ impl Docx {
         self.comments_extended
             .add_comments_extended(comments_extended);
 
+        self.update_comment_parts(&mut comments);
         self.comments.add_comments(comments);
 
         for (id, d) in hyperlink_map {
@@ -1060,6 +1408,69 @@ impl Docx {
         }
     }
 
+    // Assign durable ids to comments for commentsIds.xml and commentsExtensible.xml,
+    // and register every comment author in people.xml.
+    fn update_comment_parts(&mut self, comments: &mut [Comment]) {
+        let mut used: HashSet<String> = comments
+            .iter()
+            .filter_map(|c| c.durable_id.clone())
+            .collect();
+        let mut durable_ids: HashMap<usize, String> = HashMap::new();
+        let mut comments_ids = vec![];
+        let mut comments_extensible = vec![];
+        for comment in comments.iter_mut() {
+            // A comment with several paragraphs is collected once per paragraph.
+            if let Some(durable_id) = durable_ids.get(&comment.id) {
+                comment.durable_id = Some(durable_id.clone());
+                continue;
+            }
+            let durable_id = match comment.durable_id.clone() {
+                Some(durable_id) => durable_id,
+                None => {
+                    let mut n = comment.id + 1;
+                    while used.contains(&format!("{:08X}", n)) {
+                        n += 1;
+                    }
+                    format!("{:08X}", n)
+                }
+            };
+            used.insert(durable_id.clone());
+            durable_ids.insert(comment.id, durable_id.clone());
+            comment.durable_id = Some(durable_id.clone());
+            if let Some(para_id) = comment.paragraph_id() {
+                comments_ids.push(CommentDurableId::new(para_id.clone(), durable_id.clone()));
+            }
+            // w:date is in local time, so dateUtc is only written when it is known.
+            comments_extensible.push(CommentExtensible {
+                durable_id,
+                date_utc: comment.date_utc.clone(),
+            });
+            self.people.ensure_author(&comment.author);
+        }
+        self.comments_ids.add_comments_ids(comments_ids);
+        self.comments_extensible
+            .add_comments_extensible(comments_extensible);
+    }
+
+    // Fit auto fit tables to the text width of the section they belong to.
+    fn update_table_grids(&mut self) {
+        let text_width = self.document.section_property.text_width();
+        for child in &mut self.document.children {
+            match child {
+                DocumentChild::Table(table) => table.update_grid(text_width),
+                DocumentChild::Section(section) => {
+                    let text_width = section.property.text_width();
+                    for child in &mut section.children {
+                        if let SectionChild::Table(table) = child {
+                            table.update_grid(text_width);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
     // Traverse and clone comments from document and add to comments node.
     // reader only
     pub(crate) fn store_comments(&mut self, comments: &[Comment]) {
@@ -1426,6 +1837,127 @@ impl Docx {
         (footer_images, image_bufs)
     }
 
+    /// Collect the names of all fonts referenced by styles, numberings and contents.
+    pub fn collect_fonts(&self) -> Vec<String> {
+        let scheme = self
+            .themes
+            .first()
+            .map(|t| t.font_schema.clone())
+            .unwrap_or_default();
+        let mut names = vec![];
+        collect_fonts_from_run_property(
+            self.styles.doc_defaults.get_run_property(),
+            &scheme,
+            &mut names,
+        );
+        for style in &self.styles.styles {
+            collect_fonts_from_run_property(&style.run_property, &scheme, &mut names);
+        }
+        for level in self.numberings.abstract_nums.iter().flat_map(|n| &n.levels) {
+            collect_fonts_from_run_property(&level.run_property, &scheme, &mut names);
+        }
+
+        let mut headers = self.document.section_property.get_headers();
+        let mut footers = self.document.section_property.get_footers();
+        for child in &self.document.children {
+            match child {
+                DocumentChild::Paragraph(p) => collect_fonts_from_paragraph(p, &scheme, &mut names),
+                DocumentChild::Table(t) => collect_fonts_from_table(t, &scheme, &mut names),
+                DocumentChild::StructuredDataTag(t) => {
+                    collect_fonts_from_sdt(t, &scheme, &mut names)
+                }
+                DocumentChild::TableOfContents(t) => collect_fonts_from_toc(t, &scheme, &mut names),
+                DocumentChild::Section(section) => {
+                    headers.extend(section.property.get_headers());
+                    footers.extend(section.property.get_footers());
+                    for child in &section.children {
+                        match child {
+                            SectionChild::Paragraph(p) => {
+                                collect_fonts_from_paragraph(p, &scheme, &mut names)
+                            }
+                            SectionChild::Table(t) => {
+                                collect_fonts_from_table(t, &scheme, &mut names)
+                            }
+                            SectionChild::StructuredDataTag(t) => {
+                                collect_fonts_from_sdt(t, &scheme, &mut names)
+                            }
+                            SectionChild::TableOfContents(t) => {
+                                collect_fonts_from_toc(t, &scheme, &mut names)
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        for (_, header) in headers {
+            for child in &header.children {
+                match child {
+                    HeaderChild::Paragraph(p) => {
+                        collect_fonts_from_paragraph(p, &scheme, &mut names)
+                    }
+                    HeaderChild::Table(t) => collect_fonts_from_table(t, &scheme, &mut names),
+                    HeaderChild::StructuredDataTag(t) => {
+                        collect_fonts_from_sdt(t, &scheme, &mut names)
+                    }
+                }
+            }
+        }
+        for (_, footer) in footers {
+            for child in &footer.children {
+                match child {
+                    FooterChild::Paragraph(p) => {
+                        collect_fonts_from_paragraph(p, &scheme, &mut names)
+                    }
+                    FooterChild::Table(t) => collect_fonts_from_table(t, &scheme, &mut names),
+                    FooterChild::StructuredDataTag(t) => {
+                        collect_fonts_from_sdt(t, &scheme, &mut names)
+                    }
+                }
+            }
+        }
+
+        for p in self.footnotes.footnotes.iter().flat_map(|f| &f.content) {
+            collect_fonts_from_paragraph(p, &scheme, &mut names);
+        }
+        for child in self.comments.comments.iter().flat_map(|c| &c.children) {
+            match child {
+                CommentChild::Paragraph(p) => collect_fonts_from_paragraph(p, &scheme, &mut names),
+                CommentChild::Table(t) => collect_fonts_from_table(t, &scheme, &mut names),
+            }
+        }
+        names
+    }
+
+    // Adds the used fonts to the font table and obfuscates the embedded fonts.
+    // Returns the font table relationships and the `word/fonts` parts when fonts are embedded.
+    fn update_font_table(&mut self) -> (Option<FontTableRels>, Vec<(String, Vec<u8>)>) {
+        for name in self.collect_fonts() {
+            self.font_table.add_used(&name);
+        }
+        if self.embedded_fonts.is_empty() {
+            return (None, vec![]);
+        }
+
+        let mut rels = FontTableRels::new();
+        let mut fonts = vec![];
+        for f in &self.embedded_fonts {
+            rels = rels.add_rel();
+            let (_, rid, target) = rels.rels.last().expect("should have font rel").clone();
+            let key = f.font_key();
+            self.font_table.add_used(&f.name);
+            if let Some(font) = self.font_table.find_mut(&f.name) {
+                *font = font.clone().embed(f.embed_type, rid, &key);
+            }
+            fonts.push((target, f.obfuscated(&key)));
+        }
+        self.settings = std::mem::take(&mut self.settings).embed_true_type_fonts();
+        self.content_type = std::mem::take(&mut self.content_type).add_obfuscated_fonts();
+        (Some(rels), fonts)
+    }
+
     /// Collect footnotes from all Runs to the docx footnotes node.
     pub fn collect_footnotes(&mut self) -> bool {
         let footnotes: Vec<Footnote> = self
@@ -1485,7 +2017,7 @@ fn collect_dependencies_in_table(
     comment_map: &mut HashMap<usize, String>,
     hyperlink_map: &mut HashMap<String, String>,
 ) {
-    for TableChild::TableRow(row) in &table.rows {
+    for row in table.table_rows() {
         for TableRowChild::TableCell(cell) in &row.cells {
             for content in &cell.children {
                 match content {
@@ -1570,6 +2102,7 @@ fn collect_dependencies_in_table(
                             }
                         }
                     }
+                    _ => {}
                 }
             }
         }
@@ -1611,7 +2144,7 @@ fn store_comments_in_paragraph(paragraph: &mut Paragraph, comments: &[Comment])
 }
 
 fn store_comments_in_table(table: &mut Table, comments: &[Comment]) {
-    for TableChild::TableRow(row) in &mut table.rows {
+    for row in table.table_rows_mut() {
         for TableRowChild::TableCell(cell) in &mut row.cells {
             for content in &mut cell.children {
                 match content {
@@ -1650,6 +2183,7 @@ fn store_comments_in_table(table: &mut Table, comments: &[Comment]) {
                             }
                         }
                     }
+                    _ => {}
                 }
             }
         }
@@ -1667,7 +2201,10 @@ fn push_comment_and_comment_extended(
         if let CommentChild::Paragraph(child) = child {
             let para_id = child.id.clone();
             comments.push(c.get_comment());
-            let comment_extended = CommentExtended::new(para_id);
+            let mut comment_extended = CommentExtended::new(para_id);
+            if comment.done {
+                comment_extended = comment_extended.done();
+            }
             if let Some(parent_comment_id) = comment.parent_comment_id {
                 if let Some(parent_para_id) = comment_map.get(&parent_comment_id) {
                     comments_extended
@@ -16,7 +16,7 @@ pub trait ParagraphPropertyChangeId {
 
 #[cfg(test)]
 pub trait ParagraphPropertyChangeId {
-    fn generate(&self) -> &str {
-        "123"
+    fn generate(&self) -> String {
+        "123".to_owned()
     }
 }
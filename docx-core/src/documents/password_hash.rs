@@ -0,0 +1,132 @@
+use base64::Engine;
+use sha2::{Digest, Sha512};
+
+// Password hashing used by `w:documentProtection`.
+// Word first reduces the password with the legacy (Word 97) key derivation,
+// writes that key as an uppercase hex string and then runs the iterated
+// SHA-512 hash of ISO/IEC 29500 over the salt and the UTF-16LE hex string.
+
+pub(crate) const DEFAULT_SPIN_COUNT: usize = 100_000;
+
+// cryptAlgorithmSid of SHA-512.
+pub(crate) const SHA512_ALGORITHM_SID: usize = 14;
+
+const INITIAL_CODE_ARRAY: [u16; 15] = [
+    0xE1F0, 0x1D0F, 0xCC9C, 0x84C0, 0x110C, 0x0E10, 0xF1CE, 0x313E, 0x1872, 0xE139, 0xD40F, 0x84F9,
+    0x280C, 0xA96A, 0x4EC3,
+];
+
+const ENCRYPTION_MATRIX: [[u16; 7]; 15] = [
+    [0xAEFC, 0x4DD9, 0x9BB2, 0x2745, 0x4E8A, 0x9D14, 0x2A09],
+    [0x7B61, 0xF6C2, 0xFDA5, 0xEB6B, 0xC6F7, 0x9DCF, 0x2BBF],
+    [0x4563, 0x8AC6, 0x05AD, 0x0B5A, 0x16B4, 0x2D68, 0x5AD0],
+    [0x0375, 0x06EA, 0x0DD4, 0x1BA8, 0x3750, 0x6EA0, 0xDD40],
+    [0xD849, 0xA0B3, 0x5147, 0xA28E, 0x553D, 0xAA7A, 0x44D5],
+    [0x6F45, 0xDE8A, 0xAD35, 0x4A4B, 0x9496, 0x390D, 0x721A],
+    [0xEB23, 0xC667, 0x9CEF, 0x29FF, 0x53FE, 0xA7FC, 0x5FD9],
+    [0x47D3, 0x8FA6, 0x0F6D, 0x1EDA, 0x3DB4, 0x7B68, 0xF6D0],
+    [0xB861, 0x60E3, 0xC1C6, 0x93AD, 0x377B, 0x6EF6, 0xDDEC],
+    [0x45A0, 0x8B40, 0x06A1, 0x0D42, 0x1A84, 0x3508, 0x6A10],
+    [0xAA51, 0x4483, 0x8906, 0x022D, 0x045A, 0x08B4, 0x1168],
+    [0x76B4, 0xED68, 0xCAF1, 0x85C3, 0x1BA7, 0x374E, 0x6E9C],
+    [0x3730, 0x6E60, 0xDCC0, 0xA9A1, 0x4363, 0x86C6, 0x1DAD],
+    [0x3331, 0x6662, 0xCCC4, 0x89A9, 0x0373, 0x06E6, 0x0DCC],
+    [0x1021, 0x2042, 0x4084, 0x8108, 0x1231, 0x2462, 0x48C4],
+];
+
+// Each character contributes its low byte, or its high byte when the low byte is zero.
+fn legacy_password_bytes(password: &str) -> Vec<u8> {
+    password
+        .encode_utf16()
+        .take(15)
+        .map(|c| {
+            let low = (c & 0xFF) as u8;
+            if low == 0 {
+                (c >> 8) as u8
+            } else {
+                low
+            }
+        })
+        .collect()
+}
+
+fn rotate_left_15bit(v: u16) -> u16 {
+    ((v >> 14) & 1) | ((v << 1) & 0x7FFF)
+}
+
+pub(crate) fn legacy_password_key(password: &str) -> u32 {
+    let bytes = legacy_password_bytes(password);
+    if bytes.is_empty() {
+        return 0;
+    }
+
+    let mut high = INITIAL_CODE_ARRAY[bytes.len() - 1];
+    for (i, b) in bytes.iter().enumerate() {
+        let row = &ENCRYPTION_MATRIX[15 - bytes.len() + i];
+        for (bit, v) in row.iter().enumerate() {
+            if b & (1 << bit) != 0 {
+                high ^= v;
+            }
+        }
+    }
+
+    let mut low: u16 = 0;
+    for b in bytes.iter().rev() {
+        low = rotate_left_15bit(low) ^ (*b as u16);
+    }
+    low = rotate_left_15bit(low) ^ (bytes.len() as u16) ^ 0xCE4B;
+
+    ((high as u32) << 16) | low as u32
+}
+
+pub(crate) fn hash_password(password: &str, salt: &[u8], spin_count: usize) -> Vec<u8> {
+    let key = legacy_password_key(password).to_le_bytes();
+    let hex = format!("{:02X}{:02X}{:02X}{:02X}", key[0], key[1], key[2], key[3]);
+    let hex: Vec<u8> = hex.encode_utf16().flat_map(|c| c.to_le_bytes()).collect();
+
+    let mut hash = Sha512::new().chain_update(salt).chain_update(hex).finalize();
+    for i in 0..spin_count {
+        hash = Sha512::new()
+            .chain_update(hash)
+            .chain_update((i as u32).to_le_bytes())
+            .finalize();
+    }
+    hash.to_vec()
+}
+
+pub(crate) fn generate_salt() -> [u8; 16] {
+    let mut salt = [0u8; 16];
+    getrandom::getrandom(&mut salt).expect("should generate a random salt");
+    salt
+}
+
+pub(crate) fn encode_base64(bytes: &[u8]) -> String {
+    base64::engine::general_purpose::STANDARD.encode(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_legacy_password_key() {
+        assert_eq!(legacy_password_key(""), 0);
+        assert_ne!(legacy_password_key("secret"), legacy_password_key("Secret"));
+        // Only the first 15 characters are significant.
+        assert_eq!(
+            legacy_password_key("0123456789abcdef"),
+            legacy_password_key("0123456789abcdeXYZ")
+        );
+    }
+
+    #[test]
+    fn test_hash_password_depends_on_salt() {
+        let a = hash_password("secret", &[0; 16], 10);
+        let b = hash_password("secret", &[1; 16], 10);
+        assert_ne!(a, b);
+        assert_eq!(a, hash_password("secret", &[0; 16], 10));
+    }
+}
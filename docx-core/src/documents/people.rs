@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+use super::*;
+use crate::documents::BuildXML;
+use crate::{escape, xml_builder::*};
+
+// i.e.    <w15:person w15:author="Test Name"><w15:presenceInfo w15:providerId="None" w15:userId="Test Name"/></w15:person>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct People {
+    #[serde(
+        rename(serialize = "people", deserialize = "person"),
+        alias = "w15:person",
+        alias = "people",
+        default
+    )]
+    pub people: Vec<Person>,
+}
+
+impl People {
+    pub fn new() -> People {
+        Default::default()
+    }
+
+    pub fn add_person(mut self, p: Person) -> Self {
+        self.people.retain(|current| current.author != p.author);
+        self.people.push(p);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.people.is_empty()
+    }
+
+    // Word shows local authors with a "None" provider.
+    pub(crate) fn ensure_author(&mut self, author: &str) {
+        let escaped = escape::escape(author);
+        if !self.people.iter().any(|p| p.author == escaped) {
+            self.people
+                .push(Person::new(author).presence_info(PresenceInfo::new("None", author)));
+        }
+    }
+}
+
+impl BuildXML for People {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .declaration(Some(true))?
+            .open_people()?
+            .add_children(&self.people)?
+            .close()?
+            .into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_people() {
+        let mut people = People::new().add_person(
+            Person::new("Test Name")
+                .presence_info(PresenceInfo::new("Windows Live", "36e8d88cd6c0d524")),
+        );
+        people.ensure_author("Test Name");
+        people.ensure_author("Bob");
+        let b = people.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><w15:people xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml" xmlns:w16cex="http://schemas.microsoft.com/office/word/2018/wordml/cex" xmlns:w16cid="http://schemas.microsoft.com/office/word/2016/wordml/cid" mc:Ignorable="w14 w15 w16cex w16cid"><w15:person w15:author="Test Name"><w15:presenceInfo w15:providerId="Windows Live" w15:userId="36e8d88cd6c0d524" /></w15:person><w15:person w15:author="Bob"><w15:presenceInfo w15:providerId="None" w15:userId="Bob" /></w15:person></w15:people>"#
+        );
+    }
+}
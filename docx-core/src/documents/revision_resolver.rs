@@ -0,0 +1,1007 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+use crate::documents::*;
+use crate::escape::replace_escaped;
+
+// Selects tracked changes by author and by date.
+// Dates are compared as ISO 8601 strings, e.g. "2024-01-01T00:00:00Z".
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RevisionFilter {
+    author: Option<String>,
+    since: Option<String>,
+    until: Option<String>,
+}
+
+impl RevisionFilter {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    // Authors are compared unescaped since changes built with `author()` hold escaped names
+    // while changes read from a docx hold them as written.
+    pub fn author(mut self, author: impl Into<String>) -> Self {
+        self.author = Some(replace_escaped(&author.into()));
+        self
+    }
+
+    // Only the changes made at or after the date.
+    pub fn since(mut self, date: impl Into<String>) -> Self {
+        self.since = Some(date.into());
+        self
+    }
+
+    // Only the changes made at or before the date.
+    pub fn until(mut self, date: impl Into<String>) -> Self {
+        self.until = Some(date.into());
+        self
+    }
+
+    pub(crate) fn matches(&self, author: &str, date: &str) -> bool {
+        if matches!(&self.author, Some(a) if *a != replace_escaped(author)) {
+            return false;
+        }
+        if matches!(&self.since, Some(since) if date < since.as_str()) {
+            return false;
+        }
+        !matches!(&self.until, Some(until) if date > until.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Resolution {
+    Accept,
+    Reject,
+}
+
+// Moves the children of each paragraph whose mark was removed into the following paragraph.
+// When no paragraph follows, the paragraph is kept unless it has become empty.
+fn merge_paragraphs<T>(
+    children: &mut Vec<T>,
+    removed_marks: Vec<usize>,
+    paragraph: impl Fn(&mut T) -> Option<&mut Paragraph>,
+) {
+    for i in removed_marks.into_iter().rev() {
+        let (head, tail) = children.split_at_mut(i + 1);
+        let remove = match (
+            paragraph(&mut head[i]),
+            tail.first_mut().and_then(&paragraph),
+        ) {
+            (Some(p), Some(next)) => {
+                let mut merged = std::mem::take(&mut p.children);
+                merged.append(&mut next.children);
+                next.children = merged;
+                true
+            }
+            (Some(p), None) => p.children.is_empty(),
+            (None, _) => false,
+        };
+        if remove {
+            children.remove(i);
+        }
+    }
+}
+
+// Restores a deleted run as a regular one.
+fn restore_run(mut run: Run) -> Run {
+    run.children = run
+        .children
+        .into_iter()
+        .map(|c| match c {
+            RunChild::DeleteText(t) => RunChild::Text(t.into_text()),
+            RunChild::DeleteInstrText(t) => RunChild::InstrText(Box::new(t.into_instr_text())),
+            c => c,
+        })
+        .collect();
+    run
+}
+
+fn into_insert_child(child: ParagraphChild) -> Option<InsertChild> {
+    match child {
+        ParagraphChild::Run(run) => Some(InsertChild::Run(run)),
+        ParagraphChild::Delete(del) => Some(InsertChild::Delete(del)),
+        ParagraphChild::CommentStart(c) => Some(InsertChild::CommentStart(c)),
+        ParagraphChild::CommentEnd(c) => Some(InsertChild::CommentEnd(c)),
+        _ => None,
+    }
+}
+
+// Comment ranges are kept even when the content around them is removed.
+fn comment_markers(children: Vec<DeleteChild>) -> Vec<ParagraphChild> {
+    children
+        .into_iter()
+        .filter_map(|c| match c {
+            DeleteChild::CommentStart(c) => Some(ParagraphChild::CommentStart(c)),
+            DeleteChild::CommentEnd(c) => Some(ParagraphChild::CommentEnd(c)),
+            DeleteChild::Run(_) => None,
+        })
+        .collect()
+}
+
+// Rewrites the tracked changes selected by the filter into the accepted or rejected result.
+pub(crate) struct RevisionResolver {
+    filter: RevisionFilter,
+    resolution: Resolution,
+    // Ids of the removed move range starts, so that the matching ends are removed as well.
+    removed_move_ranges: RefCell<HashSet<usize>>,
+}
+
+impl RevisionResolver {
+    pub(crate) fn accept(filter: RevisionFilter) -> Self {
+        Self {
+            filter,
+            resolution: Resolution::Accept,
+            removed_move_ranges: Default::default(),
+        }
+    }
+
+    pub(crate) fn reject(filter: RevisionFilter) -> Self {
+        Self {
+            filter,
+            resolution: Resolution::Reject,
+            removed_move_ranges: Default::default(),
+        }
+    }
+
+    pub(crate) fn resolve(&self, document: &mut Document) {
+        let mut removed_marks = vec![];
+        // Index of the child among the kept ones.
+        let mut i = 0;
+        document.children.retain_mut(|child| {
+            let keep = match child {
+                DocumentChild::Paragraph(p) => {
+                    if !self.resolve_paragraph(p) {
+                        removed_marks.push(i);
+                    }
+                    true
+                }
+                DocumentChild::Table(t) => {
+                    self.resolve_table(t);
+                    true
+                }
+                DocumentChild::StructuredDataTag(t) => {
+                    self.resolve_sdt(t);
+                    true
+                }
+                DocumentChild::Section(section) => {
+                    self.resolve_section(section);
+                    true
+                }
+                DocumentChild::MoveFromRangeStart(start) => {
+                    self.resolve_move_range_start(start.id, &start.author, &start.date)
+                }
+                DocumentChild::MoveToRangeStart(start) => {
+                    self.resolve_move_range_start(start.id, &start.author, &start.date)
+                }
+                DocumentChild::MoveFromRangeEnd(end) => self.resolve_move_range_end(end.id),
+                DocumentChild::MoveToRangeEnd(end) => self.resolve_move_range_end(end.id),
+                _ => true,
+            };
+            if keep {
+                i += 1;
+            }
+            keep
+        });
+        merge_paragraphs(&mut document.children, removed_marks, |c| match c {
+            DocumentChild::Paragraph(p) => Some(p.as_mut()),
+            _ => None,
+        });
+        document
+            .children
+            .retain(|c| !matches!(c, DocumentChild::Table(t) if t.rows.is_empty()));
+        self.resolve_headers_footers(&mut document.section_property);
+    }
+
+    // Resolves the insertion and deletion marks of a paragraph mark or a table row.
+    // Returns false when the paragraph mark or the row is removed.
+    fn resolve_marks(&self, ins: &mut Option<Insert>, del: &mut Option<Delete>) -> bool {
+        let inserted = ins
+            .as_ref()
+            .map_or(false, |i| self.filter.matches(&i.author, &i.date));
+        let deleted = del
+            .as_ref()
+            .map_or(false, |d| self.filter.matches(&d.author, &d.date));
+        if inserted {
+            *ins = None;
+        }
+        if deleted {
+            *del = None;
+        }
+        match self.resolution {
+            Resolution::Accept => !deleted,
+            Resolution::Reject => !inserted,
+        }
+    }
+
+    // Returns false when the paragraph mark is removed.
+    fn resolve_paragraph(&self, p: &mut Paragraph) -> bool {
+        p.children = self.resolve_paragraph_children(std::mem::take(&mut p.children));
+
+        if let Some(change) = p.property.paragraph_property_change.take() {
+            if !self.filter.matches(&change.author, &change.date) {
+                p.property.paragraph_property_change = Some(change);
+            } else if self.resolution == Resolution::Reject {
+                let run_property = std::mem::take(&mut p.property.run_property);
+                let section_property = p.property.section_property.take();
+                p.property = *change.property;
+                p.property.run_property = run_property;
+                p.property.section_property = section_property;
+            }
+        }
+
+        // The original numbering is only kept as display text, so there is nothing to restore.
+        if let Some(np) = p.property.numbering_property.as_mut() {
+            if matches!(&np.numbering_change, Some(c) if self.filter.matches(&c.author, &c.date)) {
+                np.numbering_change = None;
+            }
+        }
+
+        let run_property = &mut p.property.run_property;
+        self.resolve_run_property(run_property);
+        let kept = self.resolve_marks(&mut run_property.ins, &mut run_property.del);
+        self.resolve_move_marks(&mut run_property.move_from, &mut run_property.move_to) && kept
+    }
+
+    // A moved paragraph mark is resolved like a deleted one at the source and an inserted one
+    // at the destination. Returns false when the paragraph mark is removed.
+    fn resolve_move_marks(&self, from: &mut Option<MoveFrom>, to: &mut Option<MoveTo>) -> bool {
+        let moved_from = from
+            .as_ref()
+            .map_or(false, |m| self.filter.matches(&m.author, &m.date));
+        let moved_to = to
+            .as_ref()
+            .map_or(false, |m| self.filter.matches(&m.author, &m.date));
+        if moved_from {
+            *from = None;
+        }
+        if moved_to {
+            *to = None;
+        }
+        match self.resolution {
+            Resolution::Accept => !moved_from,
+            Resolution::Reject => !moved_to,
+        }
+    }
+
+    fn resolve_run_property(&self, rp: &mut RunProperty) {
+        let Some(change) = rp.run_property_change.take() else {
+            return;
+        };
+        if !self.filter.matches(&change.author, &change.date) {
+            rp.run_property_change = Some(change);
+        } else if self.resolution == Resolution::Reject {
+            // The paragraph mark revisions are not part of the formatting change.
+            let ins = rp.ins.take();
+            let del = rp.del.take();
+            let move_from = rp.move_from.take();
+            let move_to = rp.move_to.take();
+            *rp = *change.property;
+            rp.ins = ins;
+            rp.del = del;
+            rp.move_from = move_from;
+            rp.move_to = move_to;
+        }
+    }
+
+    fn resolve_run_properties(&self, children: &mut [ParagraphChild]) {
+        for child in children {
+            match child {
+                ParagraphChild::Run(run) => self.resolve_run_property(&mut run.run_property),
+                ParagraphChild::Insert(Insert { children, .. })
+                | ParagraphChild::MoveTo(MoveTo { children, .. }) => {
+                    for c in children {
+                        match c {
+                            InsertChild::Run(run) => {
+                                self.resolve_run_property(&mut run.run_property)
+                            }
+                            InsertChild::Delete(del) => {
+                                for c in &mut del.children {
+                                    if let DeleteChild::Run(run) = c {
+                                        self.resolve_run_property(&mut run.run_property);
+                                    }
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                ParagraphChild::Delete(Delete { children, .. })
+                | ParagraphChild::MoveFrom(MoveFrom { children, .. }) => {
+                    for c in children {
+                        if let DeleteChild::Run(run) = c {
+                            self.resolve_run_property(&mut run.run_property);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn resolve_paragraph_children(&self, children: Vec<ParagraphChild>) -> Vec<ParagraphChild> {
+        let mut resolved = vec![];
+        for child in children {
+            match child {
+                ParagraphChild::Insert(ins) => resolved.extend(self.resolve_insert(ins)),
+                ParagraphChild::Delete(del) => resolved.extend(self.resolve_delete(del)),
+                ParagraphChild::MoveFrom(m) => {
+                    if self.filter.matches(&m.author, &m.date) {
+                        resolved.extend(self.resolve_deleted(m.children));
+                    } else {
+                        resolved.push(ParagraphChild::MoveFrom(m));
+                    }
+                }
+                ParagraphChild::MoveTo(mut m) => {
+                    if self.filter.matches(&m.author, &m.date) {
+                        resolved.extend(self.resolve_inserted(m.children));
+                    } else {
+                        m.children = self.resolve_nested_deletes(m.children);
+                        if !m.children.is_empty() {
+                            resolved.push(ParagraphChild::MoveTo(m));
+                        }
+                    }
+                }
+                ParagraphChild::MoveFromRangeStart(start) => {
+                    if self.resolve_move_range_start(start.id, &start.author, &start.date) {
+                        resolved.push(ParagraphChild::MoveFromRangeStart(start));
+                    }
+                }
+                ParagraphChild::MoveToRangeStart(start) => {
+                    if self.resolve_move_range_start(start.id, &start.author, &start.date) {
+                        resolved.push(ParagraphChild::MoveToRangeStart(start));
+                    }
+                }
+                ParagraphChild::MoveFromRangeEnd(end) => {
+                    if self.resolve_move_range_end(end.id) {
+                        resolved.push(ParagraphChild::MoveFromRangeEnd(end));
+                    }
+                }
+                ParagraphChild::MoveToRangeEnd(end) => {
+                    if self.resolve_move_range_end(end.id) {
+                        resolved.push(ParagraphChild::MoveToRangeEnd(end));
+                    }
+                }
+                ParagraphChild::Hyperlink(mut link) => {
+                    link.children = self.resolve_paragraph_children(link.children);
+                    resolved.push(ParagraphChild::Hyperlink(link));
+                }
+                ParagraphChild::StructuredDataTag(mut tag) => {
+                    self.resolve_sdt(&mut tag);
+                    resolved.push(ParagraphChild::StructuredDataTag(tag));
+                }
+                c => resolved.push(c),
+            }
+        }
+        self.resolve_run_properties(&mut resolved);
+        resolved
+    }
+
+    // The range of a selected move is removed together with the move.
+    // Returns false when the start marker is removed.
+    fn resolve_move_range_start(&self, id: usize, author: &str, date: &str) -> bool {
+        if self.filter.matches(author, date) {
+            self.removed_move_ranges.borrow_mut().insert(id);
+            false
+        } else {
+            true
+        }
+    }
+
+    fn resolve_move_range_end(&self, id: usize) -> bool {
+        !self.removed_move_ranges.borrow().contains(&id)
+    }
+
+    // Deletions inside an insertion or a move may still be selected.
+    fn resolve_nested_deletes(&self, children: Vec<InsertChild>) -> Vec<InsertChild> {
+        children
+            .into_iter()
+            .flat_map(|c| match c {
+                InsertChild::Delete(del) => self
+                    .resolve_delete(del)
+                    .into_iter()
+                    .filter_map(into_insert_child)
+                    .collect(),
+                c => vec![c],
+            })
+            .collect()
+    }
+
+    fn resolve_insert(&self, mut ins: Insert) -> Vec<ParagraphChild> {
+        if !self.filter.matches(&ins.author, &ins.date) {
+            ins.children = self.resolve_nested_deletes(ins.children);
+            if ins.children.is_empty() {
+                return vec![];
+            }
+            return vec![ParagraphChild::Insert(ins)];
+        }
+        self.resolve_inserted(ins.children)
+    }
+
+    fn resolve_inserted(&self, children: Vec<InsertChild>) -> Vec<ParagraphChild> {
+        let mut resolved = vec![];
+        for child in children {
+            match child {
+                InsertChild::Run(run) => {
+                    if self.resolution == Resolution::Accept {
+                        resolved.push(ParagraphChild::Run(run));
+                    }
+                }
+                InsertChild::Delete(del) => match self.resolution {
+                    Resolution::Accept => resolved.extend(self.resolve_delete(del)),
+                    Resolution::Reject => resolved.extend(comment_markers(del.children)),
+                },
+                InsertChild::CommentStart(c) => resolved.push(ParagraphChild::CommentStart(c)),
+                InsertChild::CommentEnd(c) => resolved.push(ParagraphChild::CommentEnd(c)),
+            }
+        }
+        resolved
+    }
+
+    fn resolve_delete(&self, del: Delete) -> Vec<ParagraphChild> {
+        if !self.filter.matches(&del.author, &del.date) {
+            return vec![ParagraphChild::Delete(del)];
+        }
+        self.resolve_deleted(del.children)
+    }
+
+    fn resolve_deleted(&self, children: Vec<DeleteChild>) -> Vec<ParagraphChild> {
+        match self.resolution {
+            Resolution::Accept => comment_markers(children),
+            Resolution::Reject => children
+                .into_iter()
+                .map(|c| match c {
+                    DeleteChild::Run(run) => ParagraphChild::Run(Box::new(restore_run(run))),
+                    DeleteChild::CommentStart(c) => ParagraphChild::CommentStart(c),
+                    DeleteChild::CommentEnd(c) => ParagraphChild::CommentEnd(c),
+                })
+                .collect(),
+        }
+    }
+
+    fn resolve_table(&self, table: &mut Table) {
+        if let Some(change) = table.property.table_property_change.take() {
+            if !self.filter.matches(&change.author, &change.date) {
+                table.property.table_property_change = Some(change);
+            } else if self.resolution == Resolution::Reject {
+                table.property = *change.property;
+            }
+        }
+        table.rows.retain_mut(|child| match child {
+            TableChild::TableRow(row) => self.resolve_row(row),
+            TableChild::StructuredDataTag(t) => {
+                self.resolve_sdt(t);
+                true
+            }
+            TableChild::PermStart(_) | TableChild::PermEnd(_) => true,
+        });
+    }
+
+    // Returns false when the row is removed.
+    fn resolve_row(&self, row: &mut TableRow) -> bool {
+        if !self.resolve_marks(&mut row.property.ins, &mut row.property.del) {
+            return false;
+        }
+        if let Some(change) = row.property.table_row_property_change.take() {
+            if !self.filter.matches(&change.author, &change.date) {
+                row.property.table_row_property_change = Some(change);
+            } else if self.resolution == Resolution::Reject {
+                let ins = row.property.ins.take();
+                let del = row.property.del.take();
+                row.property = *change.property;
+                row.property.ins = ins;
+                row.property.del = del;
+            }
+        }
+        row.cells
+            .retain_mut(|TableRowChild::TableCell(cell)| self.resolve_cell(cell));
+        !row.cells.is_empty()
+    }
+
+    // Returns false when the cell is removed.
+    fn resolve_cell(&self, cell: &mut TableCell) -> bool {
+        let property = &mut cell.property;
+        let inserted = property
+            .cell_insert
+            .as_ref()
+            .map_or(false, |c| self.filter.matches(&c.author, &c.date));
+        let deleted = property
+            .cell_delete
+            .as_ref()
+            .map_or(false, |c| self.filter.matches(&c.author, &c.date));
+        if inserted {
+            property.cell_insert = None;
+        }
+        if deleted {
+            property.cell_delete = None;
+        }
+        let removed = match self.resolution {
+            Resolution::Accept => deleted,
+            Resolution::Reject => inserted,
+        };
+        if removed {
+            return false;
+        }
+        if let Some(merge) = property.cell_merge.take() {
+            if !self.filter.matches(&merge.author, &merge.date) {
+                property.cell_merge = Some(merge);
+            } else {
+                let v_merge = match self.resolution {
+                    Resolution::Accept => merge.v_merge,
+                    Resolution::Reject => merge.v_merge_orig,
+                };
+                if let Some(v) = v_merge {
+                    *property = std::mem::take(property).vertical_merge(v.val);
+                }
+            }
+        }
+        if let Some(change) = property.table_cell_property_change.take() {
+            if !self.filter.matches(&change.author, &change.date) {
+                property.table_cell_property_change = Some(change);
+            } else if self.resolution == Resolution::Reject {
+                let cell_insert = property.cell_insert.take();
+                let cell_delete = property.cell_delete.take();
+                let cell_merge = property.cell_merge.take();
+                *property = *change.property;
+                property.cell_insert = cell_insert;
+                property.cell_delete = cell_delete;
+                property.cell_merge = cell_merge;
+            }
+        }
+
+        let mut removed_marks = vec![];
+        let mut i = 0;
+        cell.children.retain_mut(|content| {
+            let keep = match content {
+                TableCellContent::Paragraph(p) => {
+                    if !self.resolve_paragraph(p) {
+                        removed_marks.push(i);
+                    }
+                    true
+                }
+                TableCellContent::Table(t) => {
+                    self.resolve_table(t);
+                    true
+                }
+                TableCellContent::StructuredDataTag(t) => {
+                    self.resolve_sdt(t);
+                    true
+                }
+                TableCellContent::TableOfContents(_) => true,
+                TableCellContent::MoveFromRangeStart(start) => {
+                    self.resolve_move_range_start(start.id, &start.author, &start.date)
+                }
+                TableCellContent::MoveToRangeStart(start) => {
+                    self.resolve_move_range_start(start.id, &start.author, &start.date)
+                }
+                TableCellContent::MoveFromRangeEnd(end) => self.resolve_move_range_end(end.id),
+                TableCellContent::MoveToRangeEnd(end) => self.resolve_move_range_end(end.id),
+            };
+            if keep {
+                i += 1;
+            }
+            keep
+        });
+        merge_paragraphs(&mut cell.children, removed_marks, |c| match c {
+            TableCellContent::Paragraph(p) => Some(p),
+            _ => None,
+        });
+        cell.children
+            .retain(|c| !matches!(c, TableCellContent::Table(t) if t.rows.is_empty()));
+        // A cell must contain at least one paragraph.
+        if cell.children.is_empty() {
+            cell.children
+                .push(TableCellContent::Paragraph(Paragraph::new()));
+        }
+        true
+    }
+
+    fn resolve_sdt(&self, tag: &mut StructuredDataTag) {
+        tag.children.retain_mut(|child| match child {
+            StructuredDataTagChild::TableRow(row) => self.resolve_row(row),
+            _ => true,
+        });
+        let mut removed_marks = vec![];
+        for (i, child) in tag.children.iter_mut().enumerate() {
+            match child {
+                StructuredDataTagChild::Paragraph(p) => {
+                    if !self.resolve_paragraph(p) {
+                        removed_marks.push(i);
+                    }
+                }
+                StructuredDataTagChild::Table(t) => self.resolve_table(t),
+                StructuredDataTagChild::StructuredDataTag(t) => self.resolve_sdt(t),
+                _ => {}
+            }
+        }
+        merge_paragraphs(&mut tag.children, removed_marks, |c| match c {
+            StructuredDataTagChild::Paragraph(p) => Some(p.as_mut()),
+            _ => None,
+        });
+        tag.children
+            .retain(|c| !matches!(c, StructuredDataTagChild::Table(t) if t.rows.is_empty()));
+    }
+
+    fn resolve_section(&self, section: &mut Section) {
+        let mut removed_marks = vec![];
+        for (i, child) in section.children.iter_mut().enumerate() {
+            match child {
+                SectionChild::Paragraph(p) => {
+                    if !self.resolve_paragraph(p) {
+                        removed_marks.push(i);
+                    }
+                }
+                SectionChild::Table(t) => self.resolve_table(t),
+                SectionChild::StructuredDataTag(t) => self.resolve_sdt(t),
+                _ => {}
+            }
+        }
+        merge_paragraphs(&mut section.children, removed_marks, |c| match c {
+            SectionChild::Paragraph(p) => Some(p.as_mut()),
+            _ => None,
+        });
+        section
+            .children
+            .retain(|c| !matches!(c, SectionChild::Table(t) if t.rows.is_empty()));
+        self.resolve_headers_footers(&mut section.property);
+    }
+
+    fn resolve_headers_footers(&self, property: &mut SectionProperty) {
+        let headers = [
+            &mut property.header,
+            &mut property.first_header,
+            &mut property.even_header,
+        ];
+        for (_, header) in headers.into_iter().flatten() {
+            let mut removed_marks = vec![];
+            for (i, child) in header.children.iter_mut().enumerate() {
+                match child {
+                    HeaderChild::Paragraph(p) => {
+                        if !self.resolve_paragraph(p) {
+                            removed_marks.push(i);
+                        }
+                    }
+                    HeaderChild::Table(t) => self.resolve_table(t),
+                    HeaderChild::StructuredDataTag(t) => self.resolve_sdt(t),
+                }
+            }
+            merge_paragraphs(&mut header.children, removed_marks, |c| match c {
+                HeaderChild::Paragraph(p) => Some(p.as_mut()),
+                _ => None,
+            });
+            header
+                .children
+                .retain(|c| !matches!(c, HeaderChild::Table(t) if t.rows.is_empty()));
+        }
+        let footers = [
+            &mut property.footer,
+            &mut property.first_footer,
+            &mut property.even_footer,
+        ];
+        for (_, footer) in footers.into_iter().flatten() {
+            let mut removed_marks = vec![];
+            for (i, child) in footer.children.iter_mut().enumerate() {
+                match child {
+                    FooterChild::Paragraph(p) => {
+                        if !self.resolve_paragraph(p) {
+                            removed_marks.push(i);
+                        }
+                    }
+                    FooterChild::Table(t) => self.resolve_table(t),
+                    FooterChild::StructuredDataTag(t) => self.resolve_sdt(t),
+                }
+            }
+            merge_paragraphs(&mut footer.children, removed_marks, |c| match c {
+                FooterChild::Paragraph(p) => Some(p.as_mut()),
+                _ => None,
+            });
+            footer
+                .children
+                .retain(|c| !matches!(c, FooterChild::Table(t) if t.rows.is_empty()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn paragraph_text(p: &Paragraph) -> String {
+        p.children
+            .iter()
+            .filter_map(|c| match c {
+                ParagraphChild::Run(run) => Some(run),
+                _ => None,
+            })
+            .flat_map(|run| run.children.iter())
+            .filter_map(|c| match c {
+                RunChild::Text(t) => Some(t.text.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn document_texts(document: &Document) -> Vec<String> {
+        document
+            .children
+            .iter()
+            .filter_map(|c| match c {
+                DocumentChild::Paragraph(p) => Some(paragraph_text(p)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn tracked_document() -> Document {
+        Document::new().add_paragraph(
+            Paragraph::new()
+                .add_run(Run::new().add_text("Hello "))
+                .add_insert(
+                    Insert::new(Run::new().add_text("new "))
+                        .author("bob")
+                        .date("2024-02-01T00:00:00Z"),
+                )
+                .add_delete(
+                    Delete::new()
+                        .add_run(Run::new().add_delete_text("old "))
+                        .author("alice")
+                        .date("2024-01-01T00:00:00Z"),
+                )
+                .add_run(Run::new().add_text("world")),
+        )
+    }
+
+    #[test]
+    fn test_accept_runs() {
+        let mut document = tracked_document();
+        RevisionResolver::accept(RevisionFilter::new()).resolve(&mut document);
+        assert_eq!(document_texts(&document), vec!["Hello new world"]);
+    }
+
+    #[test]
+    fn test_reject_runs() {
+        let mut document = tracked_document();
+        RevisionResolver::reject(RevisionFilter::new()).resolve(&mut document);
+        assert_eq!(document_texts(&document), vec!["Hello old world"]);
+    }
+
+    #[test]
+    fn test_filter_by_author_and_date() {
+        let mut document = tracked_document();
+        RevisionResolver::accept(RevisionFilter::new().author("bob")).resolve(&mut document);
+        assert_eq!(document_texts(&document), vec!["Hello new world"]);
+        let DocumentChild::Paragraph(p) = &document.children[0] else {
+            unreachable!()
+        };
+        assert!(matches!(p.children[2], ParagraphChild::Delete(_)));
+
+        let mut document = tracked_document();
+        RevisionResolver::reject(RevisionFilter::new().until("2024-01-15T00:00:00Z"))
+            .resolve(&mut document);
+        let DocumentChild::Paragraph(p) = &document.children[0] else {
+            unreachable!()
+        };
+        assert!(matches!(p.children[1], ParagraphChild::Insert(_)));
+        assert_eq!(paragraph_text(p), "Hello old world");
+    }
+
+    #[test]
+    fn test_filter_by_escaped_author_of_read_document() {
+        use crate::reader::FromXML;
+
+        let xml = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body><w:p>
+            <w:ins w:id="1" w:author="O'Brien &amp; Co" w:date="2024-02-01T00:00:00Z"><w:r><w:t>new </w:t></w:r></w:ins>
+            <w:ins w:id="2" w:author="alice" w:date="2024-02-01T00:00:00Z"><w:r><w:t>other </w:t></w:r></w:ins>
+            <w:r><w:t>world</w:t></w:r>
+        </w:p></w:body></w:document>"#;
+        let mut document = Document::from_xml(xml.as_bytes()).unwrap();
+        RevisionResolver::reject(RevisionFilter::new().author("O'Brien & Co"))
+            .resolve(&mut document);
+        let DocumentChild::Paragraph(p) = &document.children[0] else {
+            unreachable!()
+        };
+        assert_eq!(p.children.len(), 2);
+        assert!(matches!(&p.children[0], ParagraphChild::Insert(i) if i.author == "alice"));
+        assert_eq!(paragraph_text(p), "world");
+    }
+
+    #[test]
+    fn test_paragraph_marks() {
+        let document = Document::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("Hello "))
+                    .delete("bob", "2024-01-01T00:00:00Z"),
+            )
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("world")))
+            .add_paragraph(
+                Paragraph::new()
+                    .add_insert(Insert::new(Run::new().add_text("Added")).author("bob"))
+                    .insert("bob", "2024-01-01T00:00:00Z"),
+            );
+
+        let mut accepted = document.clone();
+        RevisionResolver::accept(RevisionFilter::new()).resolve(&mut accepted);
+        assert_eq!(document_texts(&accepted), vec!["Hello world", "Added"]);
+
+        let mut rejected = document;
+        RevisionResolver::reject(RevisionFilter::new()).resolve(&mut rejected);
+        assert_eq!(document_texts(&rejected), vec!["Hello ", "world"]);
+    }
+
+    #[test]
+    fn test_paragraph_property_change() {
+        let mut document = Document::new().add_paragraph(
+            Paragraph::new()
+                .align(AlignmentType::Center)
+                .paragraph_property_change(
+                    ParagraphPropertyChange::new()
+                        .author("bob")
+                        .property(ParagraphProperty::new().align(AlignmentType::Left)),
+                ),
+        );
+        RevisionResolver::reject(RevisionFilter::new()).resolve(&mut document);
+        let DocumentChild::Paragraph(p) = &document.children[0] else {
+            unreachable!()
+        };
+        assert!(p.property.paragraph_property_change.is_none());
+        assert_eq!(
+            p.property.alignment,
+            Some(Justification::new(AlignmentType::Left.to_string()))
+        );
+    }
+
+    #[test]
+    fn test_table_rows() {
+        let table = Table::new(vec![
+            TableRow::new(vec![TableCell::new()
+                .add_paragraph(Paragraph::new().add_run(Run::new().add_text("kept")))]),
+            TableRow::new(vec![TableCell::new().add_paragraph(
+                Paragraph::new().add_run(Run::new().add_text("inserted")),
+            )])
+            .insert(Insert::new_with_empty().author("bob")),
+        ]);
+
+        let mut accepted = Document::new().add_table(table.clone());
+        RevisionResolver::accept(RevisionFilter::new()).resolve(&mut accepted);
+        let DocumentChild::Table(t) = &accepted.children[0] else {
+            unreachable!()
+        };
+        assert_eq!(t.rows.len(), 2);
+        assert!(matches!(&t.rows[1], TableChild::TableRow(row) if row.property.ins.is_none()));
+
+        let mut rejected = Document::new().add_table(table);
+        RevisionResolver::reject(RevisionFilter::new()).resolve(&mut rejected);
+        let DocumentChild::Table(t) = &rejected.children[0] else {
+            unreachable!()
+        };
+        assert_eq!(t.rows.len(), 1);
+    }
+
+    #[test]
+    fn test_moves() {
+        let document = Document::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_move_from_range_start(MoveFromRangeStart::new(1, "move1").author("bob"))
+                    .add_move_from(
+                        MoveFrom::new()
+                            .add_run(Run::new().add_delete_text("Moved"))
+                            .author("bob"),
+                    )
+                    .add_move_from_range_end(1),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_move_to_range_start(MoveToRangeStart::new(2, "move1").author("bob"))
+                    .add_move_to(MoveTo::new(Run::new().add_text("Moved")).author("bob"))
+                    .add_move_to_range_end(2),
+            );
+
+        let mut accepted = document.clone();
+        RevisionResolver::accept(RevisionFilter::new()).resolve(&mut accepted);
+        assert_eq!(document_texts(&accepted), vec!["", "Moved"]);
+        let DocumentChild::Paragraph(p) = &accepted.children[1] else {
+            unreachable!()
+        };
+        assert_eq!(p.children.len(), 1);
+
+        let mut rejected = document;
+        RevisionResolver::reject(RevisionFilter::new()).resolve(&mut rejected);
+        assert_eq!(document_texts(&rejected), vec!["Moved", ""]);
+    }
+
+    #[test]
+    fn test_body_move_ranges_and_moved_paragraph_marks() {
+        let document = Document::new()
+            .add_move_from_range_start(MoveFromRangeStart::new(1, "move1").author("bob"))
+            .add_paragraph(
+                Paragraph::new()
+                    .add_move_from(
+                        MoveFrom::new()
+                            .add_run(Run::new().add_delete_text("Moved"))
+                            .author("bob"),
+                    )
+                    .run_property(RunProperty::new().move_from(MoveFrom::new().author("bob"))),
+            )
+            .add_move_from_range_end(1)
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Stay")))
+            .add_move_to_range_start(MoveToRangeStart::new(2, "move1").author("bob"))
+            .add_paragraph(
+                Paragraph::new()
+                    .add_move_to(MoveTo::new(Run::new().add_text("Moved")).author("bob"))
+                    .run_property(
+                        RunProperty::new().move_to(MoveTo::new_with_empty().author("bob")),
+                    ),
+            )
+            .add_move_to_range_end(2);
+
+        let mut accepted = document.clone();
+        RevisionResolver::accept(RevisionFilter::new()).resolve(&mut accepted);
+        assert_eq!(accepted.children.len(), 2);
+        assert_eq!(document_texts(&accepted), vec!["Stay", "Moved"]);
+
+        let mut rejected = document;
+        RevisionResolver::reject(RevisionFilter::new()).resolve(&mut rejected);
+        assert_eq!(rejected.children.len(), 2);
+        assert_eq!(document_texts(&rejected), vec!["Moved", "Stay"]);
+    }
+
+    #[test]
+    fn test_run_property_change() {
+        let mut document = Document::new().add_paragraph(
+            Paragraph::new().add_run(
+                Run::new()
+                    .add_text("Hello")
+                    .bold()
+                    .run_property_change(RunPropertyChange::new().author("bob")),
+            ),
+        );
+        RevisionResolver::reject(RevisionFilter::new()).resolve(&mut document);
+        let DocumentChild::Paragraph(p) = &document.children[0] else {
+            unreachable!()
+        };
+        assert!(
+            matches!(&p.children[0], ParagraphChild::Run(run) if run.run_property == RunProperty::new())
+        );
+    }
+
+    #[test]
+    fn test_cell_revisions() {
+        let table = Table::new(vec![TableRow::new(vec![
+            TableCell::new()
+                .add_paragraph(Paragraph::new().add_run(Run::new().add_text("inserted")))
+                .cell_insert(CellInsert::new().author("bob")),
+            TableCell::new()
+                .add_paragraph(Paragraph::new().add_run(Run::new().add_text("deleted")))
+                .cell_delete(CellDelete::new().author("bob")),
+        ])]);
+
+        let mut accepted = Document::new().add_table(table.clone());
+        RevisionResolver::accept(RevisionFilter::new()).resolve(&mut accepted);
+        let DocumentChild::Table(t) = &accepted.children[0] else {
+            unreachable!()
+        };
+        let TableChild::TableRow(row) = &t.rows[0] else {
+            unreachable!()
+        };
+        assert_eq!(row.cells.len(), 1);
+        let TableRowChild::TableCell(cell) = &row.cells[0];
+        assert!(cell.property.cell_insert.is_none());
+
+        let mut rejected = Document::new().add_table(table);
+        RevisionResolver::reject(RevisionFilter::new()).resolve(&mut rejected);
+        let DocumentChild::Table(t) = &rejected.children[0] else {
+            unreachable!()
+        };
+        let TableChild::TableRow(row) = &t.rows[0] else {
+            unreachable!()
+        };
+        assert_eq!(row.cells.len(), 1);
+        let TableRowChild::TableCell(cell) = &row.cells[0];
+        assert!(cell.property.cell_delete.is_none());
+    }
+}
@@ -3,7 +3,7 @@ use std::io::Write;
 use std::str::FromStr;
 
 use crate::documents::BuildXML;
-use crate::types::CharacterSpacingValues;
+use crate::types::{CharacterSpacingValues, DocumentProtectionType, ProofStateType};
 use crate::xml_builder::*;
 
 use serde::{Deserialize, Deserializer, Serialize};
@@ -19,6 +19,37 @@ pub struct Settings {
     adjust_line_height_in_table: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     character_spacing_control: Option<CharacterSpacingValues>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    embed_true_type_fonts: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    mirror_margins: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    gutter_at_top: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    book_fold_printing: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    print_two_on_one: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    update_fields: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    track_revisions: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    document_protection: Option<DocumentProtection>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    auto_hyphenation: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hyphenation_zone: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_table_style: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    theme_font_lang: Option<ThemeFontLang>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    decimal_symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    list_separator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proof_state: Option<ProofState>,
+    compat: Compat,
 }
 
 // ============================================================================
@@ -49,10 +80,130 @@ enum SettingsChildXml {
     AdjustLineHeightInTable(SettingsOnOffXml),
     #[serde(rename = "characterSpacingControl", alias = "w:characterSpacingControl")]
     CharacterSpacingControl(SettingsValueXml),
+    #[serde(rename = "embedTrueTypeFonts", alias = "w:embedTrueTypeFonts")]
+    EmbedTrueTypeFonts(SettingsOnOffXml),
+    #[serde(rename = "mirrorMargins", alias = "w:mirrorMargins")]
+    MirrorMargins(SettingsOnOffXml),
+    #[serde(rename = "gutterAtTop", alias = "w:gutterAtTop")]
+    GutterAtTop(SettingsOnOffXml),
+    #[serde(rename = "bookFoldPrinting", alias = "w:bookFoldPrinting")]
+    BookFoldPrinting(SettingsOnOffXml),
+    #[serde(rename = "printTwoOnOne", alias = "w:printTwoOnOne")]
+    PrintTwoOnOne(SettingsOnOffXml),
+    #[serde(rename = "updateFields", alias = "w:updateFields")]
+    UpdateFields(SettingsOnOffXml),
+    #[serde(rename = "trackRevisions", alias = "w:trackRevisions")]
+    TrackRevisions(SettingsOnOffXml),
+    #[serde(rename = "documentProtection", alias = "w:documentProtection")]
+    DocumentProtection(SettingsDocumentProtectionXml),
+    #[serde(rename = "autoHyphenation", alias = "w:autoHyphenation")]
+    AutoHyphenation(SettingsOnOffXml),
+    #[serde(rename = "hyphenationZone", alias = "w:hyphenationZone")]
+    HyphenationZone(SettingsValueXml),
+    #[serde(rename = "defaultTableStyle", alias = "w:defaultTableStyle")]
+    DefaultTableStyle(SettingsValueXml),
+    #[serde(rename = "themeFontLang", alias = "w:themeFontLang")]
+    ThemeFontLang(SettingsThemeFontLangXml),
+    #[serde(rename = "decimalSymbol", alias = "w:decimalSymbol")]
+    DecimalSymbol(SettingsValueXml),
+    #[serde(rename = "listSeparator", alias = "w:listSeparator")]
+    ListSeparator(SettingsValueXml),
+    #[serde(rename = "proofState", alias = "w:proofState")]
+    ProofState(SettingsProofStateXml),
+    #[serde(rename = "compat", alias = "w:compat")]
+    Compat(SettingsCompatXml),
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct SettingsCompatXml {
+    #[serde(rename = "$value", default)]
+    children: Vec<SettingsCompatChildXml>,
+}
+
+#[derive(Debug, Deserialize)]
+enum SettingsCompatChildXml {
+    #[serde(rename = "spaceForUL", alias = "w:spaceForUL")]
+    SpaceForUl(SettingsOnOffXml),
+    #[serde(
+        rename = "balanceSingleByteDoubleByteWidth",
+        alias = "w:balanceSingleByteDoubleByteWidth"
+    )]
+    BalanceSingleByteDoubleByteWidth(SettingsOnOffXml),
+    #[serde(rename = "doNotLeaveBackslashAlone", alias = "w:doNotLeaveBackslashAlone")]
+    DoNotLeaveBackslashAlone(SettingsOnOffXml),
+    #[serde(rename = "ulTrailSpace", alias = "w:ulTrailSpace")]
+    UlTrailSpace(SettingsOnOffXml),
+    #[serde(rename = "doNotExpandShiftReturn", alias = "w:doNotExpandShiftReturn")]
+    DoNotExpandShiftReturn(SettingsOnOffXml),
+    #[serde(rename = "adjustLineHeightInTable", alias = "w:adjustLineHeightInTable")]
+    AdjustLineHeightInTable(SettingsOnOffXml),
+    #[serde(rename = "characterSpacingControl", alias = "w:characterSpacingControl")]
+    CharacterSpacingControl(SettingsValueXml),
+    #[serde(rename = "useFELayout", alias = "w:useFELayout")]
+    UseFeLayout(SettingsOnOffXml),
+    #[serde(rename = "compatSetting", alias = "w:compatSetting")]
+    CompatSetting(SettingsCompatSettingXml),
     #[serde(other)]
     Unknown,
 }
 
+#[derive(Debug, Deserialize, Default)]
+struct SettingsCompatSettingXml {
+    #[serde(rename = "@name", alias = "@w:name", default)]
+    name: Option<String>,
+    #[serde(rename = "@uri", alias = "@w:uri", default)]
+    uri: Option<String>,
+    #[serde(rename = "@val", alias = "@w:val", default)]
+    val: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct SettingsThemeFontLangXml {
+    #[serde(rename = "@val", alias = "@w:val", default)]
+    val: Option<String>,
+    #[serde(rename = "@eastAsia", alias = "@w:eastAsia", default)]
+    east_asia: Option<String>,
+    #[serde(rename = "@bidi", alias = "@w:bidi", default)]
+    bidi: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct SettingsProofStateXml {
+    #[serde(rename = "@spelling", alias = "@w:spelling", default)]
+    spelling: Option<String>,
+    #[serde(rename = "@grammar", alias = "@w:grammar", default)]
+    grammar: Option<String>,
+}
+
+// Both the transitional crypt* attributes and the newer algorithmName/hashValue/saltValue/spinCount form.
+#[derive(Debug, Deserialize, Default)]
+struct SettingsDocumentProtectionXml {
+    #[serde(rename = "@edit", alias = "@w:edit", default)]
+    edit: Option<String>,
+    #[serde(rename = "@formatting", alias = "@w:formatting", default)]
+    formatting: Option<String>,
+    #[serde(rename = "@enforcement", alias = "@w:enforcement", default)]
+    enforcement: Option<String>,
+    #[serde(rename = "@cryptAlgorithmSid", alias = "@w:cryptAlgorithmSid", default)]
+    crypt_algorithm_sid: Option<String>,
+    #[serde(rename = "@algorithmName", alias = "@w:algorithmName", default)]
+    algorithm_name: Option<String>,
+    #[serde(rename = "@cryptSpinCount", alias = "@w:cryptSpinCount", default)]
+    crypt_spin_count: Option<String>,
+    #[serde(rename = "@spinCount", alias = "@w:spinCount", default)]
+    spin_count: Option<String>,
+    #[serde(rename = "@hash", alias = "@w:hash", default)]
+    hash: Option<String>,
+    #[serde(rename = "@hashValue", alias = "@w:hashValue", default)]
+    hash_value: Option<String>,
+    #[serde(rename = "@salt", alias = "@w:salt", default)]
+    salt: Option<String>,
+    #[serde(rename = "@saltValue", alias = "@w:saltValue", default)]
+    salt_value: Option<String>,
+}
+
 #[derive(Debug, Deserialize, Default)]
 struct SettingsDefaultTabStopXml {
     #[serde(rename = "@val", alias = "@w:val", default)]
@@ -103,6 +254,29 @@ struct SettingsValueXml {
     val: Option<String>,
 }
 
+impl SettingsOnOffXml {
+    fn is_on(&self) -> bool {
+        self.val.as_deref().map(parse_on_off).unwrap_or(true)
+    }
+}
+
+fn parse_on_off(v: &str) -> bool {
+    let normalized = v.trim().to_ascii_lowercase();
+    normalized != "0" && normalized != "false" && normalized != "off"
+}
+
+// Map algorithmName onto the matching cryptAlgorithmSid.
+fn algorithm_name_to_sid(name: &str) -> Option<usize> {
+    match name.to_ascii_uppercase().as_str() {
+        "MD5" => Some(3),
+        "SHA-1" => Some(4),
+        "SHA-256" => Some(12),
+        "SHA-384" => Some(13),
+        "SHA-512" => Some(14),
+        _ => None,
+    }
+}
+
 fn normalize_doc_id(raw: Option<String>) -> Option<String> {
     raw.map(|v| v.replace(['{', '}'], "")).and_then(|v| {
         let trimmed = v.trim();
@@ -152,22 +326,10 @@ impl<'de> Deserialize<'de> for Settings {
                     doc_vars_direct.push(node);
                 }
                 SettingsChildXml::EvenAndOddHeaders(node) => {
-                    settings.even_and_odd_headers = node
-                        .val
-                        .map(|v| {
-                            let normalized = v.trim().to_ascii_lowercase();
-                            normalized != "0" && normalized != "false"
-                        })
-                        .unwrap_or(true);
+                    settings.even_and_odd_headers = node.is_on();
                 }
                 SettingsChildXml::AdjustLineHeightInTable(node) => {
-                    settings.adjust_line_height_in_table = node
-                        .val
-                        .map(|v| {
-                            let normalized = v.trim().to_ascii_lowercase();
-                            normalized != "0" && normalized != "false"
-                        })
-                        .unwrap_or(true);
+                    settings.adjust_line_height_in_table = node.is_on();
                 }
                 SettingsChildXml::CharacterSpacingControl(node) => {
                     if let Some(val) = node
@@ -177,6 +339,128 @@ impl<'de> Deserialize<'de> for Settings {
                         settings.character_spacing_control = Some(val);
                     }
                 }
+                SettingsChildXml::EmbedTrueTypeFonts(node) => {
+                    settings.embed_true_type_fonts = node.is_on();
+                }
+                SettingsChildXml::MirrorMargins(node) => {
+                    settings.mirror_margins = node.is_on();
+                }
+                SettingsChildXml::GutterAtTop(node) => {
+                    settings.gutter_at_top = node.is_on();
+                }
+                SettingsChildXml::BookFoldPrinting(node) => {
+                    settings.book_fold_printing = node.is_on();
+                }
+                SettingsChildXml::PrintTwoOnOne(node) => {
+                    settings.print_two_on_one = node.is_on();
+                }
+                SettingsChildXml::UpdateFields(node) => {
+                    settings.update_fields = node.is_on();
+                }
+                SettingsChildXml::TrackRevisions(node) => {
+                    settings.track_revisions = node.is_on();
+                }
+                SettingsChildXml::DocumentProtection(node) => {
+                    let edit = node
+                        .edit
+                        .and_then(|v| DocumentProtectionType::from_str(&v).ok())
+                        .unwrap_or(DocumentProtectionType::None);
+                    let mut protection = DocumentProtection::new(edit)
+                        .enforcement(node.enforcement.as_deref().is_some_and(parse_on_off));
+                    protection.formatting = node.formatting.as_deref().map(parse_on_off);
+                    protection.crypt_algorithm_sid = node
+                        .crypt_algorithm_sid
+                        .and_then(|v| v.parse::<usize>().ok())
+                        .or_else(|| {
+                            node.algorithm_name
+                                .as_deref()
+                                .and_then(algorithm_name_to_sid)
+                        });
+                    protection.spin_count = node
+                        .crypt_spin_count
+                        .or(node.spin_count)
+                        .and_then(|v| v.parse::<usize>().ok());
+                    protection.hash = node.hash.or(node.hash_value);
+                    protection.salt = node.salt.or(node.salt_value);
+                    settings.document_protection = Some(protection);
+                }
+                SettingsChildXml::AutoHyphenation(node) => {
+                    settings.auto_hyphenation = node.is_on();
+                }
+                SettingsChildXml::HyphenationZone(node) => {
+                    settings.hyphenation_zone = node
+                        .val
+                        .and_then(|v| v.parse::<f32>().ok().map(|v| v as usize));
+                }
+                SettingsChildXml::DefaultTableStyle(node) => {
+                    settings.default_table_style = node.val;
+                }
+                SettingsChildXml::ThemeFontLang(node) => {
+                    settings.theme_font_lang = Some(ThemeFontLang {
+                        val: node.val,
+                        east_asia: node.east_asia,
+                        bidi: node.bidi,
+                    });
+                }
+                SettingsChildXml::DecimalSymbol(node) => {
+                    settings.decimal_symbol = node.val;
+                }
+                SettingsChildXml::ListSeparator(node) => {
+                    settings.list_separator = node.val;
+                }
+                SettingsChildXml::ProofState(node) => {
+                    settings.proof_state = Some(ProofState {
+                        spelling: node
+                            .spelling
+                            .and_then(|v| ProofStateType::from_str(&v).ok()),
+                        grammar: node.grammar.and_then(|v| ProofStateType::from_str(&v).ok()),
+                    });
+                }
+                SettingsChildXml::Compat(node) => {
+                    let mut compat = Compat::empty();
+                    for child in node.children {
+                        match child {
+                            SettingsCompatChildXml::SpaceForUl(node) => {
+                                compat.space_for_ul = node.is_on();
+                            }
+                            SettingsCompatChildXml::BalanceSingleByteDoubleByteWidth(node) => {
+                                compat.balance_single_byte_double_byte_width = node.is_on();
+                            }
+                            SettingsCompatChildXml::DoNotLeaveBackslashAlone(node) => {
+                                compat.do_not_leave_backslash_alone = node.is_on();
+                            }
+                            SettingsCompatChildXml::UlTrailSpace(node) => {
+                                compat.ul_trail_space = node.is_on();
+                            }
+                            SettingsCompatChildXml::DoNotExpandShiftReturn(node) => {
+                                compat.do_not_expand_shift_return = node.is_on();
+                            }
+                            SettingsCompatChildXml::AdjustLineHeightInTable(node) => {
+                                settings.adjust_line_height_in_table = node.is_on();
+                            }
+                            SettingsCompatChildXml::CharacterSpacingControl(node) => {
+                                if let Some(val) = node
+                                    .val
+                                    .and_then(|v| CharacterSpacingValues::from_str(&v).ok())
+                                {
+                                    settings.character_spacing_control = Some(val);
+                                }
+                            }
+                            SettingsCompatChildXml::UseFeLayout(node) => {
+                                compat.use_fe_layout = node.is_on();
+                            }
+                            SettingsCompatChildXml::CompatSetting(node) => {
+                                if let (Some(name), Some(uri), Some(val)) =
+                                    (node.name, node.uri, node.val)
+                                {
+                                    compat = compat.add_setting(name, uri, val);
+                                }
+                            }
+                            SettingsCompatChildXml::Unknown => {}
+                        }
+                    }
+                    settings.compat = compat;
+                }
                 SettingsChildXml::Unknown => {}
             }
         }
@@ -230,6 +514,13 @@ impl Settings {
         self
     }
 
+    pub fn doc_var(&self, name: &str) -> Option<&str> {
+        self.doc_vars
+            .iter()
+            .find(|v| v.name.eq_ignore_ascii_case(name))
+            .map(|v| v.val.as_str())
+    }
+
     pub fn even_and_odd_headers(mut self) -> Self {
         self.even_and_odd_headers = true;
         self
@@ -244,6 +535,96 @@ impl Settings {
         self.character_spacing_control = Some(val);
         self
     }
+
+    // Set automatically when the docx has embedded fonts.
+    pub fn embed_true_type_fonts(mut self) -> Self {
+        self.embed_true_type_fonts = true;
+        self
+    }
+
+    // Swap the inside and outside margins of facing pages.
+    pub fn mirror_margins(mut self) -> Self {
+        self.mirror_margins = true;
+        self
+    }
+
+    // Place the gutter at the top of the page instead of the side.
+    pub fn gutter_at_top(mut self) -> Self {
+        self.gutter_at_top = true;
+        self
+    }
+
+    pub fn book_fold_printing(mut self) -> Self {
+        self.book_fold_printing = true;
+        self
+    }
+
+    pub fn print_two_on_one(mut self) -> Self {
+        self.print_two_on_one = true;
+        self
+    }
+
+    // Ask Word to update all fields, e.g. TOC or PAGEREF, when the document is opened.
+    pub fn update_fields(mut self) -> Self {
+        self.update_fields = true;
+        self
+    }
+
+    pub fn track_revisions(mut self) -> Self {
+        self.track_revisions = true;
+        self
+    }
+
+    pub fn document_protection(mut self, protection: DocumentProtection) -> Self {
+        self.document_protection = Some(protection);
+        self
+    }
+
+    pub fn auto_hyphenation(mut self) -> Self {
+        self.auto_hyphenation = true;
+        self
+    }
+
+    // In twips.
+    pub fn hyphenation_zone(mut self, zone: usize) -> Self {
+        self.hyphenation_zone = Some(zone);
+        self
+    }
+
+    pub fn default_table_style(mut self, style_id: impl Into<String>) -> Self {
+        self.default_table_style = Some(style_id.into());
+        self
+    }
+
+    pub fn theme_font_lang(mut self, lang: ThemeFontLang) -> Self {
+        self.theme_font_lang = Some(lang);
+        self
+    }
+
+    pub fn decimal_symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.decimal_symbol = Some(symbol.into());
+        self
+    }
+
+    pub fn list_separator(mut self, separator: impl Into<String>) -> Self {
+        self.list_separator = Some(separator.into());
+        self
+    }
+
+    pub fn proof_state(mut self, state: ProofState) -> Self {
+        self.proof_state = Some(state);
+        self
+    }
+
+    pub fn compat(mut self, compat: Compat) -> Self {
+        self.compat = compat;
+        self
+    }
+
+    pub fn compatibility_mode(mut self, mode: usize) -> Self {
+        self.compat = self.compat.compatibility_mode(mode);
+        self
+    }
 }
 
 impl Default for Settings {
@@ -256,6 +637,22 @@ impl Default for Settings {
             even_and_odd_headers: false,
             adjust_line_height_in_table: false,
             character_spacing_control: None,
+            embed_true_type_fonts: false,
+            mirror_margins: false,
+            gutter_at_top: false,
+            book_fold_printing: false,
+            print_two_on_one: false,
+            update_fields: false,
+            track_revisions: false,
+            document_protection: None,
+            auto_hyphenation: false,
+            hyphenation_zone: None,
+            default_table_style: None,
+            theme_font_lang: None,
+            decimal_symbol: None,
+            list_separator: None,
+            proof_state: None,
+            compat: Compat::new(),
         }
     }
 }
@@ -270,55 +667,49 @@ impl BuildXML for Settings {
             .open_settings()?
             .add_child(&self.default_tab_stop)?
             .add_child(&self.zoom)?
+            .apply_if(self.embed_true_type_fonts, |b| b.embed_true_type_fonts())?
+            .apply_if(self.mirror_margins, |b| b.mirror_margins())?
+            .apply_if(self.gutter_at_top, |b| b.gutter_at_top())?
+            .add_optional_child(&self.proof_state)?
+            .apply_if(self.track_revisions, |b| b.track_revisions())?
+            .add_optional_child(&self.document_protection)?
+            .apply_if(self.auto_hyphenation, |b| b.auto_hyphenation())?
+            .apply_opt(self.hyphenation_zone, |v, b| b.hyphenation_zone(v))?
+            .apply_opt(self.default_table_style.as_ref(), |v, b| {
+                b.default_table_style(v)
+            })?
+            .apply_if(self.even_and_odd_headers, |b| b.even_and_odd_headers())?
+            .apply_if(self.book_fold_printing, |b| b.book_fold_printing())?
+            .apply_if(self.print_two_on_one, |b| b.print_two_on_one())?
+            .apply_if(self.update_fields, |b| b.update_fields("true"))?
             .open_compat()?
-            .space_for_ul()?
-            .balance_single_byte_double_byte_width()?
-            .do_not_leave_backslash_alone()?
-            .ul_trail_space()?
-            .do_not_expand_shift_return()?
+            .apply_if(self.compat.space_for_ul, |b| b.space_for_ul())?
+            .apply_if(self.compat.balance_single_byte_double_byte_width, |b| {
+                b.balance_single_byte_double_byte_width()
+            })?
+            .apply_if(self.compat.do_not_leave_backslash_alone, |b| {
+                b.do_not_leave_backslash_alone()
+            })?
+            .apply_if(self.compat.ul_trail_space, |b| b.ul_trail_space())?
+            .apply_if(self.compat.do_not_expand_shift_return, |b| {
+                b.do_not_expand_shift_return()
+            })?
             .apply_opt(self.character_spacing_control, |v, b| {
                 b.character_spacing_control(&v.to_string())
             })?
             .apply_if(self.adjust_line_height_in_table, |b| {
                 b.adjust_line_height_table()
             })?
-            .use_fe_layout()?
-            .compat_setting(
-                "compatibilityMode",
-                "http://schemas.microsoft.com/office/word",
-                "15",
-            )?
-            .compat_setting(
-                "overrideTableStyleFontSizeAndJustification",
-                "http://schemas.microsoft.com/office/word",
-                "1",
-            )?
-            .compat_setting(
-                "enableOpenTypeFeatures",
-                "http://schemas.microsoft.com/office/word",
-                "1",
-            )?
-            .compat_setting(
-                "doNotFlipMirrorIndents",
-                "http://schemas.microsoft.com/office/word",
-                "1",
-            )?
-            .compat_setting(
-                "differentiateMultirowTableHeaders",
-                "http://schemas.microsoft.com/office/word",
-                "1",
-            )?
-            .compat_setting(
-                "useWord2013TrackBottomHyphenation",
-                "http://schemas.microsoft.com/office/word",
-                "0",
-            )?
+            .apply_if(self.compat.use_fe_layout, |b| b.use_fe_layout())?
+            .add_children(&self.compat.settings)?
             .close()?
             .add_optional_child(&self.doc_id)?
             .apply_if(!self.doc_vars.is_empty(), |b| {
                 b.open_doc_vars()?.add_children(&self.doc_vars)?.close()
             })?
-            .apply_if(self.even_and_odd_headers, |b| b.even_and_odd_headers())?
+            .add_optional_child(&self.theme_font_lang)?
+            .apply_opt(self.decimal_symbol.as_ref(), |v, b| b.decimal_symbol(v))?
+            .apply_opt(self.list_separator.as_ref(), |v, b| b.list_separator(v))?
             .close()?
             .into_inner()
     }
@@ -376,4 +767,107 @@ mod tests {
             Some(CharacterSpacingValues::CompressPunctuation)
         );
     }
+
+    #[test]
+    fn test_settings_page_setup() {
+        let c = Settings::new()
+            .mirror_margins()
+            .gutter_at_top()
+            .book_fold_printing()
+            .print_two_on_one();
+        let b = str::from_utf8(&c.build()).unwrap().to_owned();
+        assert!(b.contains(
+            r#"<w:zoom w:percent="100" /><w:mirrorMargins /><w:gutterAtTop /><w:bookFoldPrinting /><w:printTwoOnOne /><w:compat>"#
+        ));
+    }
+
+    #[test]
+    fn test_settings_deserialize_page_setup() {
+        let xml = r#"<w:settings xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:mirrorMargins/><w:gutterAtTop w:val="true"/><w:bookFoldPrinting w:val="0"/><w:printTwoOnOne/></w:settings>"#;
+        let settings: Settings = from_str(xml).unwrap();
+        assert!(settings.mirror_margins);
+        assert!(settings.gutter_at_top);
+        assert!(!settings.book_fold_printing);
+        assert!(settings.print_two_on_one);
+    }
+
+    #[test]
+    fn test_settings_update_fields_and_locale() {
+        let c = Settings::new()
+            .proof_state(ProofState::new().spelling(ProofStateType::Clean))
+            .track_revisions()
+            .auto_hyphenation()
+            .hyphenation_zone(357)
+            .default_table_style("TableGrid")
+            .update_fields()
+            .theme_font_lang(ThemeFontLang::new("en-US").east_asia("ja-JP"))
+            .decimal_symbol(".")
+            .list_separator(",");
+        let b = str::from_utf8(&c.build()).unwrap().to_owned();
+        assert!(b.contains(
+            r#"<w:zoom w:percent="100" /><w:proofState w:spelling="clean" /><w:trackRevisions /><w:autoHyphenation /><w:hyphenationZone w:val="357" /><w:defaultTableStyle w:val="TableGrid" /><w:updateFields w:val="true" /><w:compat>"#
+        ));
+        assert!(b.ends_with(
+            r#"</w:compat><w:themeFontLang w:val="en-US" w:eastAsia="ja-JP" /><w:decimalSymbol w:val="." /><w:listSeparator w:val="," /></w:settings>"#
+        ));
+    }
+
+    #[test]
+    fn test_settings_deserialize_update_fields_and_compat() {
+        let xml = r#"<w:settings xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:proofState w:spelling="clean" w:grammar="dirty"/><w:trackRevisions/><w:autoHyphenation w:val="false"/><w:hyphenationZone w:val="425"/><w:defaultTableStyle w:val="TableGrid"/><w:updateFields w:val="true"/><w:compat><w:useFELayout/><w:adjustLineHeightInTable/><w:compatSetting w:name="compatibilityMode" w:uri="http://schemas.microsoft.com/office/word" w:val="14"/></w:compat><w:themeFontLang w:val="en-US" w:bidi="ar-SA"/><w:decimalSymbol w:val=","/><w:listSeparator w:val=";"/></w:settings>"#;
+        let settings: Settings = from_str(xml).unwrap();
+        assert_eq!(
+            settings.proof_state,
+            Some(
+                ProofState::new()
+                    .spelling(ProofStateType::Clean)
+                    .grammar(ProofStateType::Dirty)
+            )
+        );
+        assert!(settings.track_revisions);
+        assert!(!settings.auto_hyphenation);
+        assert_eq!(settings.hyphenation_zone, Some(425));
+        assert_eq!(settings.default_table_style, Some("TableGrid".to_owned()));
+        assert!(settings.update_fields);
+        assert!(settings.adjust_line_height_in_table);
+        assert!(settings.compat.use_fe_layout);
+        assert!(!settings.compat.space_for_ul);
+        assert_eq!(settings.compat.get_compatibility_mode(), Some(14));
+        assert_eq!(
+            settings.theme_font_lang,
+            Some(ThemeFontLang::new("en-US").bidi("ar-SA"))
+        );
+        assert_eq!(settings.decimal_symbol, Some(",".to_owned()));
+        assert_eq!(settings.list_separator, Some(";".to_owned()));
+
+        let b = str::from_utf8(&settings.build()).unwrap().to_owned();
+        assert!(b.contains(
+            r#"<w:compat><w:adjustLineHeightInTable /><w:useFELayout /><w:compatSetting w:name="compatibilityMode" w:uri="http://schemas.microsoft.com/office/word" w:val="14" /></w:compat>"#
+        ));
+    }
+
+    #[test]
+    fn test_settings_document_protection() {
+        let c = Settings::new()
+            .track_revisions()
+            .document_protection(DocumentProtection::new(DocumentProtectionType::ReadOnly));
+        let b = str::from_utf8(&c.build()).unwrap().to_owned();
+        assert!(b.contains(
+            r#"<w:trackRevisions /><w:documentProtection w:edit="readOnly" w:enforcement="1" /><w:compat>"#
+        ));
+    }
+
+    #[test]
+    fn test_settings_deserialize_document_protection() {
+        let xml = r#"<w:settings xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:documentProtection w:edit="forms" w:formatting="1" w:enforcement="true" w:algorithmName="SHA-512" w:hashValue="aGFzaA==" w:saltValue="c2FsdA==" w:spinCount="100000"/></w:settings>"#;
+        let settings: Settings = from_str(xml).unwrap();
+        let protection = settings.document_protection.unwrap();
+        assert_eq!(protection.edit, DocumentProtectionType::Forms);
+        assert!(protection.enforcement);
+        assert_eq!(protection.formatting, Some(true));
+        assert_eq!(protection.crypt_algorithm_sid, Some(14));
+        assert_eq!(protection.spin_count, Some(100000));
+        assert_eq!(protection.hash, Some("aGFzaA==".to_owned()));
+        assert_eq!(protection.salt, Some("c2FsdA==".to_owned()));
+    }
 }
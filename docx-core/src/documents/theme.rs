@@ -1,13 +1,94 @@
 use serde::{Deserialize, Deserializer, Serialize};
+use std::io::Write;
 
 use super::*;
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
 
-#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+/// The document theme written to `word/theme/theme1.xml`.
+/// `Theme::new()` is the default Office theme.
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
 #[cfg_attr(feature = "wasm", ts(export))]
 #[serde(rename_all = "camelCase")]
 pub struct Theme {
+    pub name: String,
+    pub color_scheme: ColorScheme,
     pub font_schema: FontScheme,
+    pub format_scheme: FormatScheme,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            name: "Office Theme".to_owned(),
+            color_scheme: ColorScheme::default(),
+            font_schema: FontScheme::default(),
+            format_scheme: FormatScheme::default(),
+        }
+    }
+}
+
+impl Theme {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn color_scheme(mut self, s: ColorScheme) -> Self {
+        self.color_scheme = s;
+        self
+    }
+
+    pub fn font_scheme(mut self, s: FontScheme) -> Self {
+        self.font_schema = s;
+        self
+    }
+
+    pub fn format_scheme(mut self, s: FormatScheme) -> Self {
+        self.format_scheme = s;
+        self
+    }
+
+    pub fn color(mut self, t: ThemeColorType, val: impl Into<String>) -> Self {
+        self.color_scheme = self.color_scheme.color(t, val);
+        self
+    }
+
+    pub fn major_font(mut self, latin: impl Into<String>) -> Self {
+        self.font_schema.major_font.latin = latin.into();
+        self
+    }
+
+    pub fn minor_font(mut self, latin: impl Into<String>) -> Self {
+        self.font_schema.minor_font.latin = latin.into();
+        self
+    }
+}
+
+impl BuildXML for Theme {
+    fn build_to<W: Write>(
+        &self,
+        stream: xml::writer::EventWriter<W>,
+    ) -> xml::writer::Result<xml::writer::EventWriter<W>> {
+        XMLBuilder::from(stream)
+            .declaration(Some(true))?
+            .open_theme(&self.name)?
+            .open_theme_elements()?
+            .add_child(&self.color_scheme)?
+            .add_child(&self.font_schema)?
+            .add_child(&self.format_scheme)?
+            .close()?
+            .object_defaults()?
+            .extra_clr_scheme_lst()?
+            .close()?
+            .into_inner()
+    }
 }
 
 // ============================================================================
@@ -16,12 +97,18 @@ pub struct Theme {
 
 #[derive(Deserialize, Default)]
 struct ThemeElementsXml {
+    #[serde(rename = "clrScheme", alias = "a:clrScheme", default)]
+    color_scheme: Option<ColorScheme>,
     #[serde(rename = "fontScheme", alias = "a:fontScheme", default)]
     font_scheme: Option<FontScheme>,
+    #[serde(rename = "fmtScheme", alias = "a:fmtScheme", default)]
+    format_scheme: Option<FormatScheme>,
 }
 
 #[derive(Deserialize, Default)]
 struct ThemeXml {
+    #[serde(rename = "@name", default)]
+    name: Option<String>,
     #[serde(rename = "themeElements", alias = "a:themeElements", default)]
     theme_elements: ThemeElementsXml,
 }
@@ -32,8 +119,33 @@ impl<'de> Deserialize<'de> for Theme {
         D: Deserializer<'de>,
     {
         let xml = ThemeXml::deserialize(deserializer)?;
+        let elements = xml.theme_elements;
         Ok(Theme {
-            font_schema: xml.theme_elements.font_scheme.unwrap_or_default(),
+            name: xml.name.unwrap_or_else(|| "Office Theme".to_owned()),
+            color_scheme: elements.color_scheme.unwrap_or_default(),
+            font_schema: elements.font_scheme.unwrap_or_default(),
+            format_scheme: elements.format_scheme.unwrap_or_default(),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::reader::FromXML;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_theme_roundtrip() {
+        let theme = Theme::new()
+            .name("Corporate")
+            .color(ThemeColorType::Accent1, "C00000")
+            .major_font("Georgia")
+            .minor_font("Verdana");
+        let b = theme.build();
+        let read = Theme::from_xml(&b[..]).unwrap();
+        assert_eq!(read, theme);
+    }
+}
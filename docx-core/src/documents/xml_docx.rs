@@ -15,6 +15,9 @@ pub struct XMLDocx {
     pub document_rels: Vec<u8>,
     pub settings: Vec<u8>,
     pub font_table: Vec<u8>,
+    pub font_table_rels: Option<Vec<u8>>,
+    pub fonts: Vec<(String, Vec<u8>)>,
+    pub theme: Vec<u8>,
     pub numberings: Vec<u8>,
     pub media: Vec<(String, Vec<u8>)>,
     pub headers: Vec<Vec<u8>>,
@@ -22,6 +25,9 @@ pub struct XMLDocx {
     pub footers: Vec<Vec<u8>>,
     pub footer_rels: Vec<Vec<u8>>,
     pub comments_extended: Vec<u8>,
+    pub comments_ids: Option<Vec<u8>>,
+    pub comments_extensible: Option<Vec<u8>>,
+    pub people: Option<Vec<u8>>,
     pub taskpanes: Option<Vec<u8>>,
     pub taskpanes_rels: Vec<u8>,
     pub web_extensions: Vec<Vec<u8>>,
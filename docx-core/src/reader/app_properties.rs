@@ -0,0 +1,81 @@
+use serde::Deserialize;
+use std::io::{BufReader, Read};
+
+use super::*;
+use crate::reader::{FromXML, FromXMLQuickXml, ReaderError};
+
+// ============================================================================
+// XML Deserialization DTOs (quick-xml serde)
+// ============================================================================
+
+// Statistics are not read since they are computed again when the docx is built.
+#[derive(Deserialize, Default)]
+struct AppPropertiesXml {
+    #[serde(rename = "Application", default)]
+    application: Option<String>,
+    #[serde(rename = "AppVersion", default)]
+    app_version: Option<String>,
+    #[serde(rename = "Company", default)]
+    company: Option<String>,
+    #[serde(rename = "Manager", default)]
+    manager: Option<String>,
+    #[serde(rename = "HyperlinkBase", default)]
+    hyperlink_base: Option<String>,
+    #[serde(rename = "Template", default)]
+    template: Option<String>,
+}
+
+impl FromXMLQuickXml for AppProps {
+    fn from_xml_quick<R: Read>(reader: R) -> Result<Self, ReaderError> {
+        let xml: AppPropertiesXml = quick_xml::de::from_reader(BufReader::new(reader))?;
+        let mut props = AppProps::new();
+        if let Some(v) = xml.application {
+            props = props.application(v);
+        }
+        if let Some(v) = xml.app_version {
+            props = props.app_version(v);
+        }
+        if let Some(v) = xml.company {
+            props = props.company(v);
+        }
+        if let Some(v) = xml.manager {
+            props = props.manager(v);
+        }
+        if let Some(v) = xml.hyperlink_base {
+            props = props.hyperlink_base(v);
+        }
+        if let Some(v) = xml.template {
+            props = props.template(v);
+        }
+        Ok(props)
+    }
+}
+
+impl FromXML for AppProps {
+    fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
+        Self::from_xml_quick(reader)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_app_props_from_xml() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/extended-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes"><Template>Normal.dotm</Template><TotalTime>0</TotalTime><Pages>1</Pages><Words>2</Words><Characters>10</Characters><Application>Microsoft Office Word</Application><DocSecurity>0</DocSecurity><Lines>1</Lines><Paragraphs>1</Paragraphs><ScaleCrop>false</ScaleCrop><Company>ACME</Company><LinksUpToDate>false</LinksUpToDate><CharactersWithSpaces>11</CharactersWithSpaces><SharedDoc>false</SharedDoc><HyperlinksChanged>false</HyperlinksChanged><AppVersion>16.0000</AppVersion></Properties>"#;
+        let props = AppProps::from_xml(xml.as_bytes()).unwrap();
+        assert_eq!(
+            props,
+            AppProps::new()
+                .template("Normal.dotm")
+                .application("Microsoft Office Word")
+                .company("ACME")
+                .app_version("16.0000")
+        );
+    }
+}
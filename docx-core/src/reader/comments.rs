@@ -15,3 +15,102 @@ impl FromXML for Comments {
         Self::from_xml_quick(reader)
     }
 }
+
+// Apply the resolved state from commentsExtended, and the durable ids and UTC dates
+// from commentsIds and commentsExtensible to the read comments.
+pub(crate) fn apply_comment_parts(
+    comments: &mut [Comment],
+    comments_extended: &CommentsExtended,
+    comments_ids: &CommentsIds,
+    comments_extensible: &CommentsExtensible,
+) {
+    for comment in comments.iter_mut() {
+        let para_ids: Vec<String> = comment
+            .children
+            .iter()
+            .filter_map(|c| match c {
+                CommentChild::Paragraph(p) => Some(p.id.clone()),
+                CommentChild::Table(_) => None,
+            })
+            .collect();
+        if let Some(ex) = comments_extended
+            .children
+            .iter()
+            .find(|ex| para_ids.contains(&ex.paragraph_id))
+        {
+            comment.done = ex.done;
+        }
+        if let Some(id) = para_ids
+            .iter()
+            .find_map(|para_id| comments_ids.find_by_paragraph_id(para_id))
+        {
+            comment.durable_id = Some(id.durable_id.clone());
+            if let Some(date_utc) = comments_extensible
+                .find_by_durable_id(&id.durable_id)
+                .and_then(|ex| ex.date_utc.clone())
+            {
+                comment.date_utc = Some(date_utc);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_apply_comment_parts() {
+        let comments_extended = CommentsExtended::from_xml(
+            r#"<w15:commentsEx xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml"><w15:commentEx w15:paraId="12345678" w15:done="1"/></w15:commentsEx>"#
+                .as_bytes(),
+        )
+        .unwrap();
+        let comments_ids = CommentsIds::from_xml(
+            r#"<w16cid:commentsIds xmlns:w16cid="http://schemas.microsoft.com/office/word/2016/wordml/cid"><w16cid:commentId w16cid:paraId="12345678" w16cid:durableId="4C7250AB"/></w16cid:commentsIds>"#
+                .as_bytes(),
+        )
+        .unwrap();
+        let comments_extensible = CommentsExtensible::from_xml(
+            r#"<w16cex:commentsExtensible xmlns:w16cex="http://schemas.microsoft.com/office/word/2018/wordml/cex"><w16cex:commentExtensible w16cex:durableId="4C7250AB" w16cex:dateUtc="2024-09-06T02:13:00Z"/></w16cex:commentsExtensible>"#
+                .as_bytes(),
+        )
+        .unwrap();
+        let mut comments = vec![Comment::new(1).add_paragraph(Paragraph::new())];
+        apply_comment_parts(
+            &mut comments,
+            &comments_extended,
+            &comments_ids,
+            &comments_extensible,
+        );
+        assert_eq!(
+            comments[0],
+            Comment::new(1)
+                .add_paragraph(Paragraph::new())
+                .done()
+                .durable_id("4C7250AB")
+                .date_utc("2024-09-06T02:13:00Z")
+        );
+    }
+
+    #[test]
+    fn test_people_from_xml() {
+        let people = People::from_xml(
+            r#"<w15:people xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml"><w15:person w15:author="Test Name"><w15:presenceInfo w15:providerId="Windows Live" w15:userId="36e8d88cd6c0d524"/></w15:person><w15:person w15:author="Bob"/></w15:people>"#
+                .as_bytes(),
+        )
+        .unwrap();
+        assert_eq!(
+            people,
+            People::new()
+                .add_person(
+                    Person::new("Test Name")
+                        .presence_info(PresenceInfo::new("Windows Live", "36e8d88cd6c0d524"))
+                )
+                .add_person(Person::new("Bob"))
+        );
+    }
+}
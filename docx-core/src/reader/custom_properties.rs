@@ -9,19 +9,51 @@ use crate::reader::{FromXML, FromXMLQuickXml, ReaderError};
 // ============================================================================
 
 #[derive(Deserialize, Default)]
-struct LpwstrXml {
+struct VtValueXml {
     #[serde(rename = "$text", default)]
     text: String,
 }
 
 #[derive(Deserialize)]
 enum PropertyChildXml {
-    #[serde(rename = "lpwstr", alias = "vt:lpwstr")]
-    Lpwstr(LpwstrXml),
+    #[serde(
+        rename = "lpwstr",
+        alias = "vt:lpwstr",
+        alias = "lpstr",
+        alias = "vt:lpstr"
+    )]
+    Lpwstr(VtValueXml),
+    #[serde(rename = "i4", alias = "vt:i4", alias = "int", alias = "vt:int")]
+    I4(VtValueXml),
+    #[serde(rename = "r8", alias = "vt:r8")]
+    R8(VtValueXml),
+    #[serde(rename = "bool", alias = "vt:bool")]
+    Bool(VtValueXml),
+    #[serde(rename = "filetime", alias = "vt:filetime")]
+    Filetime(VtValueXml),
     #[serde(other)]
     Unknown,
 }
 
+impl PropertyChildXml {
+    fn into_value(self) -> Option<CustomPropertyValue> {
+        match self {
+            PropertyChildXml::Lpwstr(v) => Some(CustomPropertyValue::Text(v.text)),
+            PropertyChildXml::I4(v) => v.text.trim().parse().ok().map(CustomPropertyValue::Int),
+            PropertyChildXml::R8(v) => v.text.trim().parse().ok().map(CustomPropertyValue::Float),
+            PropertyChildXml::Bool(v) => match v.text.trim() {
+                "true" | "1" => Some(CustomPropertyValue::Bool(true)),
+                "false" | "0" => Some(CustomPropertyValue::Bool(false)),
+                _ => None,
+            },
+            PropertyChildXml::Filetime(v) => {
+                Some(CustomPropertyValue::DateTime(v.text.trim().to_owned()))
+            }
+            PropertyChildXml::Unknown => None,
+        }
+    }
+}
+
 #[derive(Deserialize)]
 struct PropertyXml {
     #[serde(rename = "@name", default)]
@@ -51,11 +83,8 @@ impl FromXMLQuickXml for CustomProps {
         for child in xml.children {
             if let PropertiesChildXml::Property(p) = child {
                 if !p.name.is_empty() {
-                    for pc in p.children {
-                        if let PropertyChildXml::Lpwstr(v) = pc {
-                            props = props.add_custom_property(&p.name, v.text);
-                            break;
-                        }
+                    if let Some(v) = p.children.into_iter().find_map(|c| c.into_value()) {
+                        props = props.add_custom_property(&p.name, v);
                     }
                 }
             }
@@ -69,3 +98,31 @@ impl FromXML for CustomProps {
         Self::from_xml_quick(reader)
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_custom_props_from_xml() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/custom-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes"><property fmtid="{D5CDD505-2E9C-101B-9397-08002B2CF9AE}" pid="2" name="Client"><vt:lpwstr>R &amp; D</vt:lpwstr></property><property fmtid="{D5CDD505-2E9C-101B-9397-08002B2CF9AE}" pid="3" name="Version"><vt:i4>4</vt:i4></property><property fmtid="{D5CDD505-2E9C-101B-9397-08002B2CF9AE}" pid="4" name="Ratio"><vt:r8>0.5</vt:r8></property><property fmtid="{D5CDD505-2E9C-101B-9397-08002B2CF9AE}" pid="5" name="Approved"><vt:bool>true</vt:bool></property><property fmtid="{D5CDD505-2E9C-101B-9397-08002B2CF9AE}" pid="6" name="Due"><vt:filetime>2024-01-31T09:00:00Z</vt:filetime></property></Properties>"#;
+        let props = CustomProps::from_xml(xml.as_bytes()).unwrap();
+        assert_eq!(
+            props,
+            CustomProps::new()
+                .add_custom_property("Client", "R & D")
+                .add_custom_property("Version", 4)
+                .add_custom_property("Ratio", 0.5)
+                .add_custom_property("Approved", true)
+                .add_custom_property(
+                    "Due",
+                    CustomPropertyValue::date_time("2024-01-31T09:00:00Z")
+                )
+        );
+        assert_eq!(props.get("Version"), Some(&CustomPropertyValue::Int(4)));
+    }
+}
@@ -1,5 +1,6 @@
 mod a_graphic;
 mod a_graphic_data;
+mod app_properties;
 mod attributes;
 mod bookmark_end;
 mod bookmark_start;
@@ -9,6 +10,8 @@ mod comment;
 mod comment_extended;
 mod comments;
 mod comments_extended;
+mod comments_extensible;
+mod comments_ids;
 mod custom_properties;
 mod delete;
 mod div;
@@ -20,6 +23,7 @@ mod drawing;
 mod errors;
 mod font_group;
 mod font_scheme;
+mod font_table;
 mod footer;
 mod frame_property;
 mod from_xml;
@@ -38,6 +42,7 @@ mod page_num_type;
 mod paragraph;
 mod paragraph_property;
 mod paragraph_property_change;
+mod people;
 mod pic;
 mod positional_tab;
 mod read_docx;
@@ -1,6 +1,8 @@
 // 2006
 pub(crate) const DOC_RELATIONSHIP_TYPE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument";
+pub(crate) const EXTENDED_PROPERTIES_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/extended-properties";
 pub(crate) const CUSTOM_PROPERTIES_TYPE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/custom-properties";
 pub(crate) const STYLE_RELATIONSHIP_TYPE: &str =
@@ -17,6 +19,10 @@ pub(crate) const HEADER_TYPE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/header";
 pub(crate) const FOOTER_TYPE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/footer";
+pub(crate) const FONT_TABLE_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/fontTable";
+pub(crate) const FONT_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/font";
 pub(crate) const THEME_TYPE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/theme";
 pub(crate) const IMAGE_TYPE: &str =
@@ -26,3 +32,11 @@ pub(crate) const HYPERLINK_TYPE: &str =
 // 2011
 pub(crate) const COMMENTS_EXTENDED_TYPE: &str =
     "http://schemas.microsoft.com/office/2011/relationships/commentsExtended";
+pub(crate) const PEOPLE_TYPE: &str =
+    "http://schemas.microsoft.com/office/2011/relationships/people";
+// 2016
+pub(crate) const COMMENTS_IDS_TYPE: &str =
+    "http://schemas.microsoft.com/office/2016/09/relationships/commentsIds";
+// 2018
+pub(crate) const COMMENTS_EXTENSIBLE_TYPE: &str =
+    "http://schemas.microsoft.com/office/2018/08/relationships/commentsExtensible";
@@ -1,7 +1,9 @@
+use super::comments::apply_comment_parts;
 use super::header_or_footer_rels::{read_header_or_footer_rels, ReadHeaderOrFooterRels};
 use super::namespace::*;
 use super::*;
 
+use std::path::Path;
 use zip::ZipArchive;
 
 fn read_headers(
@@ -65,6 +67,18 @@ fn read_themes(rels: &ReadDocumentRels, archive: &mut ZipArchive<Cursor<&[u8]>>)
         .collect()
 }
 
+fn read_comment_part<T: FromXML + Default>(
+    rels: &ReadDocumentRels,
+    archive: &mut ZipArchive<Cursor<&[u8]>>,
+    rel_type: &str,
+) -> T {
+    rels.find_target_path(rel_type)
+        .and_then(|paths| paths.into_iter().next())
+        .and_then(|(_, path, ..)| read_zip(archive, path.to_str()?).ok())
+        .and_then(|data| T::from_xml(&data[..]).ok())
+        .unwrap_or_default()
+}
+
 pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
     let mut docx = Docx::new();
     let cur = Cursor::new(buf);
@@ -95,6 +109,15 @@ pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
         "word/document.xml".to_owned()
     };
 
+    if let Some(app_props) = rels.find_target(EXTENDED_PROPERTIES_TYPE) {
+        let data = read_zip(&mut archive, &app_props.2);
+        if let Ok(data) = data {
+            if let Ok(app) = AppProps::from_xml(&data[..]) {
+                docx.doc_props.app = app;
+            }
+        }
+    }
+
     if let Some(custom_props) = rels.find_target(CUSTOM_PROPERTIES_TYPE) {
         let data = read_zip(&mut archive, &custom_props.2);
         if let Ok(data) = data {
@@ -133,6 +156,12 @@ pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
         CommentsExtended::default()
     };
 
+    // Read commentsIds, commentsExtensible and people
+    let comments_ids: CommentsIds = read_comment_part(&rels, &mut archive, COMMENTS_IDS_TYPE);
+    let comments_extensible: CommentsExtensible =
+        read_comment_part(&rels, &mut archive, COMMENTS_EXTENSIBLE_TYPE);
+    docx.people = read_comment_part(&rels, &mut archive, PEOPLE_TYPE);
+
     // Read comments
     let comments_path = rels.find_target_path(COMMENTS_TYPE);
     let comments = if let Some(paths) = comments_path {
@@ -174,6 +203,12 @@ pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
                         }
                     }
                 }
+                apply_comment_parts(
+                    &mut comments,
+                    &comments_extended,
+                    &comments_ids,
+                    &comments_extensible,
+                );
                 Comments { comments }
             } else {
                 Comments::default()
@@ -341,6 +376,25 @@ pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
             docx = docx.web_settings(web_settings);
         }
     }
+
+    // Read font table
+    let font_table_path = rels.find_target_path(FONT_TABLE_TYPE);
+    if let Some(paths) = font_table_path {
+        if let Some((_, font_table_path, ..)) = paths.first() {
+            let data = read_zip(
+                &mut archive,
+                font_table_path.to_str().expect("should have font table"),
+            );
+            if let Ok(data) = data {
+                if let Ok(mut font_table) = FontTable::from_xml(&data[..]) {
+                    docx.embedded_fonts =
+                        read_embedded_fonts(&mut archive, font_table_path, &mut font_table);
+                    docx.font_table = font_table;
+                }
+            }
+        }
+    }
+
     // Read media
     let media = rels.find_target_path(IMAGE_TYPE);
     docx = add_images(docx, media, &mut archive);
@@ -359,6 +413,35 @@ pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
     Ok(docx)
 }
 
+// Embedded fonts are kept as `EmbeddedFont`s and their references are removed from the table,
+// since the relationships are recreated when the docx is built.
+fn read_embedded_fonts(
+    archive: &mut ZipArchive<Cursor<&[u8]>>,
+    font_table_path: &Path,
+    font_table: &mut FontTable,
+) -> Vec<EmbeddedFont> {
+    let mut fonts = vec![];
+    let rels = read_document_rels(archive, font_table_path)
+        .ok()
+        .and_then(|rels| rels.find_target_path(FONT_TYPE))
+        .unwrap_or_default();
+    for font in font_table.fonts.iter_mut() {
+        for embed in std::mem::take(&mut font.embeds) {
+            let target = rels.iter().find(|(id, ..)| id == &embed.rid);
+            let data = target.and_then(|(_, path, _)| read_zip(archive, path.to_str()?).ok());
+            if let Some(data) = data {
+                fonts.push(EmbeddedFont::from_obfuscated(
+                    &font.name,
+                    embed.embed_type,
+                    &data,
+                    &embed.font_key,
+                ));
+            }
+        }
+    }
+    fonts
+}
+
 fn add_images(
     mut docx: Docx,
     media: Option<Vec<(RId, PathBuf, Option<String>)>>,
@@ -1,3 +1,4 @@
+use super::comments::apply_comment_parts;
 use super::header_or_footer_rels::ReadHeaderOrFooterRels;
 use super::namespace::*;
 use super::*;
@@ -58,7 +59,7 @@ fn read_comments_from_xml(
     rels: &Rels,
     part_map: &HashMap<String, String>,
     document_path: &str,
-) -> (Comments, CommentsExtended) {
+) -> (Comments, CommentsExtended, People) {
     // Simplified implementation for XML packages - try to read comments if available
     let comments_extended = if let Some((_, _, target)) = rels.find_target(COMMENTS_EXTENDED_TYPE) {
         let ext_path = format!("{}/{}", document_path.replace("document.xml", ""), target);
@@ -71,6 +72,22 @@ fn read_comments_from_xml(
         CommentsExtended::default()
     };
 
+    let read_part = |rel_type: &str| {
+        rels.find_target(rel_type).and_then(|(_, _, target)| {
+            let path = format!("{}/{}", document_path.replace("document.xml", ""), target);
+            part_map.get(&path).cloned()
+        })
+    };
+    let comments_ids = read_part(COMMENTS_IDS_TYPE)
+        .and_then(|data| CommentsIds::from_xml(data.as_bytes()).ok())
+        .unwrap_or_default();
+    let comments_extensible = read_part(COMMENTS_EXTENSIBLE_TYPE)
+        .and_then(|data| CommentsExtensible::from_xml(data.as_bytes()).ok())
+        .unwrap_or_default();
+    let people = read_part(PEOPLE_TYPE)
+        .and_then(|data| People::from_xml(data.as_bytes()).ok())
+        .unwrap_or_default();
+
     // Read comments
     let comments = if let Some((_, _, target)) = rels.find_target(COMMENTS_TYPE) {
         let comm_path = format!("{}/{}", document_path.replace("document.xml", ""), target);
@@ -109,6 +126,12 @@ fn read_comments_from_xml(
                         }
                     }
                 }
+                apply_comment_parts(
+                    &mut comments_inner,
+                    &comments_extended,
+                    &comments_ids,
+                    &comments_extensible,
+                );
                 Comments {
                     comments: comments_inner,
                 }
@@ -122,7 +145,7 @@ fn read_comments_from_xml(
         Comments::default()
     };
 
-    (comments, comments_extended)
+    (comments, comments_extended, people)
 }
 
 fn add_images_from_xml(
@@ -416,9 +439,10 @@ pub fn read_xml(xml_content: &str) -> Result<Docx, ReaderError> {
     let headers = read_headers_from_xml(&document_rels, &part_map, &document_path);
     let footers = read_footers_from_xml(&document_rels, &part_map, &document_path);
 
-    // Read comments and comments extended
-    let (comments, comments_extended) =
+    // Read comments, comments extended and people
+    let (comments, comments_extended, people) =
         read_comments_from_xml(&document_rels, &part_map, &document_path);
+    docx = docx.people(people);
 
     // Read the main document (try both with and without leading slash)
     let document = if let Some(doc_data) = part_map.get(&document_path) {
@@ -39,3 +39,13 @@ pub enum ParagraphBorderPosition {
     Between,
     Bar,
 }
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PageBorderPosition {
+    Top,
+    Left,
+    Bottom,
+    Right,
+}
@@ -44,6 +44,167 @@ pub enum BorderType {
     ArchedScallops,
     BabyPacifier,
     BabyRattle,
+    Balloons3Colors,
+    BalloonsHotAir,
+    BasicBlackDashes,
+    BasicBlackDots,
+    BasicBlackSquares,
+    BasicThinLines,
+    BasicWhiteDashes,
+    BasicWhiteDots,
+    BasicWhiteSquares,
+    BasicWideInline,
+    BasicWideMidline,
+    BasicWideOutline,
+    Bats,
+    Birds,
+    BirdsFlight,
+    Cabins,
+    CakeSlice,
+    CandyCorn,
+    CelticKnotwork,
+    CertificateBanner,
+    ChainLink,
+    ChampagneBottle,
+    CheckedBarBlack,
+    CheckedBarColor,
+    Checkered,
+    ChristmasTree,
+    CirclesLines,
+    CirclesRectangles,
+    ClassicalWave,
+    Clocks,
+    Compass,
+    Confetti,
+    ConfettiGrays,
+    ConfettiOutline,
+    ConfettiStreamers,
+    ConfettiWhite,
+    CornerTriangles,
+    CouponCutoutDashes,
+    CouponCutoutDots,
+    CrazyMaze,
+    CreaturesButterfly,
+    CreaturesFish,
+    CreaturesInsects,
+    CreaturesLadyBug,
+    CrossStitch,
+    Cup,
+    DecoArch,
+    DecoArchColor,
+    DecoBlocks,
+    DiamondsGray,
+    DoubleD,
+    DoubleDiamonds,
+    Earth1,
+    Earth2,
+    Earth3,
+    EclipsingSquares1,
+    EclipsingSquares2,
+    EggsBlack,
+    Fans,
+    Film,
+    Firecrackers,
+    FlowersBlockPrint,
+    FlowersDaisies,
+    FlowersModern1,
+    FlowersModern2,
+    FlowersPansy,
+    FlowersRedRose,
+    FlowersRoses,
+    FlowersTeacup,
+    FlowersTiny,
+    Gems,
+    GingerbreadMan,
+    Gradient,
+    Handmade1,
+    Handmade2,
+    HeartBalloon,
+    HeartGray,
+    Hearts,
+    HeebieJeebies,
+    Holly,
+    HouseFunky,
+    Hypnotic,
+    IceCreamCones,
+    LightBulb,
+    Lightning1,
+    Lightning2,
+    MapPins,
+    MapleLeaf,
+    MapleMuffins,
+    Marquee,
+    MarqueeToothed,
+    Moons,
+    Mosaic,
+    MusicNotes,
+    Northwest,
+    Ovals,
+    Packages,
+    PalmsBlack,
+    PalmsColor,
+    PaperClips,
+    Papyrus,
+    PartyFavor,
+    PartyGlass,
+    Pencils,
+    People,
+    PeopleWaving,
+    PeopleHats,
+    Poinsettias,
+    PostageStamp,
+    Pumpkin1,
+    PushPinNote2,
+    PushPinNote1,
+    Pyramids,
+    PyramidsAbove,
+    Quadrants,
+    Rings,
+    Safari,
+    Sawtooth,
+    SawtoothGray,
+    ScaredCat,
+    Seattle,
+    ShadowedSquares,
+    SharksTeeth,
+    ShorebirdTracks,
+    Skyrocket,
+    SnowflakeFancy,
+    Snowflakes,
+    Sombrero,
+    Southwest,
+    Stars,
+    StarsTop,
+    Stars3d,
+    StarsBlack,
+    StarsShadowed,
+    Sun,
+    Swirligig,
+    TornPaper,
+    TornPaperBlack,
+    Trees,
+    TriangleParty,
+    Triangles,
+    Triangle1,
+    Triangle2,
+    TriangleCircle1,
+    TriangleCircle2,
+    Shapes1,
+    Shapes2,
+    TwistedLines1,
+    TwistedLines2,
+    Vine,
+    Waveline,
+    WeavingAngles,
+    WeavingBraid,
+    WeavingRibbon,
+    WeavingStrips,
+    WhiteFlowers,
+    Woodwork,
+    XIllusions,
+    ZanyTriangles,
+    ZigZag,
+    ZigZagStitch,
 }
 
 impl fmt::Display for BorderType {
@@ -80,6 +241,167 @@ impl fmt::Display for BorderType {
             BorderType::ArchedScallops => write!(f, "archedScallops"),
             BorderType::BabyPacifier => write!(f, "babyPacifier"),
             BorderType::BabyRattle => write!(f, "babyRattle"),
+            BorderType::Balloons3Colors => write!(f, "balloons3Colors"),
+            BorderType::BalloonsHotAir => write!(f, "balloonsHotAir"),
+            BorderType::BasicBlackDashes => write!(f, "basicBlackDashes"),
+            BorderType::BasicBlackDots => write!(f, "basicBlackDots"),
+            BorderType::BasicBlackSquares => write!(f, "basicBlackSquares"),
+            BorderType::BasicThinLines => write!(f, "basicThinLines"),
+            BorderType::BasicWhiteDashes => write!(f, "basicWhiteDashes"),
+            BorderType::BasicWhiteDots => write!(f, "basicWhiteDots"),
+            BorderType::BasicWhiteSquares => write!(f, "basicWhiteSquares"),
+            BorderType::BasicWideInline => write!(f, "basicWideInline"),
+            BorderType::BasicWideMidline => write!(f, "basicWideMidline"),
+            BorderType::BasicWideOutline => write!(f, "basicWideOutline"),
+            BorderType::Bats => write!(f, "bats"),
+            BorderType::Birds => write!(f, "birds"),
+            BorderType::BirdsFlight => write!(f, "birdsFlight"),
+            BorderType::Cabins => write!(f, "cabins"),
+            BorderType::CakeSlice => write!(f, "cakeSlice"),
+            BorderType::CandyCorn => write!(f, "candyCorn"),
+            BorderType::CelticKnotwork => write!(f, "celticKnotwork"),
+            BorderType::CertificateBanner => write!(f, "certificateBanner"),
+            BorderType::ChainLink => write!(f, "chainLink"),
+            BorderType::ChampagneBottle => write!(f, "champagneBottle"),
+            BorderType::CheckedBarBlack => write!(f, "checkedBarBlack"),
+            BorderType::CheckedBarColor => write!(f, "checkedBarColor"),
+            BorderType::Checkered => write!(f, "checkered"),
+            BorderType::ChristmasTree => write!(f, "christmasTree"),
+            BorderType::CirclesLines => write!(f, "circlesLines"),
+            BorderType::CirclesRectangles => write!(f, "circlesRectangles"),
+            BorderType::ClassicalWave => write!(f, "classicalWave"),
+            BorderType::Clocks => write!(f, "clocks"),
+            BorderType::Compass => write!(f, "compass"),
+            BorderType::Confetti => write!(f, "confetti"),
+            BorderType::ConfettiGrays => write!(f, "confettiGrays"),
+            BorderType::ConfettiOutline => write!(f, "confettiOutline"),
+            BorderType::ConfettiStreamers => write!(f, "confettiStreamers"),
+            BorderType::ConfettiWhite => write!(f, "confettiWhite"),
+            BorderType::CornerTriangles => write!(f, "cornerTriangles"),
+            BorderType::CouponCutoutDashes => write!(f, "couponCutoutDashes"),
+            BorderType::CouponCutoutDots => write!(f, "couponCutoutDots"),
+            BorderType::CrazyMaze => write!(f, "crazyMaze"),
+            BorderType::CreaturesButterfly => write!(f, "creaturesButterfly"),
+            BorderType::CreaturesFish => write!(f, "creaturesFish"),
+            BorderType::CreaturesInsects => write!(f, "creaturesInsects"),
+            BorderType::CreaturesLadyBug => write!(f, "creaturesLadyBug"),
+            BorderType::CrossStitch => write!(f, "crossStitch"),
+            BorderType::Cup => write!(f, "cup"),
+            BorderType::DecoArch => write!(f, "decoArch"),
+            BorderType::DecoArchColor => write!(f, "decoArchColor"),
+            BorderType::DecoBlocks => write!(f, "decoBlocks"),
+            BorderType::DiamondsGray => write!(f, "diamondsGray"),
+            BorderType::DoubleD => write!(f, "doubleD"),
+            BorderType::DoubleDiamonds => write!(f, "doubleDiamonds"),
+            BorderType::Earth1 => write!(f, "earth1"),
+            BorderType::Earth2 => write!(f, "earth2"),
+            BorderType::Earth3 => write!(f, "earth3"),
+            BorderType::EclipsingSquares1 => write!(f, "eclipsingSquares1"),
+            BorderType::EclipsingSquares2 => write!(f, "eclipsingSquares2"),
+            BorderType::EggsBlack => write!(f, "eggsBlack"),
+            BorderType::Fans => write!(f, "fans"),
+            BorderType::Film => write!(f, "film"),
+            BorderType::Firecrackers => write!(f, "firecrackers"),
+            BorderType::FlowersBlockPrint => write!(f, "flowersBlockPrint"),
+            BorderType::FlowersDaisies => write!(f, "flowersDaisies"),
+            BorderType::FlowersModern1 => write!(f, "flowersModern1"),
+            BorderType::FlowersModern2 => write!(f, "flowersModern2"),
+            BorderType::FlowersPansy => write!(f, "flowersPansy"),
+            BorderType::FlowersRedRose => write!(f, "flowersRedRose"),
+            BorderType::FlowersRoses => write!(f, "flowersRoses"),
+            BorderType::FlowersTeacup => write!(f, "flowersTeacup"),
+            BorderType::FlowersTiny => write!(f, "flowersTiny"),
+            BorderType::Gems => write!(f, "gems"),
+            BorderType::GingerbreadMan => write!(f, "gingerbreadMan"),
+            BorderType::Gradient => write!(f, "gradient"),
+            BorderType::Handmade1 => write!(f, "handmade1"),
+            BorderType::Handmade2 => write!(f, "handmade2"),
+            BorderType::HeartBalloon => write!(f, "heartBalloon"),
+            BorderType::HeartGray => write!(f, "heartGray"),
+            BorderType::Hearts => write!(f, "hearts"),
+            BorderType::HeebieJeebies => write!(f, "heebieJeebies"),
+            BorderType::Holly => write!(f, "holly"),
+            BorderType::HouseFunky => write!(f, "houseFunky"),
+            BorderType::Hypnotic => write!(f, "hypnotic"),
+            BorderType::IceCreamCones => write!(f, "iceCreamCones"),
+            BorderType::LightBulb => write!(f, "lightBulb"),
+            BorderType::Lightning1 => write!(f, "lightning1"),
+            BorderType::Lightning2 => write!(f, "lightning2"),
+            BorderType::MapPins => write!(f, "mapPins"),
+            BorderType::MapleLeaf => write!(f, "mapleLeaf"),
+            BorderType::MapleMuffins => write!(f, "mapleMuffins"),
+            BorderType::Marquee => write!(f, "marquee"),
+            BorderType::MarqueeToothed => write!(f, "marqueeToothed"),
+            BorderType::Moons => write!(f, "moons"),
+            BorderType::Mosaic => write!(f, "mosaic"),
+            BorderType::MusicNotes => write!(f, "musicNotes"),
+            BorderType::Northwest => write!(f, "northwest"),
+            BorderType::Ovals => write!(f, "ovals"),
+            BorderType::Packages => write!(f, "packages"),
+            BorderType::PalmsBlack => write!(f, "palmsBlack"),
+            BorderType::PalmsColor => write!(f, "palmsColor"),
+            BorderType::PaperClips => write!(f, "paperClips"),
+            BorderType::Papyrus => write!(f, "papyrus"),
+            BorderType::PartyFavor => write!(f, "partyFavor"),
+            BorderType::PartyGlass => write!(f, "partyGlass"),
+            BorderType::Pencils => write!(f, "pencils"),
+            BorderType::People => write!(f, "people"),
+            BorderType::PeopleWaving => write!(f, "peopleWaving"),
+            BorderType::PeopleHats => write!(f, "peopleHats"),
+            BorderType::Poinsettias => write!(f, "poinsettias"),
+            BorderType::PostageStamp => write!(f, "postageStamp"),
+            BorderType::Pumpkin1 => write!(f, "pumpkin1"),
+            BorderType::PushPinNote2 => write!(f, "pushPinNote2"),
+            BorderType::PushPinNote1 => write!(f, "pushPinNote1"),
+            BorderType::Pyramids => write!(f, "pyramids"),
+            BorderType::PyramidsAbove => write!(f, "pyramidsAbove"),
+            BorderType::Quadrants => write!(f, "quadrants"),
+            BorderType::Rings => write!(f, "rings"),
+            BorderType::Safari => write!(f, "safari"),
+            BorderType::Sawtooth => write!(f, "sawtooth"),
+            BorderType::SawtoothGray => write!(f, "sawtoothGray"),
+            BorderType::ScaredCat => write!(f, "scaredCat"),
+            BorderType::Seattle => write!(f, "seattle"),
+            BorderType::ShadowedSquares => write!(f, "shadowedSquares"),
+            BorderType::SharksTeeth => write!(f, "sharksTeeth"),
+            BorderType::ShorebirdTracks => write!(f, "shorebirdTracks"),
+            BorderType::Skyrocket => write!(f, "skyrocket"),
+            BorderType::SnowflakeFancy => write!(f, "snowflakeFancy"),
+            BorderType::Snowflakes => write!(f, "snowflakes"),
+            BorderType::Sombrero => write!(f, "sombrero"),
+            BorderType::Southwest => write!(f, "southwest"),
+            BorderType::Stars => write!(f, "stars"),
+            BorderType::StarsTop => write!(f, "starsTop"),
+            BorderType::Stars3d => write!(f, "stars3d"),
+            BorderType::StarsBlack => write!(f, "starsBlack"),
+            BorderType::StarsShadowed => write!(f, "starsShadowed"),
+            BorderType::Sun => write!(f, "sun"),
+            BorderType::Swirligig => write!(f, "swirligig"),
+            BorderType::TornPaper => write!(f, "tornPaper"),
+            BorderType::TornPaperBlack => write!(f, "tornPaperBlack"),
+            BorderType::Trees => write!(f, "trees"),
+            BorderType::TriangleParty => write!(f, "triangleParty"),
+            BorderType::Triangles => write!(f, "triangles"),
+            BorderType::Triangle1 => write!(f, "triangle1"),
+            BorderType::Triangle2 => write!(f, "triangle2"),
+            BorderType::TriangleCircle1 => write!(f, "triangleCircle1"),
+            BorderType::TriangleCircle2 => write!(f, "triangleCircle2"),
+            BorderType::Shapes1 => write!(f, "shapes1"),
+            BorderType::Shapes2 => write!(f, "shapes2"),
+            BorderType::TwistedLines1 => write!(f, "twistedLines1"),
+            BorderType::TwistedLines2 => write!(f, "twistedLines2"),
+            BorderType::Vine => write!(f, "vine"),
+            BorderType::Waveline => write!(f, "waveline"),
+            BorderType::WeavingAngles => write!(f, "weavingAngles"),
+            BorderType::WeavingBraid => write!(f, "weavingBraid"),
+            BorderType::WeavingRibbon => write!(f, "weavingRibbon"),
+            BorderType::WeavingStrips => write!(f, "weavingStrips"),
+            BorderType::WhiteFlowers => write!(f, "whiteFlowers"),
+            BorderType::Woodwork => write!(f, "woodwork"),
+            BorderType::XIllusions => write!(f, "xIllusions"),
+            BorderType::ZanyTriangles => write!(f, "zanyTriangles"),
+            BorderType::ZigZag => write!(f, "zigZag"),
+            BorderType::ZigZagStitch => write!(f, "zigZagStitch"),
         }
     }
 }
@@ -119,173 +441,168 @@ impl FromStr for BorderType {
             "archedScallops" => Ok(BorderType::ArchedScallops),
             "babyPacifier" => Ok(BorderType::BabyPacifier),
             "babyRattle" => Ok(BorderType::BabyRattle),
+            "balloons3Colors" => Ok(BorderType::Balloons3Colors),
+            "balloonsHotAir" => Ok(BorderType::BalloonsHotAir),
+            "basicBlackDashes" => Ok(BorderType::BasicBlackDashes),
+            "basicBlackDots" => Ok(BorderType::BasicBlackDots),
+            "basicBlackSquares" => Ok(BorderType::BasicBlackSquares),
+            "basicThinLines" => Ok(BorderType::BasicThinLines),
+            "basicWhiteDashes" => Ok(BorderType::BasicWhiteDashes),
+            "basicWhiteDots" => Ok(BorderType::BasicWhiteDots),
+            "basicWhiteSquares" => Ok(BorderType::BasicWhiteSquares),
+            "basicWideInline" => Ok(BorderType::BasicWideInline),
+            "basicWideMidline" => Ok(BorderType::BasicWideMidline),
+            "basicWideOutline" => Ok(BorderType::BasicWideOutline),
+            "bats" => Ok(BorderType::Bats),
+            "birds" => Ok(BorderType::Birds),
+            "birdsFlight" => Ok(BorderType::BirdsFlight),
+            "cabins" => Ok(BorderType::Cabins),
+            "cakeSlice" => Ok(BorderType::CakeSlice),
+            "candyCorn" => Ok(BorderType::CandyCorn),
+            "celticKnotwork" => Ok(BorderType::CelticKnotwork),
+            "certificateBanner" => Ok(BorderType::CertificateBanner),
+            "chainLink" => Ok(BorderType::ChainLink),
+            "champagneBottle" => Ok(BorderType::ChampagneBottle),
+            "checkedBarBlack" => Ok(BorderType::CheckedBarBlack),
+            "checkedBarColor" => Ok(BorderType::CheckedBarColor),
+            "checkered" => Ok(BorderType::Checkered),
+            "christmasTree" => Ok(BorderType::ChristmasTree),
+            "circlesLines" => Ok(BorderType::CirclesLines),
+            "circlesRectangles" => Ok(BorderType::CirclesRectangles),
+            "classicalWave" => Ok(BorderType::ClassicalWave),
+            "clocks" => Ok(BorderType::Clocks),
+            "compass" => Ok(BorderType::Compass),
+            "confetti" => Ok(BorderType::Confetti),
+            "confettiGrays" => Ok(BorderType::ConfettiGrays),
+            "confettiOutline" => Ok(BorderType::ConfettiOutline),
+            "confettiStreamers" => Ok(BorderType::ConfettiStreamers),
+            "confettiWhite" => Ok(BorderType::ConfettiWhite),
+            "cornerTriangles" => Ok(BorderType::CornerTriangles),
+            "couponCutoutDashes" => Ok(BorderType::CouponCutoutDashes),
+            "couponCutoutDots" => Ok(BorderType::CouponCutoutDots),
+            "crazyMaze" => Ok(BorderType::CrazyMaze),
+            "creaturesButterfly" => Ok(BorderType::CreaturesButterfly),
+            "creaturesFish" => Ok(BorderType::CreaturesFish),
+            "creaturesInsects" => Ok(BorderType::CreaturesInsects),
+            "creaturesLadyBug" => Ok(BorderType::CreaturesLadyBug),
+            "crossStitch" => Ok(BorderType::CrossStitch),
+            "cup" => Ok(BorderType::Cup),
+            "decoArch" => Ok(BorderType::DecoArch),
+            "decoArchColor" => Ok(BorderType::DecoArchColor),
+            "decoBlocks" => Ok(BorderType::DecoBlocks),
+            "diamondsGray" => Ok(BorderType::DiamondsGray),
+            "doubleD" => Ok(BorderType::DoubleD),
+            "doubleDiamonds" => Ok(BorderType::DoubleDiamonds),
+            "earth1" => Ok(BorderType::Earth1),
+            "earth2" => Ok(BorderType::Earth2),
+            "earth3" => Ok(BorderType::Earth3),
+            "eclipsingSquares1" => Ok(BorderType::EclipsingSquares1),
+            "eclipsingSquares2" => Ok(BorderType::EclipsingSquares2),
+            "eggsBlack" => Ok(BorderType::EggsBlack),
+            "fans" => Ok(BorderType::Fans),
+            "film" => Ok(BorderType::Film),
+            "firecrackers" => Ok(BorderType::Firecrackers),
+            "flowersBlockPrint" => Ok(BorderType::FlowersBlockPrint),
+            "flowersDaisies" => Ok(BorderType::FlowersDaisies),
+            "flowersModern1" => Ok(BorderType::FlowersModern1),
+            "flowersModern2" => Ok(BorderType::FlowersModern2),
+            "flowersPansy" => Ok(BorderType::FlowersPansy),
+            "flowersRedRose" => Ok(BorderType::FlowersRedRose),
+            "flowersRoses" => Ok(BorderType::FlowersRoses),
+            "flowersTeacup" => Ok(BorderType::FlowersTeacup),
+            "flowersTiny" => Ok(BorderType::FlowersTiny),
+            "gems" => Ok(BorderType::Gems),
+            "gingerbreadMan" => Ok(BorderType::GingerbreadMan),
+            "gradient" => Ok(BorderType::Gradient),
+            "handmade1" => Ok(BorderType::Handmade1),
+            "handmade2" => Ok(BorderType::Handmade2),
+            "heartBalloon" => Ok(BorderType::HeartBalloon),
+            "heartGray" => Ok(BorderType::HeartGray),
+            "hearts" => Ok(BorderType::Hearts),
+            "heebieJeebies" => Ok(BorderType::HeebieJeebies),
+            "holly" => Ok(BorderType::Holly),
+            "houseFunky" => Ok(BorderType::HouseFunky),
+            "hypnotic" => Ok(BorderType::Hypnotic),
+            "iceCreamCones" => Ok(BorderType::IceCreamCones),
+            "lightBulb" => Ok(BorderType::LightBulb),
+            "lightning1" => Ok(BorderType::Lightning1),
+            "lightning2" => Ok(BorderType::Lightning2),
+            "mapPins" => Ok(BorderType::MapPins),
+            "mapleLeaf" => Ok(BorderType::MapleLeaf),
+            "mapleMuffins" => Ok(BorderType::MapleMuffins),
+            "marquee" => Ok(BorderType::Marquee),
+            "marqueeToothed" => Ok(BorderType::MarqueeToothed),
+            "moons" => Ok(BorderType::Moons),
+            "mosaic" => Ok(BorderType::Mosaic),
+            "musicNotes" => Ok(BorderType::MusicNotes),
+            "northwest" => Ok(BorderType::Northwest),
+            "ovals" => Ok(BorderType::Ovals),
+            "packages" => Ok(BorderType::Packages),
+            "palmsBlack" => Ok(BorderType::PalmsBlack),
+            "palmsColor" => Ok(BorderType::PalmsColor),
+            "paperClips" => Ok(BorderType::PaperClips),
+            "papyrus" => Ok(BorderType::Papyrus),
+            "partyFavor" => Ok(BorderType::PartyFavor),
+            "partyGlass" => Ok(BorderType::PartyGlass),
+            "pencils" => Ok(BorderType::Pencils),
+            "people" => Ok(BorderType::People),
+            "peopleWaving" => Ok(BorderType::PeopleWaving),
+            "peopleHats" => Ok(BorderType::PeopleHats),
+            "poinsettias" => Ok(BorderType::Poinsettias),
+            "postageStamp" => Ok(BorderType::PostageStamp),
+            "pumpkin1" => Ok(BorderType::Pumpkin1),
+            "pushPinNote2" => Ok(BorderType::PushPinNote2),
+            "pushPinNote1" => Ok(BorderType::PushPinNote1),
+            "pyramids" => Ok(BorderType::Pyramids),
+            "pyramidsAbove" => Ok(BorderType::PyramidsAbove),
+            "quadrants" => Ok(BorderType::Quadrants),
+            "rings" => Ok(BorderType::Rings),
+            "safari" => Ok(BorderType::Safari),
+            "sawtooth" => Ok(BorderType::Sawtooth),
+            "sawtoothGray" => Ok(BorderType::SawtoothGray),
+            "scaredCat" => Ok(BorderType::ScaredCat),
+            "seattle" => Ok(BorderType::Seattle),
+            "shadowedSquares" => Ok(BorderType::ShadowedSquares),
+            "sharksTeeth" => Ok(BorderType::SharksTeeth),
+            "shorebirdTracks" => Ok(BorderType::ShorebirdTracks),
+            "skyrocket" => Ok(BorderType::Skyrocket),
+            "snowflakeFancy" => Ok(BorderType::SnowflakeFancy),
+            "snowflakes" => Ok(BorderType::Snowflakes),
+            "sombrero" => Ok(BorderType::Sombrero),
+            "southwest" => Ok(BorderType::Southwest),
+            "stars" => Ok(BorderType::Stars),
+            "starsTop" => Ok(BorderType::StarsTop),
+            "stars3d" => Ok(BorderType::Stars3d),
+            "starsBlack" => Ok(BorderType::StarsBlack),
+            "starsShadowed" => Ok(BorderType::StarsShadowed),
+            "sun" => Ok(BorderType::Sun),
+            "swirligig" => Ok(BorderType::Swirligig),
+            "tornPaper" => Ok(BorderType::TornPaper),
+            "tornPaperBlack" => Ok(BorderType::TornPaperBlack),
+            "trees" => Ok(BorderType::Trees),
+            "triangleParty" => Ok(BorderType::TriangleParty),
+            "triangles" => Ok(BorderType::Triangles),
+            "triangle1" => Ok(BorderType::Triangle1),
+            "triangle2" => Ok(BorderType::Triangle2),
+            "triangleCircle1" => Ok(BorderType::TriangleCircle1),
+            "triangleCircle2" => Ok(BorderType::TriangleCircle2),
+            "shapes1" => Ok(BorderType::Shapes1),
+            "shapes2" => Ok(BorderType::Shapes2),
+            "twistedLines1" => Ok(BorderType::TwistedLines1),
+            "twistedLines2" => Ok(BorderType::TwistedLines2),
+            "vine" => Ok(BorderType::Vine),
+            "waveline" => Ok(BorderType::Waveline),
+            "weavingAngles" => Ok(BorderType::WeavingAngles),
+            "weavingBraid" => Ok(BorderType::WeavingBraid),
+            "weavingRibbon" => Ok(BorderType::WeavingRibbon),
+            "weavingStrips" => Ok(BorderType::WeavingStrips),
+            "whiteFlowers" => Ok(BorderType::WhiteFlowers),
+            "woodwork" => Ok(BorderType::Woodwork),
+            "xIllusions" => Ok(BorderType::XIllusions),
+            "zanyTriangles" => Ok(BorderType::ZanyTriangles),
+            "zigZag" => Ok(BorderType::ZigZag),
+            "zigZagStitch" => Ok(BorderType::ZigZagStitch),
             _ => Ok(BorderType::Single),
         }
     }
 }
-
-/*
-Unsupported types
-"balloons3Colors"
-"balloonsHotAir"
-"basicBlackDashes"
-"basicBlackDots"
-"basicBlackSquares"
-"basicThinLines"
-"basicWhiteDashes"
-"basicWhiteDots"
-"basicWhiteSquares"
-"basicWideInline"
-"basicWideMidline"
-"basicWideOutline"
-"bats"
-"birds"
-"birdsFlight"
-"cabins"
-"cakeSlice"
-"candyCorn"
-"celticKnotwork"
-"certificateBanner"
-"chainLink"
-"champagneBottle"
-"checkedBarBlack"
-"checkedBarColor"
-"checkered"
-"christmasTree"
-"circlesLines"
-"circlesRectangles"
-"classicalWave"
-"clocks"
-"compass"
-"confetti"
-"confettiGrays"
-"confettiOutline"
-"confettiStreamers"
-"confettiWhite"
-"cornerTriangles"
-"couponCutoutDashes"
-"couponCutoutDots"
-"crazyMaze"
-"creaturesButterfly"
-"creaturesFish"
-"creaturesInsects"
-"creaturesLadyBug"
-"crossStitch"
-"cup"
-"decoArch"
-"decoArchColor"
-"decoBlocks"
-"diamondsGray"
-"doubleD"
-"doubleDiamonds"
-"earth1"
-"earth2"
-"earth3"
-"eclipsingSquares1"
-"eclipsingSquares2"
-"eggsBlack"
-"fans"
-"film"
-"firecrackers"
-"flowersBlockPrint"
-"flowersDaisies"
-"flowersModern1"
-"flowersModern2"
-"flowersPansy"
-"flowersRedRose"
-"flowersRoses"
-"flowersTeacup"
-"flowersTiny"
-"gems"
-"gingerbreadMan"
-"gradient"
-"handmade1"
-"handmade2"
-"heartBalloon"
-"heartGray"
-"hearts"
-"heebieJeebies"
-"holly"
-"houseFunky"
-"hypnotic"
-"iceCreamCones"
-"lightBulb"
-"lightning1"
-"lightning2"
-"mapPins"
-"mapleLeaf"
-"mapleMuffins"
-"marquee"
-"marqueeToothed"
-"moons"
-"mosaic"
-"musicNotes"
-"northwest"
-"ovals"
-"packages"
-"palmsBlack"
-"palmsColor"
-"paperClips"
-"papyrus"
-"partyFavor"
-"partyGlass"
-"pencils"
-"people"
-"peopleWaving"
-"peopleHats"
-"poinsettias"
-"postageStamp"
-"pumpkin1"
-"pushPinNote2"
-"pushPinNote1"
-"pyramids"
-"pyramidsAbove"
-"quadrants"
-"rings"
-"safari"
-"sawtooth"
-"sawtoothGray"
-"scaredCat"
-"seattle"
-"shadowedSquares"
-"sharksTeeth"
-"shorebirdTracks"
-"skyrocket"
-"snowflakeFancy"
-"snowflakes"
-"sombrero"
-"southwest"
-"stars"
-"starsTop"
-"stars3d"
-"starsBlack"
-"starsShadowed"
-"sun"
-"swirligig"
-"tornPaper"
-"tornPaperBlack"
-"trees"
-"triangleParty"
-"triangles"
-"triangle1"
-"triangle2"
-"triangleCircle1"
-"triangleCircle2"
-"shapes1"
-"shapes2"
-"twistedLines1"
-"twistedLines2"
-"vine"
-"waveline"
-"weavingAngles"
-"weavingBraid"
-"weavingRibbon"
-"weavingStrips"
-"whiteFlowers"
-"woodwork"
-"xIllusions"
-"zanyTriangles"
-"zigZag"
-"zigZagStitch"
-"custom"
-*/
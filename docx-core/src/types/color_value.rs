@@ -0,0 +1,255 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use super::ThemeColorType;
+
+/// A color used by `w:color`, `w:shd` and the border elements.
+///
+/// Theme colors keep a fallback RGB `val` for consumers that don't resolve the theme.
+///
+/// ```
+/// use docx_rs::*;
+///
+/// let c = ColorValue::theme(ThemeColorType::Accent1).shade(0xBF);
+/// assert_eq!(c.val(), "auto");
+/// assert_eq!(ColorValue::from("FF0000"), ColorValue::Rgb("FF0000".to_owned()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum ColorValue {
+    #[default]
+    Auto,
+    Rgb(String),
+    Theme(ThemeColor),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeColor {
+    pub color: ThemeColorType,
+    pub tint: Option<u8>,
+    pub shade: Option<u8>,
+    pub val: Option<String>,
+}
+
+impl ColorValue {
+    pub fn rgb(v: impl Into<String>) -> ColorValue {
+        ColorValue::Rgb(v.into())
+    }
+
+    pub fn theme(color: ThemeColorType) -> ColorValue {
+        ColorValue::Theme(ThemeColor {
+            color,
+            tint: None,
+            shade: None,
+            val: None,
+        })
+    }
+
+    // tint, shade and fallback are ignored for non theme colors.
+    pub fn tint(mut self, v: u8) -> Self {
+        if let ColorValue::Theme(ref mut t) = self {
+            t.tint = Some(v);
+        }
+        self
+    }
+
+    pub fn shade(mut self, v: u8) -> Self {
+        if let ColorValue::Theme(ref mut t) = self {
+            t.shade = Some(v);
+        }
+        self
+    }
+
+    pub fn fallback(mut self, v: impl Into<String>) -> Self {
+        if let ColorValue::Theme(ref mut t) = self {
+            t.val = Some(v.into());
+        }
+        self
+    }
+
+    /// The value written to `w:val` / `w:color` / `w:fill`.
+    pub fn val(&self) -> String {
+        match self {
+            ColorValue::Auto => "auto".to_owned(),
+            ColorValue::Rgb(v) => v.clone(),
+            ColorValue::Theme(t) => t.val.clone().unwrap_or_else(|| "auto".to_owned()),
+        }
+    }
+
+    pub fn theme_color(&self) -> Option<&ThemeColor> {
+        match self {
+            ColorValue::Theme(t) => Some(t),
+            _ => None,
+        }
+    }
+
+    /// Build from the `val`, `themeColor`, `themeTint` and `themeShade` attributes of an element.
+    pub(crate) fn from_attrs(
+        val: Option<String>,
+        theme_color: Option<&str>,
+        tint: Option<&str>,
+        shade: Option<&str>,
+    ) -> Option<ColorValue> {
+        match theme_color.and_then(|c| ThemeColorType::from_str(c).ok()) {
+            Some(color) => Some(ColorValue::Theme(ThemeColor {
+                color,
+                tint: tint.and_then(parse_hex_byte),
+                shade: shade.and_then(parse_hex_byte),
+                val,
+            })),
+            None => val.map(ColorValue::from),
+        }
+    }
+}
+
+fn parse_hex_byte(v: &str) -> Option<u8> {
+    u8::from_str_radix(v.trim(), 16).ok()
+}
+
+impl fmt::Display for ColorValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.val())
+    }
+}
+
+impl From<&str> for ColorValue {
+    fn from(v: &str) -> Self {
+        if v.eq_ignore_ascii_case("auto") {
+            ColorValue::Auto
+        } else {
+            ColorValue::Rgb(v.to_owned())
+        }
+    }
+}
+
+impl From<String> for ColorValue {
+    fn from(v: String) -> Self {
+        ColorValue::from(v.as_str())
+    }
+}
+
+impl From<&String> for ColorValue {
+    fn from(v: &String) -> Self {
+        ColorValue::from(v.as_str())
+    }
+}
+
+impl From<ThemeColorType> for ColorValue {
+    fn from(v: ThemeColorType) -> Self {
+        ColorValue::theme(v)
+    }
+}
+
+// RGB and auto colors serialize as the plain string, theme colors as an object.
+impl Serialize for ColorValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ColorValue::Theme(t) => {
+                let mut s = serializer.serialize_struct("ThemeColor", 4)?;
+                s.serialize_field("val", &self.val())?;
+                s.serialize_field("themeColor", &t.color)?;
+                s.serialize_field("themeTint", &t.tint)?;
+                s.serialize_field("themeShade", &t.shade)?;
+                s.end()
+            }
+            _ => serializer.serialize_str(&self.val()),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ColorValueRepr {
+    Plain(String),
+    #[serde(rename_all = "camelCase")]
+    Theme {
+        val: Option<String>,
+        theme_color: ThemeColorType,
+        theme_tint: Option<u8>,
+        theme_shade: Option<u8>,
+    },
+}
+
+impl<'de> Deserialize<'de> for ColorValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match ColorValueRepr::deserialize(deserializer)? {
+            ColorValueRepr::Plain(v) => ColorValue::from(v),
+            ColorValueRepr::Theme {
+                val,
+                theme_color,
+                theme_tint,
+                theme_shade,
+            } => ColorValue::Theme(ThemeColor {
+                color: theme_color,
+                tint: theme_tint,
+                shade: theme_shade,
+                val: val.filter(|v| !v.eq_ignore_ascii_case("auto")),
+            }),
+        })
+    }
+}
+
+#[cfg(feature = "wasm")]
+impl ts_rs::TS for ColorValue {
+    fn name() -> String {
+        "string | { val: string, themeColor: string, themeTint: number | null, themeShade: number | null }"
+            .to_owned()
+    }
+
+    fn inline() -> String {
+        Self::name()
+    }
+
+    fn dependencies() -> Vec<ts_rs::Dependency> {
+        vec![]
+    }
+
+    fn transparent() -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_color_value_from_attrs() {
+        let c =
+            ColorValue::from_attrs(Some("2F5496".to_owned()), Some("accent1"), None, Some("BF"));
+        assert_eq!(
+            c,
+            Some(
+                ColorValue::theme(ThemeColorType::Accent1)
+                    .shade(0xBF)
+                    .fallback("2F5496")
+            )
+        );
+        assert_eq!(
+            ColorValue::from_attrs(Some("auto".to_owned()), None, None, None),
+            Some(ColorValue::Auto)
+        );
+    }
+
+    #[test]
+    fn test_color_value_json() {
+        assert_eq!(
+            serde_json::to_string(&ColorValue::from("FF0000")).unwrap(),
+            r#""FF0000""#
+        );
+        assert_eq!(
+            serde_json::to_string(&ColorValue::theme(ThemeColorType::Text1).tint(0x99)).unwrap(),
+            r#"{"val":"auto","themeColor":"text1","themeTint":153,"themeShade":null}"#
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+//
+// Please see https://c-rex.net/projects/samples/ooxml/e1/Part4/OOXML_P4_DOCX_ST_EdGrp_topic_ID0EFSZ6.html
+//
+use std::fmt;
+use std::str::FromStr;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub enum EditorGroupType {
+    None,
+    Everyone,
+    Administrators,
+    Contributors,
+    Editors,
+    Owners,
+    Current,
+}
+
+impl fmt::Display for EditorGroupType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EditorGroupType::None => write!(f, "none"),
+            EditorGroupType::Everyone => write!(f, "everyone"),
+            EditorGroupType::Administrators => write!(f, "administrators"),
+            EditorGroupType::Contributors => write!(f, "contributors"),
+            EditorGroupType::Editors => write!(f, "editors"),
+            EditorGroupType::Owners => write!(f, "owners"),
+            EditorGroupType::Current => write!(f, "current"),
+        }
+    }
+}
+
+impl FromStr for EditorGroupType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(EditorGroupType::None),
+            "everyone" => Ok(EditorGroupType::Everyone),
+            "administrators" => Ok(EditorGroupType::Administrators),
+            "contributors" => Ok(EditorGroupType::Contributors),
+            "editors" => Ok(EditorGroupType::Editors),
+            "owners" => Ok(EditorGroupType::Owners),
+            "current" => Ok(EditorGroupType::Current),
+            _ => Err(errors::TypeError::Unsupported(s.to_string())),
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+
+//
+// Please see https://c-rex.net/projects/samples/ooxml/e1/Part4/OOXML_P4_DOCX_FieldDefinitions_topic_ID0EMJ11.html
+//
+use std::fmt;
+use std::str::FromStr;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub enum FieldType {
+    Author,
+    Comments,
+    CreateDate,
+    Date,
+    DocProperty,
+    DocVariable,
+    FileName,
+    FileSize,
+    FormCheckBox,
+    FormDropDown,
+    FormText,
+    Hyperlink,
+    If,
+    Keywords,
+    LastSavedBy,
+    MergeField,
+    NoteRef,
+    NumChars,
+    NumPages,
+    NumWords,
+    Page,
+    PageRef,
+    PrintDate,
+    Quote,
+    Ref,
+    RevNum,
+    SaveDate,
+    Section,
+    SectionPages,
+    Seq,
+    StyleRef,
+    Subject,
+    Symbol,
+    Tc,
+    Template,
+    Time,
+    Title,
+    Toc,
+    UserName,
+    Unsupported,
+}
+
+impl fmt::Display for FieldType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FieldType::Author => write!(f, "AUTHOR"),
+            FieldType::Comments => write!(f, "COMMENTS"),
+            FieldType::CreateDate => write!(f, "CREATEDATE"),
+            FieldType::Date => write!(f, "DATE"),
+            FieldType::DocProperty => write!(f, "DOCPROPERTY"),
+            FieldType::DocVariable => write!(f, "DOCVARIABLE"),
+            FieldType::FileName => write!(f, "FILENAME"),
+            FieldType::FileSize => write!(f, "FILESIZE"),
+            FieldType::FormCheckBox => write!(f, "FORMCHECKBOX"),
+            FieldType::FormDropDown => write!(f, "FORMDROPDOWN"),
+            FieldType::FormText => write!(f, "FORMTEXT"),
+            FieldType::Hyperlink => write!(f, "HYPERLINK"),
+            FieldType::If => write!(f, "IF"),
+            FieldType::Keywords => write!(f, "KEYWORDS"),
+            FieldType::LastSavedBy => write!(f, "LASTSAVEDBY"),
+            FieldType::MergeField => write!(f, "MERGEFIELD"),
+            FieldType::NoteRef => write!(f, "NOTEREF"),
+            FieldType::NumChars => write!(f, "NUMCHARS"),
+            FieldType::NumPages => write!(f, "NUMPAGES"),
+            FieldType::NumWords => write!(f, "NUMWORDS"),
+            FieldType::Page => write!(f, "PAGE"),
+            FieldType::PageRef => write!(f, "PAGEREF"),
+            FieldType::PrintDate => write!(f, "PRINTDATE"),
+            FieldType::Quote => write!(f, "QUOTE"),
+            FieldType::Ref => write!(f, "REF"),
+            FieldType::RevNum => write!(f, "REVNUM"),
+            FieldType::SaveDate => write!(f, "SAVEDATE"),
+            FieldType::Section => write!(f, "SECTION"),
+            FieldType::SectionPages => write!(f, "SECTIONPAGES"),
+            FieldType::Seq => write!(f, "SEQ"),
+            FieldType::StyleRef => write!(f, "STYLEREF"),
+            FieldType::Subject => write!(f, "SUBJECT"),
+            FieldType::Symbol => write!(f, "SYMBOL"),
+            FieldType::Tc => write!(f, "TC"),
+            FieldType::Template => write!(f, "TEMPLATE"),
+            FieldType::Time => write!(f, "TIME"),
+            FieldType::Title => write!(f, "TITLE"),
+            FieldType::Toc => write!(f, "TOC"),
+            FieldType::UserName => write!(f, "USERNAME"),
+            FieldType::Unsupported => write!(f, "UNSUPPORTED"),
+        }
+    }
+}
+
+// Field names are case-insensitive.
+impl FromStr for FieldType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "AUTHOR" => Ok(FieldType::Author),
+            "COMMENTS" => Ok(FieldType::Comments),
+            "CREATEDATE" => Ok(FieldType::CreateDate),
+            "DATE" => Ok(FieldType::Date),
+            "DOCPROPERTY" => Ok(FieldType::DocProperty),
+            "DOCVARIABLE" => Ok(FieldType::DocVariable),
+            "FILENAME" => Ok(FieldType::FileName),
+            "FILESIZE" => Ok(FieldType::FileSize),
+            "FORMCHECKBOX" => Ok(FieldType::FormCheckBox),
+            "FORMDROPDOWN" => Ok(FieldType::FormDropDown),
+            "FORMTEXT" => Ok(FieldType::FormText),
+            "HYPERLINK" => Ok(FieldType::Hyperlink),
+            "IF" => Ok(FieldType::If),
+            "KEYWORDS" => Ok(FieldType::Keywords),
+            "LASTSAVEDBY" => Ok(FieldType::LastSavedBy),
+            "MERGEFIELD" => Ok(FieldType::MergeField),
+            "NOTEREF" => Ok(FieldType::NoteRef),
+            "NUMCHARS" => Ok(FieldType::NumChars),
+            "NUMPAGES" => Ok(FieldType::NumPages),
+            "NUMWORDS" => Ok(FieldType::NumWords),
+            "PAGE" => Ok(FieldType::Page),
+            "PAGEREF" => Ok(FieldType::PageRef),
+            "PRINTDATE" => Ok(FieldType::PrintDate),
+            "QUOTE" => Ok(FieldType::Quote),
+            "REF" => Ok(FieldType::Ref),
+            "REVNUM" => Ok(FieldType::RevNum),
+            "SAVEDATE" => Ok(FieldType::SaveDate),
+            "SECTION" => Ok(FieldType::Section),
+            "SECTIONPAGES" => Ok(FieldType::SectionPages),
+            "SEQ" => Ok(FieldType::Seq),
+            "STYLEREF" => Ok(FieldType::StyleRef),
+            "SUBJECT" => Ok(FieldType::Subject),
+            "SYMBOL" => Ok(FieldType::Symbol),
+            "TC" => Ok(FieldType::Tc),
+            "TEMPLATE" => Ok(FieldType::Template),
+            "TIME" => Ok(FieldType::Time),
+            "TITLE" => Ok(FieldType::Title),
+            "TOC" => Ok(FieldType::Toc),
+            "USERNAME" => Ok(FieldType::UserName),
+            _ => Ok(FieldType::Unsupported),
+        }
+    }
+}
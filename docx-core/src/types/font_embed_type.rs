@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+// The style slot of an embedded font, i.e. w:embedRegular, w:embedBold, w:embedItalic or w:embedBoldItalic.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FontEmbedType {
+    Regular,
+    Bold,
+    Italic,
+    BoldItalic,
+}
+
+impl fmt::Display for FontEmbedType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FontEmbedType::Regular => write!(f, "regular"),
+            FontEmbedType::Bold => write!(f, "bold"),
+            FontEmbedType::Italic => write!(f, "italic"),
+            FontEmbedType::BoldItalic => write!(f, "boldItalic"),
+        }
+    }
+}
+
+impl FromStr for FontEmbedType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "regular" => Ok(FontEmbedType::Regular),
+            "bold" => Ok(FontEmbedType::Bold),
+            "italic" => Ok(FontEmbedType::Italic),
+            "boldItalic" => Ok(FontEmbedType::BoldItalic),
+            _ => Err(errors::TypeError::Unsupported(s.to_string())),
+        }
+    }
+}
@@ -1,9 +1,14 @@
+use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::str::FromStr;
 #[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
 
+use super::errors;
+
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub enum FontPitchType {
     Default,
     Fixed,
@@ -19,3 +24,15 @@ impl fmt::Display for FontPitchType {
         }
     }
 }
+
+impl FromStr for FontPitchType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(FontPitchType::Default),
+            "fixed" => Ok(FontPitchType::Fixed),
+            "variable" => Ok(FontPitchType::Variable),
+            _ => Err(errors::TypeError::Unsupported(s.to_string())),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+//
+// Please see ST_LineNumberRestart
+//
+// <xsd:enumeration value="newPage"/>
+// <xsd:enumeration value="newSection"/>
+// <xsd:enumeration value="continuous"/>
+
+use std::fmt;
+use std::str::FromStr;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum LineNumberRestartType {
+    NewPage,
+    NewSection,
+    Continuous,
+}
+
+impl fmt::Display for LineNumberRestartType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LineNumberRestartType::NewPage => write!(f, "newPage"),
+            LineNumberRestartType::NewSection => write!(f, "newSection"),
+            LineNumberRestartType::Continuous => write!(f, "continuous"),
+        }
+    }
+}
+
+impl FromStr for LineNumberRestartType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "newPage" => Ok(LineNumberRestartType::NewPage),
+            "newSection" => Ok(LineNumberRestartType::NewSection),
+            "continuous" => Ok(LineNumberRestartType::Continuous),
+            _ => Ok(LineNumberRestartType::NewPage),
+        }
+    }
+}
@@ -3,21 +3,30 @@ pub mod border_position;
 pub mod border_type;
 pub mod break_type;
 pub mod character_spacing_values;
+pub mod color_value;
 pub mod doc_grid_type;
+pub mod document_protection_type;
 pub mod drawing_position;
+pub mod editor_group_type;
 pub mod emu;
 pub mod errors;
 pub mod field_char_type;
+pub mod field_type;
+pub mod font_embed_type;
 pub mod font_pitch_type;
 pub mod height_rule;
 pub mod hyperlink_type;
 pub mod level_suffix_type;
+pub mod line_number_restart_type;
 pub mod line_spacing_type;
+pub mod page_border_type;
 pub mod page_margin;
 pub mod page_orientation_type;
 pub mod positional_tab_alignment_type;
 pub mod positional_tab_relative_to;
+pub mod proof_state_type;
 pub mod relative_from_type;
+pub mod sdt_lock_type;
 pub mod section_type;
 pub mod shd_type;
 pub mod special_indent_type;
@@ -25,9 +34,13 @@ pub mod style_type;
 pub mod tab_leader_type;
 pub mod tab_value_type;
 pub mod table_alignment_type;
+pub mod table_auto_fit_type;
 pub mod table_layout_type;
 pub mod text_alignment_type;
 pub mod text_direction_type;
+pub mod text_effect_type;
+pub mod text_input_type;
+pub mod theme_color_type;
 pub mod vert_align_type;
 pub mod vertical_align_type;
 pub mod vertical_merge_type;
@@ -38,21 +51,30 @@ pub use border_position::*;
 pub use border_type::*;
 pub use break_type::*;
 pub use character_spacing_values::*;
+pub use color_value::*;
 pub use doc_grid_type::*;
+pub use document_protection_type::*;
 pub use drawing_position::*;
+pub use editor_group_type::*;
 pub use emu::*;
 pub use errors::*;
 pub use field_char_type::*;
+pub use field_type::*;
+pub use font_embed_type::*;
 pub use font_pitch_type::*;
 pub use height_rule::*;
 pub use hyperlink_type::*;
 pub use level_suffix_type::*;
+pub use line_number_restart_type::*;
 pub use line_spacing_type::*;
+pub use page_border_type::*;
 pub use page_margin::*;
 pub use page_orientation_type::*;
 pub use positional_tab_alignment_type::*;
 pub use positional_tab_relative_to::*;
+pub use proof_state_type::*;
 pub use relative_from_type::*;
+pub use sdt_lock_type::*;
 pub use section_type::*;
 pub use shd_type::*;
 pub use special_indent_type::*;
@@ -60,9 +82,13 @@ pub use style_type::*;
 pub use tab_leader_type::*;
 pub use tab_value_type::*;
 pub use table_alignment_type::*;
+pub use table_auto_fit_type::*;
 pub use table_layout_type::*;
 pub use text_alignment_type::*;
 pub use text_direction_type::*;
+pub use text_effect_type::*;
+pub use text_input_type::*;
+pub use theme_color_type::*;
 pub use vert_align_type::*;
 pub use vertical_align_type::*;
 pub use vertical_merge_type::*;
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+//
+// Please see CT_PageBorders
+//
+// <xsd:attribute name="zOrder" type="ST_PageBorderZOrder"/>
+// <xsd:attribute name="display" type="ST_PageBorderDisplay"/>
+// <xsd:attribute name="offsetFrom" type="ST_PageBorderOffset"/>
+
+use std::fmt;
+use std::str::FromStr;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum PageBorderOffsetType {
+    Page,
+    Text,
+}
+
+impl fmt::Display for PageBorderOffsetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PageBorderOffsetType::Page => write!(f, "page"),
+            PageBorderOffsetType::Text => write!(f, "text"),
+        }
+    }
+}
+
+impl FromStr for PageBorderOffsetType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "page" => Ok(PageBorderOffsetType::Page),
+            "text" => Ok(PageBorderOffsetType::Text),
+            _ => Ok(PageBorderOffsetType::Text),
+        }
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum PageBorderDisplayType {
+    AllPages,
+    FirstPage,
+    NotFirstPage,
+}
+
+impl fmt::Display for PageBorderDisplayType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PageBorderDisplayType::AllPages => write!(f, "allPages"),
+            PageBorderDisplayType::FirstPage => write!(f, "firstPage"),
+            PageBorderDisplayType::NotFirstPage => write!(f, "notFirstPage"),
+        }
+    }
+}
+
+impl FromStr for PageBorderDisplayType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "allPages" => Ok(PageBorderDisplayType::AllPages),
+            "firstPage" => Ok(PageBorderDisplayType::FirstPage),
+            "notFirstPage" => Ok(PageBorderDisplayType::NotFirstPage),
+            _ => Ok(PageBorderDisplayType::AllPages),
+        }
+    }
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum PageBorderZOrderType {
+    Front,
+    Back,
+}
+
+impl fmt::Display for PageBorderZOrderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PageBorderZOrderType::Front => write!(f, "front"),
+            PageBorderZOrderType::Back => write!(f, "back"),
+        }
+    }
+}
+
+impl FromStr for PageBorderZOrderType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "front" => Ok(PageBorderZOrderType::Front),
+            "back" => Ok(PageBorderZOrderType::Back),
+            _ => Ok(PageBorderZOrderType::Front),
+        }
+    }
+}
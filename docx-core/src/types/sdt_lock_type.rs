@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+//
+// Please see http://officeopenxml.com/WPcontentControls.php
+//
+use std::fmt;
+use std::str::FromStr;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub enum SdtLockType {
+    // The control cannot be deleted.
+    SdtLocked,
+    // The contents cannot be edited.
+    ContentLocked,
+    // Neither the control nor the contents can be changed.
+    SdtContentLocked,
+    Unlocked,
+}
+
+impl fmt::Display for SdtLockType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SdtLockType::SdtLocked => write!(f, "sdtLocked"),
+            SdtLockType::ContentLocked => write!(f, "contentLocked"),
+            SdtLockType::SdtContentLocked => write!(f, "sdtContentLocked"),
+            SdtLockType::Unlocked => write!(f, "unlocked"),
+        }
+    }
+}
+
+impl FromStr for SdtLockType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sdtLocked" => Ok(SdtLockType::SdtLocked),
+            "contentLocked" => Ok(SdtLockType::ContentLocked),
+            "sdtContentLocked" => Ok(SdtLockType::SdtContentLocked),
+            "unlocked" => Ok(SdtLockType::Unlocked),
+            _ => Err(errors::TypeError::Unsupported(s.to_string())),
+        }
+    }
+}
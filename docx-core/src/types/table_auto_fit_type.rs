@@ -0,0 +1,37 @@
+use serde::Serialize;
+use std::fmt;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+use std::str::FromStr;
+
+// How `w:tblGrid` is derived when it is not set explicitly.
+// `Window` fills the usable page width, `Contents` estimates column widths from cell text.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TableAutoFitType {
+    Window,
+    Contents,
+}
+
+impl fmt::Display for TableAutoFitType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TableAutoFitType::Window => write!(f, "window"),
+            TableAutoFitType::Contents => write!(f, "contents"),
+        }
+    }
+}
+
+impl FromStr for TableAutoFitType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "window" => Ok(TableAutoFitType::Window),
+            "contents" => Ok(TableAutoFitType::Contents),
+            _ => Err(errors::TypeError::Unsupported(s.to_string())),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+//
+// Please see ST_TextEffect
+//
+// <xsd:enumeration value="blinkBackground"/>
+// <xsd:enumeration value="lights"/>
+// <xsd:enumeration value="antsBlack"/>
+// <xsd:enumeration value="antsRed"/>
+// <xsd:enumeration value="shimmer"/>
+// <xsd:enumeration value="sparkle"/>
+// <xsd:enumeration value="none"/>
+
+use std::fmt;
+use std::str::FromStr;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum TextEffectType {
+    BlinkBackground,
+    Lights,
+    AntsBlack,
+    AntsRed,
+    Shimmer,
+    Sparkle,
+    None,
+}
+
+impl fmt::Display for TextEffectType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TextEffectType::BlinkBackground => write!(f, "blinkBackground"),
+            TextEffectType::Lights => write!(f, "lights"),
+            TextEffectType::AntsBlack => write!(f, "antsBlack"),
+            TextEffectType::AntsRed => write!(f, "antsRed"),
+            TextEffectType::Shimmer => write!(f, "shimmer"),
+            TextEffectType::Sparkle => write!(f, "sparkle"),
+            TextEffectType::None => write!(f, "none"),
+        }
+    }
+}
+
+impl FromStr for TextEffectType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "blinkBackground" => Ok(TextEffectType::BlinkBackground),
+            "lights" => Ok(TextEffectType::Lights),
+            "antsBlack" => Ok(TextEffectType::AntsBlack),
+            "antsRed" => Ok(TextEffectType::AntsRed),
+            "shimmer" => Ok(TextEffectType::Shimmer),
+            "sparkle" => Ok(TextEffectType::Sparkle),
+            _ => Ok(TextEffectType::None),
+        }
+    }
+}
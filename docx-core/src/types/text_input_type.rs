@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+//
+// Please see https://c-rex.net/projects/samples/ooxml/e1/Part4/OOXML_P4_DOCX_type_topic_ID0ETJ5Y.html
+//
+use std::fmt;
+use std::str::FromStr;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub enum TextInputType {
+    Regular,
+    Number,
+    Date,
+    CurrentDate,
+    CurrentTime,
+    Calculated,
+}
+
+impl fmt::Display for TextInputType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TextInputType::Regular => write!(f, "regular"),
+            TextInputType::Number => write!(f, "number"),
+            TextInputType::Date => write!(f, "date"),
+            TextInputType::CurrentDate => write!(f, "currentDate"),
+            TextInputType::CurrentTime => write!(f, "currentTime"),
+            TextInputType::Calculated => write!(f, "calculated"),
+        }
+    }
+}
+
+impl FromStr for TextInputType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "regular" => Ok(TextInputType::Regular),
+            "number" => Ok(TextInputType::Number),
+            "date" => Ok(TextInputType::Date),
+            "currentDate" => Ok(TextInputType::CurrentDate),
+            "currentTime" => Ok(TextInputType::CurrentTime),
+            "calculated" => Ok(TextInputType::Calculated),
+            _ => Err(errors::TypeError::Unsupported(s.to_string())),
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+//
+// Please see ST_ThemeColor
+//
+// <xsd:enumeration value="dark1"/>
+// <xsd:enumeration value="light1"/>
+// <xsd:enumeration value="dark2"/>
+// <xsd:enumeration value="light2"/>
+// <xsd:enumeration value="accent1"/>
+// <xsd:enumeration value="accent2"/>
+// <xsd:enumeration value="accent3"/>
+// <xsd:enumeration value="accent4"/>
+// <xsd:enumeration value="accent5"/>
+// <xsd:enumeration value="accent6"/>
+// <xsd:enumeration value="hyperlink"/>
+// <xsd:enumeration value="followedHyperlink"/>
+// <xsd:enumeration value="none"/>
+// <xsd:enumeration value="background1"/>
+// <xsd:enumeration value="text1"/>
+// <xsd:enumeration value="background2"/>
+// <xsd:enumeration value="text2"/>
+
+use std::fmt;
+use std::str::FromStr;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ThemeColorType {
+    Dark1,
+    Light1,
+    Dark2,
+    Light2,
+    Accent1,
+    Accent2,
+    Accent3,
+    Accent4,
+    Accent5,
+    Accent6,
+    Hyperlink,
+    FollowedHyperlink,
+    None,
+    Background1,
+    Text1,
+    Background2,
+    Text2,
+}
+
+impl fmt::Display for ThemeColorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ThemeColorType::Dark1 => write!(f, "dark1"),
+            ThemeColorType::Light1 => write!(f, "light1"),
+            ThemeColorType::Dark2 => write!(f, "dark2"),
+            ThemeColorType::Light2 => write!(f, "light2"),
+            ThemeColorType::Accent1 => write!(f, "accent1"),
+            ThemeColorType::Accent2 => write!(f, "accent2"),
+            ThemeColorType::Accent3 => write!(f, "accent3"),
+            ThemeColorType::Accent4 => write!(f, "accent4"),
+            ThemeColorType::Accent5 => write!(f, "accent5"),
+            ThemeColorType::Accent6 => write!(f, "accent6"),
+            ThemeColorType::Hyperlink => write!(f, "hyperlink"),
+            ThemeColorType::FollowedHyperlink => write!(f, "followedHyperlink"),
+            ThemeColorType::None => write!(f, "none"),
+            ThemeColorType::Background1 => write!(f, "background1"),
+            ThemeColorType::Text1 => write!(f, "text1"),
+            ThemeColorType::Background2 => write!(f, "background2"),
+            ThemeColorType::Text2 => write!(f, "text2"),
+        }
+    }
+}
+
+impl FromStr for ThemeColorType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dark1" => Ok(ThemeColorType::Dark1),
+            "light1" => Ok(ThemeColorType::Light1),
+            "dark2" => Ok(ThemeColorType::Dark2),
+            "light2" => Ok(ThemeColorType::Light2),
+            "accent1" => Ok(ThemeColorType::Accent1),
+            "accent2" => Ok(ThemeColorType::Accent2),
+            "accent3" => Ok(ThemeColorType::Accent3),
+            "accent4" => Ok(ThemeColorType::Accent4),
+            "accent5" => Ok(ThemeColorType::Accent5),
+            "accent6" => Ok(ThemeColorType::Accent6),
+            "hyperlink" => Ok(ThemeColorType::Hyperlink),
+            "followedHyperlink" => Ok(ThemeColorType::FollowedHyperlink),
+            "none" => Ok(ThemeColorType::None),
+            "background1" => Ok(ThemeColorType::Background1),
+            "text1" => Ok(ThemeColorType::Text1),
+            "background2" => Ok(ThemeColorType::Background2),
+            "text2" => Ok(ThemeColorType::Text2),
+            _ => Err(errors::TypeError::Unsupported(s.to_string())),
+        }
+    }
+}
@@ -11,6 +11,7 @@ pub enum VAlignType {
     Top,
     Center,
     Bottom,
+    Both,
     Unsupported,
 }
 
@@ -20,6 +21,7 @@ impl fmt::Display for VAlignType {
             VAlignType::Top => write!(f, "top"),
             VAlignType::Center => write!(f, "center"),
             VAlignType::Bottom => write!(f, "bottom"),
+            VAlignType::Both => write!(f, "both"),
             VAlignType::Unsupported => write!(f, "unsupported"),
         }
     }
@@ -32,6 +34,7 @@ impl FromStr for VAlignType {
             "top" => Ok(VAlignType::Top),
             "center" => Ok(VAlignType::Center),
             "bottom" => Ok(VAlignType::Bottom),
+            "both" => Ok(VAlignType::Both),
             _ => Ok(VAlignType::Unsupported),
         }
     }
@@ -0,0 +1,37 @@
+use super::XMLBuilder;
+use super::XmlEvent;
+use std::io::Write;
+use xml::writer::Result;
+
+impl<W: Write> XMLBuilder<W> {
+    pub(crate) fn open_comments_ids(self) -> Result<Self> {
+        self.write(
+            XmlEvent::start_element("w16cid:commentsIds")
+                .attr(
+                    "xmlns:mc",
+                    "http://schemas.openxmlformats.org/markup-compatibility/2006",
+                )
+                .attr(
+                    "xmlns:w",
+                    "http://schemas.openxmlformats.org/wordprocessingml/2006/main",
+                )
+                .attr(
+                    "xmlns:w14",
+                    "http://schemas.microsoft.com/office/word/2010/wordml",
+                )
+                .attr(
+                    "xmlns:w15",
+                    "http://schemas.microsoft.com/office/word/2012/wordml",
+                )
+                .attr(
+                    "xmlns:w16cex",
+                    "http://schemas.microsoft.com/office/word/2018/wordml/cex",
+                )
+                .attr(
+                    "xmlns:w16cid",
+                    "http://schemas.microsoft.com/office/word/2016/wordml/cid",
+                )
+                .attr("mc:Ignorable", "w14 w15 w16cex w16cid"),
+        )
+    }
+}
@@ -7,4 +7,8 @@ impl<W: Write> XMLBuilder<W> {
     open!(open_custom_properties, "Properties", "xmlns", "xmlns:vt");
     open!(open_property, "property", "fmtid", "pid", "name");
     closed_with_child!(lpwstr, "vt:lpwstr");
+    closed_with_child!(i4, "vt:i4");
+    closed_with_child!(r8, "vt:r8");
+    closed_with_child!(vt_bool, "vt:bool");
+    closed_with_child!(filetime, "vt:filetime");
 }
@@ -5,8 +5,49 @@ use crate::FrameProperty;
 use crate::TablePositionProperty;
 
 use std::io::Write;
+use xml::writer::events::StartElementBuilder;
 use xml::writer::Result;
 
+// Owned attribute values of a ColorValue, i.e. w:color, w:themeColor, w:themeTint and w:themeShade.
+pub(crate) struct ColorAttrs {
+    val: String,
+    theme_color: Option<String>,
+    tint: Option<String>,
+    shade: Option<String>,
+}
+
+impl ColorAttrs {
+    pub(crate) fn new(c: &ColorValue) -> ColorAttrs {
+        let theme = c.theme_color();
+        ColorAttrs {
+            val: c.val(),
+            theme_color: theme.map(|t| t.color.to_string()),
+            tint: theme.and_then(|t| t.tint).map(|v| format!("{:02X}", v)),
+            shade: theme.and_then(|t| t.shade).map(|v| format!("{:02X}", v)),
+        }
+    }
+
+    pub(crate) fn apply<'a>(
+        &'a self,
+        mut w: StartElementBuilder<'a>,
+        names: [&'a str; 4],
+    ) -> StartElementBuilder<'a> {
+        w = w.attr(names[0], &self.val);
+        if let Some(ref v) = self.theme_color {
+            w = w.attr(names[1], v);
+        }
+        if let Some(ref v) = self.tint {
+            w = w.attr(names[2], v);
+        }
+        if let Some(ref v) = self.shade {
+            w = w.attr(names[3], v);
+        }
+        w
+    }
+}
+
+const COLOR_ATTRS: [&str; 4] = ["w:color", "w:themeColor", "w:themeTint", "w:themeShade"];
+
 impl<W: Write> XMLBuilder<W> {
     // i.e. <w:body... >
     open!(open_body, "w:body");
@@ -90,13 +131,15 @@ impl<W: Write> XMLBuilder<W> {
             .close()
     }
 
+    // w:dataBinding, or w15:dataBinding for repeating sections
     pub(crate) fn data_binding(
         self,
+        el: &str,
         xpath: Option<&String>,
         prefix_mappings: Option<&String>,
         store_item_id: Option<&String>,
     ) -> Result<Self> {
-        let mut e = XmlEvent::start_element("w:dataBinding");
+        let mut e = XmlEvent::start_element(el);
         if let Some(xpath) = xpath {
             e = e.attr("w:xpath", xpath);
         }
@@ -109,6 +152,40 @@ impl<W: Write> XMLBuilder<W> {
         self.write(e)?.close()
     }
 
+    // w:enabled is written without w:val for enabled form fields.
+    pub(crate) fn form_field_enabled(self, enabled: bool) -> Result<Self> {
+        let mut e = XmlEvent::start_element("w:enabled");
+        if !enabled {
+            e = e.attr("w:val", "0");
+        }
+        self.write(e)?.close()
+    }
+
+    // w:dropDownList or w:comboBox
+    pub(crate) fn open_sdt_list(self, el: &str, last_value: Option<&String>) -> Result<Self> {
+        let mut e = XmlEvent::start_element(el);
+        if let Some(last_value) = last_value {
+            e = e.attr("w:lastValue", last_value);
+        }
+        self.write(e)
+    }
+
+    pub(crate) fn sdt_list_item(self, display_text: Option<&String>, value: &str) -> Result<Self> {
+        let mut e = XmlEvent::start_element("w:listItem");
+        if let Some(display_text) = display_text {
+            e = e.attr("w:displayText", display_text);
+        }
+        self.write(e.attr("w:value", value))?.close()
+    }
+
+    pub(crate) fn open_sdt_date(self, full_date: Option<&String>) -> Result<Self> {
+        let mut e = XmlEvent::start_element("w:date");
+        if let Some(full_date) = full_date {
+            e = e.attr("w:fullDate", full_date);
+        }
+        self.write(e)
+    }
+
     pub(crate) fn open_hyperlink(
         self,
         rid: Option<&String>,
@@ -146,6 +223,31 @@ impl<W: Write> XMLBuilder<W> {
     open!(open_structured_tag_content, "w:sdtContent");
     open!(open_structured_tag_property, "w:sdtPr");
     closed_with_str!(alias, "w:alias");
+    closed_with_str!(sdt_tag, "w:tag");
+    closed_with_isize!(sdt_id, "w:id");
+    closed_with_str!(sdt_lock, "w:lock");
+    open!(open_placeholder, "w:placeholder");
+    closed_with_str!(doc_part, "w:docPart");
+    closed!(showing_placeholder, "w:showingPlcHdr");
+    open!(open_sdt_checkbox, "w14:checkbox");
+    closed!(sdt_checked, "w14:checked", "w14:val");
+    closed!(sdt_checked_state, "w14:checkedState", "w14:val", "w14:font");
+    closed!(sdt_unchecked_state, "w14:uncheckedState", "w14:val", "w14:font");
+    closed_with_str!(date_format, "w:dateFormat");
+    closed_with_str!(lid, "w:lid");
+    closed_with_str!(store_mapped_data_as, "w:storeMappedDataAs");
+    closed_with_str!(calendar, "w:calendar");
+    closed!(sdt_text, "w:text");
+    closed!(sdt_text_multi_line, "w:text", "w:multiLine");
+    closed!(sdt_picture, "w:picture");
+    open!(open_repeating_section, "w15:repeatingSection");
+    closed!(section_title, "w15:sectionTitle", "w15:val");
+    closed!(
+        do_not_allow_insert_delete_section,
+        "w15:doNotAllowInsertDeleteSection",
+        "w15:val"
+    );
+    closed!(repeating_section_item, "w15:repeatingSectionItem");
 
     closed_paragraph_border_el!(paragraph_border_top, "w:top");
     closed_paragraph_border_el!(paragraph_border_left, "w:left");
@@ -174,8 +276,24 @@ impl<W: Write> XMLBuilder<W> {
     closed_with_str!(text_alignment, "w:textAlignment");
 
     closed!(field_character, "w:fldChar", "w:fldCharType", "w:dirty");
+    open!(open_field_character, "w:fldChar", "w:fldCharType", "w:dirty");
+    open!(open_form_field_data, "w:ffData");
+    closed_with_str!(calc_on_exit, "w:calcOnExit");
+    open!(open_form_text_input, "w:textInput");
+    closed_with_str!(form_field_default, "w:default");
+    closed_with_usize!(form_field_max_length, "w:maxLength");
+    closed_with_str!(form_field_format, "w:format");
+    open!(open_form_check_box, "w:checkBox");
+    closed_with_usize!(form_field_size, "w:size");
+    closed!(form_field_size_auto, "w:sizeAuto");
+    closed_with_str!(form_field_checked, "w:checked");
+    open!(open_form_drop_down_list, "w:ddList");
+    closed_with_usize!(form_field_result, "w:result");
+    closed_with_str!(form_field_list_entry, "w:listEntry");
 
     open!(open_instr_text, "w:instrText");
+    open!(open_field_simple, "w:fldSimple", "w:instr");
+    open!(open_dirty_field_simple, "w:fldSimple", "w:instr", "w:dirty");
     open!(open_delete_instr_text, "w:delInstrText");
 
     closed!(text_direction, "w:textDirection", "w:val");
@@ -190,6 +308,51 @@ impl<W: Write> XMLBuilder<W> {
     }
 
     closed_with_str!(caps, "w:caps");
+    closed!(small_caps, "w:smallCaps");
+    closed!(outline, "w:outline");
+    closed!(shadow, "w:shadow");
+    closed!(emboss, "w:emboss");
+    closed!(imprint, "w:imprint");
+    closed!(no_proof, "w:noProof");
+    closed!(web_hidden, "w:webHidden");
+    closed_with_usize!(kern, "w:kern");
+    closed_with_isize!(position, "w:position");
+    closed_with_str!(effect, "w:effect");
+
+    // i.e. <w:smallCaps w:val="false" />
+    pub(crate) fn disabled_toggle(self, el: &str) -> Result<Self> {
+        self.write(XmlEvent::start_element(el).attr("w:val", "false"))?
+            .close()
+    }
+
+    pub(crate) fn lang(
+        self,
+        val: Option<&str>,
+        east_asia: Option<&str>,
+        bidi: Option<&str>,
+    ) -> Result<Self> {
+        let mut w = XmlEvent::start_element("w:lang");
+        if let Some(val) = val {
+            w = w.attr("w:val", val);
+        }
+        if let Some(east_asia) = east_asia {
+            w = w.attr("w:eastAsia", east_asia);
+        }
+        if let Some(bidi) = bidi {
+            w = w.attr("w:bidi", bidi);
+        }
+        self.write(w)?.close()
+    }
+
+    pub(crate) fn fit_text(self, val: usize, id: Option<usize>) -> Result<Self> {
+        let val = format!("{}", val);
+        let id_string = format!("{}", id.unwrap_or_default());
+        let mut w = XmlEvent::start_element("w:fitText").attr("w:val", &val);
+        if id.is_some() {
+            w = w.attr("w:id", &id_string);
+        }
+        self.write(w)?.close()
+    }
 
     closed!(i, "w:i");
     closed!(i_cs, "w:iCs");
@@ -231,7 +394,14 @@ impl<W: Write> XMLBuilder<W> {
     closed_with_str!(link, "w:link");
 
     // i.e. <w:color ... >
-    closed_with_str!(color, "w:color");
+    pub(crate) fn color(self, color: &ColorValue) -> Result<Self> {
+        let c = ColorAttrs::new(color);
+        let w = c.apply(
+            XmlEvent::start_element("w:color"),
+            ["w:val", "w:themeColor", "w:themeTint", "w:themeShade"],
+        );
+        self.write(w)?.close()
+    }
 
     // i.e. <w:highlight ... >
     closed_with_str!(highlight, "w:highlight");
@@ -381,7 +551,22 @@ impl<W: Write> XMLBuilder<W> {
 
     closed_border_el!(text_border, "w:bdr");
 
-    closed!(shd, "w:shd", "w:val", "w:color", "w:fill");
+    pub(crate) fn shd(self, shd_type: &str, color: &ColorValue, fill: &ColorValue) -> Result<Self> {
+        let c = ColorAttrs::new(color);
+        let f = ColorAttrs::new(fill);
+        let mut w = XmlEvent::start_element("w:shd").attr("w:val", shd_type);
+        w = c.apply(w, COLOR_ATTRS);
+        w = f.apply(
+            w,
+            [
+                "w:fill",
+                "w:themeFill",
+                "w:themeFillTint",
+                "w:themeFillShade",
+            ],
+        );
+        self.write(w)?.close()
+    }
 
     closed!(tab_with_pos, "w:tab", "w:val", "w:pos");
 
@@ -394,6 +579,12 @@ impl<W: Write> XMLBuilder<W> {
     closed_with_str!(pitch, "w:pitch");
     closed_with_str!(family, "w:family");
     closed_with_str!(charset, "w:charset");
+    closed_with_str!(alt_name, "w:altName");
+    closed_with_str!(panose1, "w:panose1");
+    closed!(embed_regular, "w:embedRegular", "r:id", "w:fontKey");
+    closed!(embed_bold, "w:embedBold", "r:id", "w:fontKey");
+    closed!(embed_italic, "w:embedItalic", "r:id", "w:fontKey");
+    closed!(embed_bold_italic, "w:embedBoldItalic", "r:id", "w:fontKey");
 
     open!(open_section_property, "w:sectPr");
     closed!(header_reference, "w:headerReference", "w:type", "r:id");
@@ -401,7 +592,20 @@ impl<W: Write> XMLBuilder<W> {
 
     closed_with_str!(type_tag, "w:type");
     closed!(title_pg, "w:titlePg");
+    closed!(rtl_gutter, "w:rtlGutter");
     closed!(even_and_odd_headers, "w:evenAndOddHeaders");
+    closed!(embed_true_type_fonts, "w:embedTrueTypeFonts");
+    closed!(mirror_margins, "w:mirrorMargins");
+    closed!(gutter_at_top, "w:gutterAtTop");
+    closed!(book_fold_printing, "w:bookFoldPrinting");
+    closed!(print_two_on_one, "w:printTwoOnOne");
+    closed!(update_fields, "w:updateFields", "w:val");
+    closed!(track_revisions, "w:trackRevisions");
+    closed!(auto_hyphenation, "w:autoHyphenation");
+    closed_with_usize!(hyphenation_zone, "w:hyphenationZone");
+    closed_with_str!(default_table_style, "w:defaultTableStyle");
+    closed_with_str!(decimal_symbol, "w:decimalSymbol");
+    closed_with_str!(list_separator, "w:listSeparator");
     closed!(page_size, "w:pgSz", "w:w", "w:h");
     closed!(page_size_with_orient, "w:pgSz", "w:w", "w:h", "w:orient");
     closed!(
@@ -415,7 +619,6 @@ impl<W: Write> XMLBuilder<W> {
         "w:footer",
         "w:gutter"
     );
-    closed!(columns, "w:cols", "w:space", "w:num");
     // closed!(text_direction, "w:val");
     closed!(document_grid, "w:docGrid", "w:type", "w:linePitch");
 
@@ -428,12 +631,119 @@ impl<W: Write> XMLBuilder<W> {
         "w:author",
         "w:date"
     );
+    open!(
+        open_run_property_change,
+        "w:rPrChange",
+        "w:id",
+        "w:author",
+        "w:date"
+    );
+    open!(
+        open_table_property_change,
+        "w:tblPrChange",
+        "w:id",
+        "w:author",
+        "w:date"
+    );
+    open!(
+        open_table_row_property_change,
+        "w:trPrChange",
+        "w:id",
+        "w:author",
+        "w:date"
+    );
+    open!(
+        open_table_cell_property_change,
+        "w:tcPrChange",
+        "w:id",
+        "w:author",
+        "w:date"
+    );
+    open!(open_move_from, "w:moveFrom", "w:id", "w:author", "w:date");
+    open!(open_move_to, "w:moveTo", "w:id", "w:author", "w:date");
+    closed!(
+        move_from_range_start,
+        "w:moveFromRangeStart",
+        "w:id",
+        "w:name",
+        "w:author",
+        "w:date"
+    );
+    closed!(move_from_range_end, "w:moveFromRangeEnd", "w:id");
+    closed!(
+        move_to_range_start,
+        "w:moveToRangeStart",
+        "w:id",
+        "w:name",
+        "w:author",
+        "w:date"
+    );
+    closed!(move_to_range_end, "w:moveToRangeEnd", "w:id");
+    closed!(cell_insert, "w:cellIns", "w:id", "w:author", "w:date");
+    closed!(cell_delete, "w:cellDel", "w:id", "w:author", "w:date");
+
+    pub(crate) fn cell_merge(
+        self,
+        id: &str,
+        author: &str,
+        date: &str,
+        v_merge: Option<&str>,
+        v_merge_orig: Option<&str>,
+    ) -> Result<Self> {
+        let mut e = XmlEvent::start_element("w:cellMerge")
+            .attr("w:id", id)
+            .attr("w:author", author)
+            .attr("w:date", date);
+        if let Some(v_merge) = v_merge {
+            e = e.attr("w:vMerge", v_merge);
+        }
+        if let Some(v_merge_orig) = v_merge_orig {
+            e = e.attr("w:vMergeOrig", v_merge_orig);
+        }
+        self.write(e)?.close()
+    }
+
+    closed!(
+        numbering_change,
+        "w:numberingChange",
+        "w:id",
+        "w:author",
+        "w:date",
+        "w:original"
+    );
     // cantSplit for table row
     closed!(cant_split, "w:cantSplit");
 
     closed!(bookmark_start, "w:bookmarkStart", "w:id", "w:name");
     closed!(bookmark_end, "w:bookmarkEnd", "w:id");
 
+    pub(crate) fn perm_start(
+        self,
+        id: &str,
+        editor_group: Option<&String>,
+        editor: Option<&String>,
+        col_first: Option<usize>,
+        col_last: Option<usize>,
+    ) -> Result<Self> {
+        let col_first = col_first.map(|c| c.to_string());
+        let col_last = col_last.map(|c| c.to_string());
+        let mut e = XmlEvent::start_element("w:permStart").attr("w:id", id);
+        if let Some(editor_group) = editor_group {
+            e = e.attr("w:edGrp", editor_group);
+        }
+        if let Some(editor) = editor {
+            e = e.attr("w:ed", editor);
+        }
+        if let Some(col_first) = col_first.as_ref() {
+            e = e.attr("w:colFirst", col_first);
+        }
+        if let Some(col_last) = col_last.as_ref() {
+            e = e.attr("w:colLast", col_last);
+        }
+        self.write(e)?.close()
+    }
+    closed!(perm_end, "w:permEnd", "w:id");
+
     closed!(comment_range_start, "w:commentRangeStart", "w:id");
     closed!(comment_range_end, "w:commentRangeEnd", "w:id");
     closed!(comment_reference, "w:commentReference", "w:id");
@@ -505,6 +815,14 @@ impl<W: Write> XMLBuilder<W> {
     closed!(page_break_before, "w:pageBreakBefore");
     closed!(widow_control, "w:widowControl", "w:val");
     closed!(bidi, "w:bidi");
+    closed!(contextual_spacing, "w:contextualSpacing");
+    closed!(suppress_auto_hyphens, "w:suppressAutoHyphens");
+    closed!(suppress_line_numbers, "w:suppressLineNumbers");
+    closed!(mirror_indents, "w:mirrorIndents");
+    closed!(word_wrap, "w:wordWrap");
+    closed!(kinsoku, "w:kinsoku");
+    closed!(top_line_punct, "w:topLinePunct");
+    closed_with_str!(cnf_style, "w:cnfStyle");
     /*
     <w:lvlOverride w:ilvl="0">
       <w:startOverride w:val="1"/>
@@ -576,6 +894,37 @@ impl<W: Write> XMLBuilder<W> {
         self.write(el)?.close()
     }
 
+    // w16cid:commentId w16cid:paraId="37D90BEC" w16cid:durableId="4C7250AB"
+    closed!(
+        comment_durable_id,
+        "w16cid:commentId",
+        "w16cid:paraId",
+        "w16cid:durableId"
+    );
+
+    // w16cex:commentExtensible w16cex:durableId="4C7250AB" w16cex:dateUtc="2024-09-06T02:13:00Z"
+    pub(crate) fn comment_extensible(
+        self,
+        durable_id: &str,
+        date_utc: &Option<String>,
+    ) -> Result<Self> {
+        let mut el = XmlEvent::start_element("w16cex:commentExtensible")
+            .attr("w16cex:durableId", durable_id);
+        if let Some(date_utc) = date_utc {
+            el = el.attr("w16cex:dateUtc", date_utc);
+        }
+        self.write(el)?.close()
+    }
+
+    // people
+    open!(open_person, "w15:person", "w15:author");
+    closed!(
+        presence_info,
+        "w15:presenceInfo",
+        "w15:providerId",
+        "w15:userId"
+    );
+
     // docGrid
     pub(crate) fn doc_grid(
         self,
@@ -596,6 +945,115 @@ impl<W: Write> XMLBuilder<W> {
         self.write(w)?.close()
     }
 
+    // i.e. <w:cols ... >
+    pub(crate) fn open_columns(
+        self,
+        space: usize,
+        num: usize,
+        sep: bool,
+        equal_width: bool,
+    ) -> Result<Self> {
+        let space = format!("{}", space);
+        let num = format!("{}", num);
+        let mut w = XmlEvent::start_element("w:cols")
+            .attr("w:space", &space)
+            .attr("w:num", &num);
+        if sep {
+            w = w.attr("w:sep", "1");
+        }
+        if !equal_width {
+            w = w.attr("w:equalWidth", "0");
+        }
+        self.write(w)
+    }
+
+    pub(crate) fn column(self, width: usize, space: Option<usize>) -> Result<Self> {
+        let width = format!("{}", width);
+        let space_string = format!("{}", space.unwrap_or_default());
+        let mut w = XmlEvent::start_element("w:col").attr("w:w", &width);
+        if space.is_some() {
+            w = w.attr("w:space", &space_string);
+        }
+        self.write(w)?.close()
+    }
+
+    pub(crate) fn line_num_type(
+        self,
+        count_by: Option<usize>,
+        start: Option<usize>,
+        distance: Option<usize>,
+        restart: Option<LineNumberRestartType>,
+    ) -> Result<Self> {
+        let count_by = count_by.map(|v| v.to_string());
+        let start = start.map(|v| v.to_string());
+        let distance = distance.map(|v| v.to_string());
+        let restart = restart.map(|v| v.to_string());
+        let mut w = XmlEvent::start_element("w:lnNumType");
+        if let Some(ref v) = count_by {
+            w = w.attr("w:countBy", v);
+        }
+        if let Some(ref v) = start {
+            w = w.attr("w:start", v);
+        }
+        if let Some(ref v) = distance {
+            w = w.attr("w:distance", v);
+        }
+        if let Some(ref v) = restart {
+            w = w.attr("w:restart", v);
+        }
+        self.write(w)?.close()
+    }
+
+    // i.e. <w:pgBorders ... >
+    pub(crate) fn open_page_borders(
+        self,
+        z_order: Option<PageBorderZOrderType>,
+        display: Option<PageBorderDisplayType>,
+        offset_from: Option<PageBorderOffsetType>,
+    ) -> Result<Self> {
+        let z_order = z_order.map(|v| v.to_string());
+        let display = display.map(|v| v.to_string());
+        let offset_from = offset_from.map(|v| v.to_string());
+        let mut w = XmlEvent::start_element("w:pgBorders");
+        if let Some(ref v) = z_order {
+            w = w.attr("w:zOrder", v);
+        }
+        if let Some(ref v) = display {
+            w = w.attr("w:display", v);
+        }
+        if let Some(ref v) = offset_from {
+            w = w.attr("w:offsetFrom", v);
+        }
+        self.write(w)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn page_border(
+        self,
+        el: &str,
+        val: &str,
+        size: usize,
+        space: usize,
+        color: &str,
+        shadow: bool,
+        frame: bool,
+    ) -> Result<Self> {
+        let size = size.to_string();
+        let space = space.to_string();
+        let mut w = XmlEvent::start_element(el)
+            .attr("w:val", val)
+            .attr("w:sz", &size)
+            .attr("w:space", &space)
+            .attr("w:color", color);
+        if shadow {
+            w = w.attr("w:shadow", "1");
+        }
+        if frame {
+            w = w.attr("w:frame", "1");
+        }
+        self.write(w)?.close()
+    }
+
     /**
     pub h_space: Option<String>,
     pub v_space: Option<String>,
@@ -831,7 +1289,10 @@ mod tests {
     #[test]
     fn test_color() -> Result<()> {
         let b = XMLBuilder::new(Vec::new());
-        let r = b.color("2E74B5")?.into_inner()?.into_inner();
+        let r = b
+            .color(&ColorValue::rgb("2E74B5"))?
+            .into_inner()?
+            .into_inner();
         assert_eq!(str::from_utf8(&r).unwrap(), r#"<w:color w:val="2E74B5" />"#);
         Ok(())
     }
@@ -43,7 +43,11 @@ impl<W: Write> XMLBuilder<W> {
                     "xmlns:w14",
                     "http://schemas.microsoft.com/office/word/2010/wordml",
                 )
-                .attr("mc:Ignorable", "w14 wp14"),
+                .attr(
+                    "xmlns:w15",
+                    "http://schemas.microsoft.com/office/word/2012/wordml",
+                )
+                .attr("mc:Ignorable", "w14 w15 wp14"),
         )
     }
 }
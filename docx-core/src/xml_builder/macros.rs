@@ -515,16 +515,17 @@ macro_rules! closed_border_el {
             val: BorderType,
             size: usize,
             space: usize,
-            color: &str,
+            color: &ColorValue,
         ) -> ::xml::writer::Result<Self> {
-            self.write(
-                XmlEvent::start_element($el_name)
-                    .attr("w:val", &val.to_string())
-                    .attr("w:sz", &format!("{}", size))
-                    .attr("w:space", &format!("{}", space))
-                    .attr("w:color", color),
-            )?
-            .close()
+            let val = val.to_string();
+            let size = format!("{}", size);
+            let space = format!("{}", space);
+            let color = ColorAttrs::new(color);
+            let w = XmlEvent::start_element($el_name)
+                .attr("w:val", &val)
+                .attr("w:sz", &size)
+                .attr("w:space", &space);
+            self.write(color.apply(w, COLOR_ATTRS))?.close()
         }
     };
 }
@@ -536,16 +537,14 @@ macro_rules! closed_paragraph_border_el {
             val: &str,
             space: &str,
             size: &str,
-            color: &str,
+            color: &ColorValue,
         ) -> ::xml::writer::Result<Self> {
-            self.write(
-                XmlEvent::start_element($el_name)
-                    .attr("w:val", val)
-                    .attr("w:space", space)
-                    .attr("w:sz", size)
-                    .attr("w:color", color),
-            )?
-            .close()
+            let color = ColorAttrs::new(color);
+            let w = XmlEvent::start_element($el_name)
+                .attr("w:val", val)
+                .attr("w:space", space)
+                .attr("w:sz", size);
+            self.write(color.apply(w, COLOR_ATTRS))?.close()
         }
     };
 }
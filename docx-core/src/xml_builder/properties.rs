@@ -16,6 +16,11 @@ impl<W: Write> XMLBuilder<W> {
     closed_with_child!(characters, "Characters");
     closed_with_child!(characters_with_spaces, "CharactersWithSpaces");
     closed_with_child!(paragraphs, "Paragraphs");
+    closed_with_child!(lines, "Lines");
+    closed_with_child!(manager, "Manager");
+    closed_with_child!(company, "Company");
+    closed_with_child!(hyperlink_base, "HyperlinkBase");
+    closed_with_child!(app_version, "AppVersion");
 }
 
 #[cfg(test)]
@@ -21,4 +21,75 @@ impl<W: Write> XMLBuilder<W> {
                 ),
         )
     }
+
+    pub(crate) fn theme_font_lang(
+        self,
+        val: Option<&String>,
+        east_asia: Option<&String>,
+        bidi: Option<&String>,
+    ) -> Result<Self> {
+        let mut e = XmlEvent::start_element("w:themeFontLang");
+        if let Some(val) = val {
+            e = e.attr("w:val", val);
+        }
+        if let Some(east_asia) = east_asia {
+            e = e.attr("w:eastAsia", east_asia);
+        }
+        if let Some(bidi) = bidi {
+            e = e.attr("w:bidi", bidi);
+        }
+        self.write(e)?.close()
+    }
+
+    pub(crate) fn proof_state(
+        self,
+        spelling: Option<&String>,
+        grammar: Option<&String>,
+    ) -> Result<Self> {
+        let mut e = XmlEvent::start_element("w:proofState");
+        if let Some(spelling) = spelling {
+            e = e.attr("w:spelling", spelling);
+        }
+        if let Some(grammar) = grammar {
+            e = e.attr("w:grammar", grammar);
+        }
+        self.write(e)?.close()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn document_protection(
+        self,
+        edit: &str,
+        formatting: Option<bool>,
+        enforcement: bool,
+        crypt_algorithm_sid: Option<usize>,
+        spin_count: Option<usize>,
+        hash: Option<&String>,
+        salt: Option<&String>,
+    ) -> Result<Self> {
+        let sid = crypt_algorithm_sid.map(|s| s.to_string());
+        let spin_count = spin_count.map(|s| s.to_string());
+        let mut e = XmlEvent::start_element("w:documentProtection").attr("w:edit", edit);
+        if let Some(formatting) = formatting {
+            e = e.attr("w:formatting", if formatting { "1" } else { "0" });
+        }
+        e = e.attr("w:enforcement", if enforcement { "1" } else { "0" });
+        if let Some(sid) = sid.as_ref() {
+            e = e
+                .attr("w:cryptProviderType", "rsaAES")
+                .attr("w:cryptAlgorithmClass", "hash")
+                .attr("w:cryptAlgorithmType", "typeAny")
+                .attr("w:cryptAlgorithmSid", sid);
+        }
+        if let Some(spin_count) = spin_count.as_ref() {
+            e = e.attr("w:cryptSpinCount", spin_count);
+        }
+        if let Some(hash) = hash {
+            e = e.attr("w:hash", hash);
+        }
+        if let Some(salt) = salt {
+            e = e.attr("w:salt", salt);
+        }
+        self.write(e)?.close()
+    }
 }
@@ -0,0 +1,57 @@
+use super::XMLBuilder;
+use super::XmlEvent;
+
+use std::io::Write;
+use xml::writer::Result;
+
+impl<W: Write> XMLBuilder<W> {
+    pub(crate) fn open_theme(self, name: &str) -> Result<Self> {
+        self.write(
+            XmlEvent::start_element("a:theme")
+                .attr(
+                    "xmlns:a",
+                    "http://schemas.openxmlformats.org/drawingml/2006/main",
+                )
+                .attr("name", name),
+        )
+    }
+
+    open!(open_theme_elements, "a:themeElements");
+    closed!(object_defaults, "a:objectDefaults");
+    closed!(extra_clr_scheme_lst, "a:extraClrSchemeLst");
+
+    // color scheme
+    open!(open_clr_scheme, "a:clrScheme", "name");
+
+    // e.g. <a:accent1><a:srgbClr val="4472C4" /></a:accent1>
+    pub(crate) fn scheme_color(self, el: &str, val: &str) -> Result<Self> {
+        self.write(XmlEvent::start_element(el))?
+            .srgb_clr(val)?
+            .close()
+    }
+
+    closed!(srgb_clr, "a:srgbClr", "val");
+    closed!(scheme_clr, "a:schemeClr", "val");
+
+    // font scheme
+    open!(open_font_scheme, "a:fontScheme", "name");
+    open!(open_major_font, "a:majorFont");
+    open!(open_minor_font, "a:minorFont");
+    closed!(a_latin, "a:latin", "typeface");
+    closed!(a_ea, "a:ea", "typeface");
+    closed!(a_cs, "a:cs", "typeface");
+    closed!(a_font, "a:font", "script", "typeface");
+
+    // format scheme
+    open!(open_fmt_scheme, "a:fmtScheme", "name");
+    open!(open_fill_style_lst, "a:fillStyleLst");
+    open!(open_ln_style_lst, "a:lnStyleLst");
+    open!(open_effect_style_lst, "a:effectStyleLst");
+    open!(open_bg_fill_style_lst, "a:bgFillStyleLst");
+    open!(open_solid_fill, "a:solidFill");
+    open!(open_a_ln, "a:ln", "w", "cap", "cmpd", "algn");
+    closed!(prst_dash, "a:prstDash", "val");
+    closed!(miter, "a:miter", "lim");
+    open!(open_effect_style, "a:effectStyle");
+    closed!(effect_lst, "a:effectLst");
+}
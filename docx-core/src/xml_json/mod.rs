@@ -6,6 +6,7 @@
 //
 // Contribution
 // Unless you explicitly state otherwise, any contribution intentionally submitted for inclusion in the work by you, as defined in the Apache-2.0 license, shall be dual licensed as above, without any additional terms or conditions.
+use crate::escape::escape;
 use quick_xml::events::Event;
 use quick_xml::Reader;
 use serde::Serialize;
@@ -57,13 +58,13 @@ impl XmlData {
         write!(f, "<{}", self.name)?;
 
         for (key, val) in self.attributes.iter() {
-            write!(f, r#" {}="{}""#, key, val)?;
+            write!(f, r#" {}="{}""#, key, escape(val))?;
         }
 
         f.write_char('>')?;
 
         if let Some(ref data) = self.data {
-            write!(f, "{}", data)?
+            write!(f, "{}", escape(data))?
         }
 
         for child in self.children.iter() {
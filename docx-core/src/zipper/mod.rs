@@ -12,6 +12,7 @@ where
 
     zip.add_directory("word/", Default::default())?;
     zip.add_directory("word/_rels", Default::default())?;
+    zip.add_directory("word/theme/", Default::default())?;
     zip.add_directory("_rels/", Default::default())?;
     zip.add_directory("docProps/", Default::default())?;
 
@@ -39,12 +40,35 @@ where
     zip.write_all(&xml.settings)?;
     zip.start_file("word/fontTable.xml", options)?;
     zip.write_all(&xml.font_table)?;
+    if let Some(rels) = xml.font_table_rels {
+        zip.start_file("word/_rels/fontTable.xml.rels", options)?;
+        zip.write_all(&rels)?;
+        zip.add_directory("word/fonts/", Default::default())?;
+        for (target, data) in xml.fonts {
+            zip.start_file(format!("word/{}", target), options)?;
+            zip.write_all(&data)?;
+        }
+    }
+    zip.start_file("word/theme/theme1.xml", options)?;
+    zip.write_all(&xml.theme)?;
     zip.start_file("word/comments.xml", options)?;
     zip.write_all(&xml.comments)?;
     zip.start_file("word/numbering.xml", options)?;
     zip.write_all(&xml.numberings)?;
     zip.start_file("word/commentsExtended.xml", options)?;
     zip.write_all(&xml.comments_extended)?;
+    if let Some(comments_ids) = xml.comments_ids {
+        zip.start_file("word/commentsIds.xml", options)?;
+        zip.write_all(&comments_ids)?;
+    }
+    if let Some(comments_extensible) = xml.comments_extensible {
+        zip.start_file("word/commentsExtensible.xml", options)?;
+        zip.write_all(&comments_extensible)?;
+    }
+    if let Some(people) = xml.people {
+        zip.start_file("word/people.xml", options)?;
+        zip.write_all(&people)?;
+    }
     zip.start_file("word/footnotes.xml", options)?;
     zip.write_all(&xml.footnotes)?;
 
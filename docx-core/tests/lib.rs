@@ -273,6 +273,100 @@ pub fn comments() -> Result<(), DocxError> {
     Ok(())
 }
 
+#[test]
+pub fn resolved_comment_thread() -> Result<(), DocxError> {
+    let path = std::path::Path::new("./tests/output/resolved_comment_thread.docx");
+    let file = std::fs::File::create(path).unwrap();
+    let xml = Docx::new()
+        .add_paragraph(
+            Paragraph::new()
+                .add_comment_start(
+                    Comment::new(1)
+                        .author("bokuweb")
+                        .date("2019-01-01T00:00:00Z")
+                        .date_utc("2019-01-01T00:00:00Z")
+                        .done()
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello"))),
+                )
+                .add_comment_start(
+                    Comment::new(2)
+                        .author("alice")
+                        .date("2019-01-02T00:00:00Z")
+                        .parent_comment_id(1)
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Fixed"))),
+                )
+                .add_run(Run::new().add_text("Hello World!"))
+                .add_comment_end(1)
+                .add_comment_end(2),
+        )
+        .build();
+
+    let comments_extended = std::str::from_utf8(&xml.comments_extended).unwrap();
+    assert!(comments_extended.contains(r#"w15:done="1""#));
+    let comments_ids = std::str::from_utf8(xml.comments_ids.as_ref().unwrap()).unwrap();
+    assert!(comments_ids.contains(r#"w16cid:durableId="00000002""#));
+    assert!(comments_ids.contains(r#"w16cid:durableId="00000003""#));
+    let comments_extensible =
+        std::str::from_utf8(xml.comments_extensible.as_ref().unwrap()).unwrap();
+    assert!(comments_extensible.contains(
+        r#"<w16cex:commentExtensible w16cex:durableId="00000002" w16cex:dateUtc="2019-01-01T00:00:00Z" />"#
+    ));
+    assert!(
+        comments_extensible.contains(r#"<w16cex:commentExtensible w16cex:durableId="00000003" />"#)
+    );
+    let people = std::str::from_utf8(xml.people.as_ref().unwrap()).unwrap();
+    assert!(people.contains(r#"<w15:person w15:author="bokuweb">"#));
+    assert!(people.contains(r#"<w15:person w15:author="alice">"#));
+
+    xml.pack(file)?;
+    Ok(())
+}
+
+#[test]
+pub fn reply_resolve_and_delete_comments() -> Result<(), DocxError> {
+    let path = std::path::Path::new("./tests/output/reply_resolve_and_delete_comments.docx");
+    let file = std::fs::File::create(path).unwrap();
+    let xml = Docx::new()
+        .add_paragraph(
+            Paragraph::new()
+                .add_comment_start(
+                    Comment::new(1)
+                        .author("bokuweb")
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello"))),
+                )
+                .add_run(Run::new().add_text("Hello"))
+                .add_comment_end(1)
+                .add_comment_start(
+                    Comment::new(2)
+                        .author("bokuweb")
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("World"))),
+                )
+                .add_run(Run::new().add_text(" World"))
+                .add_comment_end(2),
+        )
+        .reply_to_comment(1, "alice", "Fixed")
+        .resolve_comment(3)
+        .delete_comment(2)
+        .build();
+
+    let document = std::str::from_utf8(&xml.document).unwrap();
+    assert!(
+        document.contains(r#"<w:commentRangeStart w:id="1" /><w:commentRangeStart w:id="3" />"#)
+    );
+    assert!(!document.contains(r#"<w:commentRangeStart w:id="2" />"#));
+    let comments = std::str::from_utf8(&xml.comments).unwrap();
+    assert!(comments.contains(r#"<w:comment w:id="3" w:author="alice""#));
+    assert!(!comments.contains(r#"<w:comment w:id="2""#));
+    let comments_extended = std::str::from_utf8(&xml.comments_extended).unwrap();
+    assert_eq!(comments_extended.matches(r#"w15:done="1""#).count(), 2);
+    assert_eq!(comments_extended.matches("w15:paraIdParent").count(), 1);
+    let people = std::str::from_utf8(xml.people.as_ref().unwrap()).unwrap();
+    assert!(people.contains(r#"<w15:person w15:author="alice">"#));
+
+    xml.pack(file)?;
+    Ok(())
+}
+
 #[test]
 pub fn comments_to_table() -> Result<(), DocxError> {
     let path = std::path::Path::new("./tests/output/comments_table.docx");
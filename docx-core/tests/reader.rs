@@ -288,6 +288,31 @@ pub fn read_extended_comment() {
     file.flush().unwrap();
 }
 
+#[test]
+pub fn reply_to_read_comment() {
+    let mut file = File::open("../fixtures/extended_comments/extended_comments.docx").unwrap();
+    let mut buf = vec![];
+    file.read_to_end(&mut buf).unwrap();
+    let docx = read_docx(&buf)
+        .unwrap()
+        .reply_to_comment(2, "bokuweb", "Thanks")
+        .resolve_comment(1);
+
+    let reply = &docx.comments.inner()[2];
+    assert_eq!(reply.id, 3);
+    assert_eq!(reply.author, "bokuweb");
+    assert_eq!(reply.parent_comment_id, Some(1));
+    assert!(docx.comments.inner().iter().all(|c| c.done));
+    let extended = &docx.comments_extended.children;
+    assert_eq!(extended.len(), 3);
+    assert_eq!(extended[2].parent_paragraph_id.as_deref(), Some("00000064"));
+    assert!(extended.iter().all(|c| c.done));
+
+    let docx = docx.delete_comment(1);
+    assert!(docx.comments.inner().is_empty());
+    assert!(docx.comments_extended.children.is_empty());
+}
+
 #[test]
 pub fn read_comment_in_delete_in_insert() {
     let mut file =
@@ -136,6 +136,9 @@ function buildComment(child: Comment) {
   if (child._parentCommentId) {
     comment = comment.parent_comment_id(child._parentCommentId);
   }
+  if (child._done) {
+    comment = comment.done();
+  }
   return comment;
 }
 
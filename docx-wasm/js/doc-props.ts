@@ -2,6 +2,12 @@ export class DocProps {
   _createdAt: string | null = null;
   _updatedAt: string | null = null;
   _customProperties: { [name: string]: string } = {};
+  _application: string | null = null;
+  _appVersion: string | null = null;
+  _company: string | null = null;
+  _manager: string | null = null;
+  _hyperlinkBase: string | null = null;
+  _template: string | null = null;
 
   createdAt(date: string) {
     this._createdAt = date;
@@ -17,4 +23,30 @@ export class DocProps {
     this._customProperties[name] = item;
     return this;
   }
+
+  application(name: string, version?: string) {
+    this._application = name;
+    this._appVersion = version ?? null;
+    return this;
+  }
+
+  company(company: string) {
+    this._company = company;
+    return this;
+  }
+
+  manager(manager: string) {
+    this._manager = manager;
+    return this;
+  }
+
+  hyperlinkBase(base: string) {
+    this._hyperlinkBase = base;
+    return this;
+  }
+
+  template(template: string) {
+    this._template = template;
+    return this;
+  }
 }
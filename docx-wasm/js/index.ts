@@ -20,8 +20,17 @@ import {
   SectionProperty,
   PageMargin,
   PageOrientationType,
+  LineNumType,
+  PageBorders,
+  SectionVAlignType,
 } from "./section-property";
-import { CommentJSON, DocGridType, DocxJSON } from "./json";
+import {
+  CommentJSON,
+  DocGridType,
+  DocxJSON,
+  FontEmbedType,
+  ThemeColorType,
+} from "./json";
 
 import * as wasm from "./pkg";
 import { Level } from "./level";
@@ -45,6 +54,10 @@ export class Docx {
   webextensions: WebExtension[] = [];
   customItems: { id: string; xml: string }[] = [];
   styles = new Styles();
+  _themeColors: { type: ThemeColorType; val: string }[] = [];
+  _themeFonts: { major: string; minor: string } | null = null;
+  _embeddedFonts: { name: string; buf: Uint8Array; type: FontEmbedType }[] =
+    [];
 
   addTableOfContents(t: TableOfContents) {
     this.children.push(t);
@@ -177,6 +190,46 @@ export class Docx {
     return this;
   }
 
+  columns(num: number, space?: number) {
+    this.sectionProperty.columns(num, space);
+    return this;
+  }
+
+  addColumn(width: number, space?: number) {
+    this.sectionProperty.addColumn(width, space);
+    return this;
+  }
+
+  columnSeparator() {
+    this.sectionProperty.columnSeparator();
+    return this;
+  }
+
+  lineNumType(l: LineNumType) {
+    this.sectionProperty.lineNumType(l);
+    return this;
+  }
+
+  pageBorders(b: PageBorders) {
+    this.sectionProperty.pageBorders(b);
+    return this;
+  }
+
+  pageVerticalAlign(v: SectionVAlignType) {
+    this.sectionProperty.verticalAlign(v);
+    return this;
+  }
+
+  bidi() {
+    this.sectionProperty.bidi();
+    return this;
+  }
+
+  rtlGutter() {
+    this.sectionProperty.rtlGutter();
+    return this;
+  }
+
   adjustLineHeightInTable() {
     this.settings.adjustLineHeightInTable();
     return this;
@@ -187,6 +240,41 @@ export class Docx {
     return this;
   }
 
+  mirrorMargins() {
+    this.settings.mirrorMargins();
+    return this;
+  }
+
+  gutterAtTop() {
+    this.settings.gutterAtTop();
+    return this;
+  }
+
+  bookFoldPrinting() {
+    this.settings.bookFoldPrinting();
+    return this;
+  }
+
+  printTwoOnOne() {
+    this.settings.printTwoOnOne();
+    return this;
+  }
+
+  themeColor(type: ThemeColorType, val: string) {
+    this._themeColors.push({ type, val });
+    return this;
+  }
+
+  themeFonts(major: string, minor: string) {
+    this._themeFonts = { major, minor };
+    return this;
+  }
+
+  embedFont(name: string, buf: Uint8Array, type: FontEmbedType = "regular") {
+    this._embeddedFonts.push({ name, buf, type });
+    return this;
+  }
+
   defaultSize(size: number) {
     this.styles.defaultSize(size);
     return this;
@@ -448,6 +536,76 @@ export class Docx {
       }
     }
 
+    if (this.settings._mirrorMargins) {
+      docx = docx.mirror_margins();
+    }
+
+    if (this.settings._gutterAtTop) {
+      docx = docx.gutter_at_top();
+    }
+
+    if (this.settings._bookFoldPrinting) {
+      docx = docx.book_fold_printing();
+    }
+
+    if (this.settings._printTwoOnOne) {
+      docx = docx.print_two_on_one();
+    }
+
+    if (this.settings._updateFields) {
+      docx = docx.update_fields();
+    }
+
+    if (this.settings._trackRevisions) {
+      docx = docx.track_revisions();
+    }
+
+    if (this.settings._documentProtection) {
+      const { edit, password } = this.settings._documentProtection;
+      docx = docx.document_protection(edit, password ?? "");
+    }
+
+    if (this.settings._autoHyphenation) {
+      docx = docx.auto_hyphenation();
+    }
+
+    if (this.settings._hyphenationZone !== null) {
+      docx = docx.hyphenation_zone(this.settings._hyphenationZone);
+    }
+
+    if (this.settings._defaultTableStyle) {
+      docx = docx.default_table_style(this.settings._defaultTableStyle);
+    }
+
+    if (this.settings._themeFontLang) {
+      const { val, eastAsia, bidi } = this.settings._themeFontLang;
+      docx = docx.theme_font_lang(val ?? "", eastAsia ?? "", bidi ?? "");
+    }
+
+    if (this.settings._decimalSymbol) {
+      docx = docx.decimal_symbol(this.settings._decimalSymbol);
+    }
+
+    if (this.settings._listSeparator) {
+      docx = docx.list_separator(this.settings._listSeparator);
+    }
+
+    if (this.settings._compatibilityMode !== null) {
+      docx = docx.compatibility_mode(this.settings._compatibilityMode);
+    }
+
+    this._themeColors.forEach(({ type, val }) => {
+      docx = docx.theme_color(type, val);
+    });
+
+    if (this._themeFonts) {
+      docx = docx.theme_fonts(this._themeFonts.major, this._themeFonts.minor);
+    }
+
+    this._embeddedFonts.forEach(({ name, buf, type }) => {
+      docx = docx.embed_font(name, buf, type);
+    });
+
     docx = docx.default_tab_stop(this.settings._defaultTabStop);
 
     this.settings._docVars.forEach((v) => {
@@ -583,6 +741,114 @@ export class Docx {
       docx = docx.doc_grid(type, linePitch, charSpace);
     }
 
+    if (this.sectionProperty._columns) {
+      const { num, space, separator, widths } = this.sectionProperty._columns;
+      docx = docx.columns(num);
+      if (space != null) {
+        docx = docx.column_space(space);
+      }
+      widths.forEach((c) => {
+        docx = docx.add_column(c.width, c.space);
+      });
+      if (separator) {
+        docx = docx.column_separator();
+      }
+    }
+
+    if (this.sectionProperty._lineNumType) {
+      const { countBy, start, distance, restart } =
+        this.sectionProperty._lineNumType;
+      let r: wasm.LineNumberRestartType | undefined;
+      switch (restart) {
+        case "newPage":
+          r = wasm.LineNumberRestartType.NewPage;
+          break;
+        case "newSection":
+          r = wasm.LineNumberRestartType.NewSection;
+          break;
+        case "continuous":
+          r = wasm.LineNumberRestartType.Continuous;
+          break;
+      }
+      docx = docx.line_num_type(
+        wasm.createLineNumType(countBy, start, distance, r)
+      );
+    }
+
+    if (this.sectionProperty._pageBorders) {
+      const { offsetFrom, display, zOrder, borders } =
+        this.sectionProperty._pageBorders;
+      let b = wasm.createPageBorders();
+      if (offsetFrom === "page") {
+        b = b.offset_from(wasm.PageBorderOffsetType.Page);
+      } else if (offsetFrom === "text") {
+        b = b.offset_from(wasm.PageBorderOffsetType.Text);
+      }
+      switch (display) {
+        case "allPages":
+          b = b.display(wasm.PageBorderDisplayType.AllPages);
+          break;
+        case "firstPage":
+          b = b.display(wasm.PageBorderDisplayType.FirstPage);
+          break;
+        case "notFirstPage":
+          b = b.display(wasm.PageBorderDisplayType.NotFirstPage);
+          break;
+      }
+      if (zOrder === "front") {
+        b = b.z_order(wasm.PageBorderZOrderType.Front);
+      } else if (zOrder === "back") {
+        b = b.z_order(wasm.PageBorderZOrderType.Back);
+      }
+      borders.forEach((border) => {
+        let position = wasm.PageBorderPosition.Top;
+        switch (border.position) {
+          case "left":
+            position = wasm.PageBorderPosition.Left;
+            break;
+          case "bottom":
+            position = wasm.PageBorderPosition.Bottom;
+            break;
+          case "right":
+            position = wasm.PageBorderPosition.Right;
+            break;
+        }
+        b = b.set_border(
+          position,
+          border.borderType,
+          border.size,
+          border.space,
+          border.color,
+          !!border.shadow,
+          !!border.frame
+        );
+      });
+      docx = docx.page_borders(b);
+    }
+
+    switch (this.sectionProperty._verticalAlign) {
+      case "top":
+        docx = docx.page_vertical_align(wasm.VAlignType.Top);
+        break;
+      case "center":
+        docx = docx.page_vertical_align(wasm.VAlignType.Center);
+        break;
+      case "both":
+        docx = docx.page_vertical_align(wasm.VAlignType.Both);
+        break;
+      case "bottom":
+        docx = docx.page_vertical_align(wasm.VAlignType.Bottom);
+        break;
+    }
+
+    if (this.sectionProperty._bidi) {
+      docx = docx.bidi();
+    }
+
+    if (this.sectionProperty._rtlGutter) {
+      docx = docx.rtl_gutter();
+    }
+
     for (const s of this.styles?.styles) {
       docx = docx.add_style(s.buildWasmObject());
     }
@@ -631,6 +897,15 @@ export class Docx {
       docx = docx.custom_property(key, item);
     });
 
+    docx = docx.app_props(
+      this.docProps._application ?? undefined,
+      this.docProps._appVersion ?? undefined,
+      this.docProps._company ?? undefined,
+      this.docProps._manager ?? undefined,
+      this.docProps._hyperlinkBase ?? undefined,
+      this.docProps._template ?? undefined
+    );
+
     if (this.docProps._updatedAt) {
       docx = docx.updated_at(this.docProps._updatedAt);
     }
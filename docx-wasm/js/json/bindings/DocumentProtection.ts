@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DocumentProtectionType } from "./DocumentProtectionType";
+
+export interface DocumentProtection { edit: DocumentProtectionType, enforcement: boolean, formatting?: boolean, cryptAlgorithmSid?: number, spinCount?: number, hash?: string, salt?: string, }
\ No newline at end of file
@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { FieldCharType } from "./FieldCharType";
+import type { FormFieldData } from "./FormFieldData";
 
-export interface FieldChar { fieldCharType: FieldCharType, dirty: boolean, }
\ No newline at end of file
+export interface FieldChar { fieldCharType: FieldCharType, dirty: boolean, formFieldData?: FormFieldData, }
\ No newline at end of file
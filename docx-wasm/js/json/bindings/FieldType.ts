@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type FieldType = "author" | "comments" | "createDate" | "date" | "docProperty" | "docVariable" | "fileName" | "fileSize" | "formCheckBox" | "formDropDown" | "formText" | "hyperlink" | "if" | "keywords" | "lastSavedBy" | "mergeField" | "noteRef" | "numChars" | "numPages" | "numWords" | "page" | "pageRef" | "printDate" | "quote" | "ref" | "revNum" | "saveDate" | "section" | "sectionPages" | "seq" | "styleRef" | "subject" | "symbol" | "tc" | "template" | "time" | "title" | "toc" | "userName" | "unsupported";
\ No newline at end of file
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FormCheckBox } from "./FormCheckBox";
+import type { FormDropDownList } from "./FormDropDownList";
+import type { FormTextInput } from "./FormTextInput";
+
+export type FormFieldControl = { "type": "textInput", "data": FormTextInput } | { "type": "checkBox", "data": FormCheckBox } | { "type": "dropDownList", "data": FormDropDownList };
\ No newline at end of file
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FormFieldControl } from "./FormFieldControl";
+
+export interface FormFieldData { name?: string, enabled: boolean, calcOnExit: boolean, control?: FormFieldControl, }
\ No newline at end of file
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TextInputType } from "./TextInputType";
+
+export interface FormTextInput { inputType?: TextInputType, default?: string, maxLength?: number, format?: string, }
\ No newline at end of file
@@ -2,4 +2,4 @@
 import type { BorderType } from "./BorderType";
 import type { TableCellBorderPosition } from "./TableCellBorderPosition";
 
-export interface TableCellBorder { borderType: BorderType, size: number, color: string, position: TableCellBorderPosition, space: number, }
\ No newline at end of file
+export interface TableCellBorder { borderType: BorderType, size: number, color: string | { val: string, themeColor: string, themeTint: number | null, themeShade: number | null }, position: TableCellBorderPosition, space: number, }
\ No newline at end of file
@@ -1,4 +1,6 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ColorScheme } from "./ColorScheme";
 import type { FontScheme } from "./FontScheme";
+import type { FormatScheme } from "./FormatScheme";
 
-export interface Theme { fontSchema: FontScheme, }
\ No newline at end of file
+export interface Theme { name: string, colorScheme: ColorScheme, fontSchema: FontScheme, formatScheme: FormatScheme, }
\ No newline at end of file
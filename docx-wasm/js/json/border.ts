@@ -1,7 +1,9 @@
+import { ColorJSON } from "./color";
+
 export type BorderJSON = {
   position: string;
   borderType: string;
   size: number;
   space: number;
-  color: string;
+  color: ColorJSON;
 };
@@ -0,0 +1,28 @@
+export type ThemeColorType =
+  | "dark1"
+  | "light1"
+  | "dark2"
+  | "light2"
+  | "accent1"
+  | "accent2"
+  | "accent3"
+  | "accent4"
+  | "accent5"
+  | "accent6"
+  | "hyperlink"
+  | "followedHyperlink"
+  | "none"
+  | "background1"
+  | "text1"
+  | "background2"
+  | "text2";
+
+// RGB hex or "auto" are serialized as a plain string.
+export type ColorJSON =
+  | string
+  | {
+      val: string;
+      themeColor: ThemeColorType;
+      themeTint: number | null;
+      themeShade: number | null;
+    };
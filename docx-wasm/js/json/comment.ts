@@ -8,6 +8,17 @@ export type CommentJSON = {
   date: string;
   children: CommentChildJSON[];
   parentCommentId: number | null;
+  done?: boolean;
+  durableId?: string;
+  dateUtc?: string;
+};
+
+export type PersonJSON = {
+  author: string;
+  presenceInfo: {
+    providerId: string;
+    userId: string;
+  } | null;
 };
 
 export type CommentRangeStartJSON = {
@@ -1,4 +1,14 @@
-import { ParagraphJSON, BookmarkStartJSON, BookmarkEndJSON } from "./paragraph";
+import {
+  ParagraphJSON,
+  BookmarkStartJSON,
+  BookmarkEndJSON,
+  PermStartJSON,
+  PermEndJSON,
+  MoveFromRangeStartJSON,
+  MoveFromRangeEndJSON,
+  MoveToRangeStartJSON,
+  MoveToRangeEndJSON,
+} from "./paragraph";
 import { TableJSON } from "./table";
 import { SectionPropertyJSON } from "./section-property";
 import { CommentRangeStartJSON, CommentRangeEndJSON } from "..";
@@ -11,6 +21,12 @@ export type DocumentChildJSON =
   | CommentRangeEndJSON
   | BookmarkStartJSON
   | BookmarkEndJSON
+  | PermStartJSON
+  | PermEndJSON
+  | MoveFromRangeStartJSON
+  | MoveFromRangeEndJSON
+  | MoveToRangeStartJSON
+  | MoveToRangeEndJSON
   | StructuredTagJSON;
 
 export type DocumentJSON = {
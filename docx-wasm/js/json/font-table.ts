@@ -0,0 +1,21 @@
+export type FontEmbedType = "regular" | "bold" | "italic" | "boldItalic";
+
+export type FontPitchType = "default" | "fixed" | "variable";
+
+export type FontJSON = {
+  name: string;
+  altName?: string;
+  panose1?: string;
+  charset?: string;
+  family?: string;
+  pitch?: FontPitchType;
+  embeds?: {
+    embedType: FontEmbedType;
+    rid: string;
+    fontKey: string;
+  }[];
+};
+
+export type FontTableJSON = {
+  fonts: FontJSON[];
+};
@@ -1,11 +1,13 @@
 import { StylesJSON } from "./styles";
 import { DocumentJSON } from "./document";
 import { NumberingsJSON } from "./numbering";
-import { CommentJSON } from "./comment";
+import { CommentJSON, PersonJSON } from "./comment";
 import { WebSettingsJSON } from "./web-settings";
+import { FontTableJSON } from "./font-table";
 
 import { Theme as ThemeJSON } from "./bindings/Theme";
 import { CharacterSpacingValues } from "../settings";
+import { DocumentProtection as DocumentProtectionJSON } from "./bindings/DocumentProtection";
 
 export type DocxJSON = {
   contentType: {
@@ -19,7 +21,14 @@ export type DocxJSON = {
     hasNumberings: boolean;
   };
   docProps: {
-    app: {};
+    app: {
+      application?: string;
+      appVersion?: string;
+      company?: string;
+      manager?: string;
+      hyperlinkBase?: string;
+      template?: string;
+    };
     core: {
       config: {
         creator: string | null;
@@ -33,9 +42,7 @@ export type DocxJSON = {
       };
     };
     custom: {
-      properties: {
-        [key: string]: string;
-      };
+      properties: CustomPropertyJSON[];
     };
   };
   styles: StylesJSON;
@@ -43,16 +50,31 @@ export type DocxJSON = {
   comments: {
     comments: CommentJSON[];
   };
+  people?: {
+    people: PersonJSON[];
+  };
   numberings: NumberingsJSON;
   settings: SettingsJSON;
   webSettings: WebSettingsJSON;
-  fontTable: {};
+  fontTable: FontTableJSON;
   themes: ThemeJSON[];
   //(id, path, base64 encoded original image data, base64 encoded png image data)
   images: [string, string, string, string][];
   hyperlinks: [string, string, string][];
 };
 
+export type CustomPropertyValueJSON =
+  | { type: "text"; value: string }
+  | { type: "int"; value: number }
+  | { type: "float"; value: number }
+  | { type: "bool"; value: boolean }
+  | { type: "dateTime"; value: string };
+
+export type CustomPropertyJSON = {
+  name: string;
+  value: CustomPropertyValueJSON;
+};
+
 export type SettingsJSON = {
   // w15:docId
   docId: string | null;
@@ -62,8 +84,34 @@ export type SettingsJSON = {
   evenAndOddHeaders: boolean;
   zoom: number;
   docVars: { name: string; val: string }[];
+  mirrorMargins?: boolean;
+  gutterAtTop?: boolean;
+  bookFoldPrinting?: boolean;
+  printTwoOnOne?: boolean;
+  embedTrueTypeFonts?: boolean;
+  updateFields?: boolean;
+  trackRevisions?: boolean;
+  documentProtection?: DocumentProtectionJSON;
+  autoHyphenation?: boolean;
+  hyphenationZone?: number;
+  defaultTableStyle?: string;
+  themeFontLang?: { val?: string; eastAsia?: string; bidi?: string };
+  decimalSymbol?: string;
+  listSeparator?: string;
+  proofState?: { spelling?: ProofStateType; grammar?: ProofStateType };
+  compat: {
+    spaceForUl: boolean;
+    balanceSingleByteDoubleByteWidth: boolean;
+    doNotLeaveBackslashAlone: boolean;
+    ulTrailSpace: boolean;
+    doNotExpandShiftReturn: boolean;
+    useFeLayout: boolean;
+    settings: { name: string; uri: string; val: string }[];
+  };
 };
 
+export type ProofStateType = "clean" | "dirty";
+
 export * from "../settings";
 export * from "./styles";
 export * from "./border";
@@ -74,9 +122,11 @@ export * from "./table";
 export * from "./numbering";
 export * from "./drawing";
 export * from "./shading";
+export * from "./color";
+export * from "./font-table";
 export * from "./web-settings";
 export * from "./comment";
 export * from "./textbox-content";
 export * from "./section-property";
 
-export { ThemeJSON };
+export { ThemeJSON, DocumentProtectionJSON };
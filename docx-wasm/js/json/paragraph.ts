@@ -6,9 +6,13 @@ import {
   SectionPropertyJSON,
 } from "..";
 import { LineSpacingJSON } from "./line_spacing";
+import { ShadingJSON } from "./shading";
 import { FrameProperty as FramePropertyJSON } from "./bindings/FrameProperty";
 import { TextAlignmentType } from "./bindings/TextAlignmentType";
 import { AlignmentType } from "./bindings/AlignmentType";
+import { FieldType } from "./bindings/FieldType";
+import { FormFieldData } from "./bindings/FormFieldData";
+import { EditorGroupType } from "./bindings/EditorGroupType";
 
 export { FrameProperty as FramePropertyJSON } from "./bindings/FrameProperty";
 
@@ -16,11 +20,42 @@ export type ParagraphChildJSON =
   | RunJSON
   | InsertJSON
   | DeleteJSON
+  | MoveFromJSON
+  | MoveToJSON
+  | MoveFromRangeStartJSON
+  | MoveFromRangeEndJSON
+  | MoveToRangeStartJSON
+  | MoveToRangeEndJSON
   | HyperlinkJSON
   | CommentRangeStartJSON
   | CommentRangeEndJSON
   | BookmarkStartJSON
-  | BookmarkEndJSON;
+  | BookmarkEndJSON
+  | PermStartJSON
+  | PermEndJSON
+  | FieldJSON;
+
+export type FieldSwitchJSON = {
+  name: string;
+  argument?: string;
+};
+
+export type FieldJSON = {
+  type: "field";
+  data: {
+    instruction: {
+      fieldType: FieldType;
+      name: string;
+      arguments: string[];
+      switches: FieldSwitchJSON[];
+    };
+    result: RunJSON[];
+    runProperty: RunPropertyJSON;
+    simple: boolean;
+    dirty: boolean;
+    formFieldData?: FormFieldData;
+  };
+};
 
 export type HyperlinkChildJSON =
   | RunJSON
@@ -51,6 +86,11 @@ export type CustomTabStopJSON = {
 export type NumberingPropertyJSON = {
   id: number | null;
   level: number | null;
+  numberingChange?: {
+    author: string;
+    date: string;
+    original: string;
+  };
 };
 
 export type ParagraphPropertyJSON = {
@@ -77,6 +117,15 @@ export type ParagraphPropertyJSON = {
   sectionProperty?: SectionPropertyJSON;
   tabs: CustomTabStopJSON[];
   frameProperty?: FramePropertyJSON;
+  shading?: ShadingJSON | null;
+  contextualSpacing?: boolean;
+  suppressAutoHyphens?: boolean;
+  suppressLineNumbers?: boolean;
+  mirrorIndents?: boolean;
+  wordWrap?: boolean;
+  kinsoku?: boolean;
+  topLinePunct?: boolean;
+  cnfStyle?: string;
 };
 
 export type ParagraphJSON = {
@@ -115,6 +164,47 @@ export type DeleteJSON = {
   data: DeleteJSONData;
 };
 
+export type MoveFromJSON = {
+  type: "moveFrom";
+  data: DeleteJSONData;
+};
+
+export type MoveToJSON = {
+  type: "moveTo";
+  data: InsertJSONData;
+};
+
+export type MoveRangeStartJSONData = {
+  id: number;
+  name: string;
+  author: string;
+  date: string;
+};
+
+export type MoveFromRangeStartJSON = {
+  type: "moveFromRangeStart";
+  data: MoveRangeStartJSONData;
+};
+
+export type MoveFromRangeEndJSON = {
+  type: "moveFromRangeEnd";
+  data: {
+    id: number;
+  };
+};
+
+export type MoveToRangeStartJSON = {
+  type: "moveToRangeStart";
+  data: MoveRangeStartJSONData;
+};
+
+export type MoveToRangeEndJSON = {
+  type: "moveToRangeEnd";
+  data: {
+    id: number;
+  };
+};
+
 export type HyperlinkJSON = {
   type: "hyperlink";
   data:
@@ -150,3 +240,21 @@ export type BookmarkEndJSON = {
     id: number;
   };
 };
+
+export type PermStartJSON = {
+  type: "permStart";
+  data: {
+    id: number;
+    editorGroup?: EditorGroupType;
+    editor?: string;
+    colFirst?: number;
+    colLast?: number;
+  };
+};
+
+export type PermEndJSON = {
+  type: "permEnd";
+  data: {
+    id: number;
+  };
+};
@@ -8,7 +8,13 @@ import {
   ShadingJSON,
 } from "..";
 import { BorderType } from "../border";
-import { VertAlignType } from "../run-property";
+import { ColorJSON } from "./color";
+import {
+  FitText,
+  Lang,
+  TextEffectType,
+  VertAlignType,
+} from "../run-property";
 import { FieldChar } from "./bindings/FieldChar";
 import { InstrHyperlink } from "./bindings/InstrHyperlink";
 import { InstrToC } from "./bindings/InstrToC";
@@ -20,7 +26,7 @@ export type TextBorderJSON = {
   borderType: BorderType;
   size: number;
   space: number;
-  color: string;
+  color: ColorJSON;
 };
 
 export type RunFontsJSON = {
@@ -40,7 +46,7 @@ export type RunPropertyJSON = {
   sz?: number | null;
   szCs?: number | null;
   fonts?: RunFontsJSON | null;
-  color?: string | null;
+  color?: ColorJSON | null;
   highlight?: string | null;
   vertAlign?: VertAlignType | null;
   underline?: string | null;
@@ -55,9 +61,28 @@ export type RunPropertyJSON = {
   textBorder?: TextBorderJSON | null;
   ins?: InsertJSONData | null;
   del?: DeleteJSONData | null;
+  moveFrom?: DeleteJSONData | null;
+  moveTo?: InsertJSONData | null;
   strike?: boolean;
   dstrike?: boolean;
   shading?: ShadingJSON | null;
+  smallCaps?: boolean | null;
+  outline?: boolean | null;
+  shadow?: boolean | null;
+  emboss?: boolean | null;
+  imprint?: boolean | null;
+  noProof?: boolean | null;
+  webHidden?: boolean | null;
+  effect?: TextEffectType | null;
+  kern?: number | null;
+  position?: number | null;
+  fitText?: FitText | null;
+  lang?: Lang | null;
+  runPropertyChange?: {
+    author: string;
+    date: string;
+    property: RunPropertyJSON;
+  };
 };
 
 export type RunChildJSON =
@@ -1,8 +1,11 @@
 import { HeaderJSON, HeaderReferenceJSON } from "./header";
 import { FooterJSON, FooterReferenceJSON } from "./footer";
 import { PageNumType as PageNumTypeJSON } from "./bindings/PageNumType";
+import { Column as ColumnJSON } from "./bindings/Column";
+import { BorderType } from "./bindings/BorderType";
 
 export { PageNumType as PageNumTypeJSON } from "./bindings/PageNumType";
+export { Column as ColumnJSON } from "./bindings/Column";
 
 export type DocGridType = "default" | "lines" | "linesAndChars" | "snapToChars";
 
@@ -19,6 +22,33 @@ export type DocGridJSON = {
   charSpace: number | null;
 };
 
+export type LineNumTypeJSON = {
+  countBy?: number;
+  start?: number;
+  distance?: number;
+  restart?: "newPage" | "newSection" | "continuous";
+};
+
+export type PageBorderJSON = {
+  position: "top" | "left" | "bottom" | "right";
+  val: BorderType;
+  size: number;
+  space: number;
+  color: string;
+  shadow: boolean;
+  frame: boolean;
+};
+
+export type PageBordersJSON = {
+  offsetFrom?: "page" | "text";
+  display?: "allPages" | "firstPage" | "notFirstPage";
+  zOrder?: "front" | "back";
+  top: PageBorderJSON | null;
+  left: PageBorderJSON | null;
+  bottom: PageBorderJSON | null;
+  right: PageBorderJSON | null;
+};
+
 export type SectionPropertyJSON = {
   pageSize: {
     w: number;
@@ -34,9 +64,18 @@ export type SectionPropertyJSON = {
     gutter: number;
   };
   columns: number;
+  space: number;
+  columnWidths?: ColumnJSON[];
+  columnSeparator?: boolean;
   docGrid?: DocGridJSON;
   sectionType?: SectionType;
   titlePg?: boolean;
+  textDirection: string;
+  lineNumType?: LineNumTypeJSON;
+  pageBorders?: PageBordersJSON;
+  verticalAlign?: "top" | "center" | "both" | "bottom";
+  bidi?: boolean;
+  rtlGutter?: boolean;
   // header
   headerReference?: HeaderReferenceJSON;
   header?: HeaderJSON;
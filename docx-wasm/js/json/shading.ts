@@ -1,5 +1,7 @@
+import { ColorJSON } from "./color";
+
 export type ShadingJSON = {
   shdType: string;
-  color: string;
-  fill: string;
+  color: ColorJSON;
+  fill: ColorJSON;
 };
@@ -5,18 +5,79 @@ import {
   CommentRangeStartJSON,
   ParagraphJSON,
   TableJSON,
+  TableRowJSON,
 } from "..";
+import { SdtLockType } from "./bindings/SdtLockType";
 
 export type StructuredTagJSON = {
   type: "structuredDataTag";
   data: {
     children: StructuredDataTagChildJSON[];
+    property: StructuredDataTagPropertyJSON;
   };
 };
 
+export type StructuredDataTagPropertyJSON = {
+  alias: string | null;
+  tag?: string;
+  id?: number;
+  lock?: SdtLockType;
+  placeholder?: string;
+  showingPlaceholder?: boolean;
+  control?: ContentControlJSON;
+};
+
+export type ListItemJSON = {
+  displayText?: string;
+  value: string;
+};
+
+export type ListControlJSON = {
+  items: ListItemJSON[];
+  lastValue?: string;
+};
+
+export type CheckBoxSymbolJSON = {
+  val: string;
+  font: string;
+};
+
+export type ContentControlJSON =
+  | {
+      type: "checkBox";
+      data: {
+        checked: boolean;
+        checkedState: CheckBoxSymbolJSON;
+        uncheckedState: CheckBoxSymbolJSON;
+      };
+    }
+  | { type: "dropDownList"; data: ListControlJSON }
+  | { type: "comboBox"; data: ListControlJSON }
+  | {
+      type: "date";
+      data: {
+        fullDate?: string;
+        dateFormat?: string;
+        lid?: string;
+        storeMappedDataAs?: string;
+        calendar?: string;
+      };
+    }
+  | { type: "text"; data: { multiLine: boolean } }
+  | { type: "picture" }
+  | {
+      type: "repeatingSection";
+      data: {
+        sectionTitle?: string;
+        doNotAllowInsertDeleteSection: boolean;
+      };
+    }
+  | { type: "repeatingSectionItem" };
+
 export type StructuredDataTagChildJSON =
   | ParagraphJSON
   | TableJSON
+  | TableRowJSON
   | CommentRangeStartJSON
   | CommentRangeEndJSON
   | BookmarkStartJSON
@@ -1,4 +1,12 @@
-import { ParagraphJSON } from "./paragraph";
+import {
+  ParagraphJSON,
+  PermStartJSON,
+  PermEndJSON,
+  MoveFromRangeStartJSON,
+  MoveFromRangeEndJSON,
+  MoveToRangeStartJSON,
+  MoveToRangeEndJSON,
+} from "./paragraph";
 import { BorderJSON } from "./border";
 import { HeightRule } from "../table-row";
 import { TextDirectionType } from "../table-cell";
@@ -14,7 +22,14 @@ export { TableCellBorder as TableCellBorderJSON } from "./bindings/TableCellBord
 
 export { TableCellBorders as TableCellBordersJSON } from "./bindings/TableCellBorders";
 
-export type TableCellChildJSON = ParagraphJSON | TableJSON | StructuredTagJSON;
+export type TableCellChildJSON =
+  | ParagraphJSON
+  | TableJSON
+  | StructuredTagJSON
+  | MoveFromRangeStartJSON
+  | MoveFromRangeEndJSON
+  | MoveToRangeStartJSON
+  | MoveToRangeEndJSON;
 
 export type WidthType = "dxa" | "auto" | "pct" | "nil";
 
@@ -34,6 +49,20 @@ export type TableCellPropertyJSON = {
   textDirection: TextDirectionType | null;
   shading: ShadingJSON | null;
   margins?: CellMarginsJSON;
+  cellInsert?: TrackChangeJSON;
+  cellDelete?: TrackChangeJSON;
+  cellMerge?: TrackChangeJSON & {
+    vMerge?: "restart" | "continue";
+    vMergeOrig?: "restart" | "continue";
+  };
+  tableCellPropertyChange?: TrackChangeJSON & {
+    property: TableCellPropertyJSON;
+  };
+};
+
+export type TrackChangeJSON = {
+  author: string;
+  date: string;
 };
 
 export type TableRowPropertyJSON = {
@@ -45,6 +74,9 @@ export type TableRowPropertyJSON = {
   widthBefore: number | null;
   del?: DeleteJSONData;
   ins?: InsertJSONData;
+  tableRowPropertyChange?: TrackChangeJSON & {
+    property: TableRowPropertyJSON;
+  };
 };
 
 export type TableCellJSON = {
@@ -101,12 +133,15 @@ export type TablePropertyJSON = {
   style?: string | null;
   layout?: TableLayoutType | null;
   position?: TablePositionPropertyJSON;
+  tablePropertyChange?: TrackChangeJSON & {
+    property: TablePropertyJSON;
+  };
 };
 
 export type TableJSON = {
   type: "table";
   data: {
-    rows: TableRowJSON[];
+    rows: (TableRowJSON | StructuredTagJSON | PermStartJSON | PermEndJSON)[];
     grid: number[];
     hasNumbering: boolean;
     property: TablePropertyJSON;
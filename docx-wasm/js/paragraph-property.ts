@@ -1,4 +1,5 @@
 import { RunProperty, createDefaultRunProperty } from "./run-property";
+import { Shading } from "./shading";
 
 import * as wasm from "./pkg";
 import { TextAlignmentType } from "./json/bindings/TextAlignmentType";
@@ -88,6 +89,15 @@ export class ParagraphProperty {
   _adjustRightInd?: number;
   _tabs?: Tab[];
   frameProperty?: FrameProperty;
+  _shading?: Shading;
+  _contextualSpacing?: boolean;
+  _suppressAutoHyphens?: boolean;
+  _suppressLineNumbers?: boolean;
+  _mirrorIndents?: boolean;
+  _wordWrap?: boolean;
+  _kinsoku?: boolean;
+  _topLinePunct?: boolean;
+  _cnfStyle?: string;
 
   constructor() {}
 
@@ -126,6 +136,55 @@ export class ParagraphProperty {
     this.styleId = id;
     return this;
   }
+
+  shading(type: string, color: string, fill: string) {
+    const s = new Shading();
+    s.color(color);
+    s.fill(fill);
+    s.type(type);
+    this._shading = s;
+    return this;
+  }
+
+  contextualSpacing(v: boolean) {
+    this._contextualSpacing = v;
+    return this;
+  }
+
+  suppressAutoHyphens(v: boolean) {
+    this._suppressAutoHyphens = v;
+    return this;
+  }
+
+  suppressLineNumbers(v: boolean) {
+    this._suppressLineNumbers = v;
+    return this;
+  }
+
+  mirrorIndents(v: boolean) {
+    this._mirrorIndents = v;
+    return this;
+  }
+
+  wordWrap(v: boolean) {
+    this._wordWrap = v;
+    return this;
+  }
+
+  kinsoku(v: boolean) {
+    this._kinsoku = v;
+    return this;
+  }
+
+  topLinePunct(v: boolean) {
+    this._topLinePunct = v;
+    return this;
+  }
+
+  cnfStyle(v: string) {
+    this._cnfStyle = v;
+    return this;
+  }
 }
 
 export const createDefaultParagraphProperty = (): ParagraphProperty => {
@@ -400,6 +459,45 @@ export const setParagraphProperty = <T extends wasm.Paragraph | wasm.Style>(
     target = target.outline_lvl(property.outlineLvl) as T;
   }
 
+  // Paragraph only. Styles are built with createParagraphProperty.
+  if (target instanceof wasm.Paragraph) {
+    if (property._shading != null) {
+      target = target.shading(
+        property._shading._type,
+        property._shading._color,
+        property._shading._fill
+      ) as T;
+    }
+
+    if (property._contextualSpacing != null) {
+      target = target.contextual_spacing(property._contextualSpacing) as T;
+    }
+
+    if (property._suppressAutoHyphens != null) {
+      target = target.suppress_auto_hyphens(property._suppressAutoHyphens) as T;
+    }
+
+    if (property._suppressLineNumbers != null) {
+      target = target.suppress_line_numbers(property._suppressLineNumbers) as T;
+    }
+
+    if (property._mirrorIndents != null) {
+      target = target.mirror_indents(property._mirrorIndents) as T;
+    }
+
+    if (property._wordWrap != null) {
+      target = target.word_wrap(property._wordWrap) as T;
+    }
+
+    if (property._kinsoku != null) {
+      target = target.kinsoku(property._kinsoku) as T;
+    }
+
+    if (property._topLinePunct != null) {
+      target = target.top_line_punct(property._topLinePunct) as T;
+    }
+  }
+
   if (property._tabs) {
     for (const tab of property._tabs) {
       let val: wasm.TabValueType | undefined;
@@ -611,6 +709,46 @@ export const createParagraphProperty = (
     p = p.outline_lvl(property.outlineLvl);
   }
 
+  if (property._shading != null) {
+    p = p.shading(
+      property._shading._type,
+      property._shading._color,
+      property._shading._fill
+    );
+  }
+
+  if (property._contextualSpacing != null) {
+    p = p.contextual_spacing(property._contextualSpacing);
+  }
+
+  if (property._suppressAutoHyphens != null) {
+    p = p.suppress_auto_hyphens(property._suppressAutoHyphens);
+  }
+
+  if (property._suppressLineNumbers != null) {
+    p = p.suppress_line_numbers(property._suppressLineNumbers);
+  }
+
+  if (property._mirrorIndents != null) {
+    p = p.mirror_indents(property._mirrorIndents);
+  }
+
+  if (property._wordWrap != null) {
+    p = p.word_wrap(property._wordWrap);
+  }
+
+  if (property._kinsoku != null) {
+    p = p.kinsoku(property._kinsoku);
+  }
+
+  if (property._topLinePunct != null) {
+    p = p.top_line_punct(property._topLinePunct);
+  }
+
+  if (property._cnfStyle != null) {
+    p = p.cnf_style(property._cnfStyle);
+  }
+
   if (property.styleId) {
     p = p.style(property.styleId);
   }
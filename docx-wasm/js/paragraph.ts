@@ -175,6 +175,46 @@ export class Paragraph {
     return this;
   }
 
+  shading(type: string, color: string, fill: string) {
+    this.property.shading(type, color, fill);
+    return this;
+  }
+
+  contextualSpacing(v: boolean) {
+    this.property.contextualSpacing(v);
+    return this;
+  }
+
+  suppressAutoHyphens(v: boolean) {
+    this.property.suppressAutoHyphens(v);
+    return this;
+  }
+
+  suppressLineNumbers(v: boolean) {
+    this.property.suppressLineNumbers(v);
+    return this;
+  }
+
+  mirrorIndents(v: boolean) {
+    this.property.mirrorIndents(v);
+    return this;
+  }
+
+  wordWrap(v: boolean) {
+    this.property.wordWrap(v);
+    return this;
+  }
+
+  kinsoku(v: boolean) {
+    this.property.kinsoku(v);
+    return this;
+  }
+
+  topLinePunct(v: boolean) {
+    this.property.topLinePunct(v);
+    return this;
+  }
+
   // run property
   size(size: number) {
     this.property.runProperty.size(size);
@@ -22,6 +22,26 @@ export type RunPropertyIns = {
   date: string;
 };
 
+export type TextEffectType =
+  | "blinkBackground"
+  | "lights"
+  | "antsBlack"
+  | "antsRed"
+  | "shimmer"
+  | "sparkle"
+  | "none";
+
+export type FitText = {
+  val: number;
+  id?: number;
+};
+
+export type Lang = {
+  val?: string;
+  eastAsia?: string;
+  bidi?: string;
+};
+
 export class RunProperty {
   _style?: string;
   _size?: number;
@@ -41,6 +61,18 @@ export class RunProperty {
   _ins?: RunPropertyIns;
   _del?: RunPropertyDel;
   _shading?: Shading;
+  _smallCaps?: boolean;
+  _outline?: boolean;
+  _shadow?: boolean;
+  _emboss?: boolean;
+  _imprint?: boolean;
+  _noProof?: boolean;
+  _webHidden?: boolean;
+  _effect?: TextEffectType;
+  _kern?: number;
+  _position?: number;
+  _fitText?: FitText;
+  _lang?: Lang;
 
   style(style: string) {
     this._style = style;
@@ -112,6 +144,103 @@ export class RunProperty {
     return this;
   }
 
+  smallCaps() {
+    this._smallCaps = true;
+    return this;
+  }
+
+  disableSmallCaps() {
+    this._smallCaps = false;
+    return this;
+  }
+
+  outline() {
+    this._outline = true;
+    return this;
+  }
+
+  disableOutline() {
+    this._outline = false;
+    return this;
+  }
+
+  shadow() {
+    this._shadow = true;
+    return this;
+  }
+
+  disableShadow() {
+    this._shadow = false;
+    return this;
+  }
+
+  emboss() {
+    this._emboss = true;
+    this._imprint = undefined;
+    return this;
+  }
+
+  disableEmboss() {
+    this._emboss = false;
+    return this;
+  }
+
+  imprint() {
+    this._imprint = true;
+    this._emboss = undefined;
+    return this;
+  }
+
+  disableImprint() {
+    this._imprint = false;
+    return this;
+  }
+
+  noProof() {
+    this._noProof = true;
+    return this;
+  }
+
+  disableNoProof() {
+    this._noProof = false;
+    return this;
+  }
+
+  webHidden() {
+    this._webHidden = true;
+    return this;
+  }
+
+  disableWebHidden() {
+    this._webHidden = false;
+    return this;
+  }
+
+  effect(effect: TextEffectType) {
+    this._effect = effect;
+    return this;
+  }
+
+  kern(kern: number) {
+    this._kern = kern;
+    return this;
+  }
+
+  position(position: number) {
+    this._position = position;
+    return this;
+  }
+
+  fitText(val: number, id?: number) {
+    this._fitText = { val, id };
+    return this;
+  }
+
+  lang(lang: Lang) {
+    this._lang = lang;
+    return this;
+  }
+
   underline(type: string) {
     this._underline = type;
     return this;
@@ -162,6 +291,25 @@ export class RunProperty {
   }
 }
 
+export const convertTextEffectType = (t: TextEffectType) => {
+  switch (t) {
+    case "blinkBackground":
+      return wasm.TextEffectType.BlinkBackground;
+    case "lights":
+      return wasm.TextEffectType.Lights;
+    case "antsBlack":
+      return wasm.TextEffectType.AntsBlack;
+    case "antsRed":
+      return wasm.TextEffectType.AntsRed;
+    case "shimmer":
+      return wasm.TextEffectType.Shimmer;
+    case "sparkle":
+      return wasm.TextEffectType.Sparkle;
+    default:
+      return wasm.TextEffectType.None;
+  }
+};
+
 export const convertBorderType = (t: BorderType) => {
   switch (t) {
     case "nil":
@@ -431,18 +579,99 @@ export const createRunProperty = (property: RunProperty): wasm.RunProperty => {
     }
   }
 
+  if (property._caps) {
+    target = target.caps();
+  }
+
+  if (property._smallCaps != null) {
+    if (property._smallCaps) {
+      target = target.small_caps();
+    } else {
+      target = target.disable_small_caps();
+    }
+  }
+
+  if (property._outline != null) {
+    if (property._outline) {
+      target = target.outline();
+    } else {
+      target = target.disable_outline();
+    }
+  }
+
+  if (property._shadow != null) {
+    if (property._shadow) {
+      target = target.shadow();
+    } else {
+      target = target.disable_shadow();
+    }
+  }
+
+  if (property._emboss != null) {
+    if (property._emboss) {
+      target = target.emboss();
+    } else {
+      target = target.disable_emboss();
+    }
+  }
+
+  if (property._imprint != null) {
+    if (property._imprint) {
+      target = target.imprint();
+    } else {
+      target = target.disable_imprint();
+    }
+  }
+
+  if (property._noProof != null) {
+    if (property._noProof) {
+      target = target.no_proof();
+    } else {
+      target = target.disable_no_proof();
+    }
+  }
+
   if (property._underline) {
     target = target.underline(property._underline);
   }
 
+  if (property._effect) {
+    target = target.effect(convertTextEffectType(property._effect));
+  }
+
   if (property._vanish) {
     target = target.vanish();
   }
 
+  if (property._webHidden != null) {
+    if (property._webHidden) {
+      target = target.web_hidden();
+    } else {
+      target = target.disable_web_hidden();
+    }
+  }
+
   if (property._characterSpacing != null) {
     target = target.character_spacing(property._characterSpacing);
   }
 
+  if (property._kern != null) {
+    target = target.kern(property._kern);
+  }
+
+  if (property._position != null) {
+    target = target.position(property._position);
+  }
+
+  if (property._fitText) {
+    target = target.fit_text(property._fitText.val, property._fitText.id);
+  }
+
+  if (property._lang) {
+    const { val, eastAsia, bidi } = property._lang;
+    target = target.lang(val, eastAsia, bidi);
+  }
+
   if (property._textBorder) {
     const { borderType, color, space, size } = property._textBorder;
     target = target.text_border(
@@ -9,9 +9,11 @@ import { Image } from "./image";
 import { PositionalTab } from "./positional-tab";
 import {
   createDefaultRunProperty,
+  createRunProperty,
+  Lang,
   RunFonts,
   RunProperty,
-  setRunProperty,
+  TextEffectType,
   VertAlignType,
 } from "./run-property";
 import { Tc } from "./tc";
@@ -136,6 +138,78 @@ export class Run {
     return this;
   }
 
+  smallCaps() {
+    this.property ??= createDefaultRunProperty();
+    this.property.smallCaps();
+    return this;
+  }
+
+  outline() {
+    this.property ??= createDefaultRunProperty();
+    this.property.outline();
+    return this;
+  }
+
+  shadow() {
+    this.property ??= createDefaultRunProperty();
+    this.property.shadow();
+    return this;
+  }
+
+  emboss() {
+    this.property ??= createDefaultRunProperty();
+    this.property.emboss();
+    return this;
+  }
+
+  imprint() {
+    this.property ??= createDefaultRunProperty();
+    this.property.imprint();
+    return this;
+  }
+
+  noProof() {
+    this.property ??= createDefaultRunProperty();
+    this.property.noProof();
+    return this;
+  }
+
+  webHidden() {
+    this.property ??= createDefaultRunProperty();
+    this.property.webHidden();
+    return this;
+  }
+
+  effect(effect: TextEffectType) {
+    this.property ??= createDefaultRunProperty();
+    this.property.effect(effect);
+    return this;
+  }
+
+  kern(kern: number) {
+    this.property ??= createDefaultRunProperty();
+    this.property.kern(kern);
+    return this;
+  }
+
+  position(position: number) {
+    this.property ??= createDefaultRunProperty();
+    this.property.position(position);
+    return this;
+  }
+
+  fitText(val: number, id?: number) {
+    this.property ??= createDefaultRunProperty();
+    this.property.fitText(val, id);
+    return this;
+  }
+
+  lang(lang: Lang) {
+    this.property ??= createDefaultRunProperty();
+    this.property.lang(lang);
+    return this;
+  }
+
   fonts(fonts: RunFonts) {
     this.property ??= createDefaultRunProperty();
     this.property.fonts(fonts);
@@ -220,7 +294,7 @@ export class Run {
     });
 
     if (this.property) {
-      run = setRunProperty(run, this.property) as wasm.Run;
+      run = run.property(createRunProperty(this.property));
     }
 
     return run;
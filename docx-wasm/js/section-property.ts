@@ -2,6 +2,7 @@ import { DocGridType } from ".";
 import { Footer } from "./footer";
 import { Header } from "./header";
 import { PageNumType } from "./json/bindings/PageNumType";
+import { BorderType } from "./border";
 
 export type DocGrid = {
   gridType: DocGridType;
@@ -9,6 +10,48 @@ export type DocGrid = {
   charSpace?: number;
 };
 
+export type Column = {
+  width: number;
+  space?: number;
+};
+
+export type Columns = {
+  num: number;
+  space?: number;
+  separator: boolean;
+  widths: Column[];
+};
+
+export type LineNumberRestartType = "newPage" | "newSection" | "continuous";
+
+export type LineNumType = {
+  countBy?: number;
+  start?: number;
+  distance?: number;
+  restart?: LineNumberRestartType;
+};
+
+export type PageBorderPosition = "top" | "left" | "bottom" | "right";
+
+export type PageBorder = {
+  position: PageBorderPosition;
+  borderType: BorderType;
+  size: number;
+  space: number;
+  color: string;
+  shadow?: boolean;
+  frame?: boolean;
+};
+
+export type PageBorders = {
+  offsetFrom?: "page" | "text";
+  display?: "allPages" | "firstPage" | "notFirstPage";
+  zOrder?: "front" | "back";
+  borders: PageBorder[];
+};
+
+export type SectionVAlignType = "top" | "center" | "both" | "bottom";
+
 export class SectionProperty {
   _pageSize: PageSize = {
     w: 11906,
@@ -17,6 +60,12 @@ export class SectionProperty {
   _titlePg: boolean | null = null;
   _pageMargin: PageMargin | null = null;
   _docGrid: DocGrid | null = null;
+  _columns: Columns | null = null;
+  _lineNumType: LineNumType | null = null;
+  _pageBorders: PageBorders | null = null;
+  _verticalAlign: SectionVAlignType | null = null;
+  _bidi: boolean = false;
+  _rtlGutter: boolean = false;
   _header: Header | null = null;
   _firstHeader: Header | null = null;
   _evenHeader: Header | null = null;
@@ -51,6 +100,48 @@ export class SectionProperty {
     return this;
   }
 
+  columns(num: number, space?: number) {
+    this._columns = { ...this._columns ?? defaultColumns(), num, space };
+    return this;
+  }
+
+  addColumn(width: number, space?: number) {
+    const c = this._columns ?? defaultColumns();
+    const widths = [...c.widths, { width, space }];
+    this._columns = { ...c, num: widths.length, widths };
+    return this;
+  }
+
+  columnSeparator() {
+    this._columns = { ...this._columns ?? defaultColumns(), separator: true };
+    return this;
+  }
+
+  lineNumType(l: LineNumType) {
+    this._lineNumType = l;
+    return this;
+  }
+
+  pageBorders(b: PageBorders) {
+    this._pageBorders = b;
+    return this;
+  }
+
+  verticalAlign(v: SectionVAlignType) {
+    this._verticalAlign = v;
+    return this;
+  }
+
+  bidi() {
+    this._bidi = true;
+    return this;
+  }
+
+  rtlGutter() {
+    this._rtlGutter = true;
+    return this;
+  }
+
   header(header: Header) {
     this._header = header;
     return this;
@@ -112,3 +203,11 @@ export const defaultPageMargin = () => {
     gutter: 0,
   };
 };
+
+export const defaultColumns = (): Columns => {
+  return {
+    num: 1,
+    separator: false,
+    widths: [],
+  };
+};
@@ -8,12 +8,44 @@ export type CharacterSpacingValues =
   | "compressPunctuation"
   | "compressPunctuationAndJapaneseKana";
 
+export type ThemeFontLang = {
+  val?: string;
+  eastAsia?: string;
+  bidi?: string;
+};
+
+export type DocumentProtectionType =
+  | "none"
+  | "readOnly"
+  | "comments"
+  | "trackedChanges"
+  | "forms";
+
+export type DocumentProtection = {
+  edit: DocumentProtectionType;
+  password?: string;
+};
+
 export class Settings {
   _docId: string | null = null;
   _docVars: DocVar[] = [];
   _defaultTabStop = 840;
   _adjustLineHeightInTable = false;
   _characterSpacingControl: CharacterSpacingValues | null = null;
+  _mirrorMargins = false;
+  _gutterAtTop = false;
+  _bookFoldPrinting = false;
+  _printTwoOnOne = false;
+  _updateFields = false;
+  _trackRevisions = false;
+  _documentProtection: DocumentProtection | null = null;
+  _autoHyphenation = false;
+  _hyphenationZone: number | null = null;
+  _defaultTableStyle: string | null = null;
+  _themeFontLang: ThemeFontLang | null = null;
+  _decimalSymbol: string | null = null;
+  _listSeparator: string | null = null;
+  _compatibilityMode: number | null = null;
 
   docId(id: string) {
     this._docId = id;
@@ -39,4 +71,74 @@ export class Settings {
     this._characterSpacingControl = t;
     return this;
   }
+
+  mirrorMargins() {
+    this._mirrorMargins = true;
+    return this;
+  }
+
+  gutterAtTop() {
+    this._gutterAtTop = true;
+    return this;
+  }
+
+  bookFoldPrinting() {
+    this._bookFoldPrinting = true;
+    return this;
+  }
+
+  printTwoOnOne() {
+    this._printTwoOnOne = true;
+    return this;
+  }
+
+  updateFields() {
+    this._updateFields = true;
+    return this;
+  }
+
+  trackRevisions() {
+    this._trackRevisions = true;
+    return this;
+  }
+
+  documentProtection(edit: DocumentProtectionType, password?: string) {
+    this._documentProtection = { edit, password };
+    return this;
+  }
+
+  autoHyphenation() {
+    this._autoHyphenation = true;
+    return this;
+  }
+
+  hyphenationZone(zone: number) {
+    this._hyphenationZone = zone;
+    return this;
+  }
+
+  defaultTableStyle(styleId: string) {
+    this._defaultTableStyle = styleId;
+    return this;
+  }
+
+  themeFontLang(lang: ThemeFontLang) {
+    this._themeFontLang = lang;
+    return this;
+  }
+
+  decimalSymbol(symbol: string) {
+    this._decimalSymbol = symbol;
+    return this;
+  }
+
+  listSeparator(separator: string) {
+    this._listSeparator = separator;
+    return this;
+  }
+
+  compatibilityMode(mode: number) {
+    this._compatibilityMode = mode;
+    return this;
+  }
 }
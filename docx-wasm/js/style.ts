@@ -209,6 +209,11 @@ export class Style {
     return this;
   }
 
+  contextualSpacing(v: boolean) {
+    this._paragraphProperty.contextualSpacing(v);
+    return this;
+  }
+
   outlineLevel(v: number) {
     this._paragraphProperty.outlineLvl = v;
     return this;
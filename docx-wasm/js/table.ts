@@ -9,6 +9,7 @@ export { TablePositionProperty } from "./json/bindings/TablePositionProperty";
 
 export type TableAlignmentType = "center" | "left" | "right";
 export type TableLayoutType = "fixed" | "autofit";
+export type TableAutoFitType = "window" | "contents";
 
 export class TablePosition {
   property: Partial<TablePositionProperty> = {};
@@ -111,6 +112,7 @@ export class Table {
   hasNumberings = false;
   rows: TableRow[] = [];
   grid: number[] = [];
+  _autoFit: TableAutoFitType | null = null;
   property: TableProperty = {
     cellMargins: createDefaultTableCellMargins(),
   };
@@ -133,6 +135,11 @@ export class Table {
     return this;
   }
 
+  autoFit(t: TableAutoFitType) {
+    this._autoFit = t;
+    return this;
+  }
+
   indent(v: number) {
     this.property.indent = v;
     return this;
@@ -230,6 +237,12 @@ export class Table {
 
     table = table.set_grid(new Uint32Array(this.grid));
 
+    if (this._autoFit === "window") {
+      table = table.auto_fit(wasm.TableAutoFitType.Window);
+    } else if (this._autoFit === "contents") {
+      table = table.auto_fit(wasm.TableAutoFitType.Contents);
+    }
+
     if (this.property.styleId) {
       table = table.style(this.property.styleId);
     }
@@ -1,5 +1,6 @@
 use super::*;
 use docx_rs::CharacterSpacingValues;
+use std::str::FromStr;
 use wasm_bindgen::prelude::*;
 
 extern crate console_error_panic_hook;
@@ -68,6 +69,38 @@ impl Docx {
         self
     }
 
+    pub fn app_props(
+        mut self,
+        application: Option<String>,
+        app_version: Option<String>,
+        company: Option<String>,
+        manager: Option<String>,
+        hyperlink_base: Option<String>,
+        template: Option<String>,
+    ) -> Self {
+        let mut p = docx_rs::AppProps::new();
+        if let Some(v) = application {
+            p = p.application(v);
+        }
+        if let Some(v) = app_version {
+            p = p.app_version(v);
+        }
+        if let Some(v) = company {
+            p = p.company(v);
+        }
+        if let Some(v) = manager {
+            p = p.manager(v);
+        }
+        if let Some(v) = hyperlink_base {
+            p = p.hyperlink_base(v);
+        }
+        if let Some(v) = template {
+            p = p.template(v);
+        }
+        self.0 = self.0.app_props(p);
+        self
+    }
+
     pub fn doc_id(mut self, id: &str) -> Docx {
         self.0 = self.0.doc_id(id);
         self
@@ -93,6 +126,115 @@ impl Docx {
         self
     }
 
+    pub fn mirror_margins(mut self) -> Self {
+        self.0 = self.0.mirror_margins();
+        self
+    }
+
+    pub fn gutter_at_top(mut self) -> Self {
+        self.0 = self.0.gutter_at_top();
+        self
+    }
+
+    pub fn book_fold_printing(mut self) -> Self {
+        self.0 = self.0.book_fold_printing();
+        self
+    }
+
+    pub fn print_two_on_one(mut self) -> Self {
+        self.0 = self.0.print_two_on_one();
+        self
+    }
+
+    pub fn update_fields(mut self) -> Self {
+        self.0 = self.0.update_fields();
+        self
+    }
+
+    pub fn track_revisions(mut self) -> Self {
+        self.0 = self.0.track_revisions();
+        self
+    }
+
+    pub fn document_protection(mut self, edit: &str, password: &str) -> Self {
+        if let Ok(edit) = docx_rs::DocumentProtectionType::from_str(edit) {
+            let mut protection = docx_rs::DocumentProtection::new(edit);
+            if !password.is_empty() {
+                protection = protection.password(password);
+            }
+            self.0 = self.0.document_protection(protection);
+        }
+        self
+    }
+
+    pub fn auto_hyphenation(mut self) -> Self {
+        self.0 = self.0.auto_hyphenation();
+        self
+    }
+
+    pub fn hyphenation_zone(mut self, zone: usize) -> Self {
+        self.0 = self.0.hyphenation_zone(zone);
+        self
+    }
+
+    pub fn default_table_style(mut self, style_id: &str) -> Self {
+        self.0 = self.0.default_table_style(style_id);
+        self
+    }
+
+    pub fn theme_font_lang(mut self, val: &str, east_asia: &str, bidi: &str) -> Self {
+        let mut lang = docx_rs::ThemeFontLang::default();
+        if !val.is_empty() {
+            lang.val = Some(val.to_owned());
+        }
+        if !east_asia.is_empty() {
+            lang.east_asia = Some(east_asia.to_owned());
+        }
+        if !bidi.is_empty() {
+            lang.bidi = Some(bidi.to_owned());
+        }
+        self.0 = self.0.theme_font_lang(lang);
+        self
+    }
+
+    pub fn decimal_symbol(mut self, symbol: &str) -> Self {
+        self.0 = self.0.decimal_symbol(symbol);
+        self
+    }
+
+    pub fn list_separator(mut self, separator: &str) -> Self {
+        self.0 = self.0.list_separator(separator);
+        self
+    }
+
+    pub fn compatibility_mode(mut self, mode: usize) -> Self {
+        self.0 = self.0.compatibility_mode(mode);
+        self
+    }
+
+    pub fn theme_color(mut self, t: &str, val: &str) -> Self {
+        if let Ok(t) = docx_rs::ThemeColorType::from_str(t) {
+            let theme = self.0.themes.first().cloned().unwrap_or_default();
+            self.0 = self.0.theme(theme.color(t, val));
+        }
+        self
+    }
+
+    pub fn theme_fonts(mut self, major: &str, minor: &str) -> Self {
+        let theme = self.0.themes.first().cloned().unwrap_or_default();
+        self.0 = self.0.theme(theme.major_font(major).minor_font(minor));
+        self
+    }
+
+    pub fn embed_font(mut self, name: &str, buf: &[u8], embed_type: &str) -> Self {
+        let mut font = docx_rs::EmbeddedFont::new(name, buf.to_vec());
+        if let Ok(t) = docx_rs::FontEmbedType::from_str(embed_type) {
+            font = font.embed_type(t);
+        }
+        self.0 = self.0.embed_font(font);
+        self
+    }
+
     pub fn header(mut self, header: Header) -> Self {
         self.0 = self.0.header(header.take());
         self
@@ -205,6 +347,51 @@ impl Docx {
         self
     }
 
+    pub fn columns(mut self, num: usize) -> Self {
+        self.0.document = self.0.document.columns(num);
+        self
+    }
+
+    pub fn column_space(mut self, space: usize) -> Self {
+        self.0.document = self.0.document.column_space(space);
+        self
+    }
+
+    pub fn add_column(mut self, width: usize, space: Option<usize>) -> Self {
+        self.0.document = self.0.document.add_column(width, space);
+        self
+    }
+
+    pub fn column_separator(mut self) -> Self {
+        self.0.document = self.0.document.column_separator();
+        self
+    }
+
+    pub fn line_num_type(mut self, l: LineNumType) -> Self {
+        self.0 = self.0.line_num_type(l.take());
+        self
+    }
+
+    pub fn page_borders(mut self, b: PageBorders) -> Self {
+        self.0 = self.0.page_borders(b.take());
+        self
+    }
+
+    pub fn page_vertical_align(mut self, v: docx_rs::VAlignType) -> Self {
+        self.0 = self.0.page_vertical_align(v);
+        self
+    }
+
+    pub fn bidi(mut self) -> Self {
+        self.0.document = self.0.document.bidi();
+        self
+    }
+
+    pub fn rtl_gutter(mut self) -> Self {
+        self.0.document = self.0.document.rtl_gutter();
+        self
+    }
+
     pub fn build(mut self, has_numberings: bool) -> Result<Vec<u8>, JsValue> {
         let buf = Vec::new();
         let mut cur = std::io::Cursor::new(buf);
@@ -10,9 +10,11 @@ mod hyperlink;
 mod insert;
 mod level;
 mod level_override;
+mod line_num_type;
 mod line_spacing;
 mod num_pages;
 mod numbering;
+mod page_borders;
 mod page_margin;
 mod page_num;
 mod page_num_type;
@@ -46,9 +48,11 @@ pub use hyperlink::*;
 pub use insert::*;
 pub use level::*;
 pub use level_override::*;
+pub use line_num_type::*;
 pub use line_spacing::*;
 pub use num_pages::*;
 pub use numbering::*;
+pub use page_borders::*;
 pub use page_margin::*;
 pub use page_num::*;
 pub use page_num_type::*;
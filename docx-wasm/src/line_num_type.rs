@@ -0,0 +1,34 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct LineNumType(docx_rs::LineNumType);
+
+#[wasm_bindgen(js_name = createLineNumType)]
+pub fn create_line_num_type(
+    count_by: Option<usize>,
+    start: Option<usize>,
+    distance: Option<usize>,
+    restart: Option<docx_rs::LineNumberRestartType>,
+) -> LineNumType {
+    let mut l = docx_rs::LineNumType::new();
+    if let Some(count_by) = count_by {
+        l = l.count_by(count_by);
+    }
+    if let Some(start) = start {
+        l = l.start(start);
+    }
+    if let Some(distance) = distance {
+        l = l.distance(distance);
+    }
+    if let Some(restart) = restart {
+        l = l.restart(restart);
+    }
+    LineNumType(l)
+}
+
+impl LineNumType {
+    pub fn take(self) -> docx_rs::LineNumType {
+        self.0
+    }
+}
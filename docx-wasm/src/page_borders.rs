@@ -0,0 +1,64 @@
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct PageBorders(docx_rs::PageBorders);
+
+#[wasm_bindgen(js_name = createPageBorders)]
+pub fn create_page_borders() -> PageBorders {
+    PageBorders(docx_rs::PageBorders::new())
+}
+
+impl PageBorders {
+    pub fn take(self) -> docx_rs::PageBorders {
+        self.0
+    }
+}
+
+#[wasm_bindgen]
+impl PageBorders {
+    pub fn offset_from(mut self, o: docx_rs::PageBorderOffsetType) -> Self {
+        self.0 = self.0.offset_from(o);
+        self
+    }
+
+    pub fn display(mut self, d: docx_rs::PageBorderDisplayType) -> Self {
+        self.0 = self.0.display(d);
+        self
+    }
+
+    pub fn z_order(mut self, z: docx_rs::PageBorderZOrderType) -> Self {
+        self.0 = self.0.z_order(z);
+        self
+    }
+
+    // `border_type` accepts any ST_Border value including art borders (e.g. "certificateBanner").
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_border(
+        mut self,
+        position: docx_rs::PageBorderPosition,
+        border_type: &str,
+        size: usize,
+        space: usize,
+        color: &str,
+        shadow: bool,
+        frame: bool,
+    ) -> Self {
+        let mut border = docx_rs::PageBorder::new(position)
+            .size(size)
+            .space(space)
+            .color(color);
+        if let Ok(t) = docx_rs::BorderType::from_str(border_type) {
+            border = border.val(t);
+        }
+        if shadow {
+            border = border.shadow();
+        }
+        if frame {
+            border = border.frame();
+        }
+        self.0 = self.0.set(border);
+        self
+    }
+}
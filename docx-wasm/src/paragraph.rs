@@ -1,4 +1,5 @@
 use super::*;
+use std::str::FromStr;
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -173,6 +174,50 @@ impl Paragraph {
         self
     }
 
+    pub fn shading(mut self, t: &str, color: &str, fill: &str) -> Self {
+        let mut s = docx_rs::Shading::new().color(color).fill(fill);
+        if let Ok(t) = docx_rs::ShdType::from_str(t) {
+            s = s.shd_type(t);
+        }
+        self.0 = self.0.shading(s);
+        self
+    }
+
+    pub fn contextual_spacing(mut self, v: bool) -> Self {
+        self.0 = self.0.contextual_spacing(v);
+        self
+    }
+
+    pub fn suppress_auto_hyphens(mut self, v: bool) -> Self {
+        self.0 = self.0.suppress_auto_hyphens(v);
+        self
+    }
+
+    pub fn suppress_line_numbers(mut self, v: bool) -> Self {
+        self.0 = self.0.suppress_line_numbers(v);
+        self
+    }
+
+    pub fn mirror_indents(mut self, v: bool) -> Self {
+        self.0 = self.0.mirror_indents(v);
+        self
+    }
+
+    pub fn word_wrap(mut self, v: bool) -> Self {
+        self.0 = self.0.word_wrap(v);
+        self
+    }
+
+    pub fn kinsoku(mut self, v: bool) -> Self {
+        self.0 = self.0.kinsoku(v);
+        self
+    }
+
+    pub fn top_line_punct(mut self, v: bool) -> Self {
+        self.0 = self.0.top_line_punct(v);
+        self
+    }
+
     pub fn delete(mut self, author: &str, date: &str) -> Self {
         self.0 = self.0.delete(author, date);
         self
@@ -167,6 +167,11 @@ impl Run {
         self.0.run_property = self.0.run_property.text_border(border);
         self
     }
+
+    pub fn property(mut self, p: RunProperty) -> Run {
+        self.0.run_property = p.take();
+        self
+    }
 }
 
 impl Run {
@@ -33,6 +33,21 @@ impl RunProperty {
         self
     }
 
+    pub fn caps(mut self) -> Self {
+        self.0 = self.0.caps();
+        self
+    }
+
+    pub fn small_caps(mut self) -> Self {
+        self.0 = self.0.small_caps();
+        self
+    }
+
+    pub fn disable_small_caps(mut self) -> Self {
+        self.0 = self.0.disable_small_caps();
+        self
+    }
+
     pub fn italic(mut self) -> Self {
         self.0 = self.0.italic();
         self
@@ -141,6 +156,104 @@ impl RunProperty {
         self.0 = self.0.shading(s);
         self
     }
+
+    pub fn outline(mut self) -> Self {
+        self.0 = self.0.outline();
+        self
+    }
+
+    pub fn disable_outline(mut self) -> Self {
+        self.0 = self.0.disable_outline();
+        self
+    }
+
+    pub fn shadow(mut self) -> Self {
+        self.0 = self.0.shadow();
+        self
+    }
+
+    pub fn disable_shadow(mut self) -> Self {
+        self.0 = self.0.disable_shadow();
+        self
+    }
+
+    pub fn emboss(mut self) -> Self {
+        self.0 = self.0.emboss();
+        self
+    }
+
+    pub fn disable_emboss(mut self) -> Self {
+        self.0 = self.0.disable_emboss();
+        self
+    }
+
+    pub fn imprint(mut self) -> Self {
+        self.0 = self.0.imprint();
+        self
+    }
+
+    pub fn disable_imprint(mut self) -> Self {
+        self.0 = self.0.disable_imprint();
+        self
+    }
+
+    pub fn no_proof(mut self) -> Self {
+        self.0 = self.0.no_proof();
+        self
+    }
+
+    pub fn disable_no_proof(mut self) -> Self {
+        self.0 = self.0.disable_no_proof();
+        self
+    }
+
+    pub fn web_hidden(mut self) -> Self {
+        self.0 = self.0.web_hidden();
+        self
+    }
+
+    pub fn disable_web_hidden(mut self) -> Self {
+        self.0 = self.0.disable_web_hidden();
+        self
+    }
+
+    pub fn effect(mut self, t: docx_rs::TextEffectType) -> Self {
+        self.0 = self.0.effect(t);
+        self
+    }
+
+    pub fn kern(mut self, v: usize) -> Self {
+        self.0 = self.0.kern(v);
+        self
+    }
+
+    pub fn position(mut self, v: i32) -> Self {
+        self.0 = self.0.position(v);
+        self
+    }
+
+    pub fn fit_text(mut self, val: usize, id: Option<usize>) -> Self {
+        let mut f = docx_rs::FitText::new(val);
+        if let Some(id) = id {
+            f = f.id(id);
+        }
+        self.0 = self.0.fit_text(f);
+        self
+    }
+
+    pub fn lang(
+        mut self,
+        val: Option<String>,
+        east_asia: Option<String>,
+        bidi: Option<String>,
+    ) -> Self {
+        let mut l = docx_rs::Lang::new();
+        l.val = val;
+        l.east_asia = east_asia;
+        l.bidi = bidi;
+        self.0 = self.0.lang(l);
+        self
+    }
 }
 
 impl RunProperty {
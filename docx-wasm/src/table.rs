@@ -54,6 +54,11 @@ impl Table {
         self
     }
 
+    pub fn auto_fit(mut self, t: docx_rs::TableAutoFitType) -> Table {
+        self.0 = self.0.auto_fit(t);
+        self
+    }
+
     pub fn position(mut self, p: TablePositionProperty) -> Table {
         self.0 = self.0.position(p.take());
         self
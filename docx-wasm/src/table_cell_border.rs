@@ -23,7 +23,7 @@ impl TableCellBorder {
     }
 
     pub fn color(mut self, color: String) -> TableCellBorder {
-        self.0.color = color;
+        self.0.color = color.into();
         self
     }
 